@@ -60,6 +60,11 @@ def init_db(conn):
             notes TEXT
         );
     """)
+    # Columns added after the first release; older databases get them here.
+    run_cols = {r["name"] for r in conn.execute("PRAGMA table_info(agent_runs)")}
+    for col in ("duration_ms", "input_tokens", "output_tokens"):
+        if col not in run_cols:
+            conn.execute(f"ALTER TABLE agent_runs ADD COLUMN {col} INTEGER")
     conn.commit()
 
 
@@ -141,8 +146,10 @@ def cmd_log_run(args):
     completed_at = datetime.now(timezone.utc).strftime('%Y-%m-%d %H:%M:%S') if args.result and args.result != 'pending' else None
     try:
         conn.execute(
-            "INSERT INTO agent_runs (task_id,agent,action,result,session_id,completed_at,notes) VALUES (?,?,?,?,?,?,?)",
-            (args.task_id, args.agent, args.action, args.result or 'pending', args.session_id, completed_at, args.notes)
+            "INSERT INTO agent_runs (task_id,agent,action,result,session_id,completed_at,notes,duration_ms,input_tokens,output_tokens) "
+            "VALUES (?,?,?,?,?,?,?,?,?,?)",
+            (args.task_id, args.agent, args.action, args.result or 'pending', args.session_id, completed_at, args.notes,
+             args.duration_ms, args.input_tokens, args.output_tokens)
         )
         conn.commit()
     except sqlite3.IntegrityError:
@@ -167,9 +174,13 @@ def cmd_runs(args):
         vals.append(args.result)
     clause = ("WHERE " + " AND ".join(where)) if where else ""
     rows = conn.execute(
-        f"SELECT id,agent,action,result,task_id,session_id,started_at,notes FROM agent_runs {clause} ORDER BY id DESC LIMIT 10",
-        vals
+        f"SELECT id,agent,action,result,task_id,session_id,started_at,completed_at,notes,duration_ms,input_tokens,output_tokens "
+        f"FROM agent_runs {clause} ORDER BY id DESC LIMIT ?",
+        vals + [args.limit]
     ).fetchall()
+    if args.json:
+        print(json.dumps([dict(r) for r in rows]))
+        return
     if not rows:
         print("no runs")
         return
@@ -362,12 +373,17 @@ def main():
     p_lr.add_argument("--result", default="pending")
     p_lr.add_argument("--session-id")
     p_lr.add_argument("--notes")
+    p_lr.add_argument("--duration-ms", type=int)
+    p_lr.add_argument("--input-tokens", type=int)
+    p_lr.add_argument("--output-tokens", type=int)
 
     # runs
     p_runs = sub.add_parser("runs", help="query agent runs")
     p_runs.add_argument("--agent")
     p_runs.add_argument("--task-id")
     p_runs.add_argument("--result")
+    p_runs.add_argument("--limit", type=int, default=10)
+    p_runs.add_argument("--json", action="store_true")
 
     # error-add
     p_ea = sub.add_parser("error-add", help="log an error pattern")
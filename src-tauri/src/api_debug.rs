@@ -0,0 +1,204 @@
+//! Opt-in protocol debug log for the Claude, Ollama and OpenCode APIs.
+//!
+//! When `api_debug_logging` is on, outgoing requests (method, URL, headers,
+//! body shape), response statuses and raw SSE events are appended to
+//! `<app_data_dir>/logs/api-debug.<date>.log`. Credentials are masked and
+//! message content is replaced by its length, so the log shows the shape of
+//! what was exchanged without what was said. Off by default; when off, each
+//! call is a single atomic load, so call sites log unconditionally.
+
+use chrono::Local;
+use serde_json::Value;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_ENABLED: &str = "api_debug_logging";
+
+/// Daily debug log files kept.
+const MAX_LOG_FILES: usize = 3;
+
+/// Header names whose values are never written.
+const SECRET_HEADERS: [&str; 5] = ["authorization", "proxy-authorization", "cookie", "set-cookie", "x-api-key"];
+
+/// JSON keys whose string values are protocol metadata and kept as is.
+/// Every other string is replaced by its length.
+const METADATA_KEYS: [&str; 16] = [
+    "type", "role", "model", "id", "event", "stop_reason", "name", "status", "mime", "tool", "callID",
+    "sessionID", "messageID", "partID", "media_type", "done_reason",
+];
+
+/// Fragments of query parameter names holding credentials.
+const SECRET_FRAGMENTS: [&str; 5] = ["token", "key", "secret", "password", "auth"];
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static WRITER: Mutex<Option<RollingFileAppender>> = Mutex::new(None);
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn is_secret(name: &str) -> bool {
+    let name = name.to_lowercase();
+    SECRET_FRAGMENTS.iter().any(|f| name.contains(f))
+}
+
+/// Opens (or closes) the debug log to match `on`.
+fn apply(app: &AppHandle, on: bool) -> Result<(), String> {
+    let mut writer = WRITER.lock().unwrap_or_else(|e| e.into_inner());
+    if on && writer.is_none() {
+        let dir = app.path().app_data_dir().map_err(|e| e.to_string())?.join("logs");
+        let appender = RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix("api-debug")
+            .filename_suffix("log")
+            .max_log_files(MAX_LOG_FILES)
+            .build(&dir)
+            .map_err(|e| format!("Cannot write the debug log to {}: {}", dir.display(), e))?;
+        *writer = Some(appender);
+    } else if !on {
+        *writer = None;
+    }
+    ENABLED.store(on, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Restores the saved toggle. Called once from `setup`.
+pub fn init(app: &AppHandle) {
+    let on = app
+        .store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_ENABLED))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if let Err(e) = apply(app, on) {
+        tracing::warn!("{}", e);
+    }
+}
+
+fn write_line(provider: &str, kind: &str, detail: &str) {
+    let mut writer = WRITER.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(out) = writer.as_mut() {
+        let _ = writeln!(
+            out,
+            "{} [{}] {} {}",
+            Local::now().format("%Y-%m-%dT%H:%M:%S%.3f%:z"),
+            provider,
+            kind,
+            detail
+        );
+    }
+}
+
+/// `url` with the values of credential-like query parameters masked.
+fn redact_url(url: &str) -> String {
+    let Ok(mut parsed) = reqwest::Url::parse(url) else { return url.to_string() };
+    if parsed.query().is_none() {
+        return url.to_string();
+    }
+    let pairs: Vec<(String, String)> = parsed
+        .query_pairs()
+        .map(|(k, v)| {
+            let v = if is_secret(&k) { "[redacted]".to_string() } else { v.into_owned() };
+            (k.into_owned(), v)
+        })
+        .collect();
+    parsed.query_pairs_mut().clear().extend_pairs(pairs);
+    parsed.to_string()
+}
+
+/// Copy of `value` keeping its structure, numbers, booleans and metadata
+/// strings; other strings (content, credentials) are replaced by their
+/// length. Tool definitions are reduced to their names.
+fn redact(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, v)| {
+                    let v = match v {
+                        Value::String(s) if !METADATA_KEYS.contains(&key.as_str()) => {
+                            Value::String(format!("[{} chars]", s.chars().count()))
+                        }
+                        Value::Array(tools) if key == "tools" => Value::Array(
+                            tools.iter().map(|tool| tool.get("name").cloned().unwrap_or(Value::Null)).collect(),
+                        ),
+                        other => redact(other),
+                    };
+                    (key.clone(), v)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact).collect()),
+        Value::String(s) => Value::String(format!("[{} chars]", s.chars().count())),
+        other => other.clone(),
+    }
+}
+
+/// Records an outgoing request.
+pub fn request(provider: &str, method: &str, url: &str, headers: &[(&str, &str)], body: Option<&Value>) {
+    if !enabled() {
+        return;
+    }
+    let headers: Vec<String> = headers
+        .iter()
+        .map(|(name, value)| {
+            if SECRET_HEADERS.contains(&name.to_lowercase().as_str()) {
+                format!("{}: [redacted]", name)
+            } else {
+                format!("{}: {}", name, value)
+            }
+        })
+        .collect();
+    let body = body.map(|b| redact(b).to_string()).unwrap_or_default();
+    write_line(
+        provider,
+        "request",
+        &format!("{} {} {{{}}} {}", method, redact_url(url), headers.join(", "), body),
+    );
+}
+
+/// Records a response status.
+pub fn response(provider: &str, url: &str, status: u16) {
+    if enabled() {
+        write_line(provider, "response", &format!("{} {}", status, redact_url(url)));
+    }
+}
+
+/// Records one server-sent event. JSON data is redacted like request
+/// bodies; anything else is logged as its length.
+pub fn sse_event(provider: &str, event: &str, data: &str) {
+    if !enabled() {
+        return;
+    }
+    let data = match serde_json::from_str::<Value>(data) {
+        Ok(v) => redact(&v).to_string(),
+        Err(_) => format!("[{} bytes]", data.len()),
+    };
+    let event = if event.is_empty() { "message" } else { event };
+    write_line(provider, "event", &format!("{} {}", event, data));
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_api_debug_logging() -> bool {
+    enabled()
+}
+
+/// Turns the API debug log on or off and remembers the choice.
+#[tauri::command]
+pub fn set_api_debug_logging(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    apply(&app, enabled)?;
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_ENABLED, serde_json::json!(enabled));
+    store.save().map_err(|e| e.to_string())?;
+    tracing::info!("API debug logging {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
@@ -0,0 +1,324 @@
+//! Encrypted backup of Winter's own data.
+//!
+//! The built-in `app-backup` task archives every store and registry in the
+//! app data directory (settings, conversations, scheduler and service
+//! registries) plus the memory database into `app-backup-<stamp>.tar.zst.age`:
+//! a zstd-compressed tar, encrypted with age to the backup passphrase kept in
+//! the settings store. `restore_backup` reverses it and restarts the app.
+
+use age::secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::backup::expand_home;
+use crate::error::AppError;
+use crate::memory::WinterMemoryDB;
+use crate::STORE_FILE;
+
+const STORE_KEY_PASSPHRASE: &str = "backup_passphrase";
+
+const ARCHIVE_EXTENSION: &str = "tar.zst.age";
+
+/// zstd level for the inner archive; app data is small, so favour ratio.
+const ZSTD_LEVEL: i32 = 9;
+
+const MIN_PASSPHRASE_LEN: usize = 8;
+
+/// Archive directories for the two kinds of data.
+const DATA_DIR: &str = "data";
+const MEMORY_DIR: &str = "memory";
+
+/// Files replaced by a restore are copied here first.
+const PRE_RESTORE_DIR: &str = "pre-restore";
+
+fn default_retention() -> usize {
+    14
+}
+
+/// Registry configuration of an app data backup task. `destination` may start with `~/`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppBackupConfig {
+    pub destination: String,
+    /// Archives kept in `destination` for this task; 0 keeps everything.
+    #[serde(default = "default_retention")]
+    pub retention: usize,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RestoreReport {
+    /// Archive paths that were restored, e.g. "data/settings.json".
+    pub restored: Vec<String>,
+    /// Where the replaced files were copied to.
+    pub previous_copy: String,
+}
+
+/// What goes into an archive: `(path inside the archive, file on disk)`.
+struct Sources {
+    data_dir: PathBuf,
+    memory_db: PathBuf,
+}
+
+impl Sources {
+    fn new(app: &AppHandle) -> Result<Self, String> {
+        let data_dir = app
+            .path()
+            .app_data_dir()
+            .map_err(|e| format!("Cannot get app data dir: {}", e))?;
+        Ok(Self { data_dir, memory_db: WinterMemoryDB::new_with_app(app).db_path() })
+    }
+
+    /// Stores and registries: the JSON files directly in the app data directory.
+    fn files(&self) -> Vec<(String, PathBuf)> {
+        let mut files: Vec<(String, PathBuf)> = std::fs::read_dir(&self.data_dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok().map(|e| e.path()))
+                    .filter(|p| p.is_file() && p.extension().is_some_and(|x| x == "json"))
+                    .filter_map(|p| {
+                        let name = p.file_name()?.to_str()?.to_string();
+                        Some((format!("{}/{}", DATA_DIR, name), p))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        files.sort();
+        if self.memory_db.is_file() {
+            files.push((format!("{}/winter.db", MEMORY_DIR), self.memory_db.clone()));
+        }
+        files
+    }
+
+    /// Where an archive entry belongs on disk, or `None` for anything this
+    /// module would not have written (including path traversal attempts).
+    fn target(&self, entry: &str) -> Option<PathBuf> {
+        let (dir, name) = entry.split_once('/')?;
+        match dir {
+            DATA_DIR if name.ends_with(".json") && !name.contains(['/', '\\']) && !name.starts_with('.') => {
+                Some(self.data_dir.join(name))
+            }
+            MEMORY_DIR if name == "winter.db" => Some(self.memory_db.clone()),
+            _ => None,
+        }
+    }
+}
+
+fn passphrase(app: &AppHandle) -> Option<String> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get(STORE_KEY_PASSPHRASE))
+        .and_then(|v| v.as_str().map(String::from))
+        .filter(|p| !p.is_empty())
+}
+
+/// Writes loaded stores to disk so the archive has their latest state.
+fn flush_stores(app: &AppHandle, files: &[(String, PathBuf)]) {
+    for (_, path) in files {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else { continue };
+        if let Some(store) = app.get_store(name) {
+            if let Err(e) = store.save() {
+                tracing::warn!("Failed to flush store {} before backup: {}", name, e);
+            }
+        }
+    }
+}
+
+fn write_archive(path: &Path, passphrase: SecretString, files: &[(String, PathBuf)]) -> Result<u64, String> {
+    let file = std::fs::File::create(path).map_err(|e| format!("Failed to create archive {}: {}", path.display(), e))?;
+    let age = age::Encryptor::with_user_passphrase(passphrase)
+        .wrap_output(file)
+        .map_err(|e| e.to_string())?;
+    let mut builder = tar::Builder::new(zstd::Encoder::new(age, ZSTD_LEVEL).map_err(|e| e.to_string())?);
+    let mut bytes = 0;
+    for (name, source) in files {
+        builder
+            .append_path_with_name(source, name)
+            .map_err(|e| format!("Failed to add {}: {}", source.display(), e))?;
+        bytes += std::fs::metadata(source).map(|m| m.len()).unwrap_or(0);
+    }
+    let zstd = builder.into_inner().map_err(|e| e.to_string())?;
+    let age = zstd.finish().map_err(|e| e.to_string())?;
+    let mut file = age.finish().map_err(|e| e.to_string())?;
+    file.flush().map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
+
+/// Runs the backup for the task `task_id`. Returns a one-line summary for the task log.
+pub async fn run(app: &AppHandle, task_id: &str, cfg: &AppBackupConfig) -> Result<String, String> {
+    let passphrase = passphrase(app).ok_or("No backup passphrase set; set one under Automation")?;
+    let sources = Sources::new(app)?;
+    let files = sources.files();
+    if files.is_empty() {
+        return Err("No app data found to back up".to_string());
+    }
+    flush_stores(app, &files);
+
+    let dest = expand_home(&cfg.destination);
+    let (task_id, retention) = (task_id.to_string(), cfg.retention);
+    tokio::task::spawn_blocking(move || {
+        std::fs::create_dir_all(&dest)
+            .map_err(|e| format!("Failed to create backup destination {}: {}", dest.display(), e))?;
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        let archive = dest.join(format!("{}-{}.{}", task_id, stamp, ARCHIVE_EXTENSION));
+        let tmp = archive.with_extension("partial");
+        let bytes = match write_archive(&tmp, SecretString::from(passphrase), &files) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                let _ = std::fs::remove_file(&tmp);
+                return Err(format!("Backup failed: {}", e));
+            }
+        };
+        std::fs::rename(&tmp, &archive).map_err(|e| format!("Failed to finalize archive: {}", e))?;
+        let pruned = crate::backup::prune(&dest, &task_id, &[ARCHIVE_EXTENSION], retention);
+        Ok(format!(
+            "Encrypted {} files ({} bytes) into {}{}",
+            files.len(),
+            bytes,
+            archive.display(),
+            if pruned > 0 { format!(", pruned {} old archives", pruned) } else { String::new() },
+        ))
+    })
+    .await
+    .map_err(|e| format!("backup panicked: {}", e))?
+}
+
+/// Decrypts `archive` and extracts the entries `sources` knows into `staging`.
+/// Returns `(archive path, staged file, target)` for each.
+fn stage(
+    archive: &Path,
+    passphrase: SecretString,
+    sources: &Sources,
+    staging: &Path,
+) -> Result<Vec<(String, PathBuf, PathBuf)>, AppError> {
+    let file = std::fs::File::open(archive)
+        .map_err(|e| AppError::NotFound(format!("Cannot open {}: {}", archive.display(), e)))?;
+    let decryptor = age::Decryptor::new_buffered(BufReader::new(file))
+        .map_err(|e| AppError::InvalidInput(format!("Not a Winter backup: {}", e)))?;
+    if !decryptor.is_scrypt() {
+        return Err(AppError::InvalidInput("Backup is not passphrase-encrypted".to_string()));
+    }
+    let identity = age::scrypt::Identity::new(passphrase);
+    let reader = decryptor
+        .decrypt(std::iter::once(&identity as &dyn age::Identity))
+        .map_err(|e| match e {
+            age::DecryptError::NoMatchingKeys | age::DecryptError::DecryptionFailed => {
+                AppError::InvalidInput("Wrong backup passphrase".to_string())
+            }
+            e => AppError::Failed(format!("Failed to decrypt backup: {}", e)),
+        })?;
+    let mut tar = tar::Archive::new(zstd::Decoder::new(reader)?);
+
+    std::fs::create_dir_all(staging)?;
+    let mut staged = Vec::new();
+    for entry in tar.entries().map_err(|e| AppError::Failed(format!("Corrupt backup: {}", e)))? {
+        let mut entry = entry.map_err(|e| AppError::Failed(format!("Corrupt backup: {}", e)))?;
+        let name = entry.path().map(|p| p.to_string_lossy().replace('\\', "/")).unwrap_or_default();
+        let Some(target) = sources.target(&name) else {
+            tracing::warn!("Skipping unexpected backup entry {}", name);
+            continue;
+        };
+        let file = staging.join(staged.len().to_string());
+        entry
+            .unpack(&file)
+            .map_err(|e| AppError::Failed(format!("Corrupt backup entry {}: {}", name, e)))?;
+        staged.push((name, file, target));
+    }
+    if staged.is_empty() {
+        return Err(AppError::InvalidInput("Backup contains no app data".to_string()));
+    }
+    Ok(staged)
+}
+
+/// Copies the files about to be replaced into `backup_dir`, then moves the
+/// staged files into place.
+fn apply(staged: &[(String, PathBuf, PathBuf)], backup_dir: &Path) -> Result<(), AppError> {
+    let _ = std::fs::remove_dir_all(backup_dir);
+    for (name, _, target) in staged {
+        if target.is_file() {
+            let copy = backup_dir.join(name);
+            if let Some(parent) = copy.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(target, &copy)?;
+        }
+    }
+    for (name, file, target) in staged {
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(file, target).map_err(|e| AppError::Io(format!("Failed to restore {}: {}", name, e)))?;
+    }
+    Ok(())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn has_backup_passphrase(app: AppHandle) -> bool {
+    passphrase(&app).is_some()
+}
+
+/// Sets the passphrase new backups are encrypted with. Existing archives keep
+/// their old passphrase.
+#[tauri::command]
+pub fn set_backup_passphrase(app: AppHandle, passphrase: String) -> Result<(), AppError> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(AppError::InvalidInput(format!(
+            "Passphrase must be at least {} characters",
+            MIN_PASSPHRASE_LEN
+        )));
+    }
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_PASSPHRASE, serde_json::json!(passphrase));
+    store.save()?;
+    Ok(())
+}
+
+/// Restores app data from an encrypted backup and restarts Winter.
+///
+/// `passphrase` defaults to the current backup passphrase; pass one to restore
+/// an archive made with another (e.g. on a new machine). Nothing is replaced
+/// unless the whole archive decrypts and unpacks. The replaced files are kept
+/// in `<app_data_dir>/pre-restore`.
+#[tauri::command]
+pub async fn restore_backup(app: AppHandle, path: String, passphrase: Option<String>) -> Result<RestoreReport, AppError> {
+    let passphrase = passphrase
+        .filter(|p| !p.is_empty())
+        .or_else(|| self::passphrase(&app))
+        .ok_or_else(|| AppError::InvalidInput("Enter the backup passphrase".to_string()))?;
+    let sources = Sources::new(&app).map_err(AppError::Io)?;
+    let archive = expand_home(path.trim());
+    let staging = sources.data_dir.join("restore-staging");
+    let previous = sources.data_dir.join(PRE_RESTORE_DIR);
+    let previous_copy = previous.to_string_lossy().to_string();
+
+    let restored = tokio::task::spawn_blocking(move || {
+        let result = stage(&archive, SecretString::from(passphrase), &sources, &staging)
+            .and_then(|staged| apply(&staged, &previous).map(|()| staged));
+        let _ = std::fs::remove_dir_all(&staging);
+        result.map(|staged| staged.into_iter().map(|(name, _, _)| name).collect::<Vec<_>>())
+    })
+    .await
+    .map_err(|e| AppError::Failed(format!("restore panicked: {}", e)))??;
+
+    // Loaded stores would write their stale contents back on exit.
+    for name in &restored {
+        let store = name.split_once('/').filter(|(dir, _)| *dir == DATA_DIR).and_then(|(_, n)| app.get_store(n));
+        if let Some(store) = store {
+            if let Err(e) = store.reload() {
+                tracing::warn!("Failed to reload store {}: {}", name, e);
+            }
+        }
+    }
+    tracing::info!("Restored {} files from {}; restarting", restored.len(), path);
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        handle.restart();
+    });
+    Ok(RestoreReport { restored, previous_copy })
+}
@@ -0,0 +1,343 @@
+//! Archive extraction and creation for the file browser.
+//!
+//! Supports `.zip`, `.tar.gz`/`.tgz`, `.tar.zst` (the format backups use)
+//! and plain `.tar`. While working, an `archive-progress`
+//! event reports the entries processed so far, at most every
+//! [`PROGRESS_INTERVAL`]. Destinations go through the file browser's sandbox;
+//! entries that would land outside the destination are refused.
+
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use walkdir::WalkDir;
+
+use crate::error::AppError;
+
+const PROGRESS_EVENT: &str = "archive-progress";
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// zstd level for `.tar.zst`, as for backups.
+const ZSTD_LEVEL: i32 = 3;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ArchiveFormat {
+    #[serde(rename = "zip")]
+    Zip,
+    #[serde(rename = "tar.gz")]
+    TarGz,
+    #[serde(rename = "tar.zst")]
+    TarZst,
+    #[serde(rename = "tar")]
+    Tar,
+}
+
+impl ArchiveFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else if name.ends_with(".tar.zst") || name.ends_with(".tzst") {
+            Some(ArchiveFormat::TarZst)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveFormat::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+/// Payload of `archive-progress`.
+#[derive(Debug, Serialize, Clone)]
+pub struct ArchiveProgress {
+    pub operation_id: String,
+    /// "extract" or "create".
+    pub operation: &'static str,
+    pub done: usize,
+    /// Entries in total, when known up front.
+    pub total: Option<usize>,
+    /// Entry being processed.
+    pub current: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArchiveSummary {
+    /// Extraction directory or created archive.
+    pub path: String,
+    pub entries: usize,
+    pub bytes: u64,
+}
+
+struct Progress {
+    app: AppHandle,
+    operation_id: String,
+    operation: &'static str,
+    total: Option<usize>,
+    done: usize,
+    last: Instant,
+}
+
+impl Progress {
+    fn new(app: &AppHandle, operation_id: String, operation: &'static str, total: Option<usize>) -> Self {
+        Progress { app: app.clone(), operation_id, operation, total, done: 0, last: Instant::now() }
+    }
+
+    fn step(&mut self, current: &str) {
+        self.done += 1;
+        if self.last.elapsed() >= PROGRESS_INTERVAL {
+            self.last = Instant::now();
+            self.emit(current);
+        }
+    }
+
+    fn emit(&self, current: &str) {
+        let _ = self.app.emit(PROGRESS_EVENT, ArchiveProgress {
+            operation_id: self.operation_id.clone(),
+            operation: self.operation,
+            done: self.done,
+            total: self.total,
+            current: current.to_string(),
+        });
+    }
+}
+
+fn io_err(what: &Path) -> impl Fn(std::io::Error) -> String + '_ {
+    move |e| format!("{}: {}", what.display(), e)
+}
+
+// ── Extraction ────────────────────────────────────────────────────────
+
+fn ensure_free(target: &Path, overwrite: bool) -> Result<(), String> {
+    if !overwrite && target.is_file() {
+        return Err(format!("{} already exists", target.display()));
+    }
+    Ok(())
+}
+
+fn extract_zip(file: File, dest: &Path, overwrite: bool, progress: &mut Progress) -> Result<u64, String> {
+    let mut zip = zip::ZipArchive::new(file).map_err(|e| format!("Invalid zip: {}", e))?;
+    progress.total = Some(zip.len());
+    let mut bytes = 0;
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i).map_err(|e| format!("Invalid zip entry: {}", e))?;
+        let name = entry.name().to_string();
+        let rel = entry
+            .enclosed_name()
+            .ok_or_else(|| format!("Refusing entry outside the destination: {}", name))?;
+        let target = dest.join(rel);
+        if entry.is_dir() {
+            std::fs::create_dir_all(&target).map_err(io_err(&target))?;
+        } else {
+            ensure_free(&target, overwrite)?;
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent).map_err(io_err(parent))?;
+            }
+            let mut out = File::create(&target).map_err(io_err(&target))?;
+            bytes += std::io::copy(&mut entry, &mut out).map_err(io_err(&target))?;
+            #[cfg(unix)]
+            if let Some(mode) = entry.unix_mode() {
+                use std::os::unix::fs::PermissionsExt;
+                let _ = std::fs::set_permissions(&target, std::fs::Permissions::from_mode(mode & 0o777));
+            }
+        }
+        progress.step(&name);
+    }
+    Ok(bytes)
+}
+
+fn extract_tar<R: Read>(reader: R, dest: &Path, overwrite: bool, progress: &mut Progress) -> Result<u64, String> {
+    let mut archive = tar::Archive::new(reader);
+    let mut bytes = 0;
+    for entry in archive.entries().map_err(|e| format!("Invalid tar: {}", e))? {
+        let mut entry = entry.map_err(|e| format!("Invalid tar entry: {}", e))?;
+        let name = entry.path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+        ensure_free(&dest.join(&name), overwrite)?;
+        bytes += entry.header().size().unwrap_or(0);
+        // `unpack_in` refuses paths that would escape `dest`.
+        let inside = entry.unpack_in(dest).map_err(|e| format!("{}: {}", name, e))?;
+        if !inside {
+            return Err(format!("Refusing entry outside the destination: {}", name));
+        }
+        progress.step(&name);
+    }
+    Ok(bytes)
+}
+
+// ── Creation ──────────────────────────────────────────────────────────
+
+/// Files and directories to archive as `(path on disk, name in the archive)`.
+fn collect_entries(paths: &[String]) -> Result<Vec<(PathBuf, PathBuf)>, String> {
+    let mut entries = Vec::new();
+    for path in paths {
+        let root = PathBuf::from(path);
+        if !root.exists() {
+            return Err(format!("{} does not exist", path));
+        }
+        let base = root.file_name().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("root"));
+        for entry in WalkDir::new(&root).follow_links(false) {
+            let entry = entry.map_err(|e| e.to_string())?;
+            let kind = entry.file_type();
+            if !kind.is_file() && !kind.is_dir() {
+                continue;
+            }
+            let rel = entry.path().strip_prefix(&root).unwrap_or(entry.path());
+            entries.push((entry.path().to_path_buf(), base.join(rel)));
+        }
+    }
+    Ok(entries)
+}
+
+fn write_zip(out: File, entries: &[(PathBuf, PathBuf)], progress: &mut Progress) -> Result<u64, String> {
+    let mut zip = zip::ZipWriter::new(out);
+    let mut bytes = 0;
+    for (path, name) in entries {
+        // Zip names always use forward slashes.
+        let name = name.to_string_lossy().replace('\\', "/");
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .large_file(true);
+        #[cfg(unix)]
+        let options = match std::fs::metadata(path) {
+            Ok(meta) => {
+                use std::os::unix::fs::PermissionsExt;
+                options.unix_permissions(meta.permissions().mode())
+            }
+            Err(_) => options,
+        };
+        if path.is_dir() {
+            zip.add_directory(name.as_str(), options).map_err(|e| e.to_string())?;
+        } else {
+            zip.start_file(name.as_str(), options).map_err(|e| e.to_string())?;
+            let mut file = File::open(path).map_err(io_err(path))?;
+            bytes += std::io::copy(&mut file, &mut zip).map_err(io_err(path))?;
+        }
+        progress.step(&name);
+    }
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
+
+fn write_tar<W: Write>(builder: &mut tar::Builder<W>, entries: &[(PathBuf, PathBuf)], progress: &mut Progress) -> Result<u64, String> {
+    let mut bytes = 0;
+    for (path, name) in entries {
+        if path.is_dir() {
+            builder.append_dir(name, path).map_err(io_err(path))?;
+        } else {
+            builder.append_path_with_name(path, name).map_err(io_err(path))?;
+            bytes += std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        }
+        progress.step(&name.to_string_lossy());
+    }
+    Ok(bytes)
+}
+
+fn create(out: File, format: ArchiveFormat, entries: &[(PathBuf, PathBuf)], progress: &mut Progress) -> Result<u64, String> {
+    match format {
+        ArchiveFormat::Zip => write_zip(out, entries, progress),
+        ArchiveFormat::Tar => {
+            let mut builder = tar::Builder::new(out);
+            let bytes = write_tar(&mut builder, entries, progress)?;
+            builder.into_inner().map_err(|e| e.to_string())?;
+            Ok(bytes)
+        }
+        ArchiveFormat::TarGz => {
+            let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(out, flate2::Compression::default()));
+            let bytes = write_tar(&mut builder, entries, progress)?;
+            builder.into_inner().and_then(|enc| enc.finish()).map_err(|e| e.to_string())?;
+            Ok(bytes)
+        }
+        ArchiveFormat::TarZst => {
+            let enc = zstd::Encoder::new(out, ZSTD_LEVEL).map_err(|e| e.to_string())?;
+            let mut builder = tar::Builder::new(enc);
+            let bytes = write_tar(&mut builder, entries, progress)?;
+            builder.into_inner().and_then(|enc| enc.finish()).map_err(|e| e.to_string())?;
+            Ok(bytes)
+        }
+    }
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Extracts an archive into `dest` (created if missing). Existing files are
+/// only replaced with `overwrite`. `operation_id` tags the progress events.
+#[tauri::command]
+pub async fn extract_archive(
+    app: AppHandle,
+    path: String,
+    dest: String,
+    overwrite: Option<bool>,
+    operation_id: Option<String>,
+) -> Result<ArchiveSummary, AppError> {
+    let source = PathBuf::from(&path);
+    let format = ArchiveFormat::from_path(&source)
+        .ok_or_else(|| AppError::InvalidInput(format!("Unsupported archive type: {}", path)))?;
+    let dest = crate::files::sandboxed(&app, &dest)?;
+    let overwrite = overwrite.unwrap_or(false);
+    let mut progress = Progress::new(&app, operation_id.unwrap_or_default(), "extract", None);
+
+    let summary = tokio::task::spawn_blocking(move || {
+        std::fs::create_dir_all(&dest).map_err(io_err(&dest))?;
+        let file = File::open(&source).map_err(io_err(&source))?;
+        let bytes = match format {
+            ArchiveFormat::Zip => extract_zip(file, &dest, overwrite, &mut progress)?,
+            ArchiveFormat::Tar => extract_tar(file, &dest, overwrite, &mut progress)?,
+            ArchiveFormat::TarGz => extract_tar(flate2::read::GzDecoder::new(file), &dest, overwrite, &mut progress)?,
+            ArchiveFormat::TarZst => {
+                let decoder = zstd::Decoder::new(file).map_err(io_err(&source))?;
+                extract_tar(decoder, &dest, overwrite, &mut progress)?
+            }
+        };
+        progress.emit("");
+        Ok::<_, String>(ArchiveSummary { path: dest.to_string_lossy().to_string(), entries: progress.done, bytes })
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+    Ok(summary)
+}
+
+/// Archives `paths` (each stored under its own name) into the archive file
+/// `dest`. The format comes from `format` or else from `dest`'s extension.
+#[tauri::command]
+pub async fn create_archive(
+    app: AppHandle,
+    paths: Vec<String>,
+    dest: String,
+    format: Option<ArchiveFormat>,
+    operation_id: Option<String>,
+) -> Result<ArchiveSummary, AppError> {
+    if paths.is_empty() {
+        return Err(AppError::InvalidInput("Nothing to archive".to_string()));
+    }
+    let dest = crate::files::sandboxed(&app, &dest)?;
+    let format = format.or_else(|| ArchiveFormat::from_path(&dest)).ok_or_else(|| {
+        AppError::InvalidInput("Archive format not given and not recognised from the file name".to_string())
+    })?;
+    if dest.exists() {
+        return Err(AppError::InvalidInput(format!("{} already exists", dest.display())));
+    }
+
+    let summary = tokio::task::spawn_blocking(move || {
+        // Collected before the archive file exists, so it never includes itself.
+        let entries = collect_entries(&paths)?;
+        let mut progress = Progress::new(&app, operation_id.unwrap_or_default(), "create", Some(entries.len()));
+        let tmp = dest.with_extension("partial");
+        let out = File::create(&tmp).map_err(io_err(&tmp))?;
+        let bytes = match create(out, format, &entries, &mut progress) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                let _ = std::fs::remove_file(&tmp);
+                return Err(format!("Failed to create archive: {}", e));
+            }
+        };
+        std::fs::rename(&tmp, &dest).map_err(io_err(&dest))?;
+        progress.emit("");
+        Ok(ArchiveSummary { path: dest.to_string_lossy().to_string(), entries: progress.done, bytes })
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+    Ok(summary)
+}
@@ -0,0 +1,265 @@
+//! Built-in backup task for the scheduler.
+//!
+//! Archives one or more source directories into a timestamped `.tar.zst` or
+//! `.tar.gz` file in the destination directory and prunes archives beyond the
+//! retention count. Incremental runs only include files modified since the
+//! newest existing archive was started; pruning never removes the newest full
+//! archive or the increments that depend on it.
+
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// zstd level used for `.tar.zst` archives; favours speed over ratio.
+const ZSTD_LEVEL: i32 = 3;
+
+const ARCHIVE_EXTENSIONS: [&str; 2] = ["tar.zst", "tar.gz"];
+
+fn default_retention() -> usize {
+    7
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    #[default]
+    Zstd,
+    Gzip,
+}
+
+impl Compression {
+    fn extension(self) -> &'static str {
+        match self {
+            Compression::Zstd => "tar.zst",
+            Compression::Gzip => "tar.gz",
+        }
+    }
+}
+
+/// Registry configuration of a backup task. Paths may start with `~/`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BackupConfig {
+    pub sources: Vec<String>,
+    pub destination: String,
+    /// Archives kept in `destination` for this task; 0 keeps everything.
+    #[serde(default = "default_retention")]
+    pub retention: usize,
+    #[serde(default)]
+    pub compression: Compression,
+    /// Only archive files changed since the newest existing archive.
+    #[serde(default)]
+    pub incremental: bool,
+    /// File or directory names skipped anywhere in the tree (e.g. "node_modules").
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Expands a leading `~` to the user's home directory.
+pub fn expand_home(path: &str) -> PathBuf {
+    let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE"));
+    match (path.strip_prefix("~"), home) {
+        (Some(rest), Ok(home)) => PathBuf::from(home).join(rest.trim_start_matches(['/', '\\'])),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Whether `name` is an archive of `prefix`: `{prefix}-YYYYMMDD-HHMMSS[-incr].{ext}`.
+/// Checking the timestamp keeps `daily-backup` from claiming `daily-backup-2`'s archives.
+fn is_archive_of(name: &str, prefix: &str, extensions: &[&str]) -> bool {
+    let Some(rest) = name.strip_prefix(prefix).and_then(|r| r.strip_prefix('-')) else {
+        return false;
+    };
+    let Some(stamp) = rest.get(..15) else { return false };
+    let stamp_ok = stamp.char_indices().all(|(i, c)| if i == 8 { c == '-' } else { c.is_ascii_digit() });
+    let suffix = &rest[15..];
+    let suffix = suffix.strip_prefix("-incr").unwrap_or(suffix);
+    stamp_ok && extensions.iter().any(|x| suffix.strip_prefix('.') == Some(x))
+}
+
+/// Archives belonging to `prefix` in `dir`, oldest first (names embed a sortable timestamp).
+fn existing_archives(dir: &Path, prefix: &str, extensions: &[&str]) -> Vec<PathBuf> {
+    let mut archives: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| {
+                    p.file_name()
+                        .and_then(|n| n.to_str())
+                        .is_some_and(|n| is_archive_of(n, prefix, extensions))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    archives.sort();
+    archives
+}
+
+fn is_incremental(archive: &Path) -> bool {
+    archive
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.contains("-incr."))
+}
+
+/// Index of the newest full archive; it and the increments after it form the
+/// chain a restore needs.
+fn newest_full(archives: &[PathBuf]) -> Option<usize> {
+    archives.iter().rposition(|a| !is_incremental(a))
+}
+
+/// Start time of a run, from the `YYYYMMDD-HHMMSS` stamp in its archive name.
+fn archive_started(archive: &Path, prefix: &str) -> Option<SystemTime> {
+    use chrono::TimeZone;
+    let name = archive.file_name()?.to_str()?;
+    let stamp = name.strip_prefix(prefix)?.strip_prefix('-')?.get(..15)?;
+    let naive = chrono::NaiveDateTime::parse_from_str(stamp, "%Y%m%d-%H%M%S").ok()?;
+    Some(chrono::Local.from_local_datetime(&naive).earliest()?.into())
+}
+
+/// Deletes the oldest archives of `prefix` beyond `retention` (0 keeps
+/// everything). The newest full archive and its increments are always kept.
+/// Returns how many were removed.
+pub fn prune(dir: &Path, prefix: &str, extensions: &[&str], retention: usize) -> usize {
+    if retention == 0 {
+        return 0;
+    }
+    let all = existing_archives(dir, prefix, extensions);
+    let protected = newest_full(&all).unwrap_or(0);
+    let excess = all.len().saturating_sub(retention).min(protected);
+    all.iter().take(excess).filter(|old| std::fs::remove_file(old).is_ok()).count()
+}
+
+struct ArchiveStats {
+    files: usize,
+    bytes: u64,
+    skipped: usize,
+}
+
+fn append_sources<W: Write>(
+    builder: &mut tar::Builder<W>,
+    cfg: &BackupConfig,
+    since: Option<SystemTime>,
+) -> Result<ArchiveStats, String> {
+    let mut stats = ArchiveStats { files: 0, bytes: 0, skipped: 0 };
+    for source in &cfg.sources {
+        let root = expand_home(source);
+        if !root.exists() {
+            return Err(format!("Backup source not found: {}", root.display()));
+        }
+        let base = root
+            .file_name()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("root"));
+
+        let walker = WalkDir::new(&root).follow_links(false).into_iter().filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            !cfg.exclude.iter().any(|x| x == name.as_ref())
+        });
+        for entry in walker {
+            let Ok(entry) = entry else {
+                stats.skipped += 1;
+                continue;
+            };
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Ok(meta) = entry.metadata() else {
+                stats.skipped += 1;
+                continue;
+            };
+            if let (Some(since), Ok(modified)) = (since, meta.modified()) {
+                if modified <= since {
+                    continue;
+                }
+            }
+            let rel = entry.path().strip_prefix(&root).unwrap_or(entry.path());
+            match builder.append_path_with_name(entry.path(), base.join(rel)) {
+                Ok(()) => {
+                    stats.files += 1;
+                    stats.bytes += meta.len();
+                }
+                Err(_) => stats.skipped += 1,
+            }
+        }
+    }
+    Ok(stats)
+}
+
+/// Runs a backup for the task `task_id`. Blocking; call from `spawn_blocking`.
+/// Returns a one-line summary for the task log.
+pub fn run(task_id: &str, cfg: &BackupConfig) -> Result<String, String> {
+    if cfg.sources.is_empty() {
+        return Err("Backup has no sources".to_string());
+    }
+    let dest = expand_home(&cfg.destination);
+    std::fs::create_dir_all(&dest)
+        .map_err(|e| format!("Failed to create backup destination {}: {}", dest.display(), e))?;
+
+    let previous = existing_archives(&dest, task_id, &ARCHIVE_EXTENSIONS);
+    // Start a new full archive when there is none to build on, or once another
+    // increment would push the current one out of the retention window.
+    let needs_full = match newest_full(&previous) {
+        None => true,
+        Some(full) => cfg.retention > 0 && previous.len() - full >= cfg.retention,
+    };
+    let since = if cfg.incremental && !needs_full {
+        previous.last().and_then(|p| archive_started(p, task_id))
+    } else {
+        None
+    };
+
+    // Taken before the walk, so files changed while archiving go into the next increment.
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let kind = if since.is_some() { "-incr" } else { "" };
+    let archive = dest.join(format!("{}-{}{}.{}", task_id, stamp, kind, cfg.compression.extension()));
+    let tmp = archive.with_extension("partial");
+    let file = std::fs::File::create(&tmp)
+        .map_err(|e| format!("Failed to create archive {}: {}", tmp.display(), e))?;
+
+    let result = match cfg.compression {
+        Compression::Zstd => zstd::Encoder::new(file, ZSTD_LEVEL)
+            .map_err(|e| e.to_string())
+            .and_then(|enc| {
+                let mut builder = tar::Builder::new(enc);
+                let stats = append_sources(&mut builder, cfg, since)?;
+                let enc = builder.into_inner().map_err(|e| e.to_string())?;
+                enc.finish().map_err(|e| e.to_string())?;
+                Ok(stats)
+            }),
+        Compression::Gzip => {
+            let enc = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            let mut builder = tar::Builder::new(enc);
+            append_sources(&mut builder, cfg, since).and_then(|stats| {
+                let enc = builder.into_inner().map_err(|e| e.to_string())?;
+                enc.finish().map_err(|e| e.to_string())?;
+                Ok(stats)
+            })
+        }
+    };
+    let stats = match result {
+        Ok(s) => s,
+        Err(e) => {
+            let _ = std::fs::remove_file(&tmp);
+            return Err(format!("Backup failed: {}", e));
+        }
+    };
+
+    if since.is_some() && stats.files == 0 {
+        let _ = std::fs::remove_file(&tmp);
+        return Ok("No changes since the last backup".to_string());
+    }
+    std::fs::rename(&tmp, &archive).map_err(|e| format!("Failed to finalize archive: {}", e))?;
+
+    let pruned = prune(&dest, task_id, &ARCHIVE_EXTENSIONS, cfg.retention);
+
+    Ok(format!(
+        "Archived {} files ({} bytes) into {}{}{}",
+        stats.files,
+        stats.bytes,
+        archive.display(),
+        if stats.skipped > 0 { format!(", {} unreadable entries skipped", stats.skipped) } else { String::new() },
+        if pruned > 0 { format!(", pruned {} old archives", pruned) } else { String::new() },
+    ))
+}
@@ -0,0 +1,359 @@
+//! Remote chat bridge: talk to Winter from Telegram or Discord.
+//!
+//! With a bot token in the `remote_bridge` settings, a background task polls
+//! for new messages (Telegram long polling, Discord REST polling of one
+//! channel) and runs each through `chat_send`, then posts the reply back.
+//! Every remote chat maps to its own conversation (`telegram:<chat id>`,
+//! `discord:<channel id>`), whose history is kept in memory; `/reset` clears
+//! it, and the chat slash commands (`/model`, `/usage`, ...) work as usual.
+//!
+//! Winter's tools can run shell commands, so only allowlisted chats/users are
+//! answered. Anyone else gets their ID back, to add it in settings.
+//! The Discord bot needs the Message Content intent to read messages.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::async_runtime::JoinHandle;
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::claude::types::{ChatMessage, ChatStreamEvent, MessageContent};
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_SETTINGS: &str = "remote_bridge";
+/// Next Telegram update to fetch, so a restart does not answer old messages again.
+const STORE_KEY_TELEGRAM_OFFSET: &str = "remote_bridge_telegram_offset";
+
+const TELEGRAM_API: &str = "https://api.telegram.org";
+const DISCORD_API: &str = "https://discord.com/api/v10";
+
+/// Telegram long-poll timeout; the HTTP timeout is a bit longer.
+const TELEGRAM_POLL_SECS: u64 = 30;
+const DISCORD_POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// Wait after a failed poll before trying again.
+const RETRY_DELAY: Duration = Duration::from_secs(15);
+
+const TELEGRAM_MAX_MESSAGE: usize = 4096;
+const DISCORD_MAX_MESSAGE: usize = 2000;
+
+/// Messages kept per remote conversation; chat_send compacts what it gets.
+const MAX_HISTORY: usize = 40;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TelegramConfig {
+    pub token: String,
+    /// Chat IDs allowed to talk to Winter.
+    #[serde(default)]
+    pub allowed_chats: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DiscordConfig {
+    pub token: String,
+    /// Channel (or DM channel) the bot listens in.
+    pub channel_id: String,
+    /// User IDs allowed to talk to Winter.
+    #[serde(default)]
+    pub allowed_users: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BridgeSettings {
+    #[serde(default)]
+    pub telegram: Option<TelegramConfig>,
+    #[serde(default)]
+    pub discord: Option<DiscordConfig>,
+}
+
+/// Running pollers and the history of each remote conversation.
+#[derive(Default)]
+pub struct BridgeState {
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+    /// Held for a whole turn, so remote messages are answered one at a time.
+    histories: Arc<tokio::sync::Mutex<HashMap<String, Vec<ChatMessage>>>>,
+}
+
+fn settings(app: &AppHandle) -> BridgeSettings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_SETTINGS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+/// Whether `conversation_id` belongs to a remote chat. Replies to those are
+/// posted back to the chat, so TTS, notifications and webhooks skip them.
+pub fn is_bridge_conversation(conversation_id: &str) -> bool {
+    conversation_id.starts_with("telegram:") || conversation_id.starts_with("discord:")
+}
+
+/// Stops running pollers and starts one per configured platform.
+pub fn restart(app: &AppHandle) {
+    let state = app.state::<BridgeState>();
+    let mut tasks = state.tasks.lock().unwrap_or_else(|e| e.into_inner());
+    for task in tasks.drain(..) {
+        task.abort();
+    }
+    let settings = settings(app);
+    if let Some(cfg) = settings.telegram.filter(|c| !c.token.trim().is_empty()) {
+        tasks.push(tauri::async_runtime::spawn(run_telegram(app.clone(), cfg)));
+    }
+    if let Some(cfg) = settings.discord.filter(|c| !c.token.trim().is_empty()) {
+        tasks.push(tauri::async_runtime::spawn(run_discord(app.clone(), cfg)));
+    }
+}
+
+/// Splits `text` into pieces of at most `max` characters.
+fn chunks(text: &str, max: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    chars.chunks(max).map(|c| c.iter().collect()).collect()
+}
+
+/// Runs one remote message through `chat_send` and returns the reply text.
+async fn answer(app: &AppHandle, conversation_id: &str, text: &str) -> String {
+    let histories = app.state::<BridgeState>().histories.clone();
+    let mut histories = histories.lock().await;
+    if text.trim() == "/reset" {
+        histories.remove(conversation_id);
+        return "Conversation cleared.".to_string();
+    }
+    let history = histories.entry(conversation_id.to_string()).or_default();
+    history.push(ChatMessage {
+        role: "user".to_string(),
+        content: MessageContent::Text(text.to_string()),
+    });
+
+    let reply = Arc::new(Mutex::new(String::new()));
+    let collector = {
+        let reply = reply.clone();
+        Channel::new(move |body: InvokeResponseBody| {
+            let InvokeResponseBody::Json(raw) = body else { return Ok(()) };
+            let Ok(event) = serde_json::from_str::<ChatStreamEvent>(&raw) else { return Ok(()) };
+            let mut reply = reply.lock().unwrap_or_else(|e| e.into_inner());
+            match event {
+                ChatStreamEvent::Delta { text } => reply.push_str(&text),
+                ChatStreamEvent::CommandResult { text, .. } => reply.push_str(&text),
+                ChatStreamEvent::Error { message } => reply.push_str(&format!("\n[error] {}", message)),
+                _ => {}
+            }
+            Ok(())
+        })
+    };
+    let result = crate::chat_send(
+        app.clone(),
+        history.clone(),
+        None,
+        Some(conversation_id.to_string()),
+        None,
+        collector,
+    )
+    .await;
+    let text = std::mem::take(&mut *reply.lock().unwrap_or_else(|e| e.into_inner()));
+
+    if let Err(e) = result {
+        history.pop();
+        return format!("Error: {}", e);
+    }
+    if crate::slash::parse(history).is_some() {
+        // Commands are not part of the conversation.
+        history.pop();
+    } else {
+        history.push(ChatMessage {
+            role: "assistant".to_string(),
+            content: MessageContent::Text(text.clone()),
+        });
+    }
+    if history.len() > MAX_HISTORY {
+        let excess = history.len() - MAX_HISTORY;
+        history.drain(..excess);
+    }
+    match text.trim() {
+        "" => "(no reply)".to_string(),
+        t => t.to_string(),
+    }
+}
+
+// ── Telegram ────────────────────────────────────────────────────────
+
+async fn telegram_send(client: &reqwest::Client, token: &str, chat_id: i64, text: &str) {
+    for chunk in chunks(text, TELEGRAM_MAX_MESSAGE) {
+        let result = client
+            .post(format!("{}/bot{}/sendMessage", TELEGRAM_API, token))
+            .json(&json!({ "chat_id": chat_id, "text": chunk }))
+            .send()
+            .await;
+        if let Err(e) = result {
+            // The URL holds the bot token.
+            tracing::warn!("Telegram send failed: {}", e.without_url());
+            return;
+        }
+    }
+}
+
+async fn run_telegram(app: AppHandle, cfg: TelegramConfig) {
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(TELEGRAM_POLL_SECS + 10))
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => return tracing::warn!("Telegram client error: {}", e),
+    };
+    let store = app.store(STORE_FILE).ok();
+    let mut offset: i64 = store
+        .as_ref()
+        .and_then(|s| s.get(STORE_KEY_TELEGRAM_OFFSET))
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0);
+    loop {
+        let resp = client
+            .get(format!("{}/bot{}/getUpdates", TELEGRAM_API, cfg.token))
+            .query(&[("offset", offset.to_string()), ("timeout", TELEGRAM_POLL_SECS.to_string())])
+            .send()
+            .await;
+        let body: Value = match resp {
+            Ok(r) => r.json().await.unwrap_or(Value::Null),
+            Err(e) => {
+                tracing::warn!("Telegram poll failed: {}", e.without_url());
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+        };
+        let Some(updates) = body["result"].as_array() else {
+            tracing::warn!("Telegram error: {}", body["description"].as_str().unwrap_or("bad response"));
+            tokio::time::sleep(RETRY_DELAY).await;
+            continue;
+        };
+        for update in updates {
+            offset = offset.max(update["update_id"].as_i64().unwrap_or(0) + 1);
+            if let Some(store) = &store {
+                store.set(STORE_KEY_TELEGRAM_OFFSET, json!(offset));
+                let _ = store.save();
+            }
+            let message = &update["message"];
+            let (Some(chat_id), Some(text)) = (message["chat"]["id"].as_i64(), message["text"].as_str()) else {
+                continue;
+            };
+            let reply = if cfg.allowed_chats.contains(&chat_id) {
+                answer(&app, &format!("telegram:{}", chat_id), text).await
+            } else {
+                format!("This chat is not allowed. Add chat ID {} in Winter's bridge settings.", chat_id)
+            };
+            telegram_send(&client, &cfg.token, chat_id, &reply).await;
+        }
+    }
+}
+
+// ── Discord ─────────────────────────────────────────────────────────
+
+async fn discord_send(client: &reqwest::Client, cfg: &DiscordConfig, text: &str) {
+    for chunk in chunks(text, DISCORD_MAX_MESSAGE) {
+        let result = client
+            .post(format!("{}/channels/{}/messages", DISCORD_API, cfg.channel_id))
+            .header("Authorization", format!("Bot {}", cfg.token))
+            .json(&json!({ "content": chunk }))
+            .send()
+            .await;
+        if let Err(e) = result {
+            tracing::warn!("Discord send failed: {}", e);
+            return;
+        }
+    }
+}
+
+/// Messages in the channel after `after` (all recent ones when `None`), oldest first.
+async fn discord_messages(
+    client: &reqwest::Client,
+    cfg: &DiscordConfig,
+    after: Option<&str>,
+) -> Result<Vec<Value>, String> {
+    let mut req = client
+        .get(format!("{}/channels/{}/messages", DISCORD_API, cfg.channel_id))
+        .header("Authorization", format!("Bot {}", cfg.token))
+        .query(&[("limit", "50")]);
+    if let Some(after) = after {
+        req = req.query(&[("after", after)]);
+    }
+    let resp = req.send().await.map_err(|e| e.to_string())?;
+    if !resp.status().is_success() {
+        return Err(format!("Discord returned {}", resp.status()));
+    }
+    let mut messages: Vec<Value> = resp.json().await.map_err(|e| e.to_string())?;
+    // Snowflake IDs grow with time.
+    messages.sort_by_key(|m| m["id"].as_str().and_then(|id| id.parse::<u64>().ok()).unwrap_or(0));
+    Ok(messages)
+}
+
+async fn run_discord(app: AppHandle, cfg: DiscordConfig) {
+    let client = reqwest::Client::new();
+    // Start after the newest existing message so old history is not replayed.
+    let mut last_id = loop {
+        match discord_messages(&client, &cfg, None).await {
+            Ok(messages) => break messages.last().and_then(|m| m["id"].as_str().map(str::to_string)),
+            Err(e) => {
+                tracing::warn!("Discord poll failed: {}", e);
+                tokio::time::sleep(RETRY_DELAY).await;
+            }
+        }
+    };
+    loop {
+        tokio::time::sleep(DISCORD_POLL_INTERVAL).await;
+        let messages = match discord_messages(&client, &cfg, last_id.as_deref()).await {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!("Discord poll failed: {}", e);
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+        };
+        for message in messages {
+            last_id = message["id"].as_str().map(str::to_string).or(last_id);
+            if message["author"]["bot"].as_bool().unwrap_or(false) {
+                continue;
+            }
+            let (Some(user_id), Some(text)) = (message["author"]["id"].as_str(), message["content"].as_str()) else {
+                continue;
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+            let reply = if cfg.allowed_users.iter().any(|u| u == user_id) {
+                answer(&app, &format!("discord:{}", cfg.channel_id), text).await
+            } else {
+                format!("You are not allowed to use this bridge. Add user ID {} in Winter's bridge settings.", user_id)
+            };
+            discord_send(&client, &cfg, &reply).await;
+        }
+    }
+}
+
+// ── Tauri Commands ──────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_bridge_settings(app: AppHandle) -> BridgeSettings {
+    settings(&app)
+}
+
+/// Saves the bridge settings and restarts the pollers with them.
+#[tauri::command]
+pub fn set_bridge_settings(app: AppHandle, settings: BridgeSettings) -> Result<(), AppError> {
+    if let Some(discord) = &settings.discord {
+        if discord.channel_id.trim().is_empty() {
+            return Err(AppError::InvalidInput("Discord needs a channel ID".to_string()));
+        }
+    }
+    let token = |s: &BridgeSettings| s.telegram.as_ref().map(|t| t.token.clone());
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    if token(&self::settings(&app)) != token(&settings) {
+        // Update IDs are per bot.
+        store.delete(STORE_KEY_TELEGRAM_OFFSET);
+    }
+    store.set(STORE_KEY_SETTINGS, json!(settings));
+    store.save().map_err(|e| e.to_string())?;
+    restart(&app);
+    Ok(())
+}
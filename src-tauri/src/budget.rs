@@ -0,0 +1,314 @@
+/// Daily or weekly spending budget for Claude API use.
+///
+/// Every direct API round (chat, sub-agents, scheduled prompts) adds its
+/// tokens to a per-day ledger with a cost estimated from list prices.
+/// `chat_send` calls `gate` before it starts: past `warn_percent` of the limit
+/// a `budget-warning` event is emitted; once the limit is reached it either
+/// fails with `AppError::BudgetExceeded` until the user confirms, answers with
+/// the cheaper `fallback_model`, or answers with the local Ollama model.
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+use crate::claude::types::{ChatMessage, ChatStreamEvent};
+use crate::error::AppError;
+use crate::{ollama, STORE_FILE};
+
+const STORE_KEY_SETTINGS: &str = "budget";
+const STORE_KEY_USAGE: &str = "budget_usage";
+
+/// Days of usage kept in the ledger; enough for any weekly window.
+const LEDGER_DAYS: i64 = 14;
+
+/// Serializes ledger updates from concurrent chats.
+static LEDGER: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetPeriod {
+    #[default]
+    Daily,
+    /// Monday to Sunday, local time.
+    Weekly,
+}
+
+impl BudgetPeriod {
+    pub fn unit(&self) -> &'static str {
+        match self {
+            BudgetPeriod::Daily => "day",
+            BudgetPeriod::Weekly => "week",
+        }
+    }
+}
+
+/// What happens to a chat once the budget is used up.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ExceedAction {
+    /// Refuse until the user confirms the message.
+    #[default]
+    Confirm,
+    /// Answer with `fallback_model` instead of the selected model.
+    FallbackModel,
+    /// Answer with the configured Ollama model, without tools.
+    Ollama,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct BudgetSettings {
+    pub enabled: bool,
+    pub period: BudgetPeriod,
+    /// Input plus output tokens per period; 0 for no token limit.
+    pub token_limit: u64,
+    /// Estimated USD per period; 0 for no cost limit.
+    pub cost_limit: f64,
+    /// Share of the limit, in percent, at which warnings start.
+    pub warn_percent: u8,
+    pub on_exceed: ExceedAction,
+    pub fallback_model: String,
+}
+
+impl Default for BudgetSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            period: BudgetPeriod::Daily,
+            token_limit: 0,
+            cost_limit: 0.0,
+            warn_percent: 80,
+            on_exceed: ExceedAction::Confirm,
+            fallback_model: "claude-haiku-4-5-20250710".to_string(),
+        }
+    }
+}
+
+/// One day of the ledger.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DayUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetState {
+    Ok,
+    Warning,
+    Exceeded,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BudgetStatus {
+    pub enabled: bool,
+    pub period: BudgetPeriod,
+    /// First day of the current period, `YYYY-MM-DD`.
+    pub period_start: String,
+    pub tokens_used: u64,
+    pub cost_used: f64,
+    pub token_limit: u64,
+    pub cost_limit: f64,
+    /// The larger share of the token and cost limits used; 0 without limits.
+    pub fraction: f64,
+    pub state: BudgetState,
+}
+
+impl BudgetStatus {
+    /// E.g. "Budget reached for this day: 1200000 of 1000000 tokens".
+    fn describe(&self) -> String {
+        let what = if self.state == BudgetState::Exceeded { "reached" } else { "almost used" };
+        let mut used = Vec::new();
+        if self.token_limit > 0 {
+            used.push(format!("{} of {} tokens", self.tokens_used, self.token_limit));
+        }
+        if self.cost_limit > 0.0 {
+            used.push(format!("${:.2} of ${:.2}", self.cost_used, self.cost_limit));
+        }
+        format!("Budget {} for this {}: {}", what, self.period.unit(), used.join(", "))
+    }
+}
+
+/// List price in USD per million input and output tokens. Unknown models
+/// are priced like Opus so the estimate errs high.
+fn price(model: &str) -> (f64, f64) {
+    if model.contains("haiku") {
+        (1.0, 5.0)
+    } else if model.contains("sonnet") {
+        (3.0, 15.0)
+    } else {
+        (15.0, 75.0)
+    }
+}
+
+pub fn get_settings(app: &AppHandle) -> BudgetSettings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get(STORE_KEY_SETTINGS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn read_ledger(app: &AppHandle) -> BTreeMap<NaiveDate, DayUsage> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get(STORE_KEY_USAGE))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+/// Adds one API round to today's usage.
+pub fn record(app: &AppHandle, model: &str, input_tokens: u64, output_tokens: u64) {
+    if input_tokens == 0 && output_tokens == 0 {
+        return;
+    }
+    let _guard = LEDGER.lock().unwrap_or_else(|e| e.into_inner());
+    let today = Local::now().date_naive();
+    let mut ledger = read_ledger(app);
+    ledger.retain(|day, _| *day > today - Duration::days(LEDGER_DAYS));
+    let (input_price, output_price) = price(model);
+    let day = ledger.entry(today).or_default();
+    day.input_tokens += input_tokens;
+    day.output_tokens += output_tokens;
+    day.cost_usd += (input_tokens as f64 * input_price + output_tokens as f64 * output_price) / 1_000_000.0;
+
+    let result = app.store(STORE_FILE).map_err(|e| e.to_string()).and_then(|store| {
+        store.set(STORE_KEY_USAGE, json!(ledger));
+        store.save().map_err(|e| e.to_string())
+    });
+    if let Err(e) = result {
+        tracing::warn!("Failed to record token usage: {}", e);
+    }
+}
+
+pub fn status(app: &AppHandle) -> BudgetStatus {
+    let settings = get_settings(app);
+    let today = Local::now().date_naive();
+    let start = match settings.period {
+        BudgetPeriod::Daily => today,
+        BudgetPeriod::Weekly => today - Duration::days(today.weekday().num_days_from_monday() as i64),
+    };
+    let (tokens_used, cost_used) = read_ledger(app)
+        .range(start..)
+        .fold((0, 0.0), |(tokens, cost), (_, day)| {
+            (tokens + day.input_tokens + day.output_tokens, cost + day.cost_usd)
+        });
+
+    let mut fraction: f64 = 0.0;
+    if settings.token_limit > 0 {
+        fraction = fraction.max(tokens_used as f64 / settings.token_limit as f64);
+    }
+    if settings.cost_limit > 0.0 {
+        fraction = fraction.max(cost_used / settings.cost_limit);
+    }
+    let state = if !settings.enabled {
+        BudgetState::Ok
+    } else if fraction >= 1.0 {
+        BudgetState::Exceeded
+    } else if fraction * 100.0 >= settings.warn_percent as f64 {
+        BudgetState::Warning
+    } else {
+        BudgetState::Ok
+    };
+    BudgetStatus {
+        enabled: settings.enabled,
+        period: settings.period,
+        period_start: start.format("%Y-%m-%d").to_string(),
+        tokens_used,
+        cost_used,
+        token_limit: settings.token_limit,
+        cost_limit: settings.cost_limit,
+        fraction,
+        state,
+    }
+}
+
+/// How `chat_send` should answer, given the budget.
+pub enum Gate {
+    /// Within budget, or the user confirmed going over it.
+    Proceed,
+    /// Over budget: use this cheaper Claude model.
+    Model(String),
+    /// Over budget: answer with Ollama.
+    Ollama,
+}
+
+/// Checks the budget before a chat. Emits `budget-warning` with the
+/// `BudgetStatus` when the warning threshold or the limit has been reached.
+pub fn gate(app: &AppHandle, confirmed: bool) -> Result<Gate, AppError> {
+    let status = status(app);
+    if status.state == BudgetState::Ok {
+        return Ok(Gate::Proceed);
+    }
+    let settings = get_settings(app);
+    let gate = match settings.on_exceed {
+        _ if status.state == BudgetState::Warning || confirmed => Gate::Proceed,
+        ExceedAction::Confirm => return Err(AppError::BudgetExceeded(status.describe())),
+        ExceedAction::FallbackModel => Gate::Model(settings.fallback_model),
+        ExceedAction::Ollama => Gate::Ollama,
+    };
+    tracing::info!("{}", status.describe());
+    let _ = app.emit("budget-warning", &status);
+    Ok(gate)
+}
+
+/// Answers `messages` with the configured Ollama model in a single
+/// completion, for when the budget is used up.
+pub async fn answer_with_ollama(
+    app: &AppHandle,
+    messages: &[ChatMessage],
+    on_event: &Channel<ChatStreamEvent>,
+) -> Result<(), AppError> {
+    let settings = ollama::get_settings(app);
+    let options = ollama::get_generation_options(app);
+    let _ = on_event.send(ChatStreamEvent::StreamStart);
+    let _ = on_event.send(ChatStreamEvent::Status {
+        text: format!("Budget reached, answering with {}", settings.model),
+    });
+    let text = ollama::reply(&settings.base_url, &settings.model, &options, messages)
+        .await
+        .map_err(AppError::Unavailable)?;
+    let _ = on_event.send(ChatStreamEvent::Delta { text });
+    let _ = on_event.send(ChatStreamEvent::StreamEnd);
+    Ok(())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_budget_settings(app: AppHandle) -> BudgetSettings {
+    get_settings(&app)
+}
+
+/// Validates and saves the budget settings.
+#[tauri::command]
+pub fn set_budget_settings(app: AppHandle, settings: BudgetSettings) -> Result<BudgetSettings, AppError> {
+    if !settings.cost_limit.is_finite() || settings.cost_limit < 0.0 {
+        return Err(AppError::InvalidInput("Cost limit must be a positive amount".to_string()));
+    }
+    if !(1..=100).contains(&settings.warn_percent) {
+        return Err(AppError::InvalidInput("Warning threshold must be between 1 and 100%".to_string()));
+    }
+    if settings.enabled && settings.token_limit == 0 && settings.cost_limit == 0.0 {
+        return Err(AppError::InvalidInput("Set a token or cost limit".to_string()));
+    }
+    let fallback_model = settings.fallback_model.trim().to_string();
+    if settings.on_exceed == ExceedAction::FallbackModel && !fallback_model.starts_with("claude-") {
+        return Err(AppError::InvalidInput(format!("Not a Claude model: {}", fallback_model)));
+    }
+    let settings = BudgetSettings { fallback_model, ..settings };
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_SETTINGS, json!(settings));
+    store.save()?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_budget_status(app: AppHandle) -> BudgetStatus {
+    status(&app)
+}
@@ -1,8 +1,9 @@
 /// HTTP client for the Anthropic Claude Messages API.
 /// Handles authentication, model selection, system prompt construction,
 /// streaming response parsing, and multi-round tool-use loops.
-use crate::claude::tools::{execute_tool, tool_definitions};
+use crate::claude::tools::execute_tool;
 use crate::claude::types::{ChatMessage, ChatStreamEvent, ContentBlock, StreamedResponse};
+use crate::error::AppError;
 use futures::StreamExt;
 use reqwest::Client;
 use serde_json::{json, Value};
@@ -20,7 +21,7 @@ const ANTHROPIC_VERSION: &str = "2023-06-01";
 const DEFAULT_MODEL: &str = "claude-opus-4-20250514";
 
 /// Store key for the user-selected Claude model override.
-const STORE_KEY_MODEL: &str = "claude_model";
+pub const STORE_KEY_MODEL: &str = "claude_model";
 
 /// Default maximum tokens for Claude responses.
 const DEFAULT_MAX_TOKENS: u32 = 16384;
@@ -28,12 +29,19 @@ const DEFAULT_MAX_TOKENS: u32 = 16384;
 /// The persistent store filename shared across the app.
 const STORE_FILE: &str = "settings.json";
 
-/// Store key for the MBTI personality modifier appended to the system prompt.
-const STORE_KEY_MBTI_MODIFIER: &str = "mbti_prompt_modifier";
-
 /// Store key for the UI language setting (en, ko, ja, zh).
 const STORE_KEY_LANGUAGE: &str = "language";
 
+/// Store key for the language Winter answers in: "auto" (mirror the user),
+/// a language code ("ko", "en", "ja", "zh") or any language name.
+pub const STORE_KEY_RESPONSE_LANGUAGE: &str = "response_language";
+
+/// Project instruction files looked up in the working directory, in order.
+const PROJECT_INSTRUCTION_FILES: &[&str] = &["WINTER.md", ".winter/instructions.md"];
+
+/// Most bytes of project instructions added to the system prompt, over all files.
+const MAX_PROJECT_INSTRUCTIONS_BYTES: usize = 32 * 1024;
+
 /// Base system prompt that defines Winter's personality and hard constraints.
 const BASE_SYSTEM_PROMPT: &str = "\
 You are Winter — a personal AI assistant that lives on the user's desktop. \
@@ -43,9 +51,14 @@ Never say \"I can't do X\" with a long disclaimer — just say what you CAN do o
 Keep responses short unless the user clearly wants detail. \
 You have tools available: you can run shell commands, read/write files, and list directories. \
 Use them when the user asks you to do something on their computer. \
+For bigger or specialised jobs, hand the work to one of your sisters with delegate_task \
+and report back what they found. \
 You have personality. You're not a search engine. You're Winter.\n\n\
 HARD RULES:\n\
-- Be concise. Every output token costs money. No narration. No filler. Results only.\n\
+- Be concise. Every output token costs money. No narration. No filler. Results only.";
+
+/// Language rule used when no response language is fixed.
+const MIRROR_LANGUAGE_RULE: &str = "\n\
 - Match the user's language. If they write in English, respond in English. \
 If they write in Korean, respond in Korean. Mirror what they use.";
 
@@ -59,13 +72,30 @@ pub fn get_model(app: &AppHandle) -> String {
         .unwrap_or_else(|| DEFAULT_MODEL.to_string())
 }
 
-pub fn build_system_prompt(app: &AppHandle) -> String {
+/// The language Winter must always answer in, or `None` to mirror the user.
+pub fn response_language(app: &AppHandle) -> Option<String> {
+    let value = app
+        .store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get(STORE_KEY_RESPONSE_LANGUAGE))
+        .and_then(|v| v.as_str().map(|s| s.trim().to_string()))
+        .filter(|s| !s.is_empty() && s != "auto")?;
+    let name = match value.as_str() {
+        "ko" => "Korean",
+        "en" => "English",
+        "ja" => "Japanese",
+        "zh" => "Chinese",
+        other => other,
+    };
+    Some(name.to_string())
+}
+
+/// Builds Winter's system prompt. `conversation_id` selects the personality
+/// preset pinned to that conversation, if any.
+pub fn build_system_prompt(app: &AppHandle, conversation_id: Option<&str>) -> String {
     let store = app.store(STORE_FILE).ok();
 
-    let modifier = store
-        .as_ref()
-        .and_then(|s| s.get(STORE_KEY_MBTI_MODIFIER))
-        .and_then(|v| v.as_str().map(|s| s.to_string()));
+    let modifier = crate::personality::modifier_for(app, conversation_id);
 
     let language = store
         .as_ref()
@@ -81,54 +111,126 @@ pub fn build_system_prompt(app: &AppHandle) -> String {
     };
 
     let mut prompt = BASE_SYSTEM_PROMPT.to_string();
-    prompt.push_str(lang_instruction);
+    match response_language(app) {
+        Some(name) => prompt.push_str(&format!(
+            "\n- Always respond in {}, whatever language the user writes in.",
+            name
+        )),
+        None => {
+            prompt.push_str(MIRROR_LANGUAGE_RULE);
+            prompt.push_str(lang_instruction);
+        }
+    }
 
-    if let Some(m) = modifier.filter(|m| !m.is_empty()) {
+    if let Some(m) = modifier {
         prompt.push_str("\n\n");
         prompt.push_str(&m);
     }
 
+    if let Some(additions) = crate::settings::profile_prompt(app) {
+        prompt.push_str("\n\n");
+        prompt.push_str(&additions);
+    }
+
+    if let Some(additions) = crate::projects::prompt_additions(app) {
+        prompt.push_str("\n\n");
+        prompt.push_str(&additions);
+    }
+
+    if let Some(instructions) = project_instructions(&tool_workspace(app)) {
+        prompt.push_str("\n\n");
+        prompt.push_str(&instructions);
+    }
+
     prompt
 }
 
+/// Contents of the project instruction files (WINTER.md, .winter/instructions.md)
+/// in `dir`, capped at `MAX_PROJECT_INSTRUCTIONS_BYTES`. Read on every call, so
+/// edits and working directory changes apply from the next turn.
+pub fn project_instructions(dir: &str) -> Option<String> {
+    let mut sections = Vec::new();
+    let mut budget = MAX_PROJECT_INSTRUCTIONS_BYTES;
+    for name in PROJECT_INSTRUCTION_FILES {
+        if budget == 0 {
+            break;
+        }
+        let path = std::path::Path::new(dir).join(name);
+        let Ok(content) = std::fs::read_to_string(&path) else { continue };
+        let content = content.trim();
+        if content.is_empty() {
+            continue;
+        }
+        let mut end = content.len().min(budget);
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        budget -= end;
+        let truncated = if end < content.len() { "\n[truncated]" } else { "" };
+        sections.push(format!(
+            "Project instructions from {}:\n{}{}",
+            path.display(),
+            &content[..end],
+            truncated
+        ));
+    }
+    (!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+/// System prompt, model and tool set of one API request.
+pub struct RequestSpec<'a> {
+    pub system_prompt: &'a str,
+    pub model: &'a str,
+    /// Tool definitions in the API's `tools` format.
+    pub tools: Value,
+}
+
 /// Streams a single Claude API request, emitting `ChatStreamEvent`s through the IPC channel.
+/// The system prompt, model and tools are taken from `spec`.
 /// Returns a `StreamedResponse` containing accumulated text, tool calls, and stop reason.
 /// Aborts early if `abort_flag` is set to true during streaming.
-pub async fn stream_response(
+pub async fn stream_request(
     client: &Client,
     access_token: &str,
     messages: &[ChatMessage],
     on_event: &Channel<ChatStreamEvent>,
-    system_prompt: &str,
     abort_flag: &AtomicBool,
-    model: &str,
-) -> Result<StreamedResponse, String> {
+    spec: &RequestSpec<'_>,
+) -> Result<StreamedResponse, AppError> {
     let body = json!({
-        "model": model,
+        "model": spec.model,
         "max_tokens": DEFAULT_MAX_TOKENS,
         "messages": messages,
         "stream": true,
-        "system": system_prompt,
-        "tools": tool_definitions(),
+        "system": spec.system_prompt,
+        "tools": spec.tools,
     });
 
-    let response = client
-        .post(CLAUDE_API_URL)
-        .header("authorization", format!("Bearer {}", access_token))
-        .header("anthropic-version", ANTHROPIC_VERSION)
-        .header("anthropic-beta", "oauth-2025-04-20")
-        .header("user-agent", "winter-app/1.0.0")
-        .header("x-app", "cli")
-        .header("content-type", "application/json")
+    let authorization = format!("Bearer {}", access_token);
+    let headers = [
+        ("authorization", authorization.as_str()),
+        ("anthropic-version", ANTHROPIC_VERSION),
+        ("anthropic-beta", "oauth-2025-04-20"),
+        ("user-agent", "winter-app/1.0.0"),
+        ("x-app", "cli"),
+        ("content-type", "application/json"),
+    ];
+    crate::api_debug::request("claude", "POST", CLAUDE_API_URL, &headers, Some(&body));
+    let mut request = client.post(CLAUDE_API_URL);
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+    let response = request
         .json(&body)
         .send()
         .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+        .map_err(|e| AppError::Network(format!("Request failed: {}", e)))?;
+    crate::api_debug::response("claude", CLAUDE_API_URL, response.status().as_u16());
 
     if !response.status().is_success() {
         let status = response.status();
         if status.as_u16() == 401 {
-            return Err("AUTH_EXPIRED".to_string());
+            return Err(AppError::AuthExpired);
         }
         let body = response.text().await.unwrap_or_default();
         // Truncate error body to avoid leaking huge base64 image data into UI
@@ -138,7 +240,9 @@ pub async fn stream_response(
         } else {
             body
         };
-        return Err(format!("API {}: {}", status, truncated));
+        let message = format!("API {}: {}", status, truncated);
+        crate::crash::record_error(&message);
+        return Err(AppError::Api { status: status.as_u16(), message });
     }
 
     let mut stream = response.bytes_stream();
@@ -151,7 +255,6 @@ pub async fn stream_response(
     let mut current_tool_input_json = String::new();
     let mut stop_reason = String::new();
     let mut input_tokens: u64 = 0;
-    #[allow(unused_assignments)]
     let mut output_tokens: u64 = 0;
 
     while let Some(chunk) = stream.next().await {
@@ -160,9 +263,11 @@ pub async fn stream_response(
                 text_content,
                 tool_uses: Vec::new(),
                 stop_reason: "aborted".to_string(),
+                input_tokens,
+                output_tokens,
             });
         }
-        let chunk = chunk.map_err(|e| format!("Stream error: {}", e))?;
+        let chunk = chunk.map_err(|e| AppError::Network(format!("Stream error: {}", e)))?;
         buffer.push_str(&String::from_utf8_lossy(&chunk));
 
         while let Some(pos) = buffer.find("\n\n") {
@@ -179,6 +284,7 @@ pub async fn stream_response(
                     data = d.to_string();
                 }
             }
+            crate::api_debug::sse_event("claude", &event_type, &data);
 
             match event_type.as_str() {
                 "message_start" => {
@@ -247,6 +353,7 @@ pub async fn stream_response(
                     }
                 }
                 "error" => {
+                    crate::crash::record_error(&data);
                     let _ = on_event.send(ChatStreamEvent::Error {
                         message: data.clone(),
                     });
@@ -260,17 +367,14 @@ pub async fn stream_response(
         text_content,
         tool_uses,
         stop_reason,
+        input_tokens,
+        output_tokens,
     })
 }
 
-pub async fn handle_tool_use(
-    tool_uses: &[(String, String, String)],
-    compaction_settings: &crate::compaction::CompactionSettings,
-    app: &AppHandle,
-    on_event: &Channel<ChatStreamEvent>,
-) -> Vec<ContentBlock> {
-    let workspace = app
-        .store(STORE_FILE)
+/// Workspace directory the hook guard checks tool paths against.
+pub fn tool_workspace(app: &AppHandle) -> String {
+    app.store(STORE_FILE)
         .ok()
         .and_then(|store| store.get("opencode_directory"))
         .and_then(|v| v.as_str().map(|s| s.to_string()))
@@ -280,7 +384,16 @@ pub async fn handle_tool_use(
                 .or_else(|_| std::env::var("USERPROFILE"))
                 .map(|h| format!("{}/.winter/workspace", h))
                 .unwrap_or_else(|_| ".".to_string())
-        });
+        })
+}
+
+pub async fn handle_tool_use(
+    tool_uses: &[(String, String, String)],
+    compaction_settings: &crate::compaction::CompactionSettings,
+    app: &AppHandle,
+    on_event: &Channel<ChatStreamEvent>,
+) -> Vec<ContentBlock> {
+    let workspace = tool_workspace(app);
 
     let mut tool_result_blocks = Vec::new();
     for (id, name, input_json) in tool_uses {
@@ -301,7 +414,29 @@ pub async fn handle_tool_use(
             continue;
         }
 
-        let (raw_output, is_error) = execute_tool(name, &input).await;
+        if !crate::projects::tool_allowed(app, name) {
+            let msg = format!("Tool '{}' is not allowed by the active project or profile tool policy", name);
+            let _ = on_event.send(ChatStreamEvent::ToolEnd {
+                id: id.clone(),
+                result: msg.clone(),
+            });
+            tool_result_blocks.push(ContentBlock::ToolResult {
+                tool_use_id: id.clone(),
+                content: msg,
+                is_error: Some(true),
+            });
+            continue;
+        }
+
+        let (raw_output, is_error) = if name == crate::claude::subagent::DELEGATE_TOOL {
+            crate::claude::subagent::delegate(app, &input, on_event).await
+        } else if name == crate::opencode::delegate::DELEGATE_TOOL {
+            crate::opencode::delegate::delegate(app, &input, on_event).await
+        } else if let Some(result) = crate::webhooks::execute_tool(app, name, &input).await {
+            result
+        } else {
+            execute_tool(name, &input).await
+        };
 
         let output = if compaction_settings.enabled && !is_error && raw_output.len() > 3000 {
             let _ = on_event.send(ChatStreamEvent::CompactionStatus {
@@ -1,4 +1,6 @@
 /// Claude API module — types, HTTP client, and tool execution.
 pub mod client;
+pub mod personas;
+pub mod subagent;
 pub mod tools;
 pub mod types;
@@ -0,0 +1,179 @@
+/// Agent personas: who Winter can delegate to with `delegate_task`.
+///
+/// Sum, Mer, Frost and Spring are built in. They can be edited, and deleting
+/// an edited built-in resets it to its defaults. User-defined agents are added
+/// next to them. Edits and custom agents live in the `agent_personas` store key.
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_PERSONAS: &str = "agent_personas";
+
+const ALL_TOOLS: &[&str] = &["shell_exec", "file_read", "file_write", "file_list"];
+const INSPECT_TOOLS: &[&str] = &["shell_exec", "file_read", "file_list"];
+const READ_ONLY_TOOLS: &[&str] = &["file_read", "file_list"];
+
+/// An agent Winter can delegate to.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AgentPersona {
+    pub id: String,
+    pub name: String,
+    /// One line on what the agent is for, shown to Winter in the tool description.
+    #[serde(default)]
+    pub role: String,
+    /// Agent-specific system prompt, appended to the shared subagent preamble.
+    pub prompt: String,
+    /// Names of the tools the agent may use.
+    pub tools: Vec<String>,
+    /// Claude model for this agent; the app's model when unset.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Set for the built-in sisters, which can be reset but not removed.
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+fn builtin(id: &str, name: &str, role: &str, prompt: &str, tools: &[&str]) -> AgentPersona {
+    AgentPersona {
+        id: id.to_string(),
+        name: name.to_string(),
+        role: role.to_string(),
+        prompt: prompt.to_string(),
+        tools: tools.iter().map(|t| t.to_string()).collect(),
+        model: None,
+        builtin: true,
+    }
+}
+
+/// The built-in sisters with their default settings.
+pub fn builtin_personas() -> Vec<AgentPersona> {
+    vec![
+        builtin(
+            "sum",
+            "Sum",
+            "implementation: writes and edits code, runs builds",
+            "You implement. Make the requested change, keep to the existing style of the code around it, \
+and build or run it to check it works.",
+            ALL_TOOLS,
+        ),
+        builtin(
+            "mer",
+            "Mer",
+            "frontend and visual work: components, styling, layout",
+            "You do frontend and visual work: UI components, styling and layout. \
+Follow the project's existing components and design conventions.",
+            ALL_TOOLS,
+        ),
+        builtin(
+            "frost",
+            "Frost",
+            "QA: reviews changes, runs tests and linters, reports problems without editing",
+            "You are QA. Review what you are pointed at, run the tests, linters and builds that apply, \
+and report concrete problems with file and line. You never edit files.",
+            INSPECT_TOOLS,
+        ),
+        builtin(
+            "spring",
+            "Spring",
+            "research: explores code and docs and reports findings, read-only",
+            "You research. Explore the files you need to answer the question and report what you found, \
+with paths. You only read.",
+            READ_ONLY_TOOLS,
+        ),
+    ]
+}
+
+fn stored(app: &AppHandle) -> Vec<AgentPersona> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_PERSONAS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn write_stored(app: &AppHandle, personas: &[AgentPersona]) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_PERSONAS, json!(personas));
+    store.save().map_err(|e| e.to_string())
+}
+
+/// All personas: the built-ins (with any edits) first, then user-defined ones.
+pub fn list(app: &AppHandle) -> Vec<AgentPersona> {
+    let mut custom = stored(app);
+    let mut personas: Vec<AgentPersona> = builtin_personas()
+        .into_iter()
+        .map(|default| match custom.iter().position(|p| p.id == default.id) {
+            Some(i) => AgentPersona {
+                builtin: true,
+                ..custom.remove(i)
+            },
+            None => default,
+        })
+        .collect();
+    personas.extend(custom.into_iter().map(|p| AgentPersona { builtin: false, ..p }));
+    personas
+}
+
+pub fn find(app: &AppHandle, id: &str) -> Option<AgentPersona> {
+    list(app).into_iter().find(|p| p.id.eq_ignore_ascii_case(id))
+}
+
+fn validate(persona: &AgentPersona) -> Result<(), String> {
+    let id_ok = !persona.id.is_empty()
+        && persona
+            .id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if !id_ok {
+        return Err("Agent ID must be lowercase letters, digits, '-' or '_'".to_string());
+    }
+    if persona.name.trim().is_empty() {
+        return Err("Agent name cannot be empty".to_string());
+    }
+    if persona.prompt.trim().is_empty() {
+        return Err("Agent prompt cannot be empty".to_string());
+    }
+    let known = crate::claude::tools::tool_names();
+    if let Some(unknown) = persona.tools.iter().find(|t| !known.contains(t)) {
+        return Err(format!("Unknown tool '{}'", unknown));
+    }
+    Ok(())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn list_agent_personas(app: AppHandle) -> Vec<AgentPersona> {
+    list(&app)
+}
+
+/// Adds or updates a persona. Saving a built-in stores an edited copy of it.
+#[tauri::command]
+pub fn save_agent_persona(app: AppHandle, mut persona: AgentPersona) -> Result<(), AppError> {
+    persona.id = persona.id.trim().to_string();
+    persona.model = persona.model.filter(|m| !m.trim().is_empty());
+    validate(&persona)?;
+    persona.builtin = builtin_personas().iter().any(|p| p.id == persona.id);
+    let mut personas = stored(&app);
+    match personas.iter_mut().find(|p| p.id == persona.id) {
+        Some(existing) => *existing = persona,
+        None => personas.push(persona),
+    }
+    Ok(write_stored(&app, &personas)?)
+}
+
+/// Removes a user-defined persona, or resets a built-in one to its defaults.
+#[tauri::command]
+pub fn delete_agent_persona(app: AppHandle, id: String) -> Result<(), AppError> {
+    let mut personas = stored(&app);
+    let before = personas.len();
+    personas.retain(|p| p.id != id);
+    if personas.len() == before && !builtin_personas().iter().any(|p| p.id == id) {
+        return Err(AppError::NotFound(format!("Agent '{}' not found", id)));
+    }
+    Ok(write_stored(&app, &personas)?)
+}
@@ -0,0 +1,393 @@
+/// Native subagents ("sisters") Winter can hand work to with the `delegate_task`
+/// tool, so delegation works without an OpenCode server.
+///
+/// Each sister runs as a separate Claude conversation with her own system prompt
+/// and a restricted tool set. Her progress is reported to the parent stream as
+/// `Status` events and her final answer becomes the tool result.
+use crate::claude::personas::{self, AgentPersona};
+use crate::claude::client::{get_model, project_instructions, stream_request, tool_workspace, RequestSpec};
+use crate::claude::tools::{execute_tool, tool_definitions_for};
+use crate::claude::types::{ChatMessage, ChatStreamEvent, ContentBlock, MessageContent};
+use crate::memory::AgentRunLog;
+use futures::stream::{self, StreamExt};
+use reqwest::Client;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tauri::{ipc::Channel, AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+/// Name of the delegation tool in Winter's tool set.
+pub const DELEGATE_TOOL: &str = "delegate_task";
+
+/// Store key of the opt-in automatic review after turns that wrote files.
+pub const STORE_KEY_AUTO_REVIEW: &str = "auto_review";
+
+/// Tools whose successful calls count as file changes for the automatic review.
+const WRITE_TOOLS: &[&str] = &["file_write"];
+
+/// Tool rounds a sister may take before she is stopped.
+const SUBAGENT_MAX_ROUNDS: usize = 15;
+
+/// Sisters running at the same time for one `delegate_task` call.
+const MAX_PARALLEL_SUBAGENTS: usize = 3;
+
+/// Tasks accepted in one `delegate_task` call.
+const MAX_DELEGATED_TASKS: usize = 6;
+
+/// Shared instructions, prefixed to every sister's own prompt.
+const SUBAGENT_PREAMBLE: &str = "\
+You are {name}, one of Winter's sisters. Winter handed you a task. \
+Work on it on your own with your tools; you cannot ask questions, so make sensible assumptions and say which. \
+End with a short report for Winter: what you did, what you found, and anything left open. \
+Be concise. No narration. Results only.";
+
+/// The `delegate_task` tool, listing the configured personas.
+pub fn tool_definition(app: &AppHandle) -> Value {
+    let personas = personas::list(app);
+    let ids: Vec<&str> = personas.iter().map(|a| a.id.as_str()).collect();
+    let mut description = String::from(
+        "Hand a self-contained task to one of your sisters. She works on it with her own tools \
+and returns a report. Use `tasks` to run independent tasks in parallel. Sisters:",
+    );
+    for agent in &personas {
+        description.push_str(&format!("\n- {} ({}): {}", agent.id, agent.name, agent.role));
+    }
+    json!({
+        "name": DELEGATE_TOOL,
+        "description": description,
+        "input_schema": {
+            "type": "object",
+            "properties": {
+                "agent": {
+                    "type": "string",
+                    "enum": ids,
+                    "description": "Which sister to hand the task to"
+                },
+                "task": { "type": "string", "description": "What to do, self-contained: the sister does not see this conversation" },
+                "context": { "type": "string", "description": "Relevant paths, findings or constraints" },
+                "tasks": {
+                    "type": "array",
+                    "description": "Several independent tasks to run in parallel instead of a single agent/task; results come back labeled per sister",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "agent": { "type": "string", "enum": ids },
+                            "task": { "type": "string" },
+                            "context": { "type": "string" }
+                        },
+                        "required": ["agent", "task"]
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn system_prompt(agent: &AgentPersona, workspace: &str) -> String {
+    let mut prompt = format!(
+        "{}\n\n{}",
+        SUBAGENT_PREAMBLE.replace("{name}", &agent.name),
+        agent.prompt
+    );
+    if let Some(instructions) = project_instructions(workspace) {
+        prompt.push_str("\n\n");
+        prompt.push_str(&instructions);
+    }
+    prompt
+}
+
+fn task_prompt(task: &str, context: Option<&str>) -> String {
+    match context.filter(|c| !c.trim().is_empty()) {
+        Some(c) => format!("{}\n\nContext:\n{}", task, c),
+        None => task.to_string(),
+    }
+}
+
+/// Runs one tool call for a sister, enforcing her tool set and the hook guard.
+async fn run_tool(agent: &AgentPersona, workspace: &str, name: &str, input: &Value) -> (String, bool) {
+    if !agent.tools.iter().any(|t| t == name) {
+        return (format!("{} is not available to {}", name, agent.name), true);
+    }
+    let hook_result = crate::hooks::HookGuard::check(name, input, workspace);
+    if hook_result.action == "block" {
+        return (crate::hooks::HookGuard::block_message(&hook_result, name), true);
+    }
+    execute_tool(name, input).await
+}
+
+/// Tokens used over all rounds of a sister's conversation.
+#[derive(Default)]
+struct Usage {
+    input_tokens: u64,
+    output_tokens: u64,
+}
+
+/// Runs `task` with the given sister until she answers, reporting progress on
+/// `parent`, and records the run in the agent history. Returns `(report, is_error)`.
+pub async fn run(
+    app: &AppHandle,
+    agent: &AgentPersona,
+    task: &str,
+    parent: &Channel<ChatStreamEvent>,
+) -> (String, bool) {
+    let started = std::time::Instant::now();
+    let mut usage = Usage::default();
+    let (report, is_error) = converse(app, agent, task, parent, &mut usage).await;
+    let cancelled = app.state::<Arc<AtomicBool>>().load(Ordering::SeqCst);
+    crate::memory::record_agent_run(
+        app,
+        AgentRunLog {
+            agent: agent.name.clone(),
+            action: crate::memory::summarize_prompt(task),
+            result: match (cancelled, is_error) {
+                (true, _) => "cancelled",
+                (false, true) => "failed",
+                (false, false) => "done",
+            }
+            .to_string(),
+            session_id: None,
+            duration_ms: started.elapsed().as_millis() as u64,
+            input_tokens: Some(usage.input_tokens),
+            output_tokens: Some(usage.output_tokens),
+            notes: is_error.then(|| report.clone()),
+        },
+    );
+    (report, is_error)
+}
+
+async fn converse(
+    app: &AppHandle,
+    agent: &AgentPersona,
+    task: &str,
+    parent: &Channel<ChatStreamEvent>,
+    usage: &mut Usage,
+) -> (String, bool) {
+    let abort_flag = app.state::<Arc<AtomicBool>>().inner().clone();
+    let status = |text: String| {
+        let _ = parent.send(ChatStreamEvent::Status { text });
+    };
+    status(format!("Delegating to {}...", agent.name));
+
+    let client = Client::new();
+    let quiet: Channel<ChatStreamEvent> = Channel::new(|_| Ok(()));
+    let workspace = tool_workspace(app);
+    let system = system_prompt(agent, &workspace);
+    let model = agent.model.clone().unwrap_or_else(|| get_model(app));
+    let tools: Vec<&str> = agent.tools.iter().map(String::as_str).collect();
+    let spec = RequestSpec {
+        system_prompt: &system,
+        model: &model,
+        tools: tool_definitions_for(&tools),
+    };
+    let mut conversation = vec![ChatMessage {
+        role: "user".to_string(),
+        content: MessageContent::Text(task.to_string()),
+    }];
+    let mut report = String::new();
+
+    for round in 0..SUBAGENT_MAX_ROUNDS {
+        if abort_flag.load(Ordering::SeqCst) {
+            return (format!("{} was cancelled", agent.name), true);
+        }
+        let access_token = match crate::fresh_access_token(app).await {
+            Ok(t) => t,
+            Err(e) => return (format!("{} could not start: {}", agent.name, e), true),
+        };
+        let result = match stream_request(&client, &access_token, &conversation, &quiet, &abort_flag, &spec).await {
+            Ok(r) => r,
+            Err(e) => return (format!("{} failed: {}", agent.name, e), true),
+        };
+        usage.input_tokens += result.input_tokens;
+        usage.output_tokens += result.output_tokens;
+        crate::budget::record(app, &model, result.input_tokens, result.output_tokens);
+        if !result.text_content.trim().is_empty() {
+            report = result.text_content.trim().to_string();
+        }
+        if result.stop_reason != "tool_use" || result.tool_uses.is_empty() {
+            status(format!("{} finished", agent.name));
+            return (report, false);
+        }
+
+        let mut assistant_blocks = Vec::new();
+        if !result.text_content.is_empty() {
+            assistant_blocks.push(ContentBlock::Text {
+                text: result.text_content,
+            });
+        }
+        let mut result_blocks = Vec::new();
+        for (id, name, input_json) in &result.tool_uses {
+            let input: Value = serde_json::from_str(input_json).unwrap_or(json!({}));
+            status(format!("{}: {} (step {})", agent.name, name, round + 1));
+            let (output, is_error) = run_tool(agent, &workspace, name, &input).await;
+            assistant_blocks.push(ContentBlock::ToolUse {
+                id: id.clone(),
+                name: name.clone(),
+                input,
+            });
+            result_blocks.push(ContentBlock::ToolResult {
+                tool_use_id: id.clone(),
+                content: output,
+                is_error: if is_error { Some(true) } else { None },
+            });
+        }
+        conversation.push(ChatMessage {
+            role: "assistant".to_string(),
+            content: MessageContent::Blocks(assistant_blocks),
+        });
+        conversation.push(ChatMessage {
+            role: "user".to_string(),
+            content: MessageContent::Blocks(result_blocks),
+        });
+    }
+
+    status(format!("{} ran out of steps", agent.name));
+    (
+        format!(
+            "{}\n\n({} stopped after {} tool rounds without a final answer)",
+            report, agent.name, SUBAGENT_MAX_ROUNDS
+        ),
+        false,
+    )
+}
+
+/// One parsed entry of a `delegate_task` call.
+struct Assignment {
+    agent: AgentPersona,
+    prompt: String,
+}
+
+fn parse_assignment(available: &[AgentPersona], input: &Value) -> Result<Assignment, String> {
+    let agent_id = input["agent"].as_str().unwrap_or("");
+    let agent = available
+        .iter()
+        .find(|a| a.id.eq_ignore_ascii_case(agent_id))
+        .cloned()
+        .ok_or_else(|| {
+            let ids: Vec<&str> = available.iter().map(|a| a.id.as_str()).collect();
+            format!("Unknown agent '{}'. Available: {}", agent_id, ids.join(", "))
+        })?;
+    let task = input["task"].as_str().unwrap_or("").trim();
+    if task.is_empty() {
+        return Err(format!("{} needs a task", DELEGATE_TOOL));
+    }
+    Ok(Assignment {
+        agent,
+        prompt: task_prompt(task, input["context"].as_str()),
+    })
+}
+
+/// Executes a `delegate_task` tool call from Winter's conversation: a single
+/// `agent`/`task`, or a `tasks` list run in parallel, at most
+/// `MAX_PARALLEL_SUBAGENTS` at a time.
+pub async fn delegate(app: &AppHandle, input: &Value, parent: &Channel<ChatStreamEvent>) -> (String, bool) {
+    let entries: Vec<Value> = match input["tasks"].as_array() {
+        Some(tasks) if !tasks.is_empty() => tasks.clone(),
+        _ => vec![input.clone()],
+    };
+    if entries.len() > MAX_DELEGATED_TASKS {
+        return (
+            format!("At most {} tasks can be delegated at once", MAX_DELEGATED_TASKS),
+            true,
+        );
+    }
+    let available = personas::list(app);
+    let assignments = match entries.iter().map(|e| parse_assignment(&available, e)).collect::<Result<Vec<_>, _>>() {
+        Ok(a) => a,
+        Err(e) => return (e, true),
+    };
+
+    // Number the labels when the same sister gets several tasks.
+    let labels: Vec<String> = assignments
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            let same = assignments.iter().filter(|b| b.agent.id == a.agent.id).count();
+            if same > 1 {
+                let nth = assignments[..=i].iter().filter(|b| b.agent.id == a.agent.id).count();
+                format!("{} #{}", a.agent.name, nth)
+            } else {
+                a.agent.name.clone()
+            }
+        })
+        .collect();
+
+    if assignments.len() > 1 {
+        let _ = parent.send(ChatStreamEvent::Status {
+            text: format!("Running {} sisters in parallel: {}", assignments.len(), labels.join(", ")),
+        });
+    }
+    let runs: Vec<_> = assignments
+        .iter()
+        .map(|a| run(app, &a.agent, &a.prompt, parent))
+        .collect();
+    let results: Vec<(String, bool)> = stream::iter(runs)
+        .buffered(MAX_PARALLEL_SUBAGENTS)
+        .collect()
+        .await;
+
+    let all_failed = results.iter().all(|(_, is_error)| *is_error);
+    let merged = labels
+        .iter()
+        .zip(&results)
+        .map(|(label, (report, is_error))| {
+            let marker = if *is_error { " (failed)" } else { "" };
+            format!("[{}{}]\n{}", label, marker, report)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    (merged, all_failed)
+}
+
+// ── Automatic review ────────────────────────────────────────────────
+
+pub fn auto_review_enabled(app: &AppHandle) -> bool {
+    app.store(crate::STORE_FILE)
+        .ok()
+        .and_then(|s| s.get(STORE_KEY_AUTO_REVIEW))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Paths written by the successful write-tool calls of one tool round.
+/// `results` are the tool result blocks, in the same order as `tool_uses`.
+pub fn written_paths(tool_uses: &[(String, String, String)], results: &[ContentBlock]) -> Vec<String> {
+    tool_uses
+        .iter()
+        .zip(results)
+        .filter(|((_, name, _), result)| {
+            WRITE_TOOLS.contains(&name.as_str())
+                && !matches!(result, ContentBlock::ToolResult { is_error: Some(true), .. })
+        })
+        .filter_map(|((_, _, input_json), _)| {
+            let input: Value = serde_json::from_str(input_json).ok()?;
+            input["path"].as_str().map(str::to_string)
+        })
+        .collect()
+}
+
+/// Has Frost review the files changed during a turn and emits her findings as
+/// a `Review` event.
+pub async fn review_changes(app: &AppHandle, paths: &[String], on_event: &Channel<ChatStreamEvent>) {
+    let Some(frost) = personas::find(app, "frost") else { return };
+    let mut unique: Vec<&str> = Vec::new();
+    for path in paths {
+        if !unique.contains(&path.as_str()) {
+            unique.push(path);
+        }
+    }
+    let task = format!(
+        "Review these files, which were just changed, for bugs, broken builds and obvious mistakes. \
+Report only real problems, or say that they look fine.\n\n{}",
+        unique.iter().map(|p| format!("- {}", p)).collect::<Vec<_>>().join("\n")
+    );
+    let (text, is_error) = run(app, &frost, &task, on_event).await;
+    if is_error {
+        tracing::warn!("Automatic review failed: {}", text);
+        return;
+    }
+    let _ = on_event.send(ChatStreamEvent::Review {
+        agent: frost.name,
+        text,
+    });
+}
@@ -1,5 +1,5 @@
 /// Tool definitions and execution logic for Claude's function-calling interface.
-/// Provides shell execution, file I/O, and directory listing capabilities.
+/// Provides shell execution, file I/O, directory listing, and symbol outline capabilities.
 use serde_json::{json, Value};
 use std::time::Duration;
 
@@ -47,6 +47,17 @@ pub fn tool_definitions() -> Value {
                 "required": ["path", "content"]
             }
         },
+        {
+            "name": "file_outline",
+            "description": "List the functions, classes and other symbols (or Markdown headings) of a source file with their line ranges. Use it to find where something is defined before reading a large file.",
+            "input_schema": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Absolute path of the file" }
+                },
+                "required": ["path"]
+            }
+        },
         {
             "name": "file_list",
             "description": "List files and directories at the given path.",
@@ -61,15 +72,39 @@ pub fn tool_definitions() -> Value {
     ])
 }
 
+/// Names of the tools in [`tool_definitions`].
+pub fn tool_names() -> Vec<String> {
+    tool_definitions()
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|t| t["name"].as_str().map(str::to_string))
+        .collect()
+}
+
+/// Tool definitions restricted to the given tool names.
+pub fn tool_definitions_for(names: &[&str]) -> Value {
+    let all = tool_definitions();
+    let allowed: Vec<Value> = all
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|t| t["name"].as_str().is_some_and(|n| names.contains(&n)))
+        .cloned()
+        .collect();
+    Value::Array(allowed)
+}
+
 /// Executes a named tool with the given JSON input arguments.
 /// Returns `(output, is_error)` — if `is_error` is true, the output is an error message.
-/// Dispatches to `shell_exec`, `file_read`, `file_write`, or `file_list`.
+/// Dispatches to `shell_exec`, `file_read`, `file_write`, `file_list`, or `file_outline`.
 pub async fn execute_tool(name: &str, input: &Value) -> (String, bool) {
     match name {
         "shell_exec" => exec_shell(input).await,
         "file_read" => read_file(input).await,
         "file_write" => write_file(input).await,
         "file_list" => list_dir(input).await,
+        "file_outline" => file_outline(input).await,
         _ => (format!("Unknown tool: {}", name), true),
     }
 }
@@ -126,6 +161,18 @@ async fn exec_shell(input: &Value) -> (String, bool) {
 }
 
 /// Reads a file at the given path and returns its contents as a string.
+async fn file_outline(input: &Value) -> (String, bool) {
+    let path = input["path"].as_str().unwrap_or("").to_string();
+    let result = tokio::task::spawn_blocking(move || crate::outline::outline(std::path::Path::new(&path)))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|r| r);
+    match result {
+        Ok(outline) => (crate::outline::render(&outline), false),
+        Err(e) => (e, true),
+    }
+}
+
 async fn read_file(input: &Value) -> (String, bool) {
     let path = input["path"].as_str().unwrap_or("");
     match tokio::fs::read_to_string(path).await {
@@ -89,7 +89,8 @@ pub struct ChatMessage {
 
 /// Events emitted from the Claude streaming API to the Tauri frontend via IPC channel.
 /// Each variant maps to a UI update (delta text, tool notification, usage stats, etc.).
-#[derive(Clone, Serialize)]
+/// Deserializable so relay channels can inspect events before forwarding them.
+#[derive(Clone, Serialize, Deserialize)]
 #[serde(tag = "event", content = "data")]
 pub enum ChatStreamEvent {
     /// Emitted once at the start of a new streaming response.
@@ -147,6 +148,38 @@ pub enum ChatStreamEvent {
         /// Status message to display in the UI.
         text: String,
     },
+    /// OpenCode is waiting for the user to answer a question before it continues.
+    #[serde(rename = "question")]
+    Question {
+        /// Question request ID, passed back to `opencode_reply_question`/`opencode_reject_question`.
+        id: String,
+        /// The questions with their options, as sent by OpenCode.
+        questions: serde_json::Value,
+    },
+    /// A pending question was answered or rejected (possibly from another client).
+    #[serde(rename = "question_resolved")]
+    QuestionResolved {
+        /// ID of the resolved question request.
+        id: String,
+    },
+    /// Findings of the automatic review run after a turn that wrote files.
+    #[serde(rename = "review")]
+    Review {
+        /// Name of the reviewing sister.
+        agent: String,
+        /// Her report.
+        text: String,
+    },
+    /// Outcome of a slash command the app handled itself instead of sending it to Claude.
+    #[serde(rename = "command_result")]
+    CommandResult {
+        /// Command name without the slash (e.g. "model").
+        command: String,
+        /// Text to show the user.
+        text: String,
+        /// Whether the command failed.
+        is_error: bool,
+    },
     /// Token usage report for the current message turn.
     #[serde(rename = "usage")]
     Usage {
@@ -169,4 +202,8 @@ pub struct StreamedResponse {
     pub tool_uses: Vec<(String, String, String)>,
     /// API stop reason (e.g. "end_turn", "tool_use", "aborted").
     pub stop_reason: String,
+    /// Input tokens billed for this round.
+    pub input_tokens: u64,
+    /// Output tokens generated in this round.
+    pub output_tokens: u64,
 }
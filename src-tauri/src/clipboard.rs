@@ -0,0 +1,43 @@
+/// Clipboard image access, so a screenshot can be pasted into chat without
+/// saving it to a file first.
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::claude::types::ImageSource;
+use crate::error::AppError;
+
+/// Encodes raw RGBA pixels as PNG.
+fn encode_png(width: usize, height: usize, rgba: &[u8]) -> Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer.write_image_data(rgba).map_err(|e| e.to_string())?;
+    writer.finish().map_err(|e| e.to_string())?;
+    Ok(out)
+}
+
+fn read_image() -> Result<Option<ImageSource>, String> {
+    let mut clipboard = arboard::Clipboard::new().map_err(|e| format!("Cannot open clipboard: {}", e))?;
+    let image = match clipboard.get_image() {
+        Ok(image) => image,
+        Err(arboard::Error::ContentNotAvailable) => return Ok(None),
+        Err(e) => return Err(format!("Cannot read clipboard image: {}", e)),
+    };
+    let png = encode_png(image.width, image.height, &image.bytes)?;
+    Ok(Some(ImageSource {
+        source_type: "base64".to_string(),
+        media_type: "image/png".to_string(),
+        data: STANDARD.encode(png),
+    }))
+}
+
+/// Returns the clipboard image as a base64 PNG source for an `image` content
+/// block, or `None` when the clipboard holds no image.
+#[tauri::command]
+pub async fn get_clipboard_image() -> Result<Option<ImageSource>, AppError> {
+    Ok(tokio::task::spawn_blocking(read_image)
+        .await
+        .map_err(|e| format!("Clipboard task failed: {}", e))??)
+}
@@ -1,7 +1,9 @@
 /// Conversation history compaction module.
 /// Primary provider: Claude Haiku (API) — fast, preserves context.
 /// Fallback provider: Ollama (local) — used when explicitly configured.
+/// Alternative provider: any OpenAI-compatible server (LM Studio, llama.cpp, vLLM).
 use crate::claude::types::{ChatMessage, ContentBlock, MessageContent};
+use crate::ollama::GenerationOptions;
 use crate::STORE_FILE;
 use reqwest::Client;
 use serde::Deserialize;
@@ -20,6 +22,9 @@ const HAIKU_MAX_TOKENS: u32 = 512;
 const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
 const OLLAMA_TIMEOUT: Duration = Duration::from_secs(30);
 
+const DEFAULT_OPENAI_URL: &str = "http://localhost:1234/v1";
+const OPENAI_TIMEOUT: Duration = Duration::from_secs(60);
+
 const MIN_SUMMARIZE_LEN: usize = 500;
 const HISTORY_COMPRESS_THRESHOLD: usize = 10;
 
@@ -35,6 +40,8 @@ Output format: what was decided, what was done, what remains. Nothing else.";
 pub enum CompactionProvider {
     Haiku,
     Ollama,
+    /// Any server exposing `/chat/completions` (LM Studio, llama.cpp server, vLLM).
+    OpenAiCompat,
 }
 
 impl CompactionProvider {
@@ -42,12 +49,14 @@ impl CompactionProvider {
         match self {
             CompactionProvider::Haiku => "haiku",
             CompactionProvider::Ollama => "ollama",
+            CompactionProvider::OpenAiCompat => "openai",
         }
     }
 
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "ollama" => CompactionProvider::Ollama,
+            "openai" | "openai-compatible" => CompactionProvider::OpenAiCompat,
             _ => CompactionProvider::Haiku, // default
         }
     }
@@ -60,6 +69,13 @@ pub struct CompactionSettings {
     pub enabled: bool,
     pub ollama_url: String,
     pub ollama_model: String,
+    /// Generation options (context size, keep_alive, ...) passed to Ollama.
+    pub ollama_options: GenerationOptions,
+    /// Base URL of the OpenAI-compatible server, including the `/v1` prefix.
+    pub openai_url: String,
+    pub openai_model: String,
+    /// Optional bearer token; most local servers accept any value or none.
+    pub openai_api_key: Option<String>,
 }
 
 pub fn get_settings(app: &AppHandle) -> CompactionSettings {
@@ -71,6 +87,10 @@ pub fn get_settings(app: &AppHandle) -> CompactionSettings {
                 enabled: true,
                 ollama_url: DEFAULT_OLLAMA_URL.to_string(),
                 ollama_model: "qwen2.5:7b".to_string(),
+                ollama_options: GenerationOptions::default(),
+                openai_url: DEFAULT_OPENAI_URL.to_string(),
+                openai_model: String::new(),
+                openai_api_key: None,
             };
         }
     };
@@ -108,11 +128,31 @@ pub fn get_settings(app: &AppHandle) -> CompactionSettings {
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "qwen2.5:7b".to_string());
 
+    let openai_url = store
+        .get("compaction_openai_url")
+        .and_then(|v| v.as_str().map(|s| s.trim_end_matches('/').to_string()))
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_OPENAI_URL.to_string());
+
+    let openai_model = store
+        .get("compaction_openai_model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default();
+
+    let openai_api_key = store
+        .get("compaction_openai_key")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.is_empty());
+
     CompactionSettings {
         provider,
         enabled,
         ollama_url,
         ollama_model,
+        ollama_options: crate::ollama::get_generation_options(app),
+        openai_url,
+        openai_model,
+        openai_api_key,
     }
 }
 
@@ -211,7 +251,12 @@ async fn summarize_with_haiku(app: &AppHandle, text: &str) -> Result<String, Str
 
 // ── Ollama Summarizer ───────────────────────────────────────────────
 
-async fn summarize_with_ollama(base_url: &str, model: &str, text: &str) -> Result<String, String> {
+async fn summarize_with_ollama(
+    base_url: &str,
+    model: &str,
+    options: &GenerationOptions,
+    text: &str,
+) -> Result<String, String> {
     if text.len() < MIN_SUMMARIZE_LEN {
         return Ok(text.to_string());
     }
@@ -224,12 +269,12 @@ async fn summarize_with_ollama(base_url: &str, model: &str, text: &str) -> Resul
     let url = format!("{}/api/generate", base_url);
     let prompt = format!("{}\n\n{}", SUMMARIZE_PROMPT, text);
 
-    let body = json!({
+    let mut body = json!({
         "model": model,
         "prompt": prompt,
         "stream": false,
-        "options": { "temperature": 0.3, "num_predict": 512 }
     });
+    options.apply(&mut body);
 
     #[derive(Deserialize)]
     struct GenResp {
@@ -255,6 +300,76 @@ async fn summarize_with_ollama(base_url: &str, model: &str, text: &str) -> Resul
     Ok(data.response.trim().to_string())
 }
 
+// ── OpenAI-Compatible Summarizer ────────────────────────────────────
+
+async fn summarize_with_openai(settings: &CompactionSettings, text: &str) -> Result<String, String> {
+    if text.len() < MIN_SUMMARIZE_LEN {
+        return Ok(text.to_string());
+    }
+    if settings.openai_model.is_empty() {
+        return Err("No model configured for the OpenAI-compatible provider".to_string());
+    }
+
+    let client = Client::builder()
+        .timeout(OPENAI_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+
+    let url = format!("{}/chat/completions", settings.openai_url);
+    let body = json!({
+        "model": settings.openai_model,
+        "temperature": 0.3,
+        "max_tokens": 512,
+        "stream": false,
+        "messages": [
+            { "role": "system", "content": SUMMARIZE_PROMPT },
+            { "role": "user", "content": text }
+        ]
+    });
+
+    #[derive(Deserialize)]
+    struct ChoiceMessage {
+        content: Option<String>,
+    }
+    #[derive(Deserialize)]
+    struct Choice {
+        message: ChoiceMessage,
+    }
+    #[derive(Deserialize)]
+    struct CompletionResp {
+        choices: Vec<Choice>,
+    }
+
+    let mut req = client.post(&url).json(&body);
+    if let Some(key) = &settings.openai_api_key {
+        req = req.header("authorization", format!("Bearer {}", key));
+    }
+    let resp = req
+        .send()
+        .await
+        .map_err(|e| format!("OpenAI-compatible request failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body_text = resp.text().await.unwrap_or_default();
+        return Err(format!("OpenAI-compatible API error {}: {}", status, body_text));
+    }
+
+    let data: CompletionResp = resp
+        .json()
+        .await
+        .map_err(|e| format!("OpenAI-compatible response parse error: {}", e))?;
+
+    let summary = data
+        .choices
+        .into_iter()
+        .next()
+        .and_then(|c| c.message.content)
+        .ok_or_else(|| "OpenAI-compatible server returned empty response".to_string())?;
+
+    Ok(summary.trim().to_string())
+}
+
 // ── Public API ──────────────────────────────────────────────────────
 
 /// Summarizes text using the configured provider (Haiku by default, Ollama as fallback).
@@ -268,23 +383,45 @@ pub async fn summarize(
         return Ok(text.to_string());
     }
 
+    // Fail fast instead of waiting out OLLAMA_TIMEOUT when the health monitor saw it go down.
+    let ollama_offline = crate::ollama::is_known_offline(app);
+
     match settings.provider {
         CompactionProvider::Haiku => {
             match summarize_with_haiku(app, text).await {
                 Ok(s) => Ok(s),
                 Err(e) => {
                     // Haiku failed → try Ollama as fallback
-                    println!("[compaction] Haiku failed ({}), falling back to Ollama", e);
-                    summarize_with_ollama(&settings.ollama_url, &settings.ollama_model, text).await
-                        .map_err(|ollama_err| {
-                            format!("Both Haiku and Ollama failed. Haiku: {}. Ollama: {}", e, ollama_err)
-                        })
+                    if ollama_offline {
+                        return Err(format!("Haiku failed ({}) and Ollama is offline", e));
+                    }
+                    tracing::warn!("Haiku failed ({}), falling back to Ollama", e);
+                    summarize_with_ollama(
+                        &settings.ollama_url,
+                        &settings.ollama_model,
+                        &settings.ollama_options,
+                        text,
+                    )
+                    .await
+                    .map_err(|ollama_err| {
+                        format!("Both Haiku and Ollama failed. Haiku: {}. Ollama: {}", e, ollama_err)
+                    })
                 }
             }
         }
         CompactionProvider::Ollama => {
-            summarize_with_ollama(&settings.ollama_url, &settings.ollama_model, text).await
+            if ollama_offline {
+                return Err("Ollama is offline".to_string());
+            }
+            summarize_with_ollama(
+                &settings.ollama_url,
+                &settings.ollama_model,
+                &settings.ollama_options,
+                text,
+            )
+            .await
         }
+        CompactionProvider::OpenAiCompat => summarize_with_openai(settings, text).await,
     }
 }
 
@@ -441,3 +578,26 @@ fn extract_text_content(content: &MessageContent) -> String {
             .join("\n"),
     }
 }
+
+// ── Forced Compaction ───────────────────────────────────────────────
+
+/// Conversations the user asked to compact with `/compact`. Their history is
+/// compressed on every turn even while automatic compaction is turned off.
+#[derive(Default)]
+pub struct ForcedCompaction(std::sync::Mutex<std::collections::HashSet<String>>);
+
+impl ForcedCompaction {
+    pub fn force(&self, conversation_id: &str) {
+        self.0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(conversation_id.to_string());
+    }
+
+    pub fn is_forced(&self, conversation_id: &str) -> bool {
+        self.0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(conversation_id)
+    }
+}
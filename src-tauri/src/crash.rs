@@ -0,0 +1,192 @@
+//! Panic and crash reporter.
+//!
+//! A panic hook writes a report (message, location, backtrace, app version and
+//! the last commands the frontend invoked) to `<app_data_dir>/crashes/`. The
+//! hook also fires for panics inside spawned async tasks, which the runtime
+//! would otherwise swallow silently. Reports stay on disk until cleared and
+//! are only sent anywhere when the user attaches them to feedback.
+//!
+//! The last error shown to the user (a failed API request or reply stream)
+//! is also remembered in memory, for feedback diagnostics.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::ipc::Invoke;
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::error::AppError;
+
+/// Commands remembered for the next report.
+const RECENT_COMMAND_LIMIT: usize = 20;
+
+/// Reports kept on disk; older ones are deleted when a new one is written.
+const MAX_REPORTS: usize = 20;
+
+static RECENT_COMMANDS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+static LAST_ERROR: Mutex<Option<LastError>> = Mutex::new(None);
+
+/// Longest error message remembered; longer ones are cut.
+const MAX_ERROR_CHARS: usize = 1000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CrashReport {
+    pub id: String,
+    /// RFC 3339 time of the panic.
+    pub timestamp: String,
+    pub app_version: String,
+    pub os: String,
+    pub thread: String,
+    pub message: String,
+    /// `file:line:column` of the panic, when known.
+    pub location: Option<String>,
+    pub backtrace: String,
+    /// Commands invoked before the panic, oldest first.
+    pub recent_commands: Vec<String>,
+}
+
+impl CrashReport {
+    /// A few lines suitable for a feedback message.
+    pub fn summary(&self) -> String {
+        format!(
+            "v{} on {} at {}\n{}{}\nRecent commands: {}",
+            self.app_version,
+            self.os,
+            self.timestamp,
+            self.message,
+            self.location
+                .as_ref()
+                .map(|l| format!(" ({})", l))
+                .unwrap_or_default(),
+            self.recent_commands.join(", "),
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LastError {
+    /// RFC 3339 time the error happened.
+    pub timestamp: String,
+    pub message: String,
+}
+
+/// Remembers `message` as the most recent error shown to the user.
+pub fn record_error(message: &str) {
+    let error = LastError {
+        timestamp: chrono::Local::now().to_rfc3339(),
+        message: message.chars().take(MAX_ERROR_CHARS).collect(),
+    };
+    *LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()) = Some(error);
+}
+
+/// The most recent error recorded since the app started.
+pub fn last_error() -> Option<LastError> {
+    LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+fn record_command(name: &str) {
+    let mut recent = RECENT_COMMANDS.lock().unwrap_or_else(|e| e.into_inner());
+    if recent.len() == RECENT_COMMAND_LIMIT {
+        recent.pop_front();
+    }
+    recent.push_back(name.to_string());
+}
+
+/// Wraps the invoke handler so every command name is remembered for crash reports.
+pub fn recording<R: Runtime>(
+    handler: impl Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        record_command(invoke.message.command());
+        handler(invoke)
+    }
+}
+
+fn crash_dir(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_data_dir().ok().map(|d| d.join("crashes"))
+}
+
+fn write_report(dir: &Path, report: &CrashReport) {
+    if std::fs::create_dir_all(dir).is_err() {
+        return;
+    }
+    let path = dir.join(format!("{}.json", report.id));
+    if let Ok(json) = serde_json::to_string_pretty(report) {
+        let _ = std::fs::write(path, json);
+    }
+    let mut reports = read_reports(dir);
+    for old in reports.drain(..).skip(MAX_REPORTS) {
+        let _ = std::fs::remove_file(dir.join(format!("{}.json", old.id)));
+    }
+}
+
+/// Reports in `dir`, newest first.
+fn read_reports(dir: &Path) -> Vec<CrashReport> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut reports: Vec<CrashReport> = entries
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|x| x == "json"))
+        .filter_map(|e| std::fs::read_to_string(e.path()).ok())
+        .filter_map(|s| serde_json::from_str(&s).ok())
+        .collect();
+    reports.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    reports
+}
+
+/// Installs the panic hook. The previous hook still runs afterwards, so
+/// panics keep showing up on stderr.
+pub fn install(app: &AppHandle) {
+    let Some(dir) = crash_dir(app) else { return };
+    let app_version = app.package_info().version.to_string();
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panic with a non-string payload".to_string());
+        let now = chrono::Local::now();
+        let report = CrashReport {
+            id: format!("crash-{}", now.format("%Y%m%d-%H%M%S%.3f")),
+            timestamp: now.to_rfc3339(),
+            app_version: app_version.clone(),
+            os: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+            thread: std::thread::current().name().unwrap_or("unnamed").to_string(),
+            message,
+            location: info.location().map(|l| l.to_string()),
+            backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+            recent_commands: RECENT_COMMANDS
+                .lock()
+                .map(|r| r.iter().cloned().collect())
+                .unwrap_or_default(),
+        };
+        write_report(&dir, &report);
+        previous(info);
+    }));
+}
+
+/// The newest crash report, if any.
+pub fn latest(app: &AppHandle) -> Option<CrashReport> {
+    read_reports(&crash_dir(app)?).into_iter().next()
+}
+
+// ── Tauri Commands ──────────────────────────────────────────────────
+
+/// Stored crash reports, newest first.
+#[tauri::command]
+pub fn get_crash_reports(app: AppHandle) -> Vec<CrashReport> {
+    crash_dir(&app).map(|d| read_reports(&d)).unwrap_or_default()
+}
+
+#[tauri::command]
+pub fn clear_crash_reports(app: AppHandle) -> Result<(), AppError> {
+    let Some(dir) = crash_dir(&app) else { return Ok(()) };
+    match std::fs::remove_dir_all(&dir) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(AppError::Io(format!("Cannot remove {}: {}", dir.display(), e))),
+    }
+}
@@ -0,0 +1,150 @@
+/// Saved directories for the directory picker: bookmarked favorites
+/// (`directory_bookmarks` store key) and the working directories used most
+/// recently (`recent_directories`, recorded by `set_working_directory`).
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::path::Path;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_BOOKMARKS: &str = "directory_bookmarks";
+const STORE_KEY_RECENT: &str = "recent_directories";
+
+/// Recent working directories kept.
+const MAX_RECENT: usize = 15;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Bookmark {
+    /// Absolute directory path.
+    pub path: String,
+    /// Display name; the directory's own name by default.
+    pub name: String,
+    /// RFC 3339 time the bookmark was added.
+    pub added_at: String,
+    /// Whether the directory still exists. Computed when listing.
+    #[serde(default, skip_deserializing)]
+    pub exists: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecentDirectory {
+    pub path: String,
+    /// RFC 3339 time it was last set as the working directory.
+    pub used_at: String,
+}
+
+fn stored(app: &AppHandle) -> Vec<Bookmark> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_BOOKMARKS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn write_stored(app: &AppHandle, bookmarks: &[Bookmark]) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_BOOKMARKS, json!(bookmarks));
+    store.save().map_err(|e| e.to_string())
+}
+
+fn list(app: &AppHandle) -> Vec<Bookmark> {
+    stored(app)
+        .into_iter()
+        .map(|b| Bookmark { exists: Path::new(&b.path).is_dir(), ..b })
+        .collect()
+}
+
+/// Path without trailing separators, so `/a/b/` and `/a/b` are one bookmark.
+fn normalize(path: &str) -> String {
+    let trimmed = path.trim().trim_end_matches(['/', '\\']);
+    if trimmed.is_empty() { path.trim() } else { trimmed }.to_string()
+}
+
+fn stored_recent(app: &AppHandle) -> Vec<RecentDirectory> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_RECENT))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+/// Moves `path` to the front of the recent directories.
+pub fn record_recent(app: &AppHandle, path: &str) -> Result<(), String> {
+    let path = normalize(path);
+    let mut recent = stored_recent(app);
+    recent.retain(|r| r.path != path);
+    recent.insert(0, RecentDirectory {
+        path,
+        used_at: chrono::Local::now().to_rfc3339(),
+    });
+    recent.truncate(MAX_RECENT);
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_RECENT, json!(recent));
+    store.save().map_err(|e| e.to_string())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Bookmarks in the order they were added.
+#[tauri::command]
+pub fn bookmark_list(app: AppHandle) -> Vec<Bookmark> {
+    list(&app)
+}
+
+/// Bookmarks a directory, or renames an existing bookmark. Returns the updated list.
+#[tauri::command]
+pub fn bookmark_add(app: AppHandle, path: String, name: Option<String>) -> Result<Vec<Bookmark>, AppError> {
+    let path = normalize(&path);
+    let dir = Path::new(&path);
+    if !dir.is_absolute() {
+        return Err(AppError::InvalidInput("Path must be absolute".to_string()));
+    }
+    if !dir.is_dir() {
+        return Err(AppError::InvalidInput(format!("Not a directory: {}", path)));
+    }
+    let name = name
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .or_else(|| dir.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| path.clone());
+
+    let mut bookmarks = stored(&app);
+    match bookmarks.iter_mut().find(|b| b.path == path) {
+        Some(existing) => existing.name = name,
+        None => bookmarks.push(Bookmark {
+            path,
+            name,
+            added_at: chrono::Local::now().to_rfc3339(),
+            exists: true,
+        }),
+    }
+    write_stored(&app, &bookmarks)?;
+    Ok(list(&app))
+}
+
+/// Removes a bookmark. Returns the updated list.
+#[tauri::command]
+pub fn bookmark_remove(app: AppHandle, path: String) -> Result<Vec<Bookmark>, AppError> {
+    let path = normalize(&path);
+    let mut bookmarks = stored(&app);
+    let before = bookmarks.len();
+    bookmarks.retain(|b| b.path != path);
+    if bookmarks.len() == before {
+        return Err(AppError::NotFound(format!("No bookmark for {}", path)));
+    }
+    write_stored(&app, &bookmarks)?;
+    Ok(list(&app))
+}
+
+/// Recent working directories that still exist, most recent first.
+#[tauri::command]
+pub fn get_recent_directories(app: AppHandle, limit: Option<usize>) -> Vec<RecentDirectory> {
+    stored_recent(&app)
+        .into_iter()
+        .filter(|r| Path::new(&r.path).is_dir())
+        .take(limit.unwrap_or(MAX_RECENT))
+        .collect()
+}
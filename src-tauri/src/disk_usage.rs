@@ -0,0 +1,189 @@
+/// Disk usage summary: what is taking up space under a directory.
+///
+/// The tree is walked in parallel without ignore rules or hidden-file
+/// filtering (`node_modules` and `.cache` are exactly what the user wants to
+/// see), symlinks are not followed, and sizes are apparent file sizes. Each
+/// walker thread adds up sizes locally and merges them when it finishes.
+///
+/// Scans are cancelled like searches: a new scan with the same `scan_id`
+/// replaces the running one, and `cancel_disk_usage` stops it. A cancelled
+/// scan returns what it counted so far with `complete: false`.
+use ignore::{WalkBuilder, WalkState};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::error::AppError;
+use crate::search::SearchRegistry;
+
+/// Directory levels broken down below the root at most.
+const MAX_DEPTH: usize = 3;
+
+/// Scan IDs share the search registry; this keeps them apart from search IDs.
+const ID_PREFIX: &str = "disk-usage:";
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Usage {
+    bytes: u64,
+    files: u64,
+}
+
+impl Usage {
+    fn add(&mut self, other: Usage) {
+        self.bytes += other.bytes;
+        self.files += other.files;
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DirUsage {
+    pub path: String,
+    pub name: String,
+    /// 1 for direct subdirectories of the root.
+    pub depth: usize,
+    pub bytes: u64,
+    pub files: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiskUsage {
+    pub path: String,
+    pub total_bytes: u64,
+    pub total_files: u64,
+    /// Bytes in files directly inside the root.
+    pub loose_bytes: u64,
+    /// Subdirectories down to the requested depth, largest first.
+    pub entries: Vec<DirUsage>,
+    /// False when the scan was cancelled before finishing.
+    pub complete: bool,
+    /// Entries that could not be read.
+    pub errors: u64,
+}
+
+/// Sizes keyed by the root-relative directory at each level (empty path = root).
+type Totals = HashMap<PathBuf, Usage>;
+
+/// One walker thread's totals, merged into the shared ones when dropped.
+struct ThreadTotals<'a> {
+    totals: Totals,
+    errors: u64,
+    shared: &'a Mutex<(Totals, u64)>,
+}
+
+impl Drop for ThreadTotals<'_> {
+    fn drop(&mut self) {
+        let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        for (dir, usage) in self.totals.drain() {
+            shared.0.entry(dir).or_default().add(usage);
+        }
+        shared.1 += self.errors;
+    }
+}
+
+impl ThreadTotals<'_> {
+    /// Adds a file to the root and to each of its ancestors down to `depth`.
+    fn add_file(&mut self, rel: &Path, bytes: u64, depth: usize) {
+        let usage = Usage { bytes, files: 1 };
+        self.totals.entry(PathBuf::new()).or_default().add(usage);
+        let mut dir = PathBuf::new();
+        // The last component is the file itself.
+        let parents = rel.components().count().saturating_sub(1);
+        for component in rel.components().take(parents.min(depth)) {
+            dir.push(component);
+            self.totals.entry(dir.clone()).or_default().add(usage);
+        }
+    }
+}
+
+fn scan(root: &Path, depth: usize, cancelled: &AtomicBool) -> DiskUsage {
+    let shared = Mutex::new((Totals::new(), 0u64));
+
+    WalkBuilder::new(root)
+        .standard_filters(false)
+        .build_parallel()
+        .run(|| {
+            let mut local = ThreadTotals { totals: Totals::new(), errors: 0, shared: &shared };
+            Box::new(move |entry| {
+                if cancelled.load(Ordering::Relaxed) {
+                    return WalkState::Quit;
+                }
+                let Ok(entry) = entry else {
+                    local.errors += 1;
+                    return WalkState::Continue;
+                };
+                if !entry.file_type().is_some_and(|t| t.is_file()) {
+                    return WalkState::Continue;
+                }
+                let Ok(meta) = entry.metadata() else {
+                    local.errors += 1;
+                    return WalkState::Continue;
+                };
+                let rel = entry.path().strip_prefix(root).unwrap_or(entry.path());
+                local.add_file(rel, meta.len(), depth);
+                WalkState::Continue
+            })
+        });
+
+    let (mut totals, errors) = shared.into_inner().unwrap_or_else(|e| e.into_inner());
+    let root_usage = totals.remove(Path::new("")).unwrap_or_default();
+    let in_subdirs: u64 = totals
+        .iter()
+        .filter(|(dir, _)| dir.components().count() == 1)
+        .map(|(_, u)| u.bytes)
+        .sum();
+    let mut entries: Vec<DirUsage> = totals
+        .into_iter()
+        .map(|(rel, usage)| DirUsage {
+            path: root.join(&rel).to_string_lossy().to_string(),
+            name: rel.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+            depth: rel.components().count(),
+            bytes: usage.bytes,
+            files: usage.files,
+        })
+        .collect();
+    entries.sort_by_key(|e| std::cmp::Reverse(e.bytes));
+
+    DiskUsage {
+        path: root.to_string_lossy().to_string(),
+        total_bytes: root_usage.bytes,
+        total_files: root_usage.files,
+        loose_bytes: root_usage.bytes - in_subdirs,
+        entries,
+        complete: !cancelled.load(Ordering::SeqCst),
+        errors,
+    }
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Sizes of the subdirectories of `path`, down to `depth` levels (1 by default, 3 at most).
+#[tauri::command]
+pub async fn get_disk_usage(
+    registry: tauri::State<'_, SearchRegistry>,
+    path: String,
+    depth: Option<usize>,
+    scan_id: Option<String>,
+) -> Result<DiskUsage, AppError> {
+    let root = PathBuf::from(&path);
+    if !root.is_dir() {
+        return Err(AppError::InvalidInput(format!("{} is not a directory", path)));
+    }
+    let depth = depth.unwrap_or(1).clamp(1, MAX_DEPTH);
+
+    let id = format!("{}{}", ID_PREFIX, scan_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()));
+    let cancelled = registry.start(&id);
+    let flag = cancelled.clone();
+    let usage = tokio::task::spawn_blocking(move || scan(&root, depth, &flag))
+        .await
+        .map_err(|e| e.to_string());
+    registry.finish(&id, &cancelled);
+    Ok(usage?)
+}
+
+/// Stops the disk usage scan running under `scan_id`, if any.
+#[tauri::command]
+pub fn cancel_disk_usage(registry: tauri::State<'_, SearchRegistry>, scan_id: String) {
+    registry.cancel(&format!("{}{}", ID_PREFIX, scan_id));
+}
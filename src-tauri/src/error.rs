@@ -0,0 +1,136 @@
+/// Typed errors for Tauri commands.
+///
+/// Commands return `Result<T, AppError>`, which reaches the frontend as
+/// `{ "code": "auth_expired", "message": "...", "retryable": false }`, so the
+/// UI can branch on `code` and offer a retry from `retryable` instead of
+/// matching message text. Helpers that still return `Result<T, String>`
+/// convert with `?` in both directions; a plain string becomes `Failed`.
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppError {
+    /// The OAuth token expired and could not be refreshed.
+    AuthExpired,
+    /// Not signed in, or a required credential is missing.
+    NotAuthenticated(String),
+    /// An argument from the caller was rejected.
+    InvalidInput(String),
+    NotFound(String),
+    /// The server could not be reached.
+    Network(String),
+    Timeout(String),
+    /// An upstream API answered with an error status; `message` includes it.
+    Api { status: u16, message: String },
+    /// A local service (OpenCode, Ollama, the scheduler) is not running.
+    Unavailable(String),
+    /// The spending budget is used up; resend with confirmation to go over it.
+    BudgetExceeded(String),
+    Io(String),
+    /// Anything not classified above.
+    Failed(String),
+}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::AuthExpired => "auth_expired",
+            AppError::NotAuthenticated(_) => "not_authenticated",
+            AppError::InvalidInput(_) => "invalid_input",
+            AppError::NotFound(_) => "not_found",
+            AppError::Network(_) => "network",
+            AppError::Timeout(_) => "timeout",
+            AppError::Api { .. } => "api",
+            AppError::Unavailable(_) => "unavailable",
+            AppError::BudgetExceeded(_) => "budget_exceeded",
+            AppError::Io(_) => "io",
+            AppError::Failed(_) => "failed",
+        }
+    }
+
+    /// Whether trying the same call again later may succeed.
+    pub fn retryable(&self) -> bool {
+        match self {
+            AppError::Network(_) | AppError::Timeout(_) | AppError::Unavailable(_) => true,
+            AppError::Api { status, .. } => *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::AuthExpired => write!(f, "Your session has expired. Please sign in again."),
+            AppError::NotAuthenticated(m)
+            | AppError::InvalidInput(m)
+            | AppError::NotFound(m)
+            | AppError::Network(m)
+            | AppError::Timeout(m)
+            | AppError::Unavailable(m)
+            | AppError::BudgetExceeded(m)
+            | AppError::Api { message: m, .. }
+            | AppError::Io(m)
+            | AppError::Failed(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("AppError", 3)?;
+        s.serialize_field("code", self.code())?;
+        s.serialize_field("message", &self.to_string())?;
+        s.serialize_field("retryable", &self.retryable())?;
+        s.end()
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        AppError::Failed(message)
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(message: &str) -> Self {
+        AppError::Failed(message.to_string())
+    }
+}
+
+impl From<AppError> for String {
+    fn from(error: AppError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(error: std::io::Error) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::NotFound => AppError::NotFound(error.to_string()),
+            std::io::ErrorKind::TimedOut => AppError::Timeout(error.to_string()),
+            _ => AppError::Io(error.to_string()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            AppError::Timeout(error.to_string())
+        } else if let Some(status) = error.status() {
+            AppError::Api { status: status.as_u16(), message: error.to_string() }
+        } else {
+            AppError::Network(error.to_string())
+        }
+    }
+}
+
+impl From<tauri_plugin_store::Error> for AppError {
+    fn from(error: tauri_plugin_store::Error) -> Self {
+        AppError::Io(error.to_string())
+    }
+}
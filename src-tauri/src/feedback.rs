@@ -0,0 +1,376 @@
+/// User feedback delivery.
+///
+/// Where feedback goes is a setting (`feedback` store key) rather than a URL
+/// baked into the binary: a Discord webhook, or a relay endpoint that
+/// receives JSON and forwards it (and can rate-limit). Release builds may set
+/// a default relay with the `WINTER_FEEDBACK_RELAY_URL` environment variable
+/// at compile time. Feedback can also be turned off entirely.
+///
+/// Reports can carry diagnostics: app version, OS, the last error and the
+/// tail of the app's logs, with secrets, home paths and email addresses
+/// masked. `get_feedback_diagnostics` shows the user exactly what would be
+/// attached. As an alternative to sending, `open_feedback_issue` opens a
+/// pre-filled GitHub issue in the browser.
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_opener::OpenerExt;
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_SETTINGS: &str = "feedback";
+
+/// Discord caps messages at 2000 characters.
+const DISCORD_MAX_CHARS: usize = 2000;
+
+const ISSUES_URL: &str = "https://github.com/gyugoat/winter-app/issues/new";
+
+/// Browsers and GitHub reject much longer URLs; the issue body is cut to fit.
+const MAX_ISSUE_URL_CHARS: usize = 7500;
+
+/// Log lines attached to diagnostics, from the end of the newest log files.
+const LOG_TAIL_LINES: usize = 40;
+
+/// Bytes read from the end of each log file.
+const LOG_TAIL_BYTES: u64 = 64 * 1024;
+
+/// Secrets and personal details masked in diagnostics.
+static SENSITIVE: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
+    [
+        (r"sk-ant-[A-Za-z0-9_\-]+", "sk-ant-[redacted]"),
+        (r"(?i)\bbearer\s+[A-Za-z0-9._~+/=\-]+", "Bearer [redacted]"),
+        (r"discord(?:app)?\.com/api/webhooks/\S+", "discord.com/api/webhooks/[redacted]"),
+        (r"(?i)\b(token|key|secret|password|auth)=[^\s&]+", "$1=[redacted]"),
+        (r"\b\d{6,}:[A-Za-z0-9_\-]{30,}\b", "[redacted bot token]"),
+        (r"[A-Za-z0-9._%+\-]+@[A-Za-z0-9.\-]+\.[A-Za-z]{2,}", "[email]"),
+    ]
+    .into_iter()
+    .filter_map(|(pattern, replacement)| Some((Regex::new(pattern).ok()?, replacement)))
+    .collect()
+});
+
+/// Where feedback is sent.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum FeedbackDestination {
+    /// A Discord webhook URL (`https://discord.com/api/webhooks/...`).
+    Discord { url: String },
+    /// An HTTPS endpoint that accepts a JSON POST. `token`, when set, is sent
+    /// as a bearer token.
+    Relay {
+        url: String,
+        #[serde(default)]
+        token: Option<String>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeedbackSettings {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// `None` until configured, unless the build has a default relay.
+    #[serde(default = "default_destination")]
+    pub destination: Option<FeedbackDestination>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_destination() -> Option<FeedbackDestination> {
+    option_env!("WINTER_FEEDBACK_RELAY_URL")
+        .filter(|url| !url.is_empty())
+        .map(|url| FeedbackDestination::Relay { url: url.to_string(), token: None })
+}
+
+impl Default for FeedbackSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            destination: default_destination(),
+        }
+    }
+}
+
+pub fn get_settings(app: &AppHandle) -> FeedbackSettings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_SETTINGS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+/// HTTPS is required, except for a relay on this machine.
+fn validate(destination: &FeedbackDestination) -> Result<(), String> {
+    let raw = match destination {
+        FeedbackDestination::Discord { url } | FeedbackDestination::Relay { url, .. } => url.trim(),
+    };
+    let url = reqwest::Url::parse(raw).map_err(|e| format!("Invalid URL '{}': {}", raw, e))?;
+    let host = url.host_str().unwrap_or_default();
+    match destination {
+        FeedbackDestination::Discord { .. } => {
+            let discord_host = ["discord.com", "discordapp.com", "ptb.discord.com", "canary.discord.com"].contains(&host);
+            if url.scheme() != "https" || !discord_host || !url.path().starts_with("/api/webhooks/") {
+                return Err("Not a Discord webhook URL (https://discord.com/api/webhooks/...)".to_string());
+            }
+        }
+        FeedbackDestination::Relay { .. } => {
+            let local = ["localhost", "127.0.0.1", "[::1]"].contains(&host);
+            if url.scheme() != "https" && !(url.scheme() == "http" && local) {
+                return Err("The relay URL must use https".to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Diagnostics {
+    pub app_version: String,
+    /// OS name and version, e.g. "Ubuntu 24.04".
+    pub os: String,
+    pub arch: String,
+    pub last_error: Option<crate::crash::LastError>,
+    /// Newest log lines, oldest first, sanitized.
+    pub logs: Vec<String>,
+}
+
+impl Diagnostics {
+    /// Markdown section for a Discord message or GitHub issue, keeping the
+    /// newest `max_log_lines` log lines.
+    fn markdown(&self, max_log_lines: usize) -> String {
+        let mut out = format!("**Diagnostics**\nWinter v{} on {} ({})\n", self.app_version, self.os, self.arch);
+        if let Some(error) = &self.last_error {
+            out.push_str(&format!("Last error ({}): {}\n", error.timestamp, error.message));
+        }
+        let skip = self.logs.len().saturating_sub(max_log_lines);
+        if skip < self.logs.len() {
+            out.push_str(&format!("```\n{}\n```\n", self.logs[skip..].join("\n")));
+        }
+        out
+    }
+}
+
+/// Masks secrets, email addresses and the home directory in `text`.
+fn sanitize(text: &str) -> String {
+    let mut text = text.to_string();
+    if let Ok(home) = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")) {
+        if home.len() > 1 {
+            text = text.replace(&home, "~");
+        }
+    }
+    for (pattern, replacement) in SENSITIVE.iter() {
+        text = pattern.replace_all(&text, *replacement).into_owned();
+    }
+    text
+}
+
+/// Last lines of the newest `.log` files in `<app_data_dir>/logs`.
+fn recent_log_lines(app: &AppHandle) -> Vec<String> {
+    let Ok(dir) = app.path().app_data_dir().map(|d| d.join("logs")) else { return Vec::new() };
+    let Ok(entries) = std::fs::read_dir(&dir) else { return Vec::new() };
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|x| x == "log"))
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .collect();
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+
+    let mut lines = Vec::new();
+    for (_, path) in files.iter().take(2) {
+        let Ok(mut file) = std::fs::File::open(path) else { continue };
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        let start = len.saturating_sub(LOG_TAIL_BYTES);
+        if file.seek(SeekFrom::Start(start)).is_err() {
+            continue;
+        }
+        let mut bytes = Vec::new();
+        if file.read_to_end(&mut bytes).is_err() {
+            continue;
+        }
+        let text = String::from_utf8_lossy(&bytes);
+        let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        // The first line is likely cut when reading from the middle.
+        let tail: Vec<&str> = text.lines().skip(usize::from(start > 0)).filter(|l| !l.trim().is_empty()).collect();
+        let skip = tail.len().saturating_sub(LOG_TAIL_LINES);
+        lines.extend(tail[skip..].iter().map(|l| format!("[{}] {}", name, sanitize(l))));
+    }
+    let skip = lines.len().saturating_sub(LOG_TAIL_LINES);
+    lines.split_off(skip)
+}
+
+pub fn collect_diagnostics(app: &AppHandle) -> Diagnostics {
+    Diagnostics {
+        app_version: app.package_info().version.to_string(),
+        os: sysinfo::System::long_os_version().unwrap_or_else(|| std::env::consts::OS.to_string()),
+        arch: std::env::consts::ARCH.to_string(),
+        last_error: crate::crash::last_error().map(|mut e| {
+            e.message = sanitize(&e.message);
+            e
+        }),
+        logs: recent_log_lines(app),
+    }
+}
+
+/// Cuts `text` to at most `max` characters.
+fn truncate_chars(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max.saturating_sub(1)).collect();
+    format!("{}…", kept)
+}
+
+async fn post(
+    destination: &FeedbackDestination,
+    text: &str,
+    crash_summary: Option<&str>,
+    diagnostics: Option<&Diagnostics>,
+    app_version: &str,
+) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let request = match destination {
+        FeedbackDestination::Discord { url } => {
+            let crash_section = crash_summary
+                .map(|summary| {
+                    let summary: String = summary.chars().take(800).collect();
+                    format!("\n\n**Latest crash report**\n```\n{}\n```", summary)
+                })
+                .unwrap_or_default();
+            let header = format!("❄️ **User Feedback Received!** (v{})\n>>> ", app_version);
+            let mut room = DISCORD_MAX_CHARS - header.chars().count() - crash_section.chars().count();
+            let diagnostics_section = diagnostics
+                .map(|d| format!("\n\n{}", truncate_chars(&d.markdown(8), room / 2)))
+                .unwrap_or_default();
+            room -= diagnostics_section.chars().count();
+            let text = truncate_chars(text, room);
+            client.post(url.trim()).json(&json!({
+                "username": "Winter Bot",
+                "avatar_url": "https://cdn-icons-png.flaticon.com/512/4712/4712035.png",
+                "content": format!("{}{}{}{}", header, text, crash_section, diagnostics_section),
+            }))
+        }
+        FeedbackDestination::Relay { url, token } => {
+            let request = client.post(url.trim()).json(&json!({
+                "text": text,
+                "app_version": app_version,
+                "crash_report": crash_summary,
+                "diagnostics": diagnostics,
+            }));
+            match token.as_deref().filter(|t| !t.is_empty()) {
+                Some(token) => request.bearer_auth(token),
+                None => request,
+            }
+        }
+    };
+
+    let resp = request
+        .timeout(std::time::Duration::from_secs(20))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send feedback: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("Feedback destination returned {}", resp.status()));
+    }
+    Ok(())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_feedback_settings(app: AppHandle) -> FeedbackSettings {
+    get_settings(&app)
+}
+
+/// Validates and stores the feedback settings.
+#[tauri::command]
+pub fn set_feedback_settings(app: AppHandle, settings: FeedbackSettings) -> Result<(), AppError> {
+    if let Some(destination) = &settings.destination {
+        validate(destination).map_err(AppError::InvalidInput)?;
+    }
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_SETTINGS, json!(settings));
+    Ok(store.save()?)
+}
+
+/// Diagnostics `send_feedback` and `open_feedback_issue` would attach, so the
+/// user can review them first.
+#[tauri::command]
+pub fn get_feedback_diagnostics(app: AppHandle) -> Diagnostics {
+    collect_diagnostics(&app)
+}
+
+/// Sends user feedback text to the configured destination. With
+/// `attach_crash_report`, a summary of the newest crash report is included;
+/// with `include_diagnostics`, the app version, OS, last error and recent
+/// sanitized log lines.
+#[tauri::command]
+pub async fn send_feedback(
+    app: AppHandle,
+    text: String,
+    attach_crash_report: Option<bool>,
+    include_diagnostics: Option<bool>,
+) -> Result<(), AppError> {
+    if text.trim().is_empty() {
+        return Err(AppError::InvalidInput("Feedback text is empty.".to_string()));
+    }
+    let settings = get_settings(&app);
+    if !settings.enabled {
+        return Err(AppError::Unavailable("Feedback is turned off.".to_string()));
+    }
+    let destination = settings
+        .destination
+        .ok_or_else(|| AppError::InvalidInput("No feedback destination is configured.".to_string()))?;
+    validate(&destination).map_err(AppError::InvalidInput)?;
+
+    let crash_summary = attach_crash_report
+        .filter(|attach| *attach)
+        .and_then(|_| crate::crash::latest(&app))
+        .map(|report| sanitize(&report.summary()));
+    let diagnostics = include_diagnostics.filter(|d| *d).map(|_| collect_diagnostics(&app));
+    let app_version = app.package_info().version.to_string();
+    Ok(post(&destination, text.trim(), crash_summary.as_deref(), diagnostics.as_ref(), &app_version).await?)
+}
+
+/// Opens a new GitHub issue pre-filled with `title` and `text` (plus
+/// diagnostics when `include_diagnostics`) in the browser, and returns its
+/// URL. Works even when sending feedback is turned off: nothing is submitted
+/// until the user does so on GitHub.
+#[tauri::command]
+pub fn open_feedback_issue(
+    app: AppHandle,
+    title: String,
+    text: String,
+    include_diagnostics: Option<bool>,
+) -> Result<String, AppError> {
+    let title = match title.trim() {
+        "" => text.lines().next().unwrap_or_default().trim().chars().take(80).collect(),
+        t => t.to_string(),
+    };
+    let mut body = text.trim().to_string();
+    if include_diagnostics.unwrap_or(false) {
+        let diagnostics = collect_diagnostics(&app);
+        body.push_str("\n\n");
+        body.push_str(&diagnostics.markdown(LOG_TAIL_LINES));
+    }
+
+    let base = format!("{}?title={}&body=", ISSUES_URL, urlencoding::encode(&title));
+    let mut encoded = urlencoding::encode(&body).into_owned();
+    // Cut the body (not the encoding) until the URL fits.
+    let mut keep = body.chars().count();
+    while base.len() + encoded.len() > MAX_ISSUE_URL_CHARS && keep > 0 {
+        keep = keep * 3 / 4;
+        encoded = urlencoding::encode(&truncate_chars(&body, keep)).into_owned();
+    }
+    let url = format!("{}{}", base, encoded);
+    app.opener()
+        .open_url(&url, None::<&str>)
+        .map_err(|e| format!("Failed to open the browser: {}", e))?;
+    Ok(url)
+}
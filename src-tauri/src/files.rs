@@ -0,0 +1,647 @@
+/// Native file browsing, independent of the OpenCode server.
+///
+/// `native_list_files` reads a directory straight from disk and returns one
+/// page of entries with their metadata. Only the names and file types of the
+/// whole directory are read for sorting and filtering; metadata is fetched for
+/// the requested page alone, so huge directories stay cheap to browse.
+///
+/// `native_file_content` sniffs the file's MIME type and returns text (in
+/// byte ranges for large files), images as base64 for preview, or a
+/// `binary` marker for everything else.
+///
+/// Deletions from the UI go to the system trash with `trash_path`. Each one is
+/// recorded in the `trashed_by_app` store key, so `list_trash` shows only what
+/// the app deleted and `restore_trashed` can put it back (Windows and Linux;
+/// macOS offers no API to restore from the trash).
+///
+/// `native_rename`, `native_move`, `native_copy` and `native_delete` manage
+/// files without going through the AI tools. They only touch paths inside the
+/// home directory or the working directory (never those directories
+/// themselves), and only replace an existing file when asked to.
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fs::Metadata;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_TRASHED: &str = "trashed_by_app";
+
+/// Files copied per directory tree at most, so a stray copy of `/` can't run forever.
+const MAX_COPY_ENTRIES: usize = 200_000;
+
+/// Slack between our deletion time and the trash's own timestamp, in seconds.
+const TRASH_TIME_SLACK: i64 = 5;
+
+/// Page size when the caller gives no `limit`.
+const DEFAULT_PAGE_SIZE: usize = 500;
+/// Largest page returned in one call.
+const MAX_PAGE_SIZE: usize = 5000;
+
+/// Bytes of text returned per read, and the default range length.
+const MAX_TEXT_READ: u64 = 2 * 1024 * 1024;
+/// Images up to this size are returned for preview.
+const MAX_IMAGE_SIZE: u64 = 10 * 1024 * 1024;
+/// Bytes inspected for magic numbers and NUL bytes.
+const SNIFF_LEN: usize = 8 * 1024;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct FileEntry {
+    pub name: String,
+    pub absolute: String,
+    /// "file", "directory" or "symlink".
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    /// Size in bytes; 0 for directories.
+    pub size: u64,
+    /// Last modification, RFC 3339.
+    pub modified: Option<String>,
+    /// `rwxr-xr-x` style mode on Unix; `r--r--r--` or `rw-rw-rw-` elsewhere.
+    pub permissions: String,
+    pub hidden: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FileListing {
+    pub path: String,
+    pub entries: Vec<FileEntry>,
+    /// Entries matching the filters, across all pages.
+    pub total: usize,
+    pub offset: usize,
+    pub has_more: bool,
+}
+
+fn is_hidden(name: &str) -> bool {
+    name.starts_with('.')
+}
+
+fn kind_of(file_type: &std::fs::FileType) -> &'static str {
+    if file_type.is_symlink() {
+        "symlink"
+    } else if file_type.is_dir() {
+        "directory"
+    } else {
+        "file"
+    }
+}
+
+#[cfg(unix)]
+fn permissions_string(meta: &Metadata) -> String {
+    use std::os::unix::fs::PermissionsExt;
+    let mode = meta.permissions().mode();
+    (0..9)
+        .map(|i| {
+            let bit = 1 << (8 - i);
+            match (mode & bit != 0, i % 3) {
+                (false, _) => '-',
+                (true, 0) => 'r',
+                (true, 1) => 'w',
+                (true, _) => 'x',
+            }
+        })
+        .collect()
+}
+
+#[cfg(not(unix))]
+fn permissions_string(meta: &Metadata) -> String {
+    if meta.permissions().readonly() { "r--r--r--" } else { "rw-rw-rw-" }.to_string()
+}
+
+fn modified_rfc3339(meta: &Metadata) -> Option<String> {
+    meta.modified()
+        .ok()
+        .map(|t| chrono::DateTime::<chrono::Local>::from(t).to_rfc3339())
+}
+
+/// Lowercased extensions without the leading dot.
+fn normalize_extensions(extensions: Option<Vec<String>>) -> Vec<String> {
+    extensions
+        .unwrap_or_default()
+        .iter()
+        .map(|e| e.trim().trim_start_matches('.').to_lowercase())
+        .filter(|e| !e.is_empty())
+        .collect()
+}
+
+fn has_extension(name: &str, extensions: &[String]) -> bool {
+    Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| extensions.contains(&e.to_lowercase()))
+}
+
+fn list_page(
+    dir: &Path,
+    offset: usize,
+    limit: usize,
+    show_hidden: bool,
+    extensions: &[String],
+) -> Result<FileListing, String> {
+    let read = std::fs::read_dir(dir).map_err(|e| format!("Cannot read {}: {}", dir.display(), e))?;
+
+    // (is_dir, name, path, kind) — file types come from the directory entry, no stat needed.
+    let mut names: Vec<(bool, String, PathBuf, &'static str)> = read
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !show_hidden && is_hidden(&name) {
+                return None;
+            }
+            let file_type = entry.file_type().ok()?;
+            let path = entry.path();
+            let is_dir = file_type.is_dir() || (file_type.is_symlink() && path.is_dir());
+            // Extension filters apply to files; directories stay so the user can navigate.
+            if !extensions.is_empty() && !is_dir && !has_extension(&name, extensions) {
+                return None;
+            }
+            Some((is_dir, name, path, kind_of(&file_type)))
+        })
+        .collect();
+    names.sort_by_cached_key(|(is_dir, name, ..)| (!is_dir, name.to_lowercase()));
+
+    let total = names.len();
+    let entries = names
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(|(_, name, path, kind)| {
+            let meta = std::fs::metadata(&path).or_else(|_| std::fs::symlink_metadata(&path)).ok();
+            FileEntry {
+                hidden: is_hidden(&name),
+                absolute: path.to_string_lossy().to_string(),
+                name,
+                kind,
+                size: meta.as_ref().filter(|m| m.is_file()).map_or(0, |m| m.len()),
+                modified: meta.as_ref().and_then(modified_rfc3339),
+                permissions: meta.as_ref().map(permissions_string).unwrap_or_default(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(FileListing {
+        path: dir.to_string_lossy().to_string(),
+        has_more: offset + entries.len() < total,
+        entries,
+        total,
+        offset,
+    })
+}
+
+/// Result of `native_file_content`.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum FileContent {
+    /// UTF-8 text from `offset`. `has_more` is set when the file continues
+    /// past the returned range.
+    Text {
+        mime: String,
+        size: u64,
+        offset: u64,
+        content: String,
+        has_more: bool,
+    },
+    /// An image, base64-encoded.
+    Image { mime: String, size: u64, data: String },
+    /// Anything that is neither text nor a previewable image.
+    Binary { mime: String, size: u64 },
+}
+
+/// MIME type by extension, for text formats magic numbers can't identify.
+fn mime_from_extension(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    Some(match ext.as_str() {
+        "txt" | "log" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "tsv" => "text/tab-separated-values",
+        "js" | "mjs" | "cjs" | "jsx" => "text/javascript",
+        "ts" | "tsx" => "text/typescript",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "yaml" | "yml" => "application/yaml",
+        "toml" => "application/toml",
+        "svg" => "image/svg+xml",
+        "rs" => "text/x-rust",
+        "py" => "text/x-python",
+        "sh" | "bash" => "text/x-shellscript",
+        _ => return None,
+    })
+}
+
+/// Skips UTF-8 continuation bytes at the start (a range that began mid-character)
+/// and drops an incomplete character at the end.
+fn utf8_range(bytes: &[u8]) -> Result<&str, std::str::Utf8Error> {
+    let start = bytes.iter().take(3).take_while(|b| (**b & 0xC0) == 0x80).count();
+    let bytes = &bytes[start..];
+    match std::str::from_utf8(bytes) {
+        Ok(s) => Ok(s),
+        Err(e) if e.error_len().is_none() => Ok(std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+fn read_content(path: &Path, offset: u64, length: u64) -> Result<FileContent, String> {
+    let cannot_read = |e: std::io::Error| format!("Cannot read {}: {}", path.display(), e);
+    let mut file = std::fs::File::open(path).map_err(cannot_read)?;
+    let size = file.metadata().map_err(cannot_read)?.len();
+
+    let mut head = Vec::with_capacity(SNIFF_LEN);
+    (&mut file).take(SNIFF_LEN as u64).read_to_end(&mut head).map_err(cannot_read)?;
+    let sniffed = infer::get(&head);
+    let mime = sniffed
+        .map(|t| t.mime_type())
+        .or_else(|| mime_from_extension(path))
+        .unwrap_or(if head.contains(&0) { "application/octet-stream" } else { "text/plain" })
+        .to_string();
+
+    if mime.starts_with("image/") {
+        if size > MAX_IMAGE_SIZE {
+            return Ok(FileContent::Binary { mime, size });
+        }
+        let bytes = std::fs::read(path).map_err(cannot_read)?;
+        return Ok(FileContent::Image { mime, size, data: STANDARD.encode(bytes) });
+    }
+    let is_text_type = sniffed.is_none_or(|t| t.matcher_type() == infer::MatcherType::Text);
+    if !is_text_type || head.contains(&0) {
+        return Ok(FileContent::Binary { mime, size });
+    }
+
+    let mut bytes = Vec::new();
+    file.seek(SeekFrom::Start(offset)).map_err(cannot_read)?;
+    file.take(length).read_to_end(&mut bytes).map_err(cannot_read)?;
+    match utf8_range(&bytes) {
+        Ok(content) => Ok(FileContent::Text {
+            mime,
+            size,
+            offset,
+            content: content.to_string(),
+            has_more: offset + (bytes.len() as u64) < size,
+        }),
+        Err(_) => Ok(FileContent::Binary { mime: "application/octet-stream".to_string(), size }),
+    }
+}
+
+// ── Trash ─────────────────────────────────────────────────────────────
+
+/// Something the app moved to the trash.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrashedEntry {
+    pub id: String,
+    pub name: String,
+    pub original_path: String,
+    pub is_dir: bool,
+    /// RFC 3339 time of the deletion.
+    pub trashed_at: String,
+    /// The same time as Unix seconds, for matching the system trash entry.
+    pub trashed_at_unix: i64,
+}
+
+fn trashed(app: &AppHandle) -> Vec<TrashedEntry> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_TRASHED))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn write_trashed(app: &AppHandle, entries: &[TrashedEntry]) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_TRASHED, json!(entries));
+    store.save().map_err(|e| e.to_string())
+}
+
+#[cfg(any(target_os = "windows", all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android"))))]
+mod system_trash {
+    use super::TrashedEntry;
+    use std::path::Path;
+    use trash::os_limited;
+
+    /// The system trash item for `entry`: same original path, deleted closest
+    /// to when the app deleted it.
+    fn find(items: &[trash::TrashItem], entry: &TrashedEntry) -> Option<trash::TrashItem> {
+        items
+            .iter()
+            .filter(|item| item.original_path() == Path::new(&entry.original_path))
+            .filter(|item| item.time_deleted >= entry.trashed_at_unix - super::TRASH_TIME_SLACK)
+            .min_by_key(|item| (item.time_deleted - entry.trashed_at_unix).abs())
+            .cloned()
+    }
+
+    /// Entries still in the system trash.
+    pub fn retain_present(entries: Vec<TrashedEntry>) -> Result<Vec<TrashedEntry>, String> {
+        let items = os_limited::list().map_err(|e| format!("Cannot read the trash: {}", e))?;
+        Ok(entries.into_iter().filter(|e| find(&items, e).is_some()).collect())
+    }
+
+    pub fn restore(entry: &TrashedEntry) -> Result<(), String> {
+        let items = os_limited::list().map_err(|e| format!("Cannot read the trash: {}", e))?;
+        let item = find(&items, entry).ok_or_else(|| format!("{} is no longer in the trash", entry.name))?;
+        os_limited::restore_all([item]).map_err(|e| format!("Cannot restore {}: {}", entry.name, e))
+    }
+}
+
+#[cfg(not(any(target_os = "windows", all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android")))))]
+mod system_trash {
+    use super::TrashedEntry;
+
+    /// The trash can't be listed here; keep every record.
+    pub fn retain_present(entries: Vec<TrashedEntry>) -> Result<Vec<TrashedEntry>, String> {
+        Ok(entries)
+    }
+
+    pub fn restore(_entry: &TrashedEntry) -> Result<(), String> {
+        Err("Restoring from the trash is not supported on this platform; use Finder's Put Back".to_string())
+    }
+}
+
+async fn move_to_trash(app: &AppHandle, path: &str) -> Result<TrashedEntry, String> {
+    let target = sandboxed(app, path)?;
+    let meta = std::fs::symlink_metadata(&target).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+    let now = chrono::Local::now();
+    let entry = TrashedEntry {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: file_name(&target),
+        original_path: target.to_string_lossy().to_string(),
+        is_dir: meta.is_dir(),
+        trashed_at: now.to_rfc3339(),
+        trashed_at_unix: now.timestamp(),
+    };
+    tokio::task::spawn_blocking(move || trash::delete(&target))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Cannot move {} to the trash: {}", path, e))?;
+
+    let mut entries = trashed(app);
+    entries.push(entry.clone());
+    write_trashed(app, &entries)?;
+    Ok(entry)
+}
+
+// ── File management ───────────────────────────────────────────────────
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+/// Directories file management may work inside.
+fn sandbox_roots(app: &AppHandle) -> Vec<PathBuf> {
+    let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")).ok();
+    [home, Some(crate::claude::client::tool_workspace(app))]
+        .into_iter()
+        .flatten()
+        .filter_map(|root| std::fs::canonicalize(root).ok())
+        .collect()
+}
+
+/// Resolves `path` through its canonical parent (so `..` and symlinked parents
+/// can't escape, while a symlink itself is handled as a link) and checks it
+/// lies strictly inside one of the sandbox roots. The path need not exist.
+pub(crate) fn sandboxed(app: &AppHandle, path: &str) -> Result<PathBuf, String> {
+    let raw = Path::new(path);
+    if !raw.is_absolute() {
+        return Err("Path must be absolute".to_string());
+    }
+    let (Some(parent), Some(name)) = (raw.parent(), raw.file_name()) else {
+        return Err(format!("Refusing to modify {}", path));
+    };
+    if name == ".." {
+        return Err(format!("Refusing to modify {}", path));
+    }
+    let parent = std::fs::canonicalize(parent).map_err(|e| format!("Cannot resolve {}: {}", parent.display(), e))?;
+    let resolved = parent.join(name);
+    let inside = sandbox_roots(app)
+        .iter()
+        .any(|root| resolved.starts_with(root) && resolved != *root);
+    if !inside {
+        return Err(format!("{} is outside the home and working directories", path));
+    }
+    Ok(resolved)
+}
+
+/// Fails when `target` exists, unless `overwrite` is set and it is a file.
+fn check_target(target: &Path, overwrite: bool) -> Result<(), String> {
+    match std::fs::symlink_metadata(target) {
+        Err(_) => Ok(()),
+        Ok(_) if !overwrite => Err(format!("{} already exists", target.display())),
+        Ok(meta) if meta.is_dir() => Err(format!("Cannot overwrite directory {}", target.display())),
+        Ok(_) => Ok(()),
+    }
+}
+
+fn rename_or_move(from: &Path, to: &Path, overwrite: bool) -> Result<(), String> {
+    if std::fs::symlink_metadata(from).is_err() {
+        return Err(format!("{} does not exist", from.display()));
+    }
+    if to.starts_with(from) {
+        return Err("Cannot move a directory into itself".to_string());
+    }
+    check_target(to, overwrite)?;
+    // Windows refuses to rename onto an existing file; elsewhere rename replaces it atomically.
+    #[cfg(windows)]
+    if overwrite && to.exists() {
+        std::fs::remove_file(to).map_err(|e| format!("Cannot replace {}: {}", to.display(), e))?;
+    }
+    match std::fs::rename(from, to) {
+        Ok(()) => Ok(()),
+        // Different filesystem: copy, then remove the original.
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+            copy_recursive(from, to)?;
+            let removed = if from.is_dir() { std::fs::remove_dir_all(from) } else { std::fs::remove_file(from) };
+            removed.map_err(|e| format!("Copied, but cannot remove {}: {}", from.display(), e))
+        }
+        Err(e) => Err(format!("Cannot move {}: {}", from.display(), e)),
+    }
+}
+
+fn copy_recursive(from: &Path, to: &Path) -> Result<(), String> {
+    let meta = std::fs::symlink_metadata(from).map_err(|e| format!("Cannot read {}: {}", from.display(), e))?;
+    if !meta.is_dir() {
+        return std::fs::copy(from, to)
+            .map(|_| ())
+            .map_err(|e| format!("Cannot copy {}: {}", from.display(), e));
+    }
+    for (i, entry) in walkdir::WalkDir::new(from).into_iter().enumerate() {
+        if i >= MAX_COPY_ENTRIES {
+            return Err(format!("Stopped after {} entries; the tree is too large", MAX_COPY_ENTRIES));
+        }
+        let entry = entry.map_err(|e| format!("Cannot read {}: {}", from.display(), e))?;
+        let rel = entry.path().strip_prefix(from).map_err(|e| e.to_string())?;
+        let dest = to.join(rel);
+        let result = if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest)
+        } else {
+            std::fs::copy(entry.path(), &dest).map(|_| ())
+        };
+        result.map_err(|e| format!("Cannot copy {}: {}", entry.path().display(), e))?;
+    }
+    Ok(())
+}
+
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> Result<T, String> + Send + 'static) -> Result<T, String> {
+    tokio::task::spawn_blocking(f).await.map_err(|e| e.to_string())?
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Lists a directory on the local disk, directories first, then by name.
+/// Hidden (dot) entries are skipped unless `show_hidden`; `extensions`
+/// (e.g. `["rs", ".md"]`) keeps only matching files.
+#[tauri::command]
+pub async fn native_list_files(
+    path: String,
+    offset: Option<usize>,
+    limit: Option<usize>,
+    show_hidden: Option<bool>,
+    extensions: Option<Vec<String>>,
+) -> Result<FileListing, AppError> {
+    let dir = PathBuf::from(&path);
+    if !dir.is_dir() {
+        return Err(AppError::InvalidInput(format!("{} is not a directory", path)));
+    }
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let extensions = normalize_extensions(extensions);
+    let show_hidden = show_hidden.unwrap_or(false);
+    Ok(tokio::task::spawn_blocking(move || list_page(&dir, offset, limit, show_hidden, &extensions))
+        .await
+        .map_err(|e| e.to_string())??)
+}
+
+/// Reads a file for preview. Text is returned from byte `offset` for up to
+/// `length` bytes (2 MB at most); ranges that split a character are trimmed
+/// to whole characters.
+#[tauri::command]
+pub async fn native_file_content(
+    path: String,
+    offset: Option<u64>,
+    length: Option<u64>,
+) -> Result<FileContent, AppError> {
+    let file = PathBuf::from(&path);
+    if !file.is_file() {
+        return Err(AppError::InvalidInput(format!("{} is not a file", path)));
+    }
+    let offset = offset.unwrap_or(0);
+    let length = length.unwrap_or(MAX_TEXT_READ).clamp(1, MAX_TEXT_READ);
+    Ok(tokio::task::spawn_blocking(move || read_content(&file, offset, length))
+        .await
+        .map_err(|e| e.to_string())??)
+}
+
+/// Moves a file or directory to the system trash. Like the other write
+/// commands, only paths inside the home and working directories are accepted.
+#[tauri::command]
+pub async fn trash_path(app: AppHandle, path: String) -> Result<TrashedEntry, AppError> {
+    Ok(move_to_trash(&app, &path).await?)
+}
+
+/// Items the app moved to the trash that are still there, newest first.
+/// Records of items emptied or restored elsewhere are dropped.
+#[tauri::command]
+pub async fn list_trash(app: AppHandle) -> Result<Vec<TrashedEntry>, AppError> {
+    let entries = trashed(&app);
+    let before = entries.len();
+    let mut present = tokio::task::spawn_blocking(move || system_trash::retain_present(entries))
+        .await
+        .map_err(|e| e.to_string())??;
+    if present.len() != before {
+        write_trashed(&app, &present)?;
+    }
+    present.reverse();
+    Ok(present)
+}
+
+/// Restores an item deleted with `trash_path` to its original location.
+#[tauri::command]
+pub async fn restore_trashed(app: AppHandle, id: String) -> Result<(), AppError> {
+    let mut entries = trashed(&app);
+    let index = entries
+        .iter()
+        .position(|e| e.id == id)
+        .ok_or_else(|| AppError::NotFound(format!("No trashed item with ID {}", id)))?;
+    let entry = entries[index].clone();
+    if Path::new(&entry.original_path).exists() {
+        return Err(AppError::InvalidInput(format!("{} already exists", entry.original_path)));
+    }
+    tokio::task::spawn_blocking(move || system_trash::restore(&entry))
+        .await
+        .map_err(|e| e.to_string())??;
+    entries.remove(index);
+    Ok(write_trashed(&app, &entries)?)
+}
+
+/// Renames a file or directory within its directory. Returns the new path.
+#[tauri::command]
+pub async fn native_rename(
+    app: AppHandle,
+    path: String,
+    new_name: String,
+    overwrite: Option<bool>,
+) -> Result<String, AppError> {
+    let new_name = new_name.trim();
+    if new_name.is_empty() || new_name == "." || new_name == ".." || new_name.contains(['/', '\\']) {
+        return Err(AppError::InvalidInput(format!("Invalid name '{}'", new_name)));
+    }
+    let from = sandboxed(&app, &path)?;
+    let to = from.with_file_name(new_name);
+    let result = to.to_string_lossy().to_string();
+    blocking(move || rename_or_move(&from, &to, overwrite.unwrap_or(false))).await?;
+    Ok(result)
+}
+
+/// Moves a file or directory to the full destination path `to`. Returns the new path.
+#[tauri::command]
+pub async fn native_move(app: AppHandle, from: String, to: String, overwrite: Option<bool>) -> Result<String, AppError> {
+    let from = sandboxed(&app, &from)?;
+    let to = sandboxed(&app, &to)?;
+    let result = to.to_string_lossy().to_string();
+    blocking(move || rename_or_move(&from, &to, overwrite.unwrap_or(false))).await?;
+    Ok(result)
+}
+
+/// Copies a file or directory tree to the full destination path `to`. Returns the new path.
+#[tauri::command]
+pub async fn native_copy(app: AppHandle, from: String, to: String, overwrite: Option<bool>) -> Result<String, AppError> {
+    let from = sandboxed(&app, &from)?;
+    let to = sandboxed(&app, &to)?;
+    let result = to.to_string_lossy().to_string();
+    blocking(move || {
+        if std::fs::symlink_metadata(&from).is_err() {
+            return Err(format!("{} does not exist", from.display()));
+        }
+        if to.starts_with(&from) {
+            return Err("Cannot copy a directory into itself".to_string());
+        }
+        check_target(&to, overwrite.unwrap_or(false))?;
+        copy_recursive(&from, &to)
+    })
+    .await?;
+    Ok(result)
+}
+
+/// Deletes a file or directory: to the trash by default (see `trash_path`),
+/// or for good with `permanent`.
+#[tauri::command]
+pub async fn native_delete(app: AppHandle, path: String, permanent: Option<bool>) -> Result<(), AppError> {
+    if !permanent.unwrap_or(false) {
+        move_to_trash(&app, &path).await?;
+        return Ok(());
+    }
+    let target = sandboxed(&app, &path)?;
+    blocking(move || {
+        let meta = std::fs::symlink_metadata(&target).map_err(|e| format!("Cannot read {}: {}", target.display(), e))?;
+        let removed = if meta.is_dir() { std::fs::remove_dir_all(&target) } else { std::fs::remove_file(&target) };
+        removed.map_err(|e| format!("Cannot delete {}: {}", target.display(), e))
+    })
+    .await?;
+    Ok(())
+}
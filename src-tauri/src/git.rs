@@ -0,0 +1,378 @@
+/// Git integration for the file browser, via libgit2 (local operations only).
+///
+/// `root` may be any directory inside a repository; the repository is found
+/// by walking up from it. File paths are reported both relative to the
+/// repository's working directory and as absolute paths, so the file tree can
+/// match them against its own entries.
+use git2::{BranchType, DiffOptions, ErrorCode, IndexAddOption, Patch, Repository, Status, StatusOptions};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+
+/// Changed files reported at most; large untracked trees are cut off here.
+const MAX_STATUS_FILES: usize = 5000;
+
+/// Patch text returned at most across all files of a diff (1 MB).
+const MAX_DIFF_BYTES: usize = 1024 * 1024;
+
+/// Change state of a file on one side (index or working tree).
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FileState {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+    Typechange,
+    Untracked,
+    Conflicted,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitFileStatus {
+    /// Path relative to the working directory, with `/` separators.
+    pub path: String,
+    pub absolute: String,
+    /// Staged change (index vs HEAD).
+    pub index: Option<FileState>,
+    /// Unstaged change (working tree vs index).
+    pub worktree: Option<FileState>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitStatus {
+    /// Working directory of the repository.
+    pub repo_root: String,
+    /// Current branch; `None` on a detached HEAD.
+    pub branch: Option<String>,
+    /// Short ID of the commit HEAD points to; `None` before the first commit.
+    pub head: Option<String>,
+    /// Upstream branch, e.g. "origin/main".
+    pub upstream: Option<String>,
+    /// Commits on the branch that are not on its upstream.
+    pub ahead: usize,
+    /// Commits on the upstream that are not on the branch.
+    pub behind: usize,
+    pub files: Vec<GitFileStatus>,
+    /// True when more than `MAX_STATUS_FILES` files changed.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FileDiff {
+    /// Path relative to the working directory.
+    pub path: String,
+    /// Previous path of a renamed file.
+    pub old_path: Option<String>,
+    pub state: FileState,
+    pub binary: bool,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Unified diff text; empty for binary files and past the size cap.
+    pub patch: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitDiff {
+    pub files: Vec<FileDiff>,
+    /// True when patch text was dropped to stay under `MAX_DIFF_BYTES`.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommitResult {
+    pub id: String,
+    pub short_id: String,
+    pub summary: String,
+    pub files_changed: usize,
+}
+
+fn open(root: &str) -> Result<Repository, String> {
+    let repo = Repository::discover(root).map_err(|e| e.message().to_string())?;
+    if repo.is_bare() {
+        return Err("Bare repositories are not supported".to_string());
+    }
+    Ok(repo)
+}
+
+fn workdir(repo: &Repository) -> PathBuf {
+    repo.workdir().map(Path::to_path_buf).unwrap_or_default()
+}
+
+/// `path` relative to the working directory; absolute paths must lie inside it.
+fn relative_path(workdir: &Path, path: &str) -> Result<String, String> {
+    let p = Path::new(path);
+    let rel = if p.is_absolute() {
+        p.strip_prefix(workdir)
+            .map_err(|_| format!("{} is outside the repository", path))?
+    } else {
+        p
+    };
+    Ok(rel.to_string_lossy().replace('\\', "/"))
+}
+
+fn index_state(status: Status) -> Option<FileState> {
+    if status.is_conflicted() {
+        Some(FileState::Conflicted)
+    } else if status.is_index_new() {
+        Some(FileState::Added)
+    } else if status.is_index_modified() {
+        Some(FileState::Modified)
+    } else if status.is_index_deleted() {
+        Some(FileState::Deleted)
+    } else if status.is_index_renamed() {
+        Some(FileState::Renamed)
+    } else if status.is_index_typechange() {
+        Some(FileState::Typechange)
+    } else {
+        None
+    }
+}
+
+fn worktree_state(status: Status) -> Option<FileState> {
+    if status.is_conflicted() {
+        Some(FileState::Conflicted)
+    } else if status.is_wt_new() {
+        Some(FileState::Untracked)
+    } else if status.is_wt_modified() {
+        Some(FileState::Modified)
+    } else if status.is_wt_deleted() {
+        Some(FileState::Deleted)
+    } else if status.is_wt_renamed() {
+        Some(FileState::Renamed)
+    } else if status.is_wt_typechange() {
+        Some(FileState::Typechange)
+    } else {
+        None
+    }
+}
+
+/// Upstream name and ahead/behind counts of the current branch, if it tracks one.
+fn upstream_info(repo: &Repository, branch: &str) -> Option<(String, usize, usize)> {
+    let local = repo.find_branch(branch, BranchType::Local).ok()?;
+    let upstream = local.upstream().ok()?;
+    let name = upstream.name().ok().flatten()?.to_string();
+    let (ahead, behind) = repo
+        .graph_ahead_behind(local.get().target()?, upstream.get().target()?)
+        .ok()?;
+    Some((name, ahead, behind))
+}
+
+fn status(root: &str) -> Result<Option<GitStatus>, String> {
+    let repo = match Repository::discover(root) {
+        Ok(repo) if repo.is_bare() => return Err("Bare repositories are not supported".to_string()),
+        Ok(repo) => repo,
+        Err(e) if e.code() == ErrorCode::NotFound => return Ok(None),
+        Err(e) => return Err(e.message().to_string()),
+    };
+    let workdir = workdir(&repo);
+
+    let head = repo.head().ok();
+    let branch = match &head {
+        Some(h) if h.is_branch() => h.shorthand().map(str::to_string),
+        Some(_) => None,
+        // Unborn branch (no commits yet): HEAD still names it.
+        None => repo
+            .find_reference("HEAD")
+            .ok()
+            .and_then(|r| r.symbolic_target().map(|t| t.trim_start_matches("refs/heads/").to_string())),
+    };
+    let head_id = head
+        .as_ref()
+        .and_then(|h| h.target())
+        .map(|oid| oid.to_string().chars().take(7).collect());
+    let (upstream, ahead, behind) = match branch.as_deref().and_then(|b| upstream_info(&repo, b)) {
+        Some((name, ahead, behind)) => (Some(name), ahead, behind),
+        None => (None, 0, 0),
+    };
+
+    let mut options = StatusOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(false)
+        .exclude_submodules(true)
+        .renames_head_to_index(true);
+    let statuses = repo.statuses(Some(&mut options)).map_err(|e| e.message().to_string())?;
+
+    let mut files = Vec::new();
+    let mut truncated = false;
+    for entry in statuses.iter() {
+        let status = entry.status();
+        let (index, worktree) = (index_state(status), worktree_state(status));
+        if index.is_none() && worktree.is_none() {
+            continue;
+        }
+        if files.len() == MAX_STATUS_FILES {
+            truncated = true;
+            break;
+        }
+        let path = String::from_utf8_lossy(entry.path_bytes()).to_string();
+        files.push(GitFileStatus {
+            absolute: workdir.join(&path).to_string_lossy().to_string(),
+            path,
+            index,
+            worktree,
+        });
+    }
+
+    Ok(Some(GitStatus {
+        repo_root: workdir.to_string_lossy().to_string(),
+        branch,
+        head: head_id,
+        upstream,
+        ahead,
+        behind,
+        files,
+        truncated,
+    }))
+}
+
+/// Changes of the working tree (staged and unstaged, including untracked
+/// files) against HEAD, optionally limited to `path`.
+fn diff(root: &str, path: Option<&str>) -> Result<GitDiff, String> {
+    let repo = open(root)?;
+    let workdir = workdir(&repo);
+    let head_tree = repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+
+    let mut options = DiffOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .show_untracked_content(true)
+        .ignore_submodules(true);
+    if let Some(path) = path {
+        options.pathspec(relative_path(&workdir, path)?);
+    }
+    let mut diff = repo
+        .diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut options))
+        .map_err(|e| e.message().to_string())?;
+    diff.find_similar(None).map_err(|e| e.message().to_string())?;
+
+    let mut files = Vec::new();
+    let mut budget = MAX_DIFF_BYTES;
+    let mut truncated = false;
+    for idx in 0..diff.deltas().len() {
+        let Some(mut patch) = Patch::from_diff(&diff, idx).map_err(|e| e.message().to_string())? else {
+            continue;
+        };
+        let delta = patch.delta();
+        let state = match delta.status() {
+            git2::Delta::Added | git2::Delta::Untracked => FileState::Added,
+            git2::Delta::Deleted => FileState::Deleted,
+            git2::Delta::Renamed => FileState::Renamed,
+            git2::Delta::Typechange => FileState::Typechange,
+            git2::Delta::Conflicted => FileState::Conflicted,
+            _ => FileState::Modified,
+        };
+        let new_path = delta.new_file().path().map(|p| p.to_string_lossy().to_string());
+        let old_path = delta.old_file().path().map(|p| p.to_string_lossy().to_string());
+        let binary = delta.flags().is_binary();
+        let (_, additions, deletions) = patch.line_stats().map_err(|e| e.message().to_string())?;
+
+        let mut text = String::new();
+        if !binary {
+            let buf = patch.to_buf().map_err(|e| e.message().to_string())?;
+            if buf.len() <= budget {
+                budget -= buf.len();
+                text = String::from_utf8_lossy(&buf).to_string();
+            } else {
+                truncated = true;
+            }
+        }
+        files.push(FileDiff {
+            path: new_path.clone().or_else(|| old_path.clone()).unwrap_or_default(),
+            old_path: old_path.filter(|_| state == FileState::Renamed),
+            state,
+            binary,
+            additions,
+            deletions,
+            patch: text,
+        });
+    }
+    Ok(GitDiff { files, truncated })
+}
+
+/// Stages `paths` (new, modified and deleted files; directories recursively)
+/// and commits the index. With no paths, commits what is already staged.
+fn commit(root: &str, message: &str, paths: &[String]) -> Result<CommitResult, String> {
+    let message = message.trim();
+    if message.is_empty() {
+        return Err("Commit message cannot be empty".to_string());
+    }
+    let repo = open(root)?;
+    let workdir = workdir(&repo);
+    let git_err = |e: git2::Error| e.message().to_string();
+
+    let mut index = repo.index().map_err(git_err)?;
+    if !paths.is_empty() {
+        let specs = paths
+            .iter()
+            .map(|p| relative_path(&workdir, p))
+            .collect::<Result<Vec<_>, _>>()?;
+        index.add_all(&specs, IndexAddOption::DEFAULT, None).map_err(git_err)?;
+        // Picks up deletions, which add_all skips.
+        index.update_all(&specs, None).map_err(git_err)?;
+        index.write().map_err(git_err)?;
+    }
+    if index.has_conflicts() {
+        return Err("Resolve the merge conflicts before committing".to_string());
+    }
+
+    let tree_id = index.write_tree().map_err(git_err)?;
+    let tree = repo.find_tree(tree_id).map_err(git_err)?;
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parent_tree = parent.as_ref().and_then(|c| c.tree().ok());
+    if parent_tree.as_ref().map(|t| t.id()) == Some(tree_id) {
+        return Err("Nothing to commit".to_string());
+    }
+    let files_changed = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+        .map(|d| d.deltas().len())
+        .unwrap_or(0);
+
+    let signature = repo.signature().map_err(|_| {
+        "Set user.name and user.email in your git config to commit".to_string()
+    })?;
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    let oid = repo
+        .commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+        .map_err(git_err)?;
+    let id = oid.to_string();
+    tracing::info!("Committed {} in {}", &id[..7], workdir.display());
+    Ok(CommitResult {
+        short_id: id[..7].to_string(),
+        id,
+        summary: message.lines().next().unwrap_or_default().to_string(),
+        files_changed,
+    })
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Branch, ahead/behind counts and changed files of the repository containing
+/// `root`. Returns `None` when `root` is not inside a git repository.
+#[tauri::command]
+pub async fn get_git_status(root: String) -> Result<Option<GitStatus>, AppError> {
+    Ok(tokio::task::spawn_blocking(move || status(&root))
+        .await
+        .map_err(|e| e.to_string())??)
+}
+
+/// Diff of the working tree against HEAD for the repository containing
+/// `root`, limited to `path` (absolute or repository-relative) when given.
+#[tauri::command]
+pub async fn get_git_diff(root: String, path: Option<String>) -> Result<GitDiff, AppError> {
+    Ok(tokio::task::spawn_blocking(move || diff(&root, path.as_deref()))
+        .await
+        .map_err(|e| e.to_string())??)
+}
+
+/// Stages `paths` and commits them with `message` using the repository's
+/// configured author. Files staged earlier are committed too.
+#[tauri::command]
+pub async fn git_commit(root: String, message: String, paths: Vec<String>) -> Result<CommitResult, AppError> {
+    Ok(tokio::task::spawn_blocking(move || commit(&root, &message, &paths))
+        .await
+        .map_err(|e| e.to_string())??)
+}
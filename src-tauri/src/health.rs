@@ -0,0 +1,241 @@
+/// Aggregate health snapshot for the status panel.
+///
+/// `get_system_health` runs every check concurrently and returns one
+/// `SystemHealth`. `problems` lists what is broken in plain words: the UI
+/// shows "everything is fine" when it is empty and the list otherwise, with
+/// the per-area sections for details.
+use serde::Serialize;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::scheduler::{self, SchedulerHealth, SharedSchedulerState};
+use crate::services::{self, HealthProbe, ServiceStatusInfo};
+use crate::{ollama, opencode, STORE_FILE, STORE_KEY_ACCESS, STORE_KEY_EXPIRES, STORE_KEY_REFRESH};
+
+/// Any HTTP answer from here, even an error status, means the API is reachable.
+const CLAUDE_PROBE_URL: &str = "https://api.anthropic.com/v1/messages";
+
+const CLAUDE_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Free space on the app data volume below which it is reported as low.
+const LOW_DISK_BYTES: u64 = 1024 * 1024 * 1024;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct AuthHealth {
+    pub authenticated: bool,
+    /// Access token expiry (Unix ms).
+    pub expires_at: Option<u64>,
+    pub expired: bool,
+    /// A refresh token is stored, so an expired access token renews itself
+    /// on the next request.
+    pub can_refresh: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ClaudeHealth {
+    pub reachable: bool,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct OllamaHealth {
+    /// Ollama compression is turned on in settings.
+    pub enabled: bool,
+    pub running: bool,
+    pub version: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct OpenCodeHealth {
+    pub enabled: bool,
+    /// Name of the active profile.
+    pub profile: String,
+    pub health: HealthProbe,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DiskHealth {
+    pub path: String,
+    /// `None` when the volume holding `path` could not be determined.
+    pub free_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+    pub low: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SystemHealth {
+    /// True when `problems` is empty.
+    pub healthy: bool,
+    pub problems: Vec<String>,
+    pub checked_at: String,
+    pub auth: AuthHealth,
+    pub claude: ClaudeHealth,
+    pub ollama: OllamaHealth,
+    pub opencode: OpenCodeHealth,
+    pub scheduler: SchedulerHealth,
+    pub failing_services: Vec<ServiceStatusInfo>,
+    pub disk: DiskHealth,
+}
+
+fn auth_health(app: &AppHandle) -> AuthHealth {
+    let store = app.store(STORE_FILE).ok();
+    let get = |key: &str| store.as_ref().and_then(|s| s.get(key));
+    let authenticated = get(STORE_KEY_ACCESS).is_some_and(|v| v.is_string());
+    let expires_at = get(STORE_KEY_EXPIRES).and_then(|v| v.as_u64());
+    AuthHealth {
+        authenticated,
+        expires_at,
+        expired: authenticated && expires_at.is_none_or(|e| crate::now_millis() > e),
+        can_refresh: get(STORE_KEY_REFRESH).is_some_and(|v| v.is_string()),
+    }
+}
+
+async fn claude_health() -> ClaudeHealth {
+    let started = Instant::now();
+    let result = match reqwest::Client::builder().timeout(CLAUDE_PROBE_TIMEOUT).build() {
+        Ok(client) => client.get(CLAUDE_PROBE_URL).send().await.map(|_| ()),
+        Err(e) => Err(e),
+    };
+    ClaudeHealth {
+        reachable: result.is_ok(),
+        latency_ms: started.elapsed().as_millis() as u64,
+        error: result.err().map(|e| e.to_string()),
+    }
+}
+
+async fn ollama_health(app: &AppHandle) -> OllamaHealth {
+    let settings = ollama::get_settings(app);
+    let result = ollama::check_health(&settings.base_url).await;
+    OllamaHealth {
+        enabled: settings.enabled,
+        running: result.is_ok(),
+        error: result.as_ref().err().cloned(),
+        version: result.ok(),
+    }
+}
+
+async fn opencode_health(app: &AppHandle) -> OpenCodeHealth {
+    let enabled = app
+        .store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get("opencode_enabled"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    OpenCodeHealth {
+        enabled,
+        profile: opencode::profiles::active(app).name,
+        health: services::opencode_health(app).await,
+    }
+}
+
+/// Free and total bytes of the volume `path` lives on: the disk with the
+/// longest mount point that contains it.
+fn disk_space(path: &Path) -> Option<(u64, u64)> {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|d| path.starts_with(d.mount_point()))
+        .max_by_key(|d| d.mount_point().as_os_str().len())
+        .map(|d| (d.available_space(), d.total_space()))
+}
+
+async fn disk_health(app: &AppHandle) -> Result<DiskHealth, AppError> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| AppError::Io(format!("App data directory unavailable: {}", e)))?;
+    let probe = dir.clone();
+    let space = tokio::task::spawn_blocking(move || disk_space(&probe))
+        .await
+        .ok()
+        .flatten();
+    Ok(DiskHealth {
+        path: dir.to_string_lossy().to_string(),
+        free_bytes: space.map(|(free, _)| free),
+        total_bytes: space.map(|(_, total)| total),
+        low: space.is_some_and(|(free, _)| free < LOW_DISK_BYTES),
+    })
+}
+
+/// Human-readable list of everything that needs attention.
+fn problems(health: &SystemHealth, services_error: Option<String>) -> Vec<String> {
+    let mut problems = Vec::new();
+    if !health.auth.authenticated {
+        problems.push("Not signed in".to_string());
+    } else if health.auth.expired && !health.auth.can_refresh {
+        problems.push("Session expired; sign in again".to_string());
+    }
+    if let Some(e) = &health.claude.error {
+        problems.push(format!("Claude API unreachable: {}", e));
+    }
+    if health.ollama.enabled && !health.ollama.running {
+        problems.push("Ollama is enabled but not running".to_string());
+    }
+    if health.opencode.enabled && !health.opencode.health.healthy {
+        let reason = health.opencode.health.error.clone().unwrap_or_else(|| "not healthy".to_string());
+        problems.push(format!("OpenCode ({}): {}", health.opencode.profile, reason));
+    }
+    if !health.scheduler.initialized {
+        problems.push("Scheduler is not running".to_string());
+    }
+    for task in &health.scheduler.failing_tasks {
+        problems.push(format!("Task '{}' failed its last run", task));
+    }
+    if let Some(e) = services_error {
+        problems.push(format!("Service status unavailable: {}", e));
+    }
+    for svc in &health.failing_services {
+        problems.push(format!("Service '{}' is down", svc.name));
+    }
+    if health.disk.low {
+        problems.push(format!(
+            "Low disk space: {} MB free for app data",
+            health.disk.free_bytes.unwrap_or(0) / (1024 * 1024)
+        ));
+    }
+    problems
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Checks auth, the Claude API, Ollama, OpenCode, the scheduler, registered
+/// services and app data disk space in one pass.
+#[tauri::command]
+pub async fn get_system_health(
+    app: AppHandle,
+    state: tauri::State<'_, SharedSchedulerState>,
+) -> Result<SystemHealth, AppError> {
+    let (claude, ollama, opencode, scheduler, services, disk) = tokio::join!(
+        claude_health(),
+        ollama_health(&app),
+        opencode_health(&app),
+        scheduler::health(&state),
+        services::failing_services(&app),
+        disk_health(&app),
+    );
+    let (failing_services, services_error) = match services {
+        Ok(list) => (list, None),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+    let mut health = SystemHealth {
+        healthy: true,
+        problems: Vec::new(),
+        checked_at: chrono::Local::now().to_rfc3339(),
+        auth: auth_health(&app),
+        claude,
+        ollama,
+        opencode,
+        scheduler,
+        failing_services,
+        disk: disk?,
+    };
+    health.problems = problems(&health, services_error);
+    health.healthy = health.problems.is_empty();
+    Ok(health)
+}
@@ -68,7 +68,7 @@ impl HookGuard {
         let input_json = match serde_json::to_string(&input) {
             Ok(j) => j,
             Err(e) => {
-                eprintln!("[hooks] Failed to serialize hook input: {}", e);
+                tracing::warn!("Failed to serialize hook input: {}", e);
                 return HookResult::allow();
             }
         };
@@ -83,7 +83,7 @@ impl HookGuard {
         {
             Ok(c) => c,
             Err(e) => {
-                eprintln!("[hooks] Failed to spawn check.py: {}", e);
+                tracing::warn!("Failed to spawn check.py: {}", e);
                 return HookResult::allow();
             }
         };
@@ -92,7 +92,7 @@ impl HookGuard {
         if let Some(stdin) = child.stdin.take() {
             let mut stdin = stdin;
             if let Err(e) = stdin.write_all(input_json.as_bytes()) {
-                eprintln!("[hooks] Failed to write to check.py stdin: {}", e);
+                tracing::warn!("Failed to write to check.py stdin: {}", e);
                 let _ = child.kill();
                 return HookResult::allow();
             }
@@ -108,18 +108,18 @@ impl HookGuard {
         let output = match rx.recv_timeout(Duration::from_secs(HOOK_TIMEOUT_SECS)) {
             Ok(Ok(out)) => out,
             Ok(Err(e)) => {
-                eprintln!("[hooks] check.py process error: {}", e);
+                tracing::warn!("check.py process error: {}", e);
                 return HookResult::allow();
             }
             Err(_) => {
-                eprintln!("[hooks] check.py timed out after {}s", HOOK_TIMEOUT_SECS);
+                tracing::warn!("check.py timed out after {}s", HOOK_TIMEOUT_SECS);
                 return HookResult::allow();
             }
         };
 
         if !output.status.success() {
-            eprintln!(
-                "[hooks] check.py exited with status {}",
+            tracing::warn!(
+                "check.py exited with status {}",
                 output.status.code().unwrap_or(-1)
             );
             return HookResult::allow();
@@ -128,7 +128,7 @@ impl HookGuard {
         let stdout = match std::str::from_utf8(&output.stdout) {
             Ok(s) => s.trim(),
             Err(e) => {
-                eprintln!("[hooks] check.py output is not valid UTF-8: {}", e);
+                tracing::warn!("check.py output is not valid UTF-8: {}", e);
                 return HookResult::allow();
             }
         };
@@ -141,10 +141,10 @@ impl HookGuard {
             Ok(result) => {
                 if result.action == "block" {
                     let msg = result.message.clone().unwrap_or_else(|| "Blocked by hook".to_string());
-                    eprintln!("[hooks] BLOCKED tool '{}': {}", tool_name, msg);
+                    tracing::warn!("BLOCKED tool '{}': {}", tool_name, msg);
                 } else if result.action == "warn" {
-                    eprintln!(
-                        "[hooks] WARN tool '{}': {}",
+                    tracing::warn!(
+                        "WARN tool '{}': {}",
                         tool_name,
                         result.message.as_deref().unwrap_or("no message")
                     );
@@ -152,7 +152,7 @@ impl HookGuard {
                 result
             }
             Err(e) => {
-                eprintln!("[hooks] Failed to parse check.py output: {} (raw: {})", e, stdout);
+                tracing::warn!("Failed to parse check.py output: {} (raw: {})", e, stdout);
                 HookResult::allow()
             }
         }
@@ -0,0 +1,118 @@
+/// Prepares dropped files as chat attachments in one call.
+///
+/// Images become `image` content blocks, small text files are read inline,
+/// and everything else (too large, binary, unreadable) is returned as a path
+/// reference the model can open with its file tools. Limits match the ones
+/// the input box applies to files picked in the browser.
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::claude::types::{ContentBlock, ImageSource};
+
+const MAX_IMAGE_SIZE: u64 = 5 * 1024 * 1024;
+const MAX_TEXT_SIZE: u64 = 500 * 1024;
+
+/// Bytes inspected for NUL when deciding whether a file is binary.
+const BINARY_SNIFF_LEN: usize = 8 * 1024;
+
+/// One prepared attachment.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IngestedFile {
+    /// An image, ready to put in a message.
+    Image { name: String, block: ContentBlock },
+    /// A text file read inline.
+    Text { name: String, path: String, content: String },
+    /// A file passed by path only.
+    Reference {
+        name: String,
+        path: String,
+        size: Option<u64>,
+        /// Why the file was not inlined.
+        reason: String,
+    },
+}
+
+fn image_media_type(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    match ext.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
+}
+
+async fn ingest(path: &str) -> IngestedFile {
+    let p = Path::new(path);
+    let name = p
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string());
+    let reference = |size: Option<u64>, reason: String| IngestedFile::Reference {
+        name: name.clone(),
+        path: path.to_string(),
+        size,
+        reason,
+    };
+
+    let meta = match tokio::fs::metadata(p).await {
+        Ok(meta) => meta,
+        Err(e) => return reference(None, format!("cannot read: {}", e)),
+    };
+    if meta.is_dir() {
+        return reference(None, "directory".to_string());
+    }
+    let size = meta.len();
+
+    if let Some(media_type) = image_media_type(p) {
+        if size > MAX_IMAGE_SIZE {
+            return reference(Some(size), "image larger than 5 MB".to_string());
+        }
+        return match tokio::fs::read(p).await {
+            Ok(bytes) => IngestedFile::Image {
+                name,
+                block: ContentBlock::Image {
+                    source: ImageSource {
+                        source_type: "base64".to_string(),
+                        media_type: media_type.to_string(),
+                        data: STANDARD.encode(bytes),
+                    },
+                },
+            },
+            Err(e) => reference(Some(size), format!("cannot read: {}", e)),
+        };
+    }
+
+    if size > MAX_TEXT_SIZE {
+        return reference(Some(size), "larger than 500 KB".to_string());
+    }
+    let bytes = match tokio::fs::read(p).await {
+        Ok(bytes) => bytes,
+        Err(e) => return reference(Some(size), format!("cannot read: {}", e)),
+    };
+    if bytes[..bytes.len().min(BINARY_SNIFF_LEN)].contains(&0) {
+        return reference(Some(size), "binary".to_string());
+    }
+    match String::from_utf8(bytes) {
+        Ok(content) => IngestedFile::Text {
+            name,
+            path: path.to_string(),
+            content,
+        },
+        Err(_) => reference(Some(size), "not UTF-8 text".to_string()),
+    }
+}
+
+/// Inspects dropped files and returns them in drop order, prepared as attachments.
+#[tauri::command]
+pub async fn ingest_files(paths: Vec<String>) -> Vec<IngestedFile> {
+    let mut files = Vec::with_capacity(paths.len());
+    for path in &paths {
+        files.push(ingest(path).await);
+    }
+    files
+}
@@ -6,19 +6,55 @@
 //! `scheduler`, `services`, `compaction`, `memory`, `modes`).
 
 mod claude;
+mod api_debug;
+mod app_backup;
+mod archive;
+mod backup;
+mod bridge;
+mod budget;
+mod clipboard;
 mod compaction;
+mod crash;
+mod directories;
+mod disk_usage;
+mod error;
+mod feedback;
+mod files;
+mod git;
+mod health;
 mod hooks;
+mod ingest;
+mod log_digest;
+mod logging;
 mod scheduler;
+mod search;
 #[allow(dead_code)]
 mod services;
+mod settings;
 mod memory;
 mod modes;
 #[allow(dead_code)]
 mod ollama;
 mod opencode;
-
-use claude::client::{build_system_prompt, get_model, handle_tool_use, stream_response};
+mod outline;
+mod palette;
+mod personality;
+mod phoenix;
+mod projects;
+mod quick_prompt;
+mod notifications;
+mod slash;
+mod sync;
+mod tabular;
+mod thumbnails;
+mod tray;
+mod tts;
+mod updater;
+mod webhooks;
+
+use claude::client::{build_system_prompt, get_model, handle_tool_use, stream_request};
 use claude::types::{ChatMessage, ChatStreamEvent, ContentBlock, MessageContent};
+use error::AppError;
 use memory::WinterMemoryDB;
 use modes::MessageMode;
 use reqwest::Client;
@@ -65,9 +101,6 @@ fn default_opencode_dir() -> String {
         .unwrap_or_else(|_| ".".to_string())
 }
 
-/// Store key for the MBTI personality modifier.
-const STORE_KEY_MBTI_MODIFIER: &str = "mbti_prompt_modifier";
-
 // ── OAuth PKCE Internals ────────────────────────────────────────────
 
 /// OAuth PKCE verifier/challenge pair, stored in app state until code exchange.
@@ -135,29 +168,29 @@ fn now_millis() -> u64 {
         .as_millis() as u64
 }
 
-/// Reads the access token from the store, returning `AUTH_EXPIRED` if the token has expired.
-fn get_access_token(app: &AppHandle) -> Result<String, String> {
-    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+/// Reads the access token from the store, returning `AppError::AuthExpired` if the token has expired.
+fn get_access_token(app: &AppHandle) -> Result<String, AppError> {
+    let store = app.store(STORE_FILE)?;
     let expires = store
         .get(STORE_KEY_EXPIRES)
         .and_then(|v| v.as_u64())
         .unwrap_or(0);
     if now_millis() > expires {
-        return Err("AUTH_EXPIRED".to_string());
+        return Err(AppError::AuthExpired);
     }
     store
         .get(STORE_KEY_ACCESS)
         .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .ok_or_else(|| "Not authenticated.".to_string())
+        .ok_or_else(|| AppError::NotAuthenticated("Not authenticated.".to_string()))
 }
 
 /// Refreshes the access token using the stored refresh token.
-async fn refresh_access_token(app: &AppHandle) -> Result<String, String> {
-    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+async fn refresh_access_token(app: &AppHandle) -> Result<String, AppError> {
+    let store = app.store(STORE_FILE)?;
     let refresh_token = store
         .get(STORE_KEY_REFRESH)
         .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .ok_or_else(|| "No refresh token.".to_string())?;
+        .ok_or_else(|| AppError::NotAuthenticated("No refresh token.".to_string()))?;
 
     let payload = json!({
         "grant_type": "refresh_token",
@@ -169,13 +202,23 @@ async fn refresh_access_token(app: &AppHandle) -> Result<String, String> {
         .header("content-type", "application/json")
         .json(&payload)
         .send()
-        .await
-        .map_err(|e| format!("{}", e))?;
-
-    if !resp.status().is_success() {
-        return Err(format!("Refresh failed: {}", resp.status()));
+        .await?;
+
+    // A rejected refresh token means signing in again.
+    match resp.status().as_u16() {
+        200..=299 => {}
+        400 | 401 | 403 => {
+            tracing::warn!("Token refresh rejected: {}", resp.status());
+            return Err(AppError::AuthExpired);
+        }
+        status => {
+            return Err(AppError::Api {
+                status,
+                message: format!("Refresh failed: {}", resp.status()),
+            })
+        }
     }
-    let tokens: TokenResponse = resp.json().await.map_err(|e| format!("{}", e))?;
+    let tokens: TokenResponse = resp.json().await?;
 
     store.set(STORE_KEY_ACCESS, json!(tokens.access_token));
     store.set(STORE_KEY_REFRESH, json!(tokens.refresh_token));
@@ -187,32 +230,32 @@ async fn refresh_access_token(app: &AppHandle) -> Result<String, String> {
     Ok(tokens.access_token)
 }
 
-/// Builds an OpenCodeClient from the user's stored URL and directory settings.
-fn get_opencode_client(app: &AppHandle) -> Result<opencode::OpenCodeClient, String> {
-    Ok(opencode::OpenCodeClient::new(
-        get_opencode_url(app),
-        get_opencode_dir(app),
-    ))
+/// Returns a usable access token, refreshing it first if it has expired.
+async fn fresh_access_token(app: &AppHandle) -> Result<String, AppError> {
+    match get_access_token(app) {
+        Ok(t) => Ok(t),
+        Err(AppError::AuthExpired) => {
+            let mutex = app.state::<tokio::sync::Mutex<()>>();
+            let _guard = mutex.lock().await;
+            refresh_access_token(app).await
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Builds an OpenCodeClient for the active OpenCode profile.
+async fn get_opencode_client(app: &AppHandle) -> Result<opencode::OpenCodeClient, String> {
+    opencode::profiles::client(app, opencode::profiles::active(app)).await
 }
 
-/// Reads the OpenCode server URL from the store, falling back to DEFAULT_OPENCODE_URL.
-fn get_opencode_url(app: &AppHandle) -> String {
-    app.store(STORE_FILE)
-        .ok()
-        .and_then(|store| store.get("opencode_url"))
-        .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .filter(|s| !s.is_empty())
-        .unwrap_or_else(|| DEFAULT_OPENCODE_URL.to_string())
+/// Builds an OpenCodeClient for the profile the given OpenCode session was created on.
+async fn get_session_client(app: &AppHandle, oc_session_id: &str) -> Result<opencode::OpenCodeClient, String> {
+    opencode::profiles::client(app, opencode::profiles::for_session(app, oc_session_id)).await
 }
 
-/// Reads the OpenCode workspace directory from the store, falling back to DEFAULT_OPENCODE_DIR.
+/// Workspace directory of the active OpenCode profile.
 fn get_opencode_dir(app: &AppHandle) -> String {
-    app.store(STORE_FILE)
-        .ok()
-        .and_then(|store| store.get("opencode_directory"))
-        .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .filter(|s| !s.is_empty())
-        .unwrap_or_else(default_opencode_dir)
+    opencode::profiles::active(app).directory
 }
 
 // ── OAuth Commands ──────────────────────────────────────────────────
@@ -220,7 +263,7 @@ fn get_opencode_dir(app: &AppHandle) -> String {
 /// Generates the OAuth authorization URL and stores the PKCE verifier in app state.
 /// The returned URL should be opened in a browser for the user to authenticate.
 #[tauri::command]
-fn get_authorize_url(app: AppHandle) -> Result<String, String> {
+fn get_authorize_url(app: AppHandle) -> Result<String, AppError> {
     let (verifier, challenge) = generate_pkce();
     let query = [
         ("code", "true"),
@@ -248,13 +291,13 @@ fn get_authorize_url(app: AppHandle) -> Result<String, String> {
 
 /// Exchanges an OAuth authorization code for access/refresh tokens, storing them persistently.
 #[tauri::command]
-async fn exchange_code(app: AppHandle, code: String) -> Result<(), String> {
+async fn exchange_code(app: AppHandle, code: String) -> Result<(), AppError> {
     let verifier = {
         let state = app.state::<Mutex<Option<PkceState>>>();
         let guard = state.lock().unwrap_or_else(|e| e.into_inner());
         match guard.as_ref() {
             Some(s) => s.verifier.clone(),
-            None => return Err("No PKCE state. Get authorize URL first.".to_string()),
+            None => return Err(AppError::InvalidInput("No PKCE state. Get authorize URL first.".to_string())),
         }
     };
 
@@ -277,7 +320,10 @@ async fn exchange_code(app: AppHandle, code: String) -> Result<(), String> {
         .await
         .map_err(|e| format!("{}", e))?;
     if !resp.status().is_success() {
-        return Err(format!("Token exchange failed: {}", resp.status()));
+        return Err(AppError::Api {
+            status: resp.status().as_u16(),
+            message: format!("Token exchange failed: {}", resp.status()),
+        });
     }
     let tokens: TokenResponse = resp.json().await.map_err(|e| format!("{}", e))?;
 
@@ -297,13 +343,13 @@ async fn exchange_code(app: AppHandle, code: String) -> Result<(), String> {
 
 /// Returns true if a non-expired access token is stored.
 #[tauri::command]
-async fn is_authenticated(app: AppHandle) -> Result<bool, String> {
+async fn is_authenticated(app: AppHandle) -> Result<bool, AppError> {
     Ok(get_access_token(&app).is_ok())
 }
 
 /// Clears all stored OAuth tokens, effectively logging the user out.
 #[tauri::command]
-async fn logout(app: AppHandle) -> Result<(), String> {
+async fn logout(app: AppHandle) -> Result<(), AppError> {
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
     store.delete(STORE_KEY_ACCESS);
     store.delete(STORE_KEY_REFRESH);
@@ -316,13 +362,43 @@ async fn logout(app: AppHandle) -> Result<(), String> {
 
 /// Sends a multi-turn chat to Claude (direct API), streaming events back through the IPC channel.
 /// Handles token refresh, tool-use loops, and optional Ollama history compression.
+/// `mode` prefixes the latest user message, as in `opencode_send`; `conversation_id`
+/// selects the conversation's pinned personality preset. Over the spending budget
+/// the call fails with `budget_exceeded` unless `confirm_budget` is set.
 #[tauri::command]
 async fn chat_send(
     app: AppHandle,
     messages: Vec<ChatMessage>,
+    mode: Option<MessageMode>,
+    conversation_id: Option<String>,
+    confirm_budget: Option<bool>,
     on_event: Channel<ChatStreamEvent>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
+    let on_event = if conversation_id.as_deref().is_some_and(bridge::is_bridge_conversation) {
+        on_event
+    } else {
+        tts::watch_stream(&app, notifications::watch_stream(&app, webhooks::watch_stream(&app, on_event)))
+    };
+    if let Some(command) = slash::parse(&messages) {
+        let _ = on_event.send(ChatStreamEvent::StreamStart);
+        let result = slash::execute(&app, &command, conversation_id.as_deref(), messages.len()).await;
+        let is_error = result.is_err();
+        let _ = on_event.send(ChatStreamEvent::CommandResult {
+            command: command.name.to_string(),
+            text: result.unwrap_or_else(|e| e),
+            is_error,
+        });
+        let _ = on_event.send(ChatStreamEvent::StreamEnd);
+        return Ok(());
+    }
+
+    let model = match budget::gate(&app, confirm_budget.unwrap_or(false))? {
+        budget::Gate::Proceed => get_model(&app),
+        budget::Gate::Model(model) => model,
+        budget::Gate::Ollama => return budget::answer_with_ollama(&app, &messages, &on_event).await,
+    };
     let mut access_token = get_access_token(&app)?;
+    let _tray = tray::busy(&app);
     let client = Client::new();
     let abort_flag = app.state::<Arc<AtomicBool>>();
     abort_flag.store(false, Ordering::SeqCst);
@@ -332,12 +408,32 @@ async fn chat_send(
         return Ok(());
     }
 
-    let system_prompt = build_system_prompt(&app);
-    let model = get_model(&app);
+    let mut system_prompt = build_system_prompt(&app, conversation_id.as_deref());
+    let mut tools = claude::tools::tool_definitions();
+    if let Some(list) = tools.as_array_mut() {
+        list.push(claude::subagent::tool_definition(&app));
+        list.extend(webhooks::tool_definitions(&app));
+    }
+    if opencode::delegate::available(&app).await {
+        if let Some(list) = tools.as_array_mut() {
+            list.push(opencode::delegate::tool_definition());
+        }
+        system_prompt = format!("{}\n\n{}", system_prompt, opencode::delegate::PROMPT_HINT);
+    }
+    projects::restrict_tools(&app, &mut tools);
+    let spec = claude::client::RequestSpec {
+        system_prompt: &system_prompt,
+        model: &model,
+        tools,
+    };
     let mut conversation = messages;
     let compaction_settings = compaction::get_settings(&app);
 
-    if compaction_settings.enabled && conversation.len() > 10 {
+    let compaction_forced = conversation_id
+        .as_deref()
+        .is_some_and(|id| app.state::<compaction::ForcedCompaction>().is_forced(id));
+
+    if (compaction_settings.enabled || compaction_forced) && conversation.len() > 10 {
         let provider_str = compaction_settings.provider.as_str().to_string();
         let _ = on_event.send(ChatStreamEvent::CompactionStatus {
             status: "compressing".to_string(),
@@ -360,13 +456,20 @@ async fn chat_send(
         });
     }
 
+    if let Some(mode) = mode {
+        if let Some(last_user) = conversation.iter_mut().rev().find(|m| m.role == "user") {
+            mode.apply_to_message(last_user);
+        }
+    }
+
+    let mut written_paths = Vec::new();
     for round in 0..MAX_TOOL_ROUNDS {
         if abort_flag.load(Ordering::SeqCst) {
             break;
         }
         if round > 0 {
             if let Err(e) = get_access_token(&app) {
-                if e == "AUTH_EXPIRED" {
+                if e == AppError::AuthExpired {
                     let mutex = app.state::<tokio::sync::Mutex<()>>();
                     let _guard = mutex.lock().await;
                     access_token = refresh_access_token(&app).await?;
@@ -374,36 +477,20 @@ async fn chat_send(
                 }
             }
         }
-        let result = match stream_response(
-            &client,
-            &access_token,
-            &conversation,
-            &on_event,
-            &system_prompt,
-            &abort_flag,
-            &model,
-        )
-        .await
+        let result = match stream_request(&client, &access_token, &conversation, &on_event, &abort_flag, &spec)
+            .await
         {
             Ok(r) => r,
-            Err(e) if e == "AUTH_EXPIRED" => {
+            Err(AppError::AuthExpired) => {
                 let mutex = app.state::<tokio::sync::Mutex<()>>();
                 let _guard = mutex.lock().await;
                 access_token = refresh_access_token(&app).await?;
                 drop(_guard);
-                stream_response(
-                    &client,
-                    &access_token,
-                    &conversation,
-                    &on_event,
-                    &system_prompt,
-                    &abort_flag,
-                    &model,
-                )
-                .await?
+                stream_request(&client, &access_token, &conversation, &on_event, &abort_flag, &spec).await?
             }
             Err(e) => return Err(e),
         };
+        budget::record(&app, &model, result.input_tokens, result.output_tokens);
 
         if result.stop_reason == "aborted" {
             break;
@@ -429,8 +516,11 @@ async fn chat_send(
                 content: MessageContent::Blocks(assistant_blocks),
             });
 
+            tray::set_state(&app, tray::TrayState::RunningTool);
             let tool_result_blocks =
                 handle_tool_use(&result.tool_uses, &compaction_settings, &app, &on_event).await;
+            tray::set_state(&app, tray::TrayState::Streaming);
+            written_paths.extend(claude::subagent::written_paths(&result.tool_uses, &tool_result_blocks));
             conversation.push(ChatMessage {
                 role: "user".to_string(),
                 content: MessageContent::Blocks(tool_result_blocks),
@@ -439,10 +529,92 @@ async fn chat_send(
             break;
         }
     }
+    if !written_paths.is_empty()
+        && !abort_flag.load(Ordering::SeqCst)
+        && claude::subagent::auto_review_enabled(&app)
+    {
+        claude::subagent::review_changes(&app, &written_paths, &on_event).await;
+    }
     let _ = on_event.send(ChatStreamEvent::StreamEnd);
     Ok(())
 }
 
+/// Runs a single prompt through the Claude tool loop without a UI channel and
+/// returns the assistant's text. Used by scheduled prompt tasks.
+async fn run_headless_prompt(
+    app: &AppHandle,
+    prompt: &str,
+    model: Option<&str>,
+) -> Result<String, String> {
+    let client = Client::new();
+    let on_event: Channel<ChatStreamEvent> = Channel::new(|_| Ok(()));
+    let abort_flag = AtomicBool::new(false);
+    let system_prompt = build_system_prompt(app, None);
+    let model = model
+        .filter(|m| !m.is_empty())
+        .map(|m| m.to_string())
+        .unwrap_or_else(|| get_model(app));
+    let mut tools = claude::tools::tool_definitions();
+    if let Some(list) = tools.as_array_mut() {
+        list.push(claude::subagent::tool_definition(app));
+        list.extend(webhooks::tool_definitions(app));
+    }
+    projects::restrict_tools(app, &mut tools);
+    let spec = claude::client::RequestSpec {
+        system_prompt: &system_prompt,
+        model: &model,
+        tools,
+    };
+    let compaction_settings = compaction::get_settings(app);
+    let mut conversation = vec![ChatMessage {
+        role: "user".to_string(),
+        content: MessageContent::Text(prompt.to_string()),
+    }];
+    let mut output = Vec::new();
+
+    for _ in 0..MAX_TOOL_ROUNDS {
+        let access_token = fresh_access_token(app).await?;
+        let result =
+            stream_request(&client, &access_token, &conversation, &on_event, &abort_flag, &spec).await?;
+        budget::record(app, &model, result.input_tokens, result.output_tokens);
+
+        if !result.text_content.trim().is_empty() {
+            output.push(result.text_content.trim().to_string());
+        }
+        if result.stop_reason != "tool_use" || result.tool_uses.is_empty() {
+            break;
+        }
+
+        let mut assistant_blocks = Vec::new();
+        if !result.text_content.is_empty() {
+            assistant_blocks.push(ContentBlock::Text {
+                text: result.text_content,
+            });
+        }
+        for (id, name, input_json) in &result.tool_uses {
+            let input: serde_json::Value =
+                serde_json::from_str(input_json).unwrap_or(json!({}));
+            assistant_blocks.push(ContentBlock::ToolUse {
+                id: id.clone(),
+                name: name.clone(),
+                input,
+            });
+        }
+        conversation.push(ChatMessage {
+            role: "assistant".to_string(),
+            content: MessageContent::Blocks(assistant_blocks),
+        });
+
+        let tool_result_blocks =
+            handle_tool_use(&result.tool_uses, &compaction_settings, app, &on_event).await;
+        conversation.push(ChatMessage {
+            role: "user".to_string(),
+            content: MessageContent::Blocks(tool_result_blocks),
+        });
+    }
+    Ok(output.join("\n\n"))
+}
+
 /// Aborts the currently running chat_send stream by setting the abort flag.
 #[tauri::command]
 fn abort_stream(app: AppHandle) {
@@ -450,51 +622,85 @@ fn abort_stream(app: AppHandle) {
         .store(true, Ordering::SeqCst);
 }
 
-// ── Feedback Command ────────────────────────────────────────────────
+// ── Compaction Commands ─────────────────────────────────────────────
+
+/// Returns the currently configured context-compression provider ("haiku", "ollama", or "openai").
+#[tauri::command]
+async fn compaction_get_provider(app: AppHandle) -> String {
+    compaction::get_settings(&app).provider.as_str().to_string()
+}
 
-/// Sends user feedback text to the Winter Discord webhook.
+/// Persists the context-compression provider choice ("haiku", "ollama", or "openai").
 #[tauri::command]
-async fn send_feedback(_app: AppHandle, text: String) -> Result<(), String> {
-    const DISCORD_WEBHOOK_URL: &str = "https://discord.com/api/webhooks/1472879486923046963/dncdu4PiCQXR6vG7H0Tp6m1WB37MJlArhskCuStnqpiBih7qsrvYzVa2YwGdRwQNK35K";
+async fn compaction_set_provider(app: AppHandle, provider: String) -> Result<(), AppError> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set("compaction_provider", json!(provider));
+    store.save().map_err(|e| e.to_string())?;
+    Ok(())
+}
 
-    if text.trim().is_empty() {
-        return Err("Feedback text is empty.".to_string());
+/// Configures the OpenAI-compatible compaction provider (LM Studio, llama.cpp server, vLLM).
+/// `url` is the API base including `/v1`, e.g. "http://localhost:1234/v1".
+#[tauri::command]
+async fn compaction_set_openai_config(
+    app: AppHandle,
+    url: String,
+    model: String,
+    api_key: Option<String>,
+) -> Result<(), AppError> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(AppError::InvalidInput("URL must start with http:// or https://".to_string()));
     }
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set("compaction_openai_url", json!(url));
+    store.set("compaction_openai_model", json!(model));
+    store.set("compaction_openai_key", json!(api_key.unwrap_or_default()));
+    store.save().map_err(|e| e.to_string())?;
+    Ok(())
+}
 
-    let client = reqwest::Client::new();
-    let payload = serde_json::json!({
-        "username": "Winter Bot",
-        "avatar_url": "https://cdn-icons-png.flaticon.com/512/4712/4712035.png",
-        "content": format!("❄️ **User Feedback Received!**\n>>> {}", text)
-    });
+// ── Language Commands ───────────────────────────────────────────────
 
-    let resp = client
-        .post(DISCORD_WEBHOOK_URL)
-        .json(&payload)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to send webhook: {}", e))?;
+/// Returns the response language preference: "auto", a language code or a language name.
+#[tauri::command]
+async fn get_response_language(app: AppHandle) -> String {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get(claude::client::STORE_KEY_RESPONSE_LANGUAGE))
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "auto".to_string())
+}
 
-    if !resp.status().is_success() {
-        return Err(format!("Discord Error: {}", resp.status()));
+/// Sets the language Winter answers in. "auto" mirrors the user's language;
+/// anything else ("ko", "en", or a name like "German") is enforced in the system prompt.
+#[tauri::command]
+async fn set_response_language(app: AppHandle, language: String) -> Result<(), AppError> {
+    let language = language.trim();
+    if language.is_empty() || language.len() > 40 {
+        return Err(AppError::InvalidInput(
+            "Language must be \"auto\", a language code or a language name".to_string(),
+        ));
     }
-
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(claude::client::STORE_KEY_RESPONSE_LANGUAGE, json!(language));
+    store.save().map_err(|e| e.to_string())?;
     Ok(())
 }
 
-// ── Compaction Commands ─────────────────────────────────────────────
+// ── Subagent Commands ───────────────────────────────────────────────
 
-/// Returns the currently configured context-compression provider ("ollama" or "haiku").
+/// Returns whether Frost automatically reviews files written during a chat turn.
 #[tauri::command]
-async fn compaction_get_provider(app: AppHandle) -> String {
-    compaction::get_settings(&app).provider.as_str().to_string()
+async fn get_auto_review(app: AppHandle) -> bool {
+    claude::subagent::auto_review_enabled(&app)
 }
 
-/// Persists the context-compression provider choice ("ollama" or "haiku").
+/// Enables or disables the automatic Frost review after turns that write files.
 #[tauri::command]
-async fn compaction_set_provider(app: AppHandle, provider: String) -> Result<(), String> {
+async fn set_auto_review(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
-    store.set("compaction_provider", json!(provider));
+    store.set(claude::subagent::STORE_KEY_AUTO_REVIEW, json!(enabled));
     store.save().map_err(|e| e.to_string())?;
     Ok(())
 }
@@ -509,27 +715,97 @@ async fn ollama_is_installed() -> bool {
 
 /// Attempts to install Ollama via the system package manager, or opens the download page.
 #[tauri::command]
-async fn ollama_install(app: AppHandle) -> Result<String, String> {
-    ollama::install(&app).await
+async fn ollama_install(app: AppHandle) -> Result<String, AppError> {
+    Ok(ollama::install(&app).await?)
+}
+
+/// Starts `ollama serve` if Ollama is installed but not running, waiting until it is ready.
+/// Emits `ollama-ready` once the server answers; returns its version string.
+#[tauri::command]
+async fn ollama_start(app: AppHandle) -> Result<String, AppError> {
+    let settings = ollama::get_settings(&app);
+    let server = app.state::<ollama::ServerState>();
+    Ok(ollama::start_server(&app, &server, &settings.base_url).await?)
+}
+
+/// Stops the `ollama serve` process started by `ollama_start`.
+#[tauri::command]
+async fn ollama_stop(app: AppHandle) -> Result<(), AppError> {
+    Ok(ollama::stop_server(&app.state::<ollama::ServerState>()).await?)
 }
 
 /// Checks if the Ollama server is reachable, returning its version string.
 #[tauri::command]
-async fn ollama_check(app: AppHandle) -> Result<String, String> {
+async fn ollama_check(app: AppHandle) -> Result<String, AppError> {
     let settings = ollama::get_settings(&app);
-    ollama::check_health(&settings.base_url).await
+    ollama::check_health(&settings.base_url).await.map_err(AppError::Unavailable)
 }
 
 /// Returns the list of locally available Ollama models.
 #[tauri::command]
-async fn ollama_models(app: AppHandle) -> Result<Vec<String>, String> {
+async fn ollama_models(app: AppHandle) -> Result<Vec<String>, AppError> {
+    let settings = ollama::get_settings(&app);
+    ollama::list_models(&settings.base_url).await.map_err(AppError::Unavailable)
+}
+
+/// Returns the Ollama generation options (num_ctx, num_predict, temperature, keep_alive).
+#[tauri::command]
+async fn ollama_get_options(app: AppHandle) -> ollama::GenerationOptions {
+    ollama::get_generation_options(&app)
+}
+
+/// Persists the Ollama generation options passed through on every generate request.
+/// `keep_alive` accepts a duration ("10m") or seconds ("-1" keeps the model loaded).
+#[tauri::command]
+async fn ollama_set_options(
+    app: AppHandle,
+    options: ollama::GenerationOptions,
+) -> Result<(), AppError> {
+    if options.temperature < 0.0 {
+        return Err(AppError::InvalidInput("Temperature must not be negative".to_string()));
+    }
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set("ollama_num_ctx", json!(options.num_ctx.unwrap_or(0)));
+    store.set("ollama_num_predict", json!(options.num_predict));
+    store.set("ollama_temperature", json!(options.temperature));
+    store.set("ollama_keep_alive", json!(options.keep_alive.unwrap_or_default()));
+    store.save().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Deletes a locally installed Ollama model.
+/// Call once without `confirm` to get the size that would be reclaimed, then again
+/// with `confirm: true` after the user agrees to actually remove it.
+#[tauri::command]
+async fn ollama_delete(
+    app: AppHandle,
+    model: String,
+    confirm: Option<bool>,
+) -> Result<ollama::DeleteReport, AppError> {
     let settings = ollama::get_settings(&app);
-    ollama::list_models(&settings.base_url).await
+    Ok(ollama::delete_model(&settings.base_url, &model, confirm.unwrap_or(false)).await?)
+}
+
+/// Embeds the given texts with the configured Ollama embedding model.
+/// Returns one vector per input, in order.
+#[tauri::command]
+async fn ollama_embed(app: AppHandle, texts: Vec<String>) -> Result<Vec<Vec<f32>>, AppError> {
+    let settings = ollama::get_settings(&app);
+    Ok(ollama::embed(&settings.base_url, &settings.embed_model, &texts).await?)
+}
+
+/// Sets the Ollama model used for embeddings, persisting the setting.
+#[tauri::command]
+async fn ollama_set_embed_model(app: AppHandle, model: String) -> Result<(), AppError> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set("ollama_embed_model", json!(model));
+    store.save().map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 /// Enables or disables Ollama integration, persisting the setting.
 #[tauri::command]
-async fn ollama_toggle(app: AppHandle, enabled: bool) -> Result<(), String> {
+async fn ollama_toggle(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
     store.set("ollama_enabled", json!(enabled));
     store.save().map_err(|e| e.to_string())?;
@@ -538,7 +814,7 @@ async fn ollama_toggle(app: AppHandle, enabled: bool) -> Result<(), String> {
 
 /// Updates the Ollama server URL and model, persisting the settings.
 #[tauri::command]
-async fn ollama_set_config(app: AppHandle, url: String, model: String) -> Result<(), String> {
+async fn ollama_set_config(app: AppHandle, url: String, model: String) -> Result<(), AppError> {
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
     store.set("ollama_url", json!(url));
     store.set("ollama_model", json!(model));
@@ -548,30 +824,35 @@ async fn ollama_set_config(app: AppHandle, url: String, model: String) -> Result
 
 // ── Claude Usage Command ────────────────────────────────────────────
 
-/// Fetches Claude API usage data (rate limit windows) using the token from auth.json.
-/// Reads the OpenCode auth file to reuse the existing Anthropic session token.
-#[tauri::command]
-async fn fetch_claude_usage(_app: AppHandle) -> Result<ClaudeUsage, String> {
-    let home = std::env::var("HOME")
-        .map_err(|_| "Cannot find HOME directory".to_string())?;
-    let auth_path = std::path::PathBuf::from(home).join(".winter/data/opencode/auth.json");
+/// Store key of an external `auth.json` (OpenCode's format) to read the usage
+/// token from when not signed in through Winter. Unset by default.
+const STORE_KEY_USAGE_AUTH_FILE: &str = "claude_usage_auth_file";
+
+/// The configured external auth file, if any.
+fn usage_auth_file(app: &AppHandle) -> Option<std::path::PathBuf> {
+    app.store(STORE_FILE)
+        .ok()?
+        .get(STORE_KEY_USAGE_AUTH_FILE)
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.is_empty())
+        .map(|p| backup::expand_home(&p))
+}
 
-    let auth_content = std::fs::read_to_string(&auth_path)
-        .map_err(|e| format!("Cannot read auth.json: {}", e))?;
+/// Reads the Anthropic access token from an OpenCode-style `auth.json`.
+fn read_auth_file_token(path: &std::path::Path) -> Result<String, AppError> {
+    let auth_content = std::fs::read_to_string(path)
+        .map_err(|e| AppError::NotFound(format!("Cannot read {}: {}", path.display(), e)))?;
     let auth: serde_json::Value = serde_json::from_str(&auth_content)
-        .map_err(|e| format!("Cannot parse auth.json: {}", e))?;
-    let access_token = auth
-        .get("anthropic")
+        .map_err(|e| AppError::Failed(format!("Cannot parse {}: {}", path.display(), e)))?;
+    auth.get("anthropic")
         .and_then(|a| a.get("access"))
         .and_then(|a| a.as_str())
-        .ok_or_else(|| "No access token in auth.json".to_string())?;
-
-    let client = Client::builder()
-        .timeout(std::time::Duration::from_secs(15))
-        .build()
-        .map_err(|e| format!("HTTP client error: {}", e))?;
+        .map(|s| s.to_string())
+        .ok_or_else(|| AppError::NotAuthenticated(format!("No access token in {}", path.display())))
+}
 
-    let body: serde_json::Value = client
+async fn request_claude_usage(client: &Client, access_token: &str) -> Result<serde_json::Value, AppError> {
+    let resp = client
         .get("https://api.anthropic.com/api/oauth/usage")
         .header("authorization", format!("Bearer {}", access_token))
         .header("user-agent", "winter-app")
@@ -579,11 +860,40 @@ async fn fetch_claude_usage(_app: AppHandle) -> Result<ClaudeUsage, String> {
         .header("anthropic-version", "2023-06-01")
         .header("anthropic-beta", "oauth-2025-04-20")
         .send()
-        .await
-        .map_err(|e| format!("Usage request failed: {}", e))?
-        .json()
-        .await
-        .map_err(|e| format!("Usage parse failed: {}", e))?;
+        .await?;
+    match resp.status().as_u16() {
+        200..=299 => Ok(resp.json().await?),
+        401 => Err(AppError::AuthExpired),
+        status => Err(AppError::Api { status, message: format!("Usage request failed: {}", resp.status()) }),
+    }
+}
+
+/// Fetches Claude API usage data (rate limit windows) with the in-app OAuth
+/// token, refreshing it when it has expired or is rejected. Without an in-app
+/// sign-in, falls back to the external auth file if one is configured.
+#[tauri::command]
+async fn fetch_claude_usage(app: AppHandle) -> Result<ClaudeUsage, AppError> {
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()?;
+
+    let body = match fresh_access_token(&app).await {
+        Ok(access_token) => match request_claude_usage(&client, &access_token).await {
+            Err(AppError::AuthExpired) => {
+                let mutex = app.state::<tokio::sync::Mutex<()>>();
+                let _guard = mutex.lock().await;
+                let access_token = refresh_access_token(&app).await?;
+                drop(_guard);
+                request_claude_usage(&client, &access_token).await?
+            }
+            result => result?,
+        },
+        Err(e @ (AppError::NotAuthenticated(_) | AppError::AuthExpired)) => match usage_auth_file(&app) {
+            Some(path) => request_claude_usage(&client, &read_auth_file_token(&path)?).await?,
+            None => return Err(e),
+        },
+        Err(e) => return Err(e),
+    };
 
     let parse_limit = |key: &str| -> Option<UsageLimit> {
         body.get(key).and_then(|v| {
@@ -606,9 +916,24 @@ async fn fetch_claude_usage(_app: AppHandle) -> Result<ClaudeUsage, String> {
     })
 }
 
+/// Sets or clears (`None`) the external auth file `fetch_claude_usage` falls
+/// back to, e.g. `~/.winter/data/opencode/auth.json`.
+#[tauri::command]
+async fn set_usage_auth_file(app: AppHandle, path: Option<String>) -> Result<(), AppError> {
+    let store = app.store(STORE_FILE)?;
+    match path.map(|p| p.trim().to_string()).filter(|p| !p.is_empty()) {
+        Some(p) => store.set(STORE_KEY_USAGE_AUTH_FILE, json!(p)),
+        None => {
+            store.delete(STORE_KEY_USAGE_AUTH_FILE);
+        }
+    }
+    store.save()?;
+    Ok(())
+}
+
 /// Stores a Claude session key in the persistent store.
 #[tauri::command]
-async fn set_session_key(app: AppHandle, key: String) -> Result<(), String> {
+async fn set_session_key(app: AppHandle, key: String) -> Result<(), AppError> {
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
     store.set("claude_session_key", json!(key));
     store.save().map_err(|e| e.to_string())?;
@@ -619,7 +944,7 @@ async fn set_session_key(app: AppHandle, key: String) -> Result<(), String> {
 
 /// Returns the configured OpenCode workspace directory, or the default if not set.
 #[tauri::command]
-async fn get_working_directory(app: AppHandle) -> Result<String, String> {
+async fn get_working_directory(app: AppHandle) -> Result<String, AppError> {
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
     let dir = store
         .get("opencode_directory")
@@ -632,122 +957,53 @@ async fn get_working_directory(app: AppHandle) -> Result<String, String> {
 /// Validates and stores a new OpenCode workspace directory.
 /// The path must be absolute and must exist as a directory.
 #[tauri::command]
-async fn set_working_directory(app: AppHandle, directory: String) -> Result<(), String> {
+async fn set_working_directory(app: AppHandle, directory: String) -> Result<(), AppError> {
     let path = std::path::Path::new(&directory);
     if !path.is_absolute() {
-        return Err("Path must be absolute".to_string());
+        return Err(AppError::InvalidInput("Path must be absolute".to_string()));
     }
     if !path.exists() {
-        return Err(format!("Directory does not exist: {}", directory));
+        return Err(AppError::NotFound(format!("Directory does not exist: {}", directory)));
     }
     if !path.is_dir() {
-        return Err(format!("Not a directory: {}", directory));
+        return Err(AppError::InvalidInput(format!("Not a directory: {}", directory)));
     }
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
     store.set("opencode_directory", json!(directory));
     store.save().map_err(|e| e.to_string())?;
+    if let Err(e) = directories::record_recent(&app, &directory) {
+        tracing::warn!("Failed to record recent directory: {}", e);
+    }
     Ok(())
 }
 
 /// Returns the current user's home directory ($HOME on Unix, $USERPROFILE on Windows).
 /// Frontend uses this to initialize path fields before store settings are loaded.
 #[tauri::command]
-fn get_home_dir() -> Result<String, String> {
+fn get_home_dir() -> Result<String, AppError> {
     std::env::var("HOME")
         .or_else(|_| std::env::var("USERPROFILE"))
-        .map_err(|_| "Cannot determine home directory".to_string())
+        .map_err(|_| AppError::NotFound("Cannot determine home directory".to_string()))
 }
 
 /// Creates a new directory at an absolute path that does not already exist.
 #[tauri::command]
-async fn create_directory(path: String) -> Result<(), String> {
+async fn create_directory(path: String) -> Result<(), AppError> {
     let p = std::path::Path::new(&path);
     if !p.is_absolute() {
-        return Err("Path must be absolute".to_string());
+        return Err(AppError::InvalidInput("Path must be absolute".to_string()));
     }
     if p.exists() {
-        return Err(format!("Already exists: {}", path));
-    }
-    tokio::fs::create_dir_all(&path)
-        .await
-        .map_err(|e| format!("Failed to create directory: {}", e))
-}
-
-/// BFS search for directories matching a query string under a root path.
-/// Skips common noise directories (node_modules, .git, target, etc.) for performance.
-#[tauri::command]
-async fn search_directories(
-    root: String,
-    query: String,
-    max_results: Option<usize>,
-) -> Result<Vec<serde_json::Value>, String> {
-    use std::collections::VecDeque;
-    let limit = max_results.unwrap_or(20);
-    let q = query.to_lowercase();
-    let root_path = std::path::PathBuf::from(&root);
-    if !root_path.is_dir() {
-        return Err("Root is not a directory".to_string());
-    }
-    let skip: std::collections::HashSet<&str> = [
-        "node_modules",
-        ".git",
-        "target",
-        "__pycache__",
-        ".cache",
-        ".local",
-        ".npm",
-        ".bun",
-        "backups",
-        ".rustup",
-        ".vscode-server",
-        "hourly",
-        "daily",
-    ]
-    .into_iter()
-    .collect();
-    let mut results = Vec::new();
-    let mut queue = VecDeque::new();
-    queue.push_back((root_path, 0u8));
-    while let Some((dir, depth)) = queue.pop_front() {
-        if results.len() >= limit {
-            break;
-        }
-        let mut entries = match tokio::fs::read_dir(&dir).await {
-            Ok(e) => e,
-            Err(_) => continue,
-        };
-        while let Ok(Some(entry)) = entries.next_entry().await {
-            let name = entry.file_name().to_string_lossy().to_string();
-            let ft = match entry.file_type().await {
-                Ok(ft) => ft,
-                Err(_) => continue,
-            };
-            if !ft.is_dir() {
-                continue;
-            }
-            if skip.contains(name.as_str()) {
-                continue;
-            }
-            let abs = entry.path().to_string_lossy().to_string();
-            if name.to_lowercase().contains(&q) {
-                results.push(serde_json::json!({ "name": name, "absolute": abs }));
-                if results.len() >= limit {
-                    break;
-                }
-            }
-            if depth < 6 {
-                queue.push_back((entry.path(), depth + 1));
-            }
-        }
+        return Err(AppError::InvalidInput(format!("Already exists: {}", path)));
     }
-    Ok(results)
+    Ok(tokio::fs::create_dir_all(&path).await?)
 }
 
 // ── OpenCode Bridge Commands ────────────────────────────────────────
 
 /// Returns true if the OpenCode server is reachable and the opencode_enabled setting is true.
 #[tauri::command]
-async fn opencode_check(app: AppHandle) -> Result<bool, String> {
+async fn opencode_check(app: AppHandle) -> Result<bool, AppError> {
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
     let enabled = store
         .get("opencode_enabled")
@@ -758,31 +1014,47 @@ async fn opencode_check(app: AppHandle) -> Result<bool, String> {
         return Ok(false);
     }
 
-    let client = get_opencode_client(&app)?;
+    let client = get_opencode_client(&app).await?;
     Ok(client.health_check().await)
 }
 
-/// Creates a new OpenCode session and returns its session ID.
+/// Creates a new OpenCode session on the given profile (the active one by default)
+/// and returns its session ID. The session stays bound to that profile.
 #[tauri::command]
-async fn opencode_create_session(app: AppHandle) -> Result<String, String> {
-    let client = get_opencode_client(&app)?;
+async fn opencode_create_session(app: AppHandle, profile: Option<String>) -> Result<String, AppError> {
+    let profile = opencode::profiles::resolve(&app, profile.as_deref())?;
+    let client = opencode::profiles::client(&app, profile.clone()).await?;
     let session = client.create_session().await?;
+    opencode::profiles::bind_session(&app, &session.id, Some(&profile))?;
     Ok(session.id)
 }
 
 /// Sends a user message to an OpenCode session, streaming events back via the IPC channel.
 /// Handles SSE subscription in a parallel task, with abort support and MBTI modifier injection.
-/// Images are passed as `[(media_type, base64_data)]` and forwarded as OpenCode "file" parts.
+/// Images are passed as `[(media_type, base64_data)]`, attachments as file paths read from
+/// disk; both are forwarded as OpenCode "file" parts. Without a `mode`, the session's
+/// remembered conversation mode is used.
 #[tauri::command]
 async fn opencode_send(
     app: AppHandle,
     oc_session_id: String,
     content: String,
     images: Option<Vec<(String, String)>>,
+    attachments: Option<Vec<String>>,
     mode: Option<MessageMode>,
     on_event: Channel<ChatStreamEvent>,
-) -> Result<(), String> {
-    let client = get_opencode_client(&app)?;
+) -> Result<(), AppError> {
+    let client = get_session_client(&app, &oc_session_id).await?;
+    let mut files: Vec<opencode::types::OcFilePart> = images
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        .map(|(i, (mime, data))| opencode::types::OcFilePart::image(i, mime, data))
+        .collect();
+    for path in attachments.unwrap_or_default() {
+        files.push(opencode::types::OcFilePart::from_path(std::path::Path::new(&path))?);
+    }
+
     let abort_flag = app.state::<Arc<AtomicBool>>();
     abort_flag.store(false, Ordering::SeqCst);
     tokio::task::yield_now().await;
@@ -792,17 +1064,15 @@ async fn opencode_send(
         return Ok(());
     }
 
-    let prompt_client = get_opencode_client(&app)?;
+    let prompt_client = get_session_client(&app, &oc_session_id).await?;
     let session_id_clone = oc_session_id.clone();
-    let content_clone = mode.unwrap_or(MessageMode::Normal).apply(&content);
+    let content_clone = mode
+        .unwrap_or_else(|| modes::conversation_mode(&app, &oc_session_id))
+        .apply(&content);
 
     let store_ref = app.store(STORE_FILE).ok();
 
-    let mbti_modifier = store_ref
-        .as_ref()
-        .and_then(|store| store.get(STORE_KEY_MBTI_MODIFIER))
-        .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .filter(|s| !s.is_empty());
+    let mbti_modifier = personality::modifier_for(&app, Some(&oc_session_id));
 
     let lang_code = store_ref
         .as_ref()
@@ -810,11 +1080,14 @@ async fn opencode_send(
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "en".to_string());
 
-    let lang_instruction = match lang_code.as_str() {
-        "ko" => None,
-        "ja" => Some("Respond in Japanese (日本語で回答してください).".to_string()),
-        "zh" => Some("Respond in Chinese (请用中文回答).".to_string()),
-        _ => None,
+    let lang_instruction = match claude::client::response_language(&app) {
+        Some(name) => Some(format!("Always respond in {}, whatever language the user writes in.", name)),
+        None => match lang_code.as_str() {
+            "ko" => None,
+            "ja" => Some("Respond in Japanese (日本語で回答してください).".to_string()),
+            "zh" => Some("Respond in Chinese (请用中文回答).".to_string()),
+            _ => None,
+        },
     };
 
     let system_prompt = match (&mbti_modifier, &lang_instruction) {
@@ -830,31 +1103,56 @@ async fn opencode_send(
 
     let sse_handle = tokio::spawn({
         let session_id = oc_session_id;
-        let on_ev = on_event;
+        let on_ev = tts::watch_stream(&app, notifications::watch_stream(&app, webhooks::watch_stream(&app, on_event)));
         let flag = abort_flag.inner().clone();
         async move { client.subscribe_sse(&session_id, &on_ev, &flag, known_msg_ids).await }
     });
 
     tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 
-    let imgs = images.unwrap_or_default();
     if let Err(e) = prompt_client
-        .prompt_async(&session_id_clone, &content_clone, &imgs, system_prompt.as_deref())
+        .prompt_async(&session_id_clone, &content_clone, &files, system_prompt.as_deref())
         .await
     {
         abort_flag.store(true, Ordering::SeqCst);
         return Err(e);
     }
 
-    sse_handle
+    let result = sse_handle
         .await
-        .map_err(|e| format!("SSE task panicked: {}", e))?
+        .map_err(|e| format!("SSE task panicked: {}", e))?;
+    Ok(result?)
+}
+
+/// Re-attaches to a session's event stream without sending a prompt, e.g. after
+/// the window reloads while OpenCode is still working. A reply in progress is
+/// streamed from its current text onward; returns when it finishes or
+/// `opencode_abort` fires.
+#[tauri::command]
+async fn opencode_subscribe(
+    app: AppHandle,
+    oc_session_id: String,
+    on_event: Channel<ChatStreamEvent>,
+) -> Result<(), AppError> {
+    let client = get_session_client(&app, &oc_session_id).await?;
+    let abort_flag = app.state::<Arc<AtomicBool>>();
+    abort_flag.store(false, Ordering::SeqCst);
+
+    let on_event = tts::watch_stream(&app, notifications::watch_stream(&app, webhooks::watch_stream(&app, on_event)));
+    if on_event.send(ChatStreamEvent::StreamStart).is_err() {
+        return Ok(());
+    }
+    // No dedup baseline: the in-progress assistant message must not be skipped,
+    // and finished messages produce no further events anyway.
+    Ok(client
+        .subscribe_sse(&oc_session_id, &on_event, abort_flag.inner(), Default::default())
+        .await?)
 }
 
 /// Aborts the currently running OpenCode session prompt.
 #[tauri::command]
-async fn opencode_abort(app: AppHandle, oc_session_id: String) -> Result<(), String> {
-    let client = get_opencode_client(&app)?;
+async fn opencode_abort(app: AppHandle, oc_session_id: String) -> Result<(), AppError> {
+    let client = get_session_client(&app, &oc_session_id).await?;
     app.state::<Arc<AtomicBool>>()
         .store(true, Ordering::SeqCst);
     client.abort(&oc_session_id).await
@@ -862,8 +1160,8 @@ async fn opencode_abort(app: AppHandle, oc_session_id: String) -> Result<(), Str
 
 /// Returns path info from the OpenCode server.
 #[tauri::command]
-async fn opencode_get_path(app: AppHandle) -> Result<serde_json::Value, String> {
-    let client = get_opencode_client(&app)?;
+async fn opencode_get_path(app: AppHandle) -> Result<serde_json::Value, AppError> {
+    let client = get_opencode_client(&app).await?;
     client.get_path_info().await
 }
 
@@ -879,8 +1177,8 @@ async fn opencode_get_path(app: AppHandle) -> Result<serde_json::Value, String>
 /// Hidden directories (names starting with `.`) are **never** filtered out:
 /// if the user explicitly navigates into one, its full contents are returned.
 #[tauri::command]
-async fn opencode_list_files(app: AppHandle, path: String) -> Result<serde_json::Value, String> {
-    let client = get_opencode_client(&app)?;
+async fn opencode_list_files(app: AppHandle, path: String) -> Result<serde_json::Value, AppError> {
+    let client = get_opencode_client(&app).await?;
 
     // Normalise absolute paths → relative to workspace so OpenCode can resolve them.
     let effective_path = if std::path::Path::new(&path).is_absolute() {
@@ -896,8 +1194,8 @@ async fn opencode_list_files(app: AppHandle, path: String) -> Result<serde_json:
             Err(_) => {
                 // Path is outside the workspace — forward as-is and let the
                 // server decide whether to allow or reject it.
-                eprintln!(
-                    "[opencode_list_files] path '{}' is outside workspace '{}', forwarding as-is",
+                tracing::debug!(
+                    "opencode_list_files: path '{}' is outside workspace '{}', forwarding as-is",
                     path, workspace
                 );
                 path
@@ -915,16 +1213,16 @@ async fn opencode_list_files(app: AppHandle, path: String) -> Result<serde_json:
 async fn opencode_file_content(
     app: AppHandle,
     path: String,
-) -> Result<serde_json::Value, String> {
-    let client = get_opencode_client(&app)?;
+) -> Result<serde_json::Value, AppError> {
+    let client = get_opencode_client(&app).await?;
     let dir = get_opencode_dir(&app);
     client.file_content(&path, &dir).await
 }
 
 /// Returns all pending questions from the OpenCode session awaiting user answers.
 #[tauri::command]
-async fn opencode_get_questions(app: AppHandle) -> Result<serde_json::Value, String> {
-    let client = get_opencode_client(&app)?;
+async fn opencode_get_questions(app: AppHandle) -> Result<serde_json::Value, AppError> {
+    let client = get_opencode_client(&app).await?;
     client.get_questions().await
 }
 
@@ -934,8 +1232,8 @@ async fn opencode_reply_question(
     app: AppHandle,
     request_id: String,
     answers: serde_json::Value,
-) -> Result<(), String> {
-    let client = get_opencode_client(&app)?;
+) -> Result<(), AppError> {
+    let client = get_opencode_client(&app).await?;
     client.reply_question(&request_id, answers).await
 }
 
@@ -944,8 +1242,8 @@ async fn opencode_reply_question(
 async fn opencode_reject_question(
     app: AppHandle,
     request_id: String,
-) -> Result<(), String> {
-    let client = get_opencode_client(&app)?;
+) -> Result<(), AppError> {
+    let client = get_opencode_client(&app).await?;
     client.reject_question(&request_id).await
 }
 
@@ -954,23 +1252,51 @@ async fn opencode_reject_question(
 async fn opencode_get_messages(
     app: AppHandle,
     session_id: String,
-) -> Result<serde_json::Value, String> {
-    let client = get_opencode_client(&app)?;
+) -> Result<serde_json::Value, AppError> {
+    let client = get_session_client(&app, &session_id).await?;
     client.get_session_messages(&session_id).await
 }
 
-/// Lists all OpenCode sessions for the current workspace directory.
+/// Lists all OpenCode sessions of a profile (the active one by default), most recently
+/// updated first. Subagent sessions are left out unless `include_children` is set.
+#[tauri::command]
+async fn opencode_list_sessions(
+    app: AppHandle,
+    include_children: Option<bool>,
+    profile: Option<String>,
+) -> Result<Vec<opencode::types::OcSession>, AppError> {
+    let profile = opencode::profiles::resolve(&app, profile.as_deref())?;
+    let client = opencode::profiles::client(&app, profile).await?;
+    let mut sessions = client.list_sessions().await?;
+    if !include_children.unwrap_or(false) {
+        sessions.retain(|s| s.parent_id.is_none());
+    }
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.time.as_ref().map(|t| t.updated).unwrap_or(0)));
+    Ok(sessions)
+}
+
+/// Loads a session and its message history so the UI can continue it.
 #[tauri::command]
-async fn opencode_list_sessions(app: AppHandle) -> Result<Vec<opencode::types::OcSession>, String> {
-    let client = get_opencode_client(&app)?;
-    client.list_sessions().await
+async fn opencode_resume_session(
+    app: AppHandle,
+    session_id: String,
+) -> Result<opencode::types::OcResumedSession, AppError> {
+    let client = get_session_client(&app, &session_id).await?;
+    let (session, messages) = tokio::try_join!(
+        client.get_session(&session_id),
+        client.get_session_messages(&session_id),
+    )?;
+    Ok(opencode::types::OcResumedSession { session, messages })
 }
 
 /// Deletes the given OpenCode session permanently.
 #[tauri::command]
-async fn opencode_delete_session(app: AppHandle, session_id: String) -> Result<(), String> {
-    let client = get_opencode_client(&app)?;
-    client.delete_session(&session_id).await
+async fn opencode_delete_session(app: AppHandle, session_id: String) -> Result<(), AppError> {
+    let client = get_session_client(&app, &session_id).await?;
+    client.delete_session(&session_id).await?;
+    modes::forget_conversation(&app, &session_id)?;
+    personality::forget_conversation(&app, &session_id)?;
+    Ok(opencode::profiles::bind_session(&app, &session_id, None)?)
 }
 
 /// Renames the given OpenCode session to a new title.
@@ -979,8 +1305,8 @@ async fn opencode_rename_session(
     app: AppHandle,
     session_id: String,
     title: String,
-) -> Result<(), String> {
-    let client = get_opencode_client(&app)?;
+) -> Result<(), AppError> {
+    let client = get_session_client(&app, &session_id).await?;
     client.rename_session(&session_id, &title).await
 }
 
@@ -989,8 +1315,21 @@ async fn opencode_rename_session(
 /// Runs `winter-db.py recover` and returns the compact memory output.
 /// Used by the frontend to restore context after session compaction.
 #[tauri::command]
-async fn winter_db_recover(app: AppHandle) -> Result<String, String> {
-    WinterMemoryDB::new_with_app(&app).recover().await
+async fn winter_db_recover(app: AppHandle) -> Result<String, AppError> {
+    Ok(WinterMemoryDB::new_with_app(&app).recover().await?)
+}
+
+/// Recent delegations to subagents and OpenCode, newest first: which agent ran
+/// what, for how long, with how many tokens and how it ended.
+#[tauri::command]
+async fn get_agent_history(
+    app: AppHandle,
+    agent: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<memory::AgentRun>, AppError> {
+    Ok(WinterMemoryDB::new_with_app(&app)
+        .agent_runs(agent.as_deref(), limit.unwrap_or(50))
+        .await?)
 }
 
 /// Sends an OpenCode prompt with an optional MessageMode prefix applied to the content.
@@ -1002,18 +1341,19 @@ async fn send_opencode_prompt_with_mode(
     content: String,
     mode: MessageMode,
     system: Option<String>,
-) -> Result<(), String> {
-    let client = get_opencode_client(&app)?;
+) -> Result<(), AppError> {
+    let client = get_session_client(&app, &session_id).await?;
     let prefixed_content = mode.apply(&content);
+    // Text only: this command takes no attachments (see `opencode_send` for those).
     client
-        .prompt_async(&session_id, &prefixed_content, system.as_deref())
+        .prompt_async(&session_id, &prefixed_content, &[], system.as_deref())
         .await
 }
 
 /// Checks if Tailscale is active on this machine by running `tailscale status --json`.
 /// Returns true if the command exits successfully (Tailscale is connected).
 #[tauri::command]
-async fn check_tailscale() -> Result<bool, String> {
+async fn check_tailscale() -> Result<bool, AppError> {
     match tokio::process::Command::new("tailscale")
         .arg("status")
         .arg("--json")
@@ -1033,11 +1373,34 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_updater::Builder::new().build())
         .manage(Mutex::new(None::<PkceState>))
         .manage(Arc::new(AtomicBool::new(false)))
         .manage(tokio::sync::Mutex::new(()))
         .manage(scheduler::SharedSchedulerState::default())
+        .manage(ollama::ServerState::default())
+        .manage(ollama::HealthState::default())
+        .manage(services::WatchdogState::default())
+        .manage(phoenix::PhoenixState::default())
+        .manage(opencode::tunnel::SshTunnels::default())
+        .manage(compaction::ForcedCompaction::default())
+        .manage(bridge::BridgeState::default())
+        .manage(search::SearchRegistry::default())
+        .manage(tabular::TabularIndexes::default())
         .setup(|app| {
+            logging::init(app.handle());
+            api_debug::init(app.handle());
+            crash::install(app.handle());
+            tray::init(app)?;
+            quick_prompt::init(app.handle());
+            ollama::spawn_health_monitor(app.handle().clone());
+            services::spawn_watchdog(app.handle().clone());
+            services::spawn_status_poller(app.handle().clone());
+            phoenix::spawn_phoenix(app.handle().clone());
+            updater::spawn_update_checker(app.handle().clone());
+            sync::spawn_sync_loop(app.handle().clone());
+            bridge::restart(app.handle());
             let app_handle = app.handle().clone();
             let state: tauri::State<scheduler::SharedSchedulerState> = app.state();
             let state_clone = state.inner().clone();
@@ -1045,36 +1408,98 @@ pub fn run() {
                 match scheduler::init_scheduler(&app_handle).await {
                     Ok(inner) => {
                         *state_clone.lock().await = Some(inner);
-                        scheduler::start_enabled_jobs(&state_clone).await;
+                        scheduler::start_enabled_jobs(&app_handle, &state_clone).await;
+                        tray::sync_scheduler(&app_handle).await;
                     }
                     Err(e) => {
-                        eprintln!("[scheduler] Failed to initialize: {}", e);
+                        tracing::error!("Scheduler failed to initialize: {}", e);
                     }
                 }
             });
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![
+        .invoke_handler(crash::recording(tauri::generate_handler![
             get_authorize_url,
             exchange_code,
             is_authenticated,
             logout,
             chat_send,
-            send_feedback,
+            feedback::send_feedback,
+            feedback::get_feedback_settings,
+            feedback::set_feedback_settings,
+            feedback::get_feedback_diagnostics,
+            feedback::open_feedback_issue,
+            logging::get_log_settings,
+            logging::set_log_settings,
+            logging::get_app_logs,
+            api_debug::get_api_debug_logging,
+            api_debug::set_api_debug_logging,
             abort_stream,
             compaction_get_provider,
             compaction_set_provider,
+            compaction_set_openai_config,
+            get_response_language,
+            set_response_language,
+            get_auto_review,
+            set_auto_review,
+            notifications::get_background_notifications,
+            notifications::set_background_notifications,
+            palette::list_commands,
+            bridge::get_bridge_settings,
+            bridge::set_bridge_settings,
+            webhooks::list_webhooks,
+            webhooks::save_webhook,
+            webhooks::delete_webhook,
+            webhooks::test_webhook,
+            crash::get_crash_reports,
+            crash::clear_crash_reports,
+            updater::check_for_updates,
+            updater::install_update,
+            updater::get_auto_update_check,
+            updater::set_auto_update_check,
+            clipboard::get_clipboard_image,
+            ingest::ingest_files,
+            tts::speak,
+            tts::get_tts_settings,
+            tts::set_tts_settings,
+            quick_prompt::get_quick_prompt_hotkey,
+            quick_prompt::set_quick_prompt_hotkey,
+            quick_prompt::submit_quick_prompt,
+            quick_prompt::hide_quick_prompt,
+            claude::personas::list_agent_personas,
+            claude::personas::save_agent_persona,
+            claude::personas::delete_agent_persona,
             ollama_is_installed,
             ollama_install,
+            ollama_start,
+            ollama_stop,
             ollama_check,
             ollama_models,
+            ollama_delete,
+            ollama_get_options,
+            ollama_set_options,
+            ollama_embed,
+            ollama_set_embed_model,
             ollama_toggle,
             ollama_set_config,
             fetch_claude_usage,
+            set_usage_auth_file,
+            budget::get_budget_settings,
+            budget::set_budget_settings,
+            budget::get_budget_status,
+            app_backup::has_backup_passphrase,
+            app_backup::set_backup_passphrase,
+            app_backup::restore_backup,
+            sync::get_sync_settings,
+            sync::set_sync_settings,
+            sync::get_sync_status,
+            sync::sync_now,
+            sync::resolve_sync_conflict,
             set_session_key,
             opencode_check,
             opencode_create_session,
             opencode_send,
+            opencode_subscribe,
             opencode_abort,
             opencode_get_path,
             opencode_list_files,
@@ -1084,26 +1509,102 @@ pub fn run() {
             opencode_reject_question,
             opencode_get_messages,
             opencode_list_sessions,
+            opencode_resume_session,
             opencode_delete_session,
+            modes::get_conversation_mode,
+            modes::set_conversation_mode,
+            personality::list_personality_presets,
+            personality::apply_personality_preset,
+            personality::save_personality_preset,
+            personality::delete_personality_preset,
+            personality::get_conversation_personality,
+            personality::set_conversation_personality,
             opencode_rename_session,
+            opencode::profiles::list_opencode_profiles,
+            opencode::profiles::save_opencode_profile,
+            opencode::profiles::delete_opencode_profile,
+            opencode::profiles::set_active_opencode_profile,
+            opencode::profiles::get_opencode_session_profile,
+            opencode::profiles::set_opencode_workspace,
+            opencode::profiles::get_ssh_tunnels,
+            opencode::profiles::connect_ssh_tunnel,
+            opencode::profiles::close_ssh_tunnel,
             get_working_directory,
             set_working_directory,
             get_home_dir,
             create_directory,
-            search_directories,
+            search::search_directories,
+            directories::bookmark_list,
+            directories::bookmark_add,
+            directories::bookmark_remove,
+            directories::get_recent_directories,
+            search::search_file_contents,
+            search::cancel_search,
+            files::native_list_files,
+            files::native_file_content,
+            files::trash_path,
+            files::list_trash,
+            files::restore_trashed,
+            files::native_rename,
+            files::native_move,
+            files::native_copy,
+            files::native_delete,
+            archive::extract_archive,
+            archive::create_archive,
+            disk_usage::get_disk_usage,
+            disk_usage::cancel_disk_usage,
+            projects::list_projects,
+            projects::get_active_project,
+            projects::save_project,
+            projects::delete_project,
+            projects::switch_project,
+            git::get_git_status,
+            git::get_git_diff,
+            git::git_commit,
+            outline::get_file_outline,
+            tabular::preview_tabular,
+            thumbnails::get_thumbnail,
+            settings::export_settings,
+            settings::import_settings,
+            settings::profile_list,
+            settings::profile_save,
+            settings::profile_delete,
+            settings::profile_apply,
             scheduler::get_scheduler_status,
             scheduler::toggle_task,
             scheduler::run_task_now,
             scheduler::get_task_log,
+            scheduler::stream_task_log,
+            scheduler::stop_task_log_stream,
+            scheduler::clear_task_log,
+            scheduler::get_task_history,
+            scheduler::validate_schedule,
             scheduler::create_task,
             scheduler::delete_task,
             scheduler::update_task,
+            scheduler::export_tasks,
+            scheduler::import_tasks,
+            scheduler::scheduler_pause,
+            scheduler::scheduler_resume,
+            scheduler::scheduler_is_paused,
             services::get_services_status,
+            health::get_system_health,
             services::control_service,
+            services::add_service,
+            services::update_service,
+            services::remove_service,
+            services::get_service_logs,
+            services::install_service,
+            services::set_service_enabled,
+            services::set_service_watchdog,
+            phoenix::get_phoenix_config,
+            phoenix::set_phoenix_config,
+            phoenix::get_phoenix_log,
             winter_db_recover,
+            get_agent_history,
             send_opencode_prompt_with_mode,
             check_tailscale,
-        ])
+        ]))
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
\ No newline at end of file
@@ -0,0 +1,249 @@
+//! Built-in log digest task for the scheduler.
+//!
+//! Reads whatever was appended to the configured log files since the previous
+//! run, keeps lines matching the error/warning patterns, groups repeats, and
+//! returns a short markdown digest. Read offsets are kept per task in a small
+//! JSON state file so each run only looks at new lines.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use crate::backup::expand_home;
+
+/// First run (or a file seen for the first time) only reads this much from the end.
+const FIRST_SCAN_BYTES: u64 = 256 * 1024;
+/// Upper bound on bytes read from a single file per run.
+const MAX_SCAN_BYTES: u64 = 8 * 1024 * 1024;
+/// Longest line kept in the digest; longer lines are cut.
+const MAX_LINE_CHARS: usize = 300;
+
+pub fn default_error_patterns() -> Vec<String> {
+    ["error", "fatal", "panic", "exception", "traceback", "failed"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+pub fn default_warning_patterns() -> Vec<String> {
+    vec!["warn".to_string()]
+}
+
+fn default_max_entries() -> usize {
+    20
+}
+
+/// Registry configuration of a log digest task. Paths may start with `~/`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LogDigestConfig {
+    /// Log files, or directories whose top-level `*.log` files are scanned.
+    pub paths: Vec<String>,
+    /// Case-insensitive substrings that mark a line as an error.
+    #[serde(default = "default_error_patterns")]
+    pub error_patterns: Vec<String>,
+    /// Case-insensitive substrings that mark a line as a warning.
+    #[serde(default = "default_warning_patterns")]
+    pub warning_patterns: Vec<String>,
+    /// Distinct lines listed per severity.
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+    /// File the digest is appended to, in addition to the task log.
+    #[serde(default)]
+    pub output_file: Option<String>,
+    /// Show a desktop notification when errors were found.
+    #[serde(default)]
+    pub notify: bool,
+}
+
+/// Per-file read offsets from the previous run.
+#[derive(Default, Serialize, Deserialize)]
+struct DigestState {
+    offsets: HashMap<String, u64>,
+}
+
+#[derive(Default)]
+struct Findings {
+    /// Normalized line -> (count, first original line, source file name).
+    errors: HashMap<String, (usize, String, String)>,
+    warnings: HashMap<String, (usize, String, String)>,
+}
+
+fn collect_files(paths: &[String]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for p in paths {
+        let path = expand_home(p);
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                let mut logs: Vec<PathBuf> = entries
+                    .filter_map(|e| e.ok().map(|e| e.path()))
+                    .filter(|p| p.is_file() && p.extension().is_some_and(|x| x == "log"))
+                    .collect();
+                logs.sort();
+                files.extend(logs);
+            }
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+    files
+}
+
+/// Replaces digit runs so lines that differ only by timestamps or ids group together.
+fn normalize(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_digits = false;
+    for c in line.chars() {
+        if c.is_ascii_digit() {
+            if !in_digits {
+                out.push('#');
+            }
+            in_digits = true;
+        } else {
+            out.push(c);
+            in_digits = false;
+        }
+    }
+    out
+}
+
+fn matches_any(lower: &str, patterns: &[String]) -> bool {
+    patterns.iter().any(|p| !p.is_empty() && lower.contains(&p.to_lowercase()))
+}
+
+/// Reads new bytes from `path` starting at `offset` and returns them with the new offset.
+/// A file that shrank is assumed to have been rotated and is read from the start.
+fn read_new(path: &Path, offset: Option<u64>) -> std::io::Result<(String, u64)> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = match offset {
+        Some(o) if o <= len => o,
+        Some(_) => 0,
+        None => len.saturating_sub(FIRST_SCAN_BYTES),
+    };
+    let start = start.max(len.saturating_sub(MAX_SCAN_BYTES));
+    file.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::new();
+    file.take(len - start).read_to_end(&mut buf)?;
+    // Leave a trailing partial line for the next run.
+    let end = match buf.iter().rposition(|&b| b == b'\n') {
+        Some(i) => i + 1,
+        None => 0,
+    };
+    buf.truncate(end);
+    Ok((String::from_utf8_lossy(&buf).into_owned(), start + end as u64))
+}
+
+fn scan(text: &str, source: &str, cfg: &LogDigestConfig, findings: &mut Findings) {
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let lower = trimmed.to_lowercase();
+        let bucket = if matches_any(&lower, &cfg.error_patterns) {
+            &mut findings.errors
+        } else if matches_any(&lower, &cfg.warning_patterns) {
+            &mut findings.warnings
+        } else {
+            continue;
+        };
+        let shown: String = trimmed.chars().take(MAX_LINE_CHARS).collect();
+        bucket
+            .entry(normalize(trimmed))
+            .or_insert_with(|| (0, shown, source.to_string()))
+            .0 += 1;
+    }
+}
+
+fn render_section(out: &mut String, title: &str, entries: &HashMap<String, (usize, String, String)>, max: usize) {
+    if entries.is_empty() {
+        return;
+    }
+    let total: usize = entries.values().map(|(n, _, _)| n).sum();
+    out.push_str(&format!("### {} ({})\n", title, total));
+    let mut sorted: Vec<_> = entries.values().collect();
+    sorted.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    for (count, line, source) in sorted.iter().take(max) {
+        out.push_str(&format!("- [{}] {}×  {}\n", source, count, line));
+    }
+    if sorted.len() > max {
+        out.push_str(&format!("- … {} more distinct lines\n", sorted.len() - max));
+    }
+    out.push('\n');
+}
+
+fn load_state(path: &Path) -> DigestState {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_state(path: &Path, state: &DigestState) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create digest dir: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(state).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write digest state: {}", e))
+}
+
+/// Builds a digest of new log lines. `state_path` holds the read offsets for
+/// this task. Blocking; call from `spawn_blocking`. Returns the digest text.
+pub fn run(task_id: &str, state_path: &Path, cfg: &LogDigestConfig) -> Result<String, String> {
+    let files = collect_files(&cfg.paths);
+    if files.is_empty() {
+        return Err("No log files found for the configured paths".to_string());
+    }
+
+    let mut state = load_state(state_path);
+    let mut findings = Findings::default();
+    let mut scanned = 0;
+    for file in &files {
+        let key = file.to_string_lossy().into_owned();
+        let Ok((text, offset)) = read_new(file, state.offsets.get(&key).copied()) else {
+            continue;
+        };
+        let source = file.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        scan(&text, &source, cfg, &mut findings);
+        state.offsets.insert(key, offset);
+        scanned += 1;
+    }
+    // Forget files that are no longer configured or were removed.
+    state.offsets.retain(|k, _| files.iter().any(|f| f.to_string_lossy() == k.as_str()));
+    save_state(state_path, &state)?;
+
+    let error_count: usize = findings.errors.values().map(|(n, _, _)| n).sum();
+    let warning_count: usize = findings.warnings.values().map(|(n, _, _)| n).sum();
+    let mut digest = format!(
+        "{} errors, {} warnings in {} files\n\n",
+        error_count, warning_count, scanned
+    );
+    render_section(&mut digest, "Errors", &findings.errors, cfg.max_entries);
+    render_section(&mut digest, "Warnings", &findings.warnings, cfg.max_entries);
+
+    if error_count + warning_count > 0 {
+        if let Some(path) = cfg.output_file.as_deref().filter(|p| !p.is_empty()) {
+            let path = expand_home(path);
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let mut f = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .map_err(|e| format!("Failed to open output file: {}", e))?;
+            let ts = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+            writeln!(f, "## {}\n\n{}", ts, digest)
+                .map_err(|e| format!("Failed to write output file: {}", e))?;
+        }
+    }
+    if cfg.notify && error_count > 0 {
+        crate::notifications::send(
+            &format!("{}: {} new errors", task_id, error_count),
+            &format!("{} warnings across {} log files", warning_count, scanned),
+        );
+    }
+
+    Ok(digest.trim_end().to_string())
+}
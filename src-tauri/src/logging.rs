@@ -0,0 +1,306 @@
+//! Application logging.
+//!
+//! Log events go through `tracing` to stderr and to a daily rotated file,
+//! `<app_data_dir>/logs/winter.<date>.log`, so a failed stream or scheduler
+//! run can be looked into without launching the app from a terminal. The
+//! last `MAX_LOG_FILES` days are kept.
+//!
+//! Levels are a setting (`log_levels` store key): one for the app and
+//! optional overrides per module (`scheduler`, `opencode::client`, ...).
+//! Other crates log at `warn` and above. Changes apply without a restart.
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::time::FormatTime;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_SETTINGS: &str = "log_levels";
+
+/// Log files are `<prefix>.<date>.<suffix>`.
+const LOG_FILE_PREFIX: &str = "winter";
+const LOG_FILE_SUFFIX: &str = "log";
+
+/// Daily log files kept; older ones are deleted on rotation.
+const MAX_LOG_FILES: usize = 7;
+
+/// Target prefix of this crate's events, left out of `LogEntry::target`.
+const CRATE_TARGET: &str = "winter_app_lib";
+
+const LEVELS: [&str; 6] = ["off", "error", "warn", "info", "debug", "trace"];
+
+/// Entries returned by `get_app_logs` when no count is given.
+const DEFAULT_LOG_LINES: usize = 200;
+const MAX_LOG_LINES: usize = 5000;
+
+/// Bytes read from the end of each log file by `get_app_logs`.
+const MAX_READ_BYTES: u64 = 4 * 1024 * 1024;
+
+static FILTER: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LogSettings {
+    /// Level for the whole app: off, error, warn, info, debug or trace.
+    #[serde(default = "default_level")]
+    pub level: String,
+    /// Per-module overrides, e.g. `{"scheduler": "debug"}`.
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
+}
+
+fn default_level() -> String {
+    "info".to_string()
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            level: default_level(),
+            modules: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LogEntry {
+    pub timestamp: String,
+    /// Lowercase level: error, warn, info, debug or trace.
+    pub level: String,
+    /// Module the event came from, e.g. `scheduler`.
+    pub target: String,
+    pub message: String,
+}
+
+/// Local time with milliseconds and UTC offset.
+struct LocalTime;
+
+impl FormatTime for LocalTime {
+    fn format_time(&self, w: &mut Writer<'_>) -> std::fmt::Result {
+        write!(w, "{}", Local::now().format("%Y-%m-%dT%H:%M:%S%.3f%:z"))
+    }
+}
+
+fn log_dir(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_data_dir().ok().map(|d| d.join("logs"))
+}
+
+fn validate_level(level: &str) -> Result<String, String> {
+    let level = level.trim().to_lowercase();
+    if LEVELS.contains(&level.as_str()) {
+        Ok(level)
+    } else {
+        Err(format!("Unknown log level '{}' (expected one of {})", level, LEVELS.join(", ")))
+    }
+}
+
+/// Normalizes `settings`, rejecting unknown levels and module names.
+fn validate(settings: &LogSettings) -> Result<LogSettings, String> {
+    let mut modules = BTreeMap::new();
+    for (module, level) in &settings.modules {
+        let module = module.trim().trim_start_matches(CRATE_TARGET).trim_start_matches("::");
+        let valid = !module.is_empty()
+            && module.split("::").all(|part| {
+                !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            });
+        if !valid {
+            return Err(format!("Invalid module name '{}'", module));
+        }
+        modules.insert(module.to_string(), validate_level(level)?);
+    }
+    Ok(LogSettings {
+        level: validate_level(&settings.level)?,
+        modules,
+    })
+}
+
+fn filter(settings: &LogSettings) -> EnvFilter {
+    let mut directives = vec!["warn".to_string(), format!("{}={}", CRATE_TARGET, settings.level)];
+    directives.extend(
+        settings
+            .modules
+            .iter()
+            .map(|(module, level)| format!("{}::{}={}", CRATE_TARGET, module, level)),
+    );
+    EnvFilter::try_new(directives.join(",")).unwrap_or_else(|_| EnvFilter::new("warn"))
+}
+
+pub fn get_settings(app: &AppHandle) -> LogSettings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_SETTINGS))
+        .and_then(|v| serde_json::from_value::<LogSettings>(v).ok())
+        .and_then(|settings| validate(&settings).ok())
+        .unwrap_or_default()
+}
+
+/// Installs the global subscriber. Called once from `setup`, after the store
+/// plugin is available. If the log directory cannot be created, logs only go
+/// to stderr.
+pub fn init(app: &AppHandle) {
+    let (filter_layer, handle) = reload::Layer::new(filter(&get_settings(app)));
+    let _ = FILTER.set(handle);
+
+    let appender = log_dir(app).and_then(|dir| {
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(LOG_FILE_PREFIX)
+            .filename_suffix(LOG_FILE_SUFFIX)
+            .max_log_files(MAX_LOG_FILES)
+            .build(&dir)
+            .map_err(|e| eprintln!("[logging] Cannot write logs to {}: {}", dir.display(), e))
+            .ok()
+    });
+    let file_layer = appender.map(|appender| {
+        tracing_subscriber::fmt::layer()
+            .with_timer(LocalTime)
+            .with_ansi(false)
+            .with_writer(appender)
+    });
+    let stderr_layer = tracing_subscriber::fmt::layer()
+        .with_timer(LocalTime)
+        .with_writer(std::io::stderr);
+
+    if let Err(e) = tracing_subscriber::registry()
+        .with(filter_layer)
+        .with(file_layer)
+        .with(stderr_layer)
+        .try_init()
+    {
+        eprintln!("[logging] Failed to install the logger: {}", e);
+    }
+}
+
+/// Parses a line written by the file layer:
+/// `<timestamp> <LEVEL> <target>: <message>`. Other lines continue the
+/// previous entry's message.
+fn parse_line(line: &str) -> Option<LogEntry> {
+    let (timestamp, rest) = line.split_once(' ')?;
+    if !timestamp.starts_with(|c: char| c.is_ascii_digit()) || !timestamp.contains('T') {
+        return None;
+    }
+    let rest = rest.trim_start();
+    let (level, rest) = rest.split_once(' ')?;
+    let level = level.to_lowercase();
+    if !LEVELS[1..].contains(&level.as_str()) {
+        return None;
+    }
+    let (target, message) = rest.trim_start().split_once(": ")?;
+    let target = target.trim_start_matches(CRATE_TARGET).trim_start_matches("::");
+    Some(LogEntry {
+        timestamp: timestamp.to_string(),
+        level,
+        target: if target.is_empty() { "app".to_string() } else { target.to_string() },
+        message: message.to_string(),
+    })
+}
+
+/// Entries of one log file, oldest first, reading at most the last
+/// `MAX_READ_BYTES`.
+fn read_entries(path: &std::path::Path) -> Vec<LogEntry> {
+    let Ok(mut file) = std::fs::File::open(path) else { return Vec::new() };
+    let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let start = len.saturating_sub(MAX_READ_BYTES);
+    let mut bytes = Vec::new();
+    if file.seek(SeekFrom::Start(start)).and_then(|_| file.read_to_end(&mut bytes)).is_err() {
+        return Vec::new();
+    }
+    let mut entries: Vec<LogEntry> = Vec::new();
+    for line in String::from_utf8_lossy(&bytes).lines() {
+        match parse_line(line) {
+            Some(entry) => entries.push(entry),
+            None => {
+                if let Some(last) = entries.last_mut() {
+                    last.message.push('\n');
+                    last.message.push_str(line);
+                }
+            }
+        }
+    }
+    entries
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_log_settings(app: AppHandle) -> LogSettings {
+    get_settings(&app)
+}
+
+/// Saves log levels and applies them right away.
+#[tauri::command]
+pub fn set_log_settings(app: AppHandle, settings: LogSettings) -> Result<LogSettings, AppError> {
+    let settings = validate(&settings)?;
+    if let Some(handle) = FILTER.get() {
+        handle.reload(filter(&settings)).map_err(|e| e.to_string())?;
+    }
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(
+        STORE_KEY_SETTINGS,
+        serde_json::to_value(&settings).map_err(|e| e.to_string())?,
+    );
+    store.save().map_err(|e| e.to_string())?;
+    tracing::info!("Log levels set to {} {:?}", settings.level, settings.modules);
+    Ok(settings)
+}
+
+/// The last `lines` app log entries (200 by default, at most 5000), oldest
+/// first. With `level`, only entries at that level or more severe are
+/// returned, e.g. `warn` returns warnings and errors.
+#[tauri::command]
+pub async fn get_app_logs(app: AppHandle, level: Option<String>, lines: Option<usize>) -> Result<Vec<LogEntry>, AppError> {
+    let min_rank = match level.as_deref().map(str::trim).filter(|l| !l.is_empty()) {
+        Some(level) => {
+            let level = validate_level(level)?;
+            LEVELS.iter().position(|l| *l == level).unwrap_or(LEVELS.len())
+        }
+        None => LEVELS.len(),
+    };
+    let wanted = lines.unwrap_or(DEFAULT_LOG_LINES).clamp(1, MAX_LOG_LINES);
+    let Some(dir) = log_dir(&app) else { return Ok(Vec::new()) };
+
+    Ok(tokio::task::spawn_blocking(move || {
+        let Ok(read) = std::fs::read_dir(&dir) else { return Vec::new() };
+        let prefix = format!("{}.", LOG_FILE_PREFIX);
+        let suffix = format!(".{}", LOG_FILE_SUFFIX);
+        // Dated names sort chronologically.
+        let mut files: Vec<PathBuf> = read
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with(&prefix) && n.ends_with(&suffix))
+            })
+            .collect();
+        files.sort();
+
+        let mut entries: Vec<LogEntry> = Vec::new();
+        for path in files.iter().rev() {
+            let mut older: Vec<LogEntry> = read_entries(path)
+                .into_iter()
+                .filter(|e| LEVELS.iter().position(|l| *l == e.level).is_some_and(|rank| rank <= min_rank))
+                .collect();
+            older.append(&mut entries);
+            entries = older;
+            if entries.len() >= wanted {
+                break;
+            }
+        }
+        let skip = entries.len().saturating_sub(wanted);
+        entries.split_off(skip)
+    })
+    .await
+    .map_err(|e| e.to_string())?)
+}
@@ -1,12 +1,46 @@
 /// Interface to Winter's SQLite memory database (winter-db.py).
 /// Provides context recovery for session continuity by running the Python script
 /// and returning its compact output to the frontend.
+use serde::{Deserialize, Serialize};
 use tauri::Manager;
 
 /// Fallback path to the winter-db.py script relative to $HOME.
 /// Used in dev mode where the Tauri resource dir is not bundled.
 const WINTER_DB_DEV_RELATIVE: &str = ".winter/workspace/projects/scripts/winter-db.py";
 
+/// A finished agent run to record in the `agent_runs` table.
+#[derive(Debug, Clone)]
+pub struct AgentRunLog {
+    /// Agent name, e.g. "Frost" or "OpenCode".
+    pub agent: String,
+    /// Short summary of the prompt the agent was given.
+    pub action: String,
+    /// "done", "failed" or "cancelled".
+    pub result: String,
+    pub session_id: Option<String>,
+    pub duration_ms: u64,
+    pub input_tokens: Option<u64>,
+    pub output_tokens: Option<u64>,
+    pub notes: Option<String>,
+}
+
+/// A row of the `agent_runs` table, as returned by `winter-db.py runs --json`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AgentRun {
+    pub id: i64,
+    pub agent: String,
+    pub action: String,
+    pub result: String,
+    pub task_id: Option<String>,
+    pub session_id: Option<String>,
+    pub started_at: String,
+    pub completed_at: Option<String>,
+    pub notes: Option<String>,
+    pub duration_ms: Option<u64>,
+    pub input_tokens: Option<u64>,
+    pub output_tokens: Option<u64>,
+}
+
 /// Manages access to the winter-db.py Python script for memory operations.
 /// Calls the script as a subprocess to avoid embedding Python logic in Rust.
 pub struct WinterMemoryDB {
@@ -33,15 +67,19 @@ impl WinterMemoryDB {
         Self { script_path }
     }
 
-    /// Runs `python3 <script_path> recover` and returns the compact output.
-    /// This output contains active tasks, recent snapshots, and agent execution history.
-    pub async fn recover(&self) -> Result<String, String> {
+    /// The SQLite file, which `winter-db.py` keeps next to itself.
+    pub fn db_path(&self) -> std::path::PathBuf {
+        std::path::Path::new(&self.script_path).with_file_name("winter.db")
+    }
+
+    /// Runs `python3 <script_path> <args>` and returns its stdout.
+    async fn run(&self, args: &[String]) -> Result<String, String> {
         if !std::path::Path::new(&self.script_path).exists() {
             return Err(format!("winter-db.py not found at {}", self.script_path));
         }
         let output = tokio::process::Command::new("python3")
             .arg(&self.script_path)
-            .arg("recover")
+            .args(args)
             .kill_on_drop(true)
             .output()
             .await
@@ -49,9 +87,77 @@ impl WinterMemoryDB {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("winter-db.py recover failed: {}", stderr));
+            let cmd = args.first().map(String::as_str).unwrap_or("");
+            return Err(format!("winter-db.py {} failed: {}", cmd, stderr));
         }
 
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
+
+    /// Runs `python3 <script_path> recover` and returns the compact output.
+    /// This output contains active tasks, recent snapshots, and agent execution history.
+    pub async fn recover(&self) -> Result<String, String> {
+        self.run(&["recover".to_string()]).await
+    }
+
+    /// Records a finished agent run via `winter-db.py log-run`.
+    pub async fn log_run(&self, run: &AgentRunLog) -> Result<(), String> {
+        let mut args: Vec<String> = vec![
+            "log-run".into(),
+            "--agent".into(),
+            run.agent.clone(),
+            "--action".into(),
+            run.action.clone(),
+            "--result".into(),
+            run.result.clone(),
+            "--duration-ms".into(),
+            run.duration_ms.to_string(),
+        ];
+        let optional = [
+            ("--session-id", run.session_id.clone()),
+            ("--input-tokens", run.input_tokens.map(|t| t.to_string())),
+            ("--output-tokens", run.output_tokens.map(|t| t.to_string())),
+            ("--notes", run.notes.clone()),
+        ];
+        for (flag, value) in optional {
+            if let Some(v) = value {
+                args.push(flag.into());
+                args.push(v);
+            }
+        }
+        self.run(&args).await.map(|_| ())
+    }
+
+    /// Most recent agent runs, newest first, optionally for one agent only.
+    pub async fn agent_runs(&self, agent: Option<&str>, limit: usize) -> Result<Vec<AgentRun>, String> {
+        let mut args: Vec<String> = vec!["runs".into(), "--json".into(), "--limit".into(), limit.to_string()];
+        if let Some(agent) = agent {
+            args.push("--agent".into());
+            args.push(agent.to_string());
+        }
+        let out = self.run(&args).await?;
+        serde_json::from_str(out.trim()).map_err(|e| format!("Failed to parse agent runs: {}", e))
+    }
+}
+
+/// Records an agent run in the background; failures are only logged, since
+/// history must never get in the way of the run itself.
+pub fn record_agent_run(app: &tauri::AppHandle, run: AgentRunLog) {
+    let db = WinterMemoryDB::new_with_app(app);
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = db.log_run(&run).await {
+            tracing::warn!("Failed to record agent run: {}", e);
+        }
+    });
+}
+
+/// First line of a prompt, shortened for the run history.
+pub fn summarize_prompt(prompt: &str) -> String {
+    const MAX_CHARS: usize = 120;
+    let line = prompt.lines().find(|l| !l.trim().is_empty()).unwrap_or("").trim();
+    if line.chars().count() > MAX_CHARS {
+        format!("{}...", line.chars().take(MAX_CHARS).collect::<String>())
+    } else {
+        line.to_string()
+    }
 }
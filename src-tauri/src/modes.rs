@@ -1,6 +1,18 @@
 /// Message mode prefixes injected before user messages.
 /// Mirrors oh-my-opencode plugin behavior for enhanced agent workflows.
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::claude::types::{ChatMessage, ContentBlock, MessageContent};
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+/// Store key of the mode each conversation was last used in. Conversations in
+/// Normal mode are not listed.
+const STORE_KEY_CONVERSATION_MODES: &str = "conversation_modes";
 
 /// Available message modes for controlling agent behavior.
 /// Each mode prepends a specific prefix to the user's message before sending to OpenCode.
@@ -54,4 +66,75 @@ impl MessageMode {
             None => content.to_string(),
         }
     }
+
+    /// Applies this mode's prefix to the text of a chat message. For messages
+    /// made of blocks (e.g. text with images) the first text block gets it.
+    pub fn apply_to_message(&self, message: &mut ChatMessage) {
+        match &mut message.content {
+            MessageContent::Text(text) => *text = self.apply(text),
+            MessageContent::Blocks(blocks) => {
+                if let Some(ContentBlock::Text { text }) =
+                    blocks.iter_mut().find(|b| matches!(b, ContentBlock::Text { .. }))
+                {
+                    *text = self.apply(text);
+                }
+            }
+        }
+    }
+}
+
+// ── Per-conversation mode ───────────────────────────────────────────
+
+fn conversation_modes(app: &AppHandle) -> HashMap<String, MessageMode> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_CONVERSATION_MODES))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn write_conversation_modes(app: &AppHandle, modes: &HashMap<String, MessageMode>) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_CONVERSATION_MODES, json!(modes));
+    store.save().map_err(|e| e.to_string())
+}
+
+/// The mode a conversation was last used in; Normal for unknown ones.
+pub fn conversation_mode(app: &AppHandle, conversation_id: &str) -> MessageMode {
+    conversation_modes(app)
+        .remove(conversation_id)
+        .unwrap_or(MessageMode::Normal)
+}
+
+/// Drops the stored mode of a deleted conversation.
+pub fn forget_conversation(app: &AppHandle, conversation_id: &str) -> Result<(), String> {
+    let mut modes = conversation_modes(app);
+    if modes.remove(conversation_id).is_some() {
+        write_conversation_modes(app, &modes)?;
+    }
+    Ok(())
+}
+
+/// Returns the mode to restore when a conversation is reopened. The
+/// conversation ID is the OpenCode session ID when there is one.
+#[tauri::command]
+pub fn get_conversation_mode(app: AppHandle, conversation_id: String) -> MessageMode {
+    conversation_mode(&app, &conversation_id)
+}
+
+/// Remembers the mode a conversation is used in.
+#[tauri::command]
+pub fn set_conversation_mode(app: AppHandle, conversation_id: String, mode: MessageMode) -> Result<(), AppError> {
+    let mut modes = conversation_modes(&app);
+    match mode {
+        MessageMode::Normal => {
+            if modes.remove(&conversation_id).is_none() {
+                return Ok(());
+            }
+        }
+        mode => {
+            modes.insert(conversation_id, mode);
+        }
+    }
+    Ok(write_conversation_modes(&app, &modes)?)
 }
@@ -0,0 +1,164 @@
+//! Native desktop notifications.
+//!
+//! Uses each platform's own tooling (`notify-send`, `osascript`, PowerShell toast)
+//! so no extra plugin is required. Title and body are passed through environment
+//! variables, which avoids quoting issues in the scripts.
+//!
+//! Also notifies when a chat reply finishes or fails while the main window is
+//! in the background (setting `notify_background_replies`, on by default).
+
+use serde_json::json;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::claude::types::ChatStreamEvent;
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const ENV_TITLE: &str = "WINTER_NOTIFY_TITLE";
+const ENV_BODY: &str = "WINTER_NOTIFY_BODY";
+
+const STORE_KEY_BACKGROUND_REPLIES: &str = "notify_background_replies";
+
+/// Length of the reply preview shown in the notification, in characters.
+const PREVIEW_CHARS: usize = 140;
+
+#[cfg(target_os = "linux")]
+fn command() -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", "notify-send --app-name=Winter \"$WINTER_NOTIFY_TITLE\" \"$WINTER_NOTIFY_BODY\""]);
+    cmd
+}
+
+#[cfg(target_os = "macos")]
+fn command() -> Command {
+    let mut cmd = Command::new("osascript");
+    cmd.args([
+        "-e",
+        "display notification (system attribute \"WINTER_NOTIFY_BODY\") with title (system attribute \"WINTER_NOTIFY_TITLE\")",
+    ]);
+    cmd
+}
+
+#[cfg(target_os = "windows")]
+fn command() -> Command {
+    const SCRIPT: &str = "\
+[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; \
+$t = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02); \
+$x = $t.GetElementsByTagName('text'); \
+$x.Item(0).AppendChild($t.CreateTextNode($env:WINTER_NOTIFY_TITLE)) > $null; \
+$x.Item(1).AppendChild($t.CreateTextNode($env:WINTER_NOTIFY_BODY)) > $null; \
+[Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('Winter').Show([Windows.UI.Notifications.ToastNotification]::new($t))";
+    let mut cmd = Command::new("powershell");
+    cmd.args(["-NoProfile", "-NonInteractive", "-Command", SCRIPT]);
+    cmd
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn command() -> Command {
+    Command::new("true")
+}
+
+/// Shows a desktop notification. Fire-and-forget: failures are only logged.
+pub fn send(title: &str, body: &str) {
+    let result = command()
+        .env(ENV_TITLE, title)
+        .env(ENV_BODY, body)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    match result {
+        Ok(mut child) => {
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+        }
+        Err(e) => tracing::warn!("Failed to show notification: {}", e),
+    }
+}
+
+// ── Background Replies ──────────────────────────────────────────────
+
+fn background_replies_enabled(app: &AppHandle) -> bool {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_BACKGROUND_REPLIES))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true)
+}
+
+/// Whether the main window is unfocused, minimized or hidden.
+fn in_background(app: &AppHandle) -> bool {
+    let Some(window) = app.get_webview_window("main") else { return true };
+    !window.is_focused().unwrap_or(false)
+        || window.is_minimized().unwrap_or(false)
+        || !window.is_visible().unwrap_or(true)
+}
+
+fn preview(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= PREVIEW_CHARS {
+        return text;
+    }
+    let cut: String = text.chars().take(PREVIEW_CHARS).collect();
+    format!("{}...", cut.trim_end())
+}
+
+#[derive(Default)]
+struct Reply {
+    text: String,
+    notified: bool,
+}
+
+/// Wraps a chat stream channel so a notification is shown when the reply
+/// ends or fails while the window is in the background. Events are forwarded
+/// to `on_event` unchanged. Returns `on_event` itself when the setting is off.
+pub fn watch_stream(app: &AppHandle, on_event: Channel<ChatStreamEvent>) -> Channel<ChatStreamEvent> {
+    if !background_replies_enabled(app) {
+        return on_event;
+    }
+    let app = app.clone();
+    let reply = Mutex::new(Reply::default());
+    Channel::new(move |body: InvokeResponseBody| {
+        let InvokeResponseBody::Json(raw) = body else { return Ok(()) };
+        let Ok(event) = serde_json::from_str::<ChatStreamEvent>(&raw) else { return Ok(()) };
+        let mut reply = reply.lock().unwrap_or_else(|e| e.into_inner());
+        match &event {
+            ChatStreamEvent::StreamStart => *reply = Reply::default(),
+            ChatStreamEvent::Delta { text } => reply.text.push_str(text),
+            ChatStreamEvent::StreamEnd if !reply.notified && in_background(&app) => {
+                reply.notified = true;
+                let body = match preview(&reply.text) {
+                    p if p.is_empty() => "Reply finished".to_string(),
+                    p => p,
+                };
+                send("Winter", &body);
+            }
+            ChatStreamEvent::Error { message } if !reply.notified && in_background(&app) => {
+                reply.notified = true;
+                send("Winter: reply failed", &preview(message));
+            }
+            _ => {}
+        }
+        drop(reply);
+        on_event.send(event)
+    })
+}
+
+// ── Tauri Commands ──────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_background_notifications(app: AppHandle) -> bool {
+    background_replies_enabled(&app)
+}
+
+#[tauri::command]
+pub fn set_background_notifications(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_BACKGROUND_REPLIES, json!(enabled));
+    Ok(store.save()?)
+}
@@ -1,7 +1,7 @@
 //! Ollama local-LLM integration for Winter App.
 //!
-//! Handles Ollama installation detection, server health checks, model listing,
-//! and conversation-history compression.
+//! Handles Ollama installation detection, server lifecycle and health checks, model listing,
+//! model deletion, embeddings, and conversation-history compression.
 //!
 //! **Note:** As of the current release, Claude Haiku handles context compression
 //! by default (see `compaction.rs`). Ollama remains available as an optional
@@ -10,11 +10,12 @@
 use crate::claude::types::{ChatMessage, ContentBlock, MessageContent};
 use crate::STORE_FILE;
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::time::Duration;
-use std::process::Command; 
-use tauri::AppHandle;
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_store::StoreExt;
 use tauri_plugin_opener::OpenerExt;
 
@@ -30,6 +31,12 @@ const MIN_SUMMARIZE_LEN: usize = 500;
 /// Minimum number of messages in history before compression is attempted.
 const HISTORY_COMPRESS_THRESHOLD: usize = 10;
 
+/// Default embedding model used by [`embed`] when none is configured.
+const DEFAULT_EMBED_MODEL: &str = "nomic-embed-text";
+
+/// Maximum number of inputs sent to `/api/embed` in a single request.
+const EMBED_BATCH_SIZE: usize = 32;
+
 /// Selects a default Ollama model based on available system RAM.
 ///
 /// Allocates up to 25 % of free memory to the model:
@@ -55,10 +62,80 @@ pub struct OllamaSettings {
     pub base_url: String,
     /// Ollama model name to use for summarisation (e.g. `"qwen2.5:7b"`).
     pub model: String,
+    /// Ollama model name used for embeddings (e.g. `"nomic-embed-text"`).
+    pub embed_model: String,
+}
+
+/// Generation options forwarded to `/api/generate`.
+///
+/// `keep_alive` controls how long Ollama keeps the model loaded after a request
+/// (e.g. `"10m"`, or `"-1"` to keep it warm indefinitely between compactions).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationOptions {
+    /// Context window size in tokens; `None` keeps the model's default.
+    pub num_ctx: Option<u32>,
+    /// Maximum number of tokens to generate.
+    pub num_predict: i32,
+    /// Sampling temperature.
+    pub temperature: f32,
+    /// Model keep-alive duration; `None` keeps Ollama's default (5 minutes).
+    pub keep_alive: Option<String>,
+}
+
+impl Default for GenerationOptions {
+    fn default() -> Self {
+        GenerationOptions { num_ctx: None, num_predict: 512, temperature: 0.3, keep_alive: None }
+    }
+}
+
+impl GenerationOptions {
+    /// Writes `options` and `keep_alive` into an Ollama request body.
+    pub fn apply(&self, body: &mut serde_json::Value) {
+        let mut options = json!({ "temperature": self.temperature, "num_predict": self.num_predict });
+        if let Some(ctx) = self.num_ctx {
+            options["num_ctx"] = json!(ctx);
+        }
+        body["options"] = options;
+        if let Some(ref ka) = self.keep_alive {
+            // Ollama accepts either a duration string ("10m") or a number of seconds (-1, 0).
+            body["keep_alive"] = match ka.parse::<i64>() {
+                Ok(secs) => json!(secs),
+                Err(_) => json!(ka),
+            };
+        }
+    }
 }
 
 // ── Settings ───────────────────────────────────────────────────────
 
+/// Loads the user's Ollama generation options from the store, falling back to defaults.
+pub fn get_generation_options(app: &AppHandle) -> GenerationOptions {
+    let defaults = GenerationOptions::default();
+    let Ok(store) = app.store(STORE_FILE) else { return defaults };
+
+    GenerationOptions {
+        num_ctx: store
+            .get("ollama_num_ctx")
+            .and_then(|v| v.as_u64())
+            .filter(|n| *n > 0)
+            .map(|n| n as u32),
+        num_predict: store
+            .get("ollama_num_predict")
+            .and_then(|v| v.as_i64())
+            .map(|n| n as i32)
+            .unwrap_or(defaults.num_predict),
+        temperature: store
+            .get("ollama_temperature")
+            .and_then(|v| v.as_f64())
+            .map(|t| t as f32)
+            .unwrap_or(defaults.temperature),
+        keep_alive: store
+            .get("ollama_keep_alive")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .filter(|s| !s.is_empty()),
+    }
+}
+
 /// Loads Ollama settings from the Tauri persistent store.
 ///
 /// Falls back to sensible defaults (disabled, localhost, RAM-appropriate model)
@@ -71,6 +148,7 @@ pub fn get_settings(app: &AppHandle) -> OllamaSettings {
                 enabled: false,
                 base_url: DEFAULT_OLLAMA_URL.to_string(),
                 model: default_model_for_system(),
+                embed_model: DEFAULT_EMBED_MODEL.to_string(),
             };
         }
     };
@@ -90,10 +168,17 @@ pub fn get_settings(app: &AppHandle) -> OllamaSettings {
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(default_model_for_system);
 
+    let embed_model = store
+        .get("ollama_embed_model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_EMBED_MODEL.to_string());
+
     OllamaSettings {
         enabled,
         base_url,
         model,
+        embed_model,
     }
 }
 
@@ -189,7 +274,7 @@ pub async fn install(app: &AppHandle) -> Result<String, String> {
                     Ok(out) if out.status.success() => {
                         return Ok("Ollama installed via Homebrew! Please restart.".to_string());
                     }
-                    _ => { println!("Brew install failed."); }
+                    _ => { tracing::warn!("Brew install failed."); }
                 }
             }
         }
@@ -202,6 +287,202 @@ pub async fn install(app: &AppHandle) -> Result<String, String> {
     }
 }
 
+// ── Server Lifecycle ───────────────────────────────────────────────
+
+/// Maximum time to wait for a freshly spawned `ollama serve` to answer health checks.
+const SERVE_READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Interval between health polls while waiting for the server to come up.
+const SERVE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Interval at which the supervisor checks whether the spawned server has exited.
+const SUPERVISE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Tauri state holding the `ollama serve` child process spawned by Winter, if any.
+/// Servers started outside the app are never tracked here and never stopped by it.
+#[derive(Default, Clone)]
+pub struct ServerState {
+    child: Arc<tokio::sync::Mutex<Option<tokio::process::Child>>>,
+}
+
+/// Resolves the `ollama` executable: PATH first, then the platform's common install paths.
+async fn find_binary() -> Option<String> {
+    let lookup = if cfg!(target_os = "windows") { ("cmd", vec!["/C", "where", "ollama"]) } else { ("which", vec!["ollama"]) };
+    if let Ok(out) = tokio::process::Command::new(lookup.0).args(&lookup.1).output().await {
+        if out.status.success() {
+            if let Some(first) = String::from_utf8_lossy(&out.stdout).lines().next() {
+                return Some(first.trim().to_string());
+            }
+        }
+    }
+    let candidates: Vec<String> = if cfg!(target_os = "windows") {
+        let home = std::env::var("LOCALAPPDATA").unwrap_or_default();
+        vec![
+            format!("{}\\Programs\\Ollama\\ollama.exe", home),
+            format!("{}\\Ollama\\ollama.exe", home),
+            "C:\\Program Files\\Ollama\\ollama.exe".to_string(),
+        ]
+    } else {
+        vec![
+            "/Applications/Ollama.app/Contents/Resources/ollama".to_string(),
+            "/usr/local/bin/ollama".to_string(),
+            "/usr/bin/ollama".to_string(),
+        ]
+    };
+    candidates.into_iter().find(|p| std::path::Path::new(p).exists())
+}
+
+/// Starts `ollama serve` if the server at `base_url` is not already reachable.
+///
+/// Polls `/api/version` until the server answers (emitting `ollama-ready` with the
+/// version string) or [`SERVE_READY_TIMEOUT`] elapses. A background supervisor then
+/// watches the child and emits `ollama-exited` if it dies.
+pub async fn start_server(app: &AppHandle, state: &ServerState, base_url: &str) -> Result<String, String> {
+    if let Ok(version) = check_health(base_url).await {
+        return Ok(version);
+    }
+    let binary = find_binary().await.ok_or_else(|| "Ollama is not installed".to_string())?;
+
+    {
+        let mut guard = state.child.lock().await;
+        if guard.is_none() {
+            let mut cmd = tokio::process::Command::new(&binary);
+            cmd.arg("serve")
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .kill_on_drop(true);
+            // Bind the server to the configured host:port rather than Ollama's default.
+            let host = base_url.trim_start_matches("http://").trim_start_matches("https://");
+            if !host.is_empty() && base_url != DEFAULT_OLLAMA_URL {
+                cmd.env("OLLAMA_HOST", host);
+            }
+            let child = cmd.spawn().map_err(|e| format!("Failed to spawn ollama serve: {}", e))?;
+            *guard = Some(child);
+            spawn_supervisor(app.clone(), state.clone());
+        }
+    }
+
+    let deadline = tokio::time::Instant::now() + SERVE_READY_TIMEOUT;
+    while tokio::time::Instant::now() < deadline {
+        if let Ok(version) = check_health(base_url).await {
+            let _ = app.emit("ollama-ready", &version);
+            return Ok(version);
+        }
+        if state.child.lock().await.is_none() {
+            return Err("ollama serve exited before becoming ready".to_string());
+        }
+        tokio::time::sleep(SERVE_POLL_INTERVAL).await;
+    }
+    Err(format!("Ollama did not become ready within {}s", SERVE_READY_TIMEOUT.as_secs()))
+}
+
+/// Stops the `ollama serve` process previously spawned by [`start_server`].
+pub async fn stop_server(state: &ServerState) -> Result<(), String> {
+    let mut guard = state.child.lock().await;
+    let mut child = guard.take().ok_or_else(|| "Ollama was not started by Winter".to_string())?;
+    child.kill().await.map_err(|e| format!("Failed to stop ollama serve: {}", e))
+}
+
+/// Watches the spawned server and clears the state (emitting `ollama-exited`) once it exits.
+fn spawn_supervisor(app: AppHandle, state: ServerState) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(SUPERVISE_INTERVAL).await;
+            let mut guard = state.child.lock().await;
+            let Some(child) = guard.as_mut() else { return };
+            match child.try_wait() {
+                Ok(Some(status)) => {
+                    *guard = None;
+                    let _ = app.emit("ollama-exited", status.code());
+                    return;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!("Failed to poll ollama serve: {}", e);
+                    return;
+                }
+            }
+        }
+    });
+}
+
+// ── Health Monitor ─────────────────────────────────────────────────
+
+/// Interval between background health probes.
+const HEALTH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Timeout for a single background health probe — much shorter than [`OLLAMA_TIMEOUT`].
+const HEALTH_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Last health state observed by the background monitor.
+/// `None` until the first probe has run (or while Ollama is disabled).
+#[derive(Default, Clone)]
+pub struct HealthState {
+    online: Arc<std::sync::Mutex<Option<bool>>>,
+}
+
+/// Payload of the `ollama-health` event.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthEvent {
+    /// Whether the server answered the last probe.
+    pub online: bool,
+    /// Server version when online.
+    pub version: Option<String>,
+}
+
+/// Returns true only if the monitor has positively seen the server go down,
+/// so callers can skip Ollama instead of waiting for a request timeout.
+pub fn is_known_offline(app: &AppHandle) -> bool {
+    app.try_state::<HealthState>()
+        .map(|s| *s.online.lock().unwrap_or_else(|e| e.into_inner()) == Some(false))
+        .unwrap_or(false)
+}
+
+/// Returns true if Ollama is in use — either enabled directly or selected as compaction provider.
+fn monitoring_wanted(app: &AppHandle) -> bool {
+    get_settings(app).enabled
+        || crate::compaction::get_settings(app).provider == crate::compaction::CompactionProvider::Ollama
+}
+
+/// Spawns the background loop that probes `/api/version` while Ollama is in use
+/// and emits `ollama-health` whenever the server goes down or comes back.
+pub fn spawn_health_monitor(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let client = match Client::builder().timeout(HEALTH_PROBE_TIMEOUT).build() {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::warn!("Health monitor disabled: {}", e);
+                return;
+            }
+        };
+        loop {
+            let state = app.state::<HealthState>().inner().clone();
+            if monitoring_wanted(&app) {
+                let base_url = get_settings(&app).base_url;
+                let version = probe_version(&client, &base_url).await;
+                let online = version.is_some();
+                let previous = state.online.lock().unwrap_or_else(|e| e.into_inner()).replace(online);
+                if previous != Some(online) {
+                    let _ = app.emit("ollama-health", HealthEvent { online, version });
+                }
+            } else {
+                *state.online.lock().unwrap_or_else(|e| e.into_inner()) = None;
+            }
+            tokio::time::sleep(HEALTH_INTERVAL).await;
+        }
+    });
+}
+
+/// Single short-timeout probe of `/api/version`; returns the version if reachable.
+async fn probe_version(client: &Client, base_url: &str) -> Option<String> {
+    #[derive(Deserialize)]
+    struct VersionResp { version: String }
+
+    let resp = client.get(format!("{}/api/version", base_url)).send().await.ok()?;
+    resp.json::<VersionResp>().await.ok().map(|v| v.version)
+}
+
 // ── API Helpers ────────────────────────────────────────────────────
 
 /// Builds a reusable `reqwest::Client` with [`OLLAMA_TIMEOUT`] applied.
@@ -240,28 +521,92 @@ pub async fn list_models(base_url: &str) -> Result<Vec<String>, String> {
     Ok(data.models.into_iter().map(|m| m.name).collect())
 }
 
+/// Result of a model deletion request, returned to the settings UI.
+///
+/// When `deleted` is false the model was only inspected — the caller must
+/// confirm before anything is removed from disk.
+#[derive(Debug, Serialize)]
+pub struct DeleteReport {
+    /// Model name as reported by `/api/tags`.
+    pub model: String,
+    /// On-disk size of the model in bytes (the space reclaimed once deleted).
+    pub size_bytes: u64,
+    /// Whether the model was actually removed.
+    pub deleted: bool,
+}
+
+/// Looks up a single model's on-disk size via `/api/tags`.
+///
+/// Returns an error if the model is not installed locally.
+pub async fn model_size(base_url: &str, model: &str) -> Result<u64, String> {
+    let client = build_client()?;
+    let url = format!("{}/api/tags", base_url);
+
+    #[derive(Deserialize)] struct Model { name: String, #[serde(default)] size: u64 }
+    #[derive(Deserialize)] struct ModelsResp { models: Vec<Model> }
+
+    let resp = client.get(&url).send().await.map_err(|e| format!("List failed: {}", e))?;
+    let data: ModelsResp = resp.json().await.map_err(|e| format!("Invalid models: {}", e))?;
+    data.models
+        .into_iter()
+        .find(|m| m.name == model)
+        .map(|m| m.size)
+        .ok_or_else(|| format!("Model '{}' is not installed", model))
+}
+
+/// Deletes a local model via `DELETE /api/delete`.
+///
+/// Without `confirm`, nothing is removed: the report only carries the size
+/// that would be reclaimed so the UI can ask the user first.
+pub async fn delete_model(base_url: &str, model: &str, confirm: bool) -> Result<DeleteReport, String> {
+    let size_bytes = model_size(base_url, model).await?;
+    if !confirm {
+        return Ok(DeleteReport { model: model.to_string(), size_bytes, deleted: false });
+    }
+
+    let client = build_client()?;
+    let url = format!("{}/api/delete", base_url);
+    let resp = client
+        .delete(&url)
+        .json(&json!({ "model": model }))
+        .send()
+        .await
+        .map_err(|e| format!("Delete failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("Ollama error: {}", resp.status()));
+    }
+    Ok(DeleteReport { model: model.to_string(), size_bytes, deleted: true })
+}
+
 /// Summarises `text` using the Ollama `/api/generate` endpoint.
 ///
 /// Texts shorter than [`MIN_SUMMARIZE_LEN`] are returned unchanged.
 /// The prompt instructs the model to emit only decisions, actions, and remaining
 /// work — suppressing the "User asked X, then Y" pattern.
-pub async fn summarize(base_url: &str, model: &str, text: &str) -> Result<String, String> {
+pub async fn summarize(base_url: &str, model: &str, options: &GenerationOptions, text: &str) -> Result<String, String> {
     if text.len() < MIN_SUMMARIZE_LEN { return Ok(text.to_string()); }
 
-    let client = build_client()?;
-    let url = format!("{}/api/generate", base_url);
     let prompt = format!("Extract ONLY the key facts and decisions from this conversation. \
 Do NOT list user requests. Do NOT write \"User asked X, then Y\". \
 Output format: what was decided, what was done, what remains. Nothing else.\n\n{}", text);
 
-    let body = json!({
-        "model": model, "prompt": prompt, "stream": false,
-        "options": { "temperature": 0.3, "num_predict": 512 }
-    });
+    generate(base_url, model, options, &prompt).await
+}
+
+/// Runs a single non-streaming `/api/generate` completion and returns the trimmed response.
+pub async fn generate(base_url: &str, model: &str, options: &GenerationOptions, prompt: &str) -> Result<String, String> {
+    let client = build_client()?;
+    let url = format!("{}/api/generate", base_url);
+
+    let mut body = json!({ "model": model, "prompt": prompt, "stream": false });
+    options.apply(&mut body);
 
     #[derive(Deserialize)] struct GenResp { response: String }
+    crate::api_debug::request("ollama", "POST", &url, &[], Some(&body));
     let resp = client.post(&url).json(&body).send().await.map_err(|e| format!("Gen failed: {}", e))?;
-    
+    crate::api_debug::response("ollama", &url, resp.status().as_u16());
+
     if !resp.status().is_success() {
         return Err(format!("Ollama error: {}", resp.status()));
     }
@@ -269,6 +614,47 @@ Output format: what was decided, what was done, what remains. Nothing else.\n\n{
     Ok(data.response.trim().to_string())
 }
 
+/// Answers a conversation with one `/api/generate` completion over its text
+/// as a transcript. Images and tool calls are left out.
+pub async fn reply(base_url: &str, model: &str, options: &GenerationOptions, messages: &[ChatMessage]) -> Result<String, String> {
+    let mut prompt = String::new();
+    for msg in messages {
+        prompt.push_str(&format!("[{}]: {}\n\n", msg.role, extract_text_content(&msg.content)));
+    }
+    prompt.push_str("[assistant]:");
+    generate(base_url, model, options, &prompt).await
+}
+
+/// Embeds `texts` with the Ollama `/api/embed` endpoint.
+///
+/// Inputs are sent in batches of [`EMBED_BATCH_SIZE`]; the returned vectors are
+/// in the same order as `texts`. Used by semantic memory and conversation search.
+pub async fn embed(base_url: &str, model: &str, texts: &[String]) -> Result<Vec<Vec<f32>>, String> {
+    if texts.is_empty() { return Ok(Vec::new()); }
+
+    let client = build_client()?;
+    let url = format!("{}/api/embed", base_url);
+
+    #[derive(Deserialize)] struct EmbedResp { embeddings: Vec<Vec<f32>> }
+
+    let mut vectors = Vec::with_capacity(texts.len());
+    for batch in texts.chunks(EMBED_BATCH_SIZE) {
+        let body = json!({ "model": model, "input": batch });
+        crate::api_debug::request("ollama", "POST", &url, &[], Some(&body));
+        let resp = client.post(&url).json(&body).send().await.map_err(|e| format!("Embed failed: {}", e))?;
+        crate::api_debug::response("ollama", &url, resp.status().as_u16());
+        if !resp.status().is_success() {
+            return Err(format!("Ollama error: {}", resp.status()));
+        }
+        let data: EmbedResp = resp.json().await.map_err(|e| format!("Invalid embeddings: {}", e))?;
+        if data.embeddings.len() != batch.len() {
+            return Err(format!("Expected {} embeddings, got {}", batch.len(), data.embeddings.len()));
+        }
+        vectors.extend(data.embeddings);
+    }
+    Ok(vectors)
+}
+
 /// Sentinel prefix written at the start of a compressed-history message.
 const PRIOR_CONTEXT_PREFIX: &str = "[Prior context —";
 
@@ -282,7 +668,7 @@ const PRIOR_CONTEXT_PREFIX: &str = "[Prior context —";
 ///
 /// Returns the shortened message list on success, or the original list if the
 /// text to compress is below the minimum length threshold.
-pub async fn compress_history(base_url: &str, model: &str, messages: &[ChatMessage]) -> Result<Vec<ChatMessage>, String> {
+pub async fn compress_history(base_url: &str, model: &str, options: &GenerationOptions, messages: &[ChatMessage]) -> Result<Vec<ChatMessage>, String> {
     if messages.len() <= HISTORY_COMPRESS_THRESHOLD { return Ok(messages.to_vec()); }
 
     // Dynamic keep: at least 2 user+assistant turn pairs, min 4, max 8
@@ -311,7 +697,7 @@ pub async fn compress_history(base_url: &str, model: &str, messages: &[ChatMessa
     } else {
         transcript
     };
-    let summary = summarize(base_url, model, &input).await?;
+    let summary = summarize(base_url, model, options, &input).await?;
 
     let total_compressed = if existing_summary.is_some() {
         // Count includes previously compressed messages
@@ -1,14 +1,147 @@
 /// HTTP client for the OpenCode server API.
 /// Manages sessions, prompt submission, SSE streaming, and file/question proxying.
 use crate::claude::types::ChatStreamEvent;
-use crate::opencode::types::{OcSession, SseEnvelope, SseMessagePart};
+use crate::error::AppError;
+use crate::opencode::types::{OcFilePart, OcSession, SseEnvelope, SseMessagePart};
+use base64::Engine;
 use futures::StreamExt;
 use reqwest::Client;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::ipc::Channel;
 
+/// Largest file accepted as a prompt attachment; the whole file is inlined as base64.
+const MAX_ATTACHMENT_BYTES: u64 = 20 * 1024 * 1024;
+
+/// How long the event stream may stay quiet before the session's status is checked.
+const QUIET_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+/// A session that is busy but has sent nothing for this long is treated as stuck.
+const STALL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+/// Consecutive failed status checks before the server is given up on.
+const MAX_FAILED_CHECKS: u32 = 3;
+
+/// Error for a request that got no response: an unreachable server is
+/// `Unavailable` (OpenCode is not running), a slow one `Timeout`.
+fn request_error(context: &str, error: reqwest::Error) -> AppError {
+    let message = format!("{}: {}", context, error);
+    if error.is_timeout() {
+        AppError::Timeout(message)
+    } else if error.is_connect() {
+        AppError::Unavailable(message)
+    } else {
+        AppError::Network(message)
+    }
+}
+
+/// Error for a non-success response; a missing session or file is `NotFound`.
+fn status_error(status: reqwest::StatusCode, message: String) -> AppError {
+    if status == reqwest::StatusCode::NOT_FOUND {
+        AppError::NotFound(message)
+    } else {
+        AppError::Api { status: status.as_u16(), message }
+    }
+}
+
+/// Activity bookkeeping for a streamed session.
+struct Keepalive {
+    last_activity: std::time::Instant,
+    last_check: std::time::Instant,
+    failed_checks: u32,
+}
+
+impl Keepalive {
+    fn new() -> Self {
+        let now = std::time::Instant::now();
+        Keepalive {
+            last_activity: now,
+            last_check: now,
+            failed_checks: 0,
+        }
+    }
+
+    fn touch(&mut self) {
+        self.last_activity = std::time::Instant::now();
+        self.failed_checks = 0;
+    }
+}
+
+/// What a quiet event stream turned out to mean.
+enum QuietOutcome {
+    /// The session is still working (or a question is open); keep waiting.
+    Waiting,
+    /// The session went idle without the stream reporting it.
+    Finished,
+    /// The session or server is stuck; the message explains why.
+    Stuck(String),
+}
+
+/// Guesses a MIME type from the file extension. Unknown extensions are sent as
+/// `text/plain` when the content is valid UTF-8 and `application/octet-stream` otherwise.
+fn attachment_mime(path: &Path, bytes: &[u8]) -> String {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let mime = match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        "md" | "markdown" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "csv" => "text/csv",
+        _ if std::str::from_utf8(bytes).is_ok() => "text/plain",
+        _ => "application/octet-stream",
+    };
+    mime.to_string()
+}
+
+impl OcFilePart {
+    /// Wraps an already-encoded image from the chat input; `index` only names the file.
+    pub fn image(index: usize, mime: String, data: String) -> Self {
+        let ext = mime.split('/').next_back().unwrap_or("png").to_string();
+        OcFilePart {
+            filename: format!("image_{}.{}", index, ext),
+            mime,
+            data,
+        }
+    }
+
+    /// Reads a file from disk and encodes it for a prompt.
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let meta = std::fs::metadata(path)
+            .map_err(|e| format!("Cannot read attachment {}: {}", path.display(), e))?;
+        if !meta.is_file() {
+            return Err(format!("Attachment is not a file: {}", path.display()));
+        }
+        if meta.len() > MAX_ATTACHMENT_BYTES {
+            return Err(format!(
+                "Attachment {} is too large ({} bytes, max {})",
+                path.display(),
+                meta.len(),
+                MAX_ATTACHMENT_BYTES
+            ));
+        }
+        let bytes = std::fs::read(path)
+            .map_err(|e| format!("Cannot read attachment {}: {}", path.display(), e))?;
+        Ok(OcFilePart {
+            mime: attachment_mime(path, &bytes),
+            filename: path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "attachment".to_string()),
+            data: base64::engine::general_purpose::STANDARD.encode(&bytes),
+        })
+    }
+}
+
 /// HTTP client for communicating with a running OpenCode server instance.
 /// All requests include a `?directory=<workspace>` parameter to scope operations.
 pub struct OpenCodeClient {
@@ -62,7 +195,7 @@ impl OpenCodeClient {
     }
 
     /// Creates a new OpenCode session and returns its metadata.
-    pub async fn create_session(&self) -> Result<OcSession, String> {
+    pub async fn create_session(&self) -> Result<OcSession, AppError> {
         let url = self.url("/session");
         let resp = self
             .client
@@ -71,43 +204,43 @@ impl OpenCodeClient {
             .body("{}")
             .send()
             .await
-            .map_err(|e| format!("Failed to create session: {}", e))?;
+            .map_err(|e| request_error("Failed to create session", e))?;
 
         if !resp.status().is_success() {
             let status = resp.status();
             let body = resp.text().await.unwrap_or_default();
-            return Err(format!(
+            return Err(status_error(status, format!(
                 "Create session failed: HTTP {} — {}",
                 status, body
-            ));
+            )));
         }
 
         resp.json::<OcSession>()
             .await
-            .map_err(|e| format!("Failed to parse created session: {}", e))
+            .map_err(|e| AppError::Failed(format!("Failed to parse created session: {}", e)))
     }
 
     /// Sends a prompt to the given session asynchronously (fire-and-forget server-side).
     /// Optionally appends a system modifier. Returns immediately once the server accepts the prompt.
-    /// Images are sent as OpenCode "file" parts with data-URL encoding.
+    /// Images and other attachments are sent as OpenCode "file" parts with data-URL encoding.
     pub async fn prompt_async(
         &self,
         session_id: &str,
         content: &str,
-        images: &[(String, String)], // (media_type, base64_data)
+        files: &[OcFilePart],
         system: Option<&str>,
-    ) -> Result<(), String> {
+    ) -> Result<(), AppError> {
         let url = self.url(&format!("/session/{}/prompt_async", session_id));
 
         let mut parts = Vec::<serde_json::Value>::new();
 
-        // Add image parts first (OpenCode "file" format with data: URLs)
-        for (i, (mime, b64)) in images.iter().enumerate() {
+        // Add file parts first (OpenCode "file" format with data: URLs)
+        for file in files {
             parts.push(serde_json::json!({
                 "type": "file",
-                "mime": mime,
-                "url": format!("data:{};base64,{}", mime, b64),
-                "filename": format!("image_{}.{}", i, mime.split('/').last().unwrap_or("png"))
+                "mime": file.mime,
+                "url": format!("data:{};base64,{}", file.mime, file.data),
+                "filename": file.filename
             }));
         }
 
@@ -121,6 +254,7 @@ impl OpenCodeClient {
             body["system"] = serde_json::Value::String(s.to_string());
         }
 
+        crate::api_debug::request("opencode", "POST", &url, &[("content-type", "application/json")], Some(&body));
         let resp = self
             .client
             .post(&url)
@@ -128,7 +262,8 @@ impl OpenCodeClient {
             .json(&body)
             .send()
             .await
-            .map_err(|e| format!("Failed to send prompt: {}", e))?;
+            .map_err(|e| request_error("Failed to send prompt", e))?;
+        crate::api_debug::response("opencode", &url, resp.status().as_u16());
 
         if !resp.status().is_success() {
             let status = resp.status();
@@ -140,10 +275,10 @@ impl OpenCodeClient {
             } else {
                 body_text
             };
-            return Err(format!(
+            return Err(status_error(status, format!(
                 "Prompt failed: HTTP {} — {}",
                 status, truncated
-            ));
+            )));
         }
 
         Ok(())
@@ -172,54 +307,54 @@ impl OpenCodeClient {
     }
 
     /// Sends an abort request to halt the current running prompt in the given session.
-    pub async fn abort(&self, session_id: &str) -> Result<(), String> {
+    pub async fn abort(&self, session_id: &str) -> Result<(), AppError> {
         let url = self.url(&format!("/session/{}/abort", session_id));
         let resp = self
             .client
             .post(&url)
             .send()
             .await
-            .map_err(|e| format!("Failed to abort: {}", e))?;
+            .map_err(|e| request_error("Failed to abort", e))?;
 
         if !resp.status().is_success() {
-            return Err(format!("Abort failed: HTTP {}", resp.status()));
+            return Err(status_error(resp.status(), format!("Abort failed: HTTP {}", resp.status())));
         }
 
         Ok(())
     }
 
     /// Returns path info from the OpenCode server (working directory, etc.).
-    pub async fn get_path_info(&self) -> Result<serde_json::Value, String> {
+    pub async fn get_path_info(&self) -> Result<serde_json::Value, AppError> {
         let url = self.url("/path");
         let resp = self
             .client
             .get(&url)
             .send()
             .await
-            .map_err(|e| format!("Path request failed: {}", e))?;
+            .map_err(|e| request_error("Path request failed", e))?;
         if !resp.status().is_success() {
-            return Err(format!("Path request failed: HTTP {}", resp.status()));
+            return Err(status_error(resp.status(), format!("Path request failed: HTTP {}", resp.status())));
         }
         resp.json::<serde_json::Value>()
             .await
-            .map_err(|e| format!("Path parse failed: {}", e))
+            .map_err(|e| AppError::Failed(format!("Path parse failed: {}", e)))
     }
 
     /// Lists files at the given path within the OpenCode workspace.
-    pub async fn list_files(&self, path: &str) -> Result<serde_json::Value, String> {
+    pub async fn list_files(&self, path: &str) -> Result<serde_json::Value, AppError> {
         let url = self.url(&format!("/file?path={}", urlencoding::encode(path)));
         let resp = self
             .client
             .get(&url)
             .send()
             .await
-            .map_err(|e| format!("List files failed: {}", e))?;
+            .map_err(|e| request_error("List files failed", e))?;
         if !resp.status().is_success() {
-            return Err(format!("List files failed: HTTP {}", resp.status()));
+            return Err(status_error(resp.status(), format!("List files failed: HTTP {}", resp.status())));
         }
         resp.json::<serde_json::Value>()
             .await
-            .map_err(|e| format!("List files parse failed: {}", e))
+            .map_err(|e| AppError::Failed(format!("List files parse failed: {}", e)))
     }
 
     /// Returns the content of a file at the given path in the OpenCode workspace.
@@ -227,7 +362,7 @@ impl OpenCodeClient {
         &self,
         path: &str,
         opencode_dir: &str,
-    ) -> Result<serde_json::Value, String> {
+    ) -> Result<serde_json::Value, AppError> {
         let url = self.url(&format!(
             "/file/content?path={}",
             urlencoding::encode(path)
@@ -238,33 +373,33 @@ impl OpenCodeClient {
             .header("x-opencode-directory", opencode_dir)
             .send()
             .await
-            .map_err(|e| format!("File content failed: {}", e))?;
+            .map_err(|e| request_error("File content failed", e))?;
         if !resp.status().is_success() {
-            return Err(format!("File content failed: HTTP {}", resp.status()));
+            return Err(status_error(resp.status(), format!("File content failed: HTTP {}", resp.status())));
         }
         resp.json::<serde_json::Value>()
             .await
-            .map_err(|e| format!("File content parse failed: {}", e))
+            .map_err(|e| AppError::Failed(format!("File content parse failed: {}", e)))
     }
 
     /// Returns all pending questions awaiting user input in the OpenCode session.
-    pub async fn get_questions(&self) -> Result<serde_json::Value, String> {
+    pub async fn get_questions(&self) -> Result<serde_json::Value, AppError> {
         let url = self.url("/question");
         let resp = self
             .client
             .get(&url)
             .send()
             .await
-            .map_err(|e| format!("Questions request failed: {}", e))?;
+            .map_err(|e| request_error("Questions request failed", e))?;
         if !resp.status().is_success() {
-            return Err(format!(
+            return Err(status_error(resp.status(), format!(
                 "Questions request failed: HTTP {}",
                 resp.status()
-            ));
+            )));
         }
         resp.json::<serde_json::Value>()
             .await
-            .map_err(|e| format!("Questions parse failed: {}", e))
+            .map_err(|e| AppError::Failed(format!("Questions parse failed: {}", e)))
     }
 
     /// Submits answers to a pending question in the OpenCode session.
@@ -272,7 +407,7 @@ impl OpenCodeClient {
         &self,
         request_id: &str,
         answers: serde_json::Value,
-    ) -> Result<(), String> {
+    ) -> Result<(), AppError> {
         let url = self.url(&format!("/question/{}/reply", request_id));
         let resp = self
             .client
@@ -281,24 +416,24 @@ impl OpenCodeClient {
             .json(&serde_json::json!({ "answers": answers }))
             .send()
             .await
-            .map_err(|e| format!("Reply failed: {}", e))?;
+            .map_err(|e| request_error("Reply failed", e))?;
         if !resp.status().is_success() {
-            return Err(format!("Reply failed: HTTP {}", resp.status()));
+            return Err(status_error(resp.status(), format!("Reply failed: HTTP {}", resp.status())));
         }
         Ok(())
     }
 
     /// Rejects a pending question in the OpenCode session without providing answers.
-    pub async fn reject_question(&self, request_id: &str) -> Result<(), String> {
+    pub async fn reject_question(&self, request_id: &str) -> Result<(), AppError> {
         let url = self.url(&format!("/question/{}/reject", request_id));
         let resp = self
             .client
             .post(&url)
             .send()
             .await
-            .map_err(|e| format!("Reject failed: {}", e))?;
+            .map_err(|e| request_error("Reject failed", e))?;
         if !resp.status().is_success() {
-            return Err(format!("Reject failed: HTTP {}", resp.status()));
+            return Err(status_error(resp.status(), format!("Reject failed: HTTP {}", resp.status())));
         }
         Ok(())
     }
@@ -307,70 +442,91 @@ impl OpenCodeClient {
     pub async fn get_session_messages(
         &self,
         session_id: &str,
-    ) -> Result<serde_json::Value, String> {
+    ) -> Result<serde_json::Value, AppError> {
         let url = self.url(&format!("/session/{}/message", session_id));
         let resp = self
             .client
             .get(&url)
             .send()
             .await
-            .map_err(|e| format!("Messages request failed: {}", e))?;
+            .map_err(|e| request_error("Messages request failed", e))?;
         if !resp.status().is_success() {
-            return Err(format!(
+            return Err(status_error(resp.status(), format!(
                 "Messages request failed: HTTP {}",
                 resp.status()
-            ));
+            )));
         }
         resp.json::<serde_json::Value>()
             .await
-            .map_err(|e| format!("Messages parse failed: {}", e))
+            .map_err(|e| AppError::Failed(format!("Messages parse failed: {}", e)))
     }
 
     /// Lists all OpenCode sessions for the current workspace directory.
-    pub async fn list_sessions(&self) -> Result<Vec<OcSession>, String> {
+    pub async fn list_sessions(&self) -> Result<Vec<OcSession>, AppError> {
         let url = self.url("/session");
         let resp = self
             .client
             .get(&url)
             .send()
             .await
-            .map_err(|e| format!("Failed to list sessions: {}", e))?;
+            .map_err(|e| request_error("Failed to list sessions", e))?;
 
         if !resp.status().is_success() {
             let status = resp.status();
             let body = resp.text().await.unwrap_or_default();
-            return Err(format!(
+            return Err(status_error(status, format!(
                 "List sessions failed: HTTP {} — {}",
                 status, body
-            ));
+            )));
         }
 
         resp.json::<Vec<OcSession>>()
             .await
-            .map_err(|e| format!("Failed to parse sessions: {}", e))
+            .map_err(|e| AppError::Failed(format!("Failed to parse sessions: {}", e)))
+    }
+
+    /// Fetches a single OpenCode session by ID.
+    pub async fn get_session(&self, session_id: &str) -> Result<OcSession, AppError> {
+        let url = self.url(&format!("/session/{}", session_id));
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| request_error("Failed to get session", e))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(status_error(status, format!("Get session failed: HTTP {} — {}", status, body)));
+        }
+
+        resp.json::<OcSession>()
+            .await
+            .map_err(|e| AppError::Failed(format!("Failed to parse session: {}", e)))
     }
 
     /// Deletes the given OpenCode session.
-    pub async fn delete_session(&self, session_id: &str) -> Result<(), String> {
+    pub async fn delete_session(&self, session_id: &str) -> Result<(), AppError> {
         let url = self.url(&format!("/session/{}", session_id));
         let resp = self
             .client
             .delete(&url)
             .send()
             .await
-            .map_err(|e| format!("Failed to delete session: {}", e))?;
+            .map_err(|e| request_error("Failed to delete session", e))?;
 
         if !resp.status().is_success() {
             let status = resp.status();
             let body = resp.text().await.unwrap_or_default();
-            return Err(format!("Delete session failed: HTTP {} — {}", status, body));
+            return Err(status_error(status, format!("Delete session failed: HTTP {} — {}", status, body)));
         }
 
         Ok(())
     }
 
     /// Renames the given OpenCode session to the specified title.
-    pub async fn rename_session(&self, session_id: &str, title: &str) -> Result<(), String> {
+    pub async fn rename_session(&self, session_id: &str, title: &str) -> Result<(), AppError> {
         let url = self.url(&format!("/session/{}", session_id));
         let body = serde_json::json!({ "title": title });
         let resp = self
@@ -380,48 +536,180 @@ impl OpenCodeClient {
             .json(&body)
             .send()
             .await
-            .map_err(|e| format!("Failed to rename session: {}", e))?;
+            .map_err(|e| request_error("Failed to rename session", e))?;
 
         if !resp.status().is_success() {
             let status = resp.status();
             let body_text = resp.text().await.unwrap_or_default();
-            return Err(format!(
+            return Err(status_error(status, format!(
                 "Rename session failed: HTTP {} — {}",
                 status, body_text
-            ));
+            )));
         }
 
         Ok(())
     }
 
-    /// Sends an idle "continue" ping to prevent session timeout.
-    /// Used internally when no SSE activity is detected for IDLE_TIMEOUT seconds.
-    async fn send_idle_ping(&self, session_id: &str, ping_num: u32, max_pings: u32) {
-        eprintln!(
-            "[winter-app] idle-ping {}/{} for session {}",
-            ping_num, max_pings, session_id
-        );
-        if let Ok(pc) = Client::builder()
-            .timeout(std::time::Duration::from_secs(10))
-            .build()
+    /// Reports whether OpenCode is still working on the session, from
+    /// `/session/status`. Servers without that endpoint are asked for the
+    /// session's messages instead: the session is busy until its last assistant
+    /// message has a completion time.
+    async fn is_session_busy(&self, session_id: &str) -> Result<bool, String> {
+        let resp = self
+            .client
+            .get(self.url("/session/status"))
+            .send()
+            .await
+            .map_err(|e| format!("Status request failed: {}", e))?;
+        if resp.status().is_success() {
+            let statuses = resp
+                .json::<Value>()
+                .await
+                .map_err(|e| format!("Status parse failed: {}", e))?;
+            // Sessions missing from the map are idle.
+            return Ok(statuses
+                .get(session_id)
+                .and_then(|s| s.get("type"))
+                .and_then(|t| t.as_str())
+                .is_some_and(|t| t != "idle"));
+        }
+
+        let messages = self.get_session_messages(session_id).await?;
+        let last = messages
+            .as_array()
+            .and_then(|m| m.last())
+            .and_then(|m| m.get("info"));
+        Ok(match last {
+            Some(info) if info.get("role").and_then(|v| v.as_str()) == Some("assistant") => info
+                .get("time")
+                .and_then(|t| t.get("completed"))
+                .is_none_or(|c| c.is_null()),
+            // A user message without a reply yet means a turn is pending.
+            Some(_) => true,
+            None => false,
+        })
+    }
+
+    /// Decides what a stream that has been quiet for a while means. Checks at
+    /// most once per `QUIET_CHECK_INTERVAL`; open questions count as activity.
+    async fn check_quiet(
+        &self,
+        session_id: &str,
+        keepalive: &mut Keepalive,
+        questions_pending: bool,
+    ) -> QuietOutcome {
+        if questions_pending {
+            keepalive.touch();
+        }
+        if keepalive.last_activity.elapsed() < QUIET_CHECK_INTERVAL
+            || keepalive.last_check.elapsed() < QUIET_CHECK_INTERVAL
         {
-            let ping_url = self.url(&format!("/session/{}/prompt_async", session_id));
-            let body = serde_json::json!({
-                "parts": [{"type": "text", "text": "continue"}]
-            });
-            let _ = pc
-                .post(&ping_url)
-                .header("content-type", "application/json")
-                .json(&body)
-                .send()
-                .await;
+            return QuietOutcome::Waiting;
+        }
+        keepalive.last_check = std::time::Instant::now();
+
+        match self.is_session_busy(session_id).await {
+            Ok(true) => {
+                keepalive.failed_checks = 0;
+                if keepalive.last_activity.elapsed() >= STALL_TIMEOUT {
+                    QuietOutcome::Stuck(format!(
+                        "OpenCode has reported no progress for {} minutes",
+                        STALL_TIMEOUT.as_secs() / 60
+                    ))
+                } else {
+                    QuietOutcome::Waiting
+                }
+            }
+            Ok(false) => QuietOutcome::Finished,
+            Err(e) => {
+                keepalive.failed_checks += 1;
+                tracing::warn!(
+                    "session status check {}/{} failed: {}",
+                    keepalive.failed_checks, MAX_FAILED_CHECKS, e
+                );
+                if keepalive.failed_checks >= MAX_FAILED_CHECKS {
+                    QuietOutcome::Stuck(format!("OpenCode server is not responding: {}", e))
+                } else {
+                    QuietOutcome::Waiting
+                }
+            }
+        }
+    }
+
+    /// Returns the request ID of a question request if it belongs to `session_id`.
+    fn question_for_session(request: &Value, session_id: &str) -> Option<String> {
+        if request.get("sessionID").and_then(|v| v.as_str()) != Some(session_id) {
+            return None;
+        }
+        request
+            .get("id")
+            .and_then(|v| v.as_str())
+            .map(|id| id.to_string())
+    }
+
+    /// Re-reads the session's messages after a reconnect without a resumable event id
+    /// and emits whatever text/reasoning the stream missed. `text_lengths` is shared with
+    /// the SSE loop, so text already delivered is never sent twice.
+    async fn backfill_text(
+        &self,
+        session_id: &str,
+        on_event: &Channel<ChatStreamEvent>,
+        known_msg_ids: &std::collections::HashSet<String>,
+        user_msg_ids: &std::collections::HashSet<String>,
+        text_lengths: &mut HashMap<String, usize>,
+    ) {
+        let messages = match self.get_session_messages(session_id).await {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!("SSE backfill failed: {}", e);
+                return;
+            }
+        };
+        for msg in messages.as_array().into_iter().flatten() {
+            let info = msg.get("info");
+            let mid = info
+                .and_then(|i| i.get("id"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            let role = info
+                .and_then(|i| i.get("role"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            if role != "assistant" || known_msg_ids.contains(mid) || user_msg_ids.contains(mid) {
+                continue;
+            }
+            for part in msg.get("parts").and_then(|p| p.as_array()).into_iter().flatten() {
+                let (Some(part_id), Some(full_text)) = (
+                    part.get("id").and_then(|v| v.as_str()),
+                    part.get("text").and_then(|v| v.as_str()),
+                ) else {
+                    continue;
+                };
+                let prev_len = text_lengths.get(part_id).copied().unwrap_or(0);
+                if full_text.len() <= prev_len || !full_text.is_char_boundary(prev_len) {
+                    continue;
+                }
+                let delta = full_text[prev_len..].to_string();
+                let event = match part.get("type").and_then(|v| v.as_str()) {
+                    Some("text") => ChatStreamEvent::Delta { text: delta },
+                    Some("reasoning") => ChatStreamEvent::Reasoning { text: delta },
+                    _ => continue,
+                };
+                let _ = on_event.send(event);
+                text_lengths.insert(part_id.to_string(), full_text.len());
+            }
         }
     }
 
     /// Subscribes to the global SSE event stream and emits `ChatStreamEvent`s via the IPC channel.
     /// Filters events to the given `session_id` only, skipping pre-existing message IDs.
-    /// Includes idle-ping logic: if no activity for 60s, sends "continue" (max 3 times).
-    /// Auto-reconnects on stream errors. Returns when the assistant message finishes or abort fires.
+    /// When the stream goes quiet the session status is checked: an idle session
+    /// ends the stream, and an error is only raised when the server stops answering or a
+    /// busy session makes no progress for `STALL_TIMEOUT`. Pending OpenCode questions are
+    /// emitted as `Question` events and never count as a stall.
+    /// Auto-reconnects on stream errors, resuming from the last seen event id via
+    /// `Last-Event-ID`; when the server never sent ids, missed text is backfilled from
+    /// the session's messages instead. Returns when the assistant message finishes or abort fires.
     pub async fn subscribe_sse(
         &self,
         session_id: &str,
@@ -429,8 +717,6 @@ impl OpenCodeClient {
         abort_flag: &AtomicBool,
         known_msg_ids: std::collections::HashSet<String>,
     ) -> Result<(), String> {
-        const IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
-        const MAX_IDLE_PINGS: u32 = 3;
         const RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
 
         let url = self.url("/global/event");
@@ -439,28 +725,47 @@ impl OpenCodeClient {
         let mut tool_started: HashMap<String, bool> = HashMap::new();
         let mut user_msg_ids: std::collections::HashSet<String> =
             std::collections::HashSet::new();
-        let mut idle_ping_count: u32 = 0;
-        let mut last_session_activity = std::time::Instant::now();
+        let mut keepalive = Keepalive::new();
+        let mut last_event_id: Option<String> = None;
+        let mut reconnecting = false;
+        // Questions waiting on the user; the session is not stalled while any are open.
+        let mut pending_questions: std::collections::HashSet<String> =
+            std::collections::HashSet::new();
 
         'reconnect: loop {
             if abort_flag.load(Ordering::SeqCst) {
                 return Ok(());
             }
 
-            if idle_ping_count >= MAX_IDLE_PINGS
-                && last_session_activity.elapsed() >= IDLE_TIMEOUT
+            match self
+                .check_quiet(session_id, &mut keepalive, !pending_questions.is_empty())
+                .await
             {
-                let _ = on_event.send(ChatStreamEvent::Error {
-                    message: "SSE connection lost, all idle pings exhausted".to_string(),
-                });
-                return Ok(());
+                QuietOutcome::Waiting => {}
+                QuietOutcome::Finished => {
+                    self.backfill_text(
+                        session_id,
+                        on_event,
+                        &known_msg_ids,
+                        &user_msg_ids,
+                        &mut text_lengths,
+                    )
+                    .await;
+                    let _ = on_event.send(ChatStreamEvent::StreamEnd);
+                    return Ok(());
+                }
+                QuietOutcome::Stuck(message) => {
+                    crate::crash::record_error(&message);
+                    let _ = on_event.send(ChatStreamEvent::Error { message });
+                    return Ok(());
+                }
             }
 
             let sse_client = match Client::builder().build() {
                 Ok(c) => c,
                 Err(e) => {
-                    eprintln!(
-                        "[winter-app] Failed to create SSE client: {}, retrying...",
+                    tracing::warn!(
+                        "Failed to create SSE client: {}, retrying...",
                         e
                     );
                     tokio::time::sleep(RECONNECT_DELAY).await;
@@ -468,48 +773,67 @@ impl OpenCodeClient {
                 }
             };
 
-            let resp = match sse_client
-                .get(&url)
-                .header("accept", "text/event-stream")
-                .send()
-                .await
-            {
+            let mut request = sse_client.get(&url).header("accept", "text/event-stream");
+            if let Some(id) = &last_event_id {
+                request = request.header("last-event-id", id.as_str());
+            }
+            crate::api_debug::request(
+                "opencode",
+                "GET",
+                &url,
+                &[("accept", "text/event-stream"), ("last-event-id", last_event_id.as_deref().unwrap_or(""))],
+                None,
+            );
+
+            let resp = match request.send().await {
                 Ok(r) => r,
                 Err(e) => {
-                    eprintln!("[winter-app] SSE connection failed: {}, retrying...", e);
-                    if idle_ping_count < MAX_IDLE_PINGS
-                        && last_session_activity.elapsed() >= IDLE_TIMEOUT
-                    {
-                        idle_ping_count += 1;
-                        self.send_idle_ping(session_id, idle_ping_count, MAX_IDLE_PINGS)
-                            .await;
-                        last_session_activity = std::time::Instant::now();
-                    }
+                    tracing::warn!("SSE connection failed: {}, retrying...", e);
                     tokio::time::sleep(RECONNECT_DELAY).await;
                     continue 'reconnect;
                 }
             };
 
+            crate::api_debug::response("opencode", &url, resp.status().as_u16());
             if !resp.status().is_success() {
                 let status = resp.status();
-                eprintln!("[winter-app] SSE HTTP {}, retrying...", status);
-                if idle_ping_count < MAX_IDLE_PINGS
-                    && last_session_activity.elapsed() >= IDLE_TIMEOUT
-                {
-                    idle_ping_count += 1;
-                    self.send_idle_ping(session_id, idle_ping_count, MAX_IDLE_PINGS)
-                        .await;
-                    last_session_activity = std::time::Instant::now();
-                }
+                tracing::warn!("SSE HTTP {}, retrying...", status);
                 tokio::time::sleep(RECONNECT_DELAY).await;
                 continue 'reconnect;
             }
 
-            eprintln!(
-                "[winter-app] SSE connected for session {}",
-                session_id
+            tracing::info!(
+                "SSE connected for session {} (last event id: {})",
+                session_id,
+                last_event_id.as_deref().unwrap_or("none")
             );
 
+            if reconnecting && last_event_id.is_none() {
+                self.backfill_text(
+                    session_id,
+                    on_event,
+                    &known_msg_ids,
+                    &user_msg_ids,
+                    &mut text_lengths,
+                )
+                .await;
+            }
+            reconnecting = true;
+
+            // A question asked while disconnected would otherwise never reach the UI.
+            if let Ok(questions) = self.get_questions().await {
+                for q in questions.as_array().into_iter().flatten() {
+                    if let Some(id) = Self::question_for_session(q, session_id) {
+                        if pending_questions.insert(id.clone()) {
+                            let _ = on_event.send(ChatStreamEvent::Question {
+                                id,
+                                questions: q.get("questions").cloned().unwrap_or(Value::Null),
+                            });
+                        }
+                    }
+                }
+            }
+
             let mut stream = resp.bytes_stream();
             let mut buffer = String::new();
 
@@ -526,18 +850,33 @@ impl OpenCodeClient {
                 {
                     Ok(Some(chunk)) => chunk,
                     Ok(None) => {
-                        eprintln!("[winter-app] SSE stream closed, reconnecting...");
+                        tracing::info!("SSE stream closed, reconnecting...");
                         tokio::time::sleep(RECONNECT_DELAY).await;
                         continue 'reconnect;
                     }
                     Err(_) => {
-                        if idle_ping_count < MAX_IDLE_PINGS
-                            && last_session_activity.elapsed() >= IDLE_TIMEOUT
+                        match self
+                            .check_quiet(session_id, &mut keepalive, !pending_questions.is_empty())
+                            .await
                         {
-                            idle_ping_count += 1;
-                            self.send_idle_ping(session_id, idle_ping_count, MAX_IDLE_PINGS)
+                            QuietOutcome::Waiting => {}
+                            QuietOutcome::Finished => {
+                                self.backfill_text(
+                                    session_id,
+                                    on_event,
+                                    &known_msg_ids,
+                                    &user_msg_ids,
+                                    &mut text_lengths,
+                                )
                                 .await;
-                            last_session_activity = std::time::Instant::now();
+                                let _ = on_event.send(ChatStreamEvent::StreamEnd);
+                                return Ok(());
+                            }
+                            QuietOutcome::Stuck(message) => {
+                                crate::crash::record_error(&message);
+                                let _ = on_event.send(ChatStreamEvent::Error { message });
+                                return Ok(());
+                            }
                         }
                         continue;
                     }
@@ -546,8 +885,8 @@ impl OpenCodeClient {
                 let chunk = match chunk {
                     Ok(c) => c,
                     Err(e) => {
-                        eprintln!(
-                            "[winter-app] SSE stream error: {}, reconnecting...",
+                        tracing::warn!(
+                            "SSE stream error: {}, reconnecting...",
                             e
                         );
                         tokio::time::sleep(RECONNECT_DELAY).await;
@@ -561,6 +900,15 @@ impl OpenCodeClient {
                     let event_block = buffer[..pos].to_string();
                     buffer = buffer[pos + 2..].to_string();
 
+                    if let Some(id) = event_block
+                        .lines()
+                        .find_map(|line| line.strip_prefix("id:"))
+                        .map(|id| id.trim())
+                        .filter(|id| !id.is_empty())
+                    {
+                        last_event_id = Some(id.to_string());
+                    }
+
                     let data_line = event_block
                         .lines()
                         .find(|line| line.starts_with("data: "))
@@ -570,6 +918,7 @@ impl OpenCodeClient {
                         Some(d) => d,
                         None => continue,
                     };
+                    crate::api_debug::sse_event("opencode", "", data_str);
 
                     let envelope: SseEnvelope = match serde_json::from_str(data_str) {
                         Ok(e) => e,
@@ -598,8 +947,7 @@ impl OpenCodeClient {
                                 continue;
                             }
 
-                            last_session_activity = std::time::Instant::now();
-                            idle_ping_count = 0;
+                            keepalive.touch();
 
                             match &part.message_id {
                                 Some(mid)
@@ -800,8 +1148,7 @@ impl OpenCodeClient {
                                         continue;
                                     }
 
-                                    last_session_activity = std::time::Instant::now();
-                                    idle_ping_count = 0;
+                                    keepalive.touch();
 
                                     let role =
                                         info.get("role").and_then(|v| v.as_str()).unwrap_or("");
@@ -845,8 +1192,8 @@ impl OpenCodeClient {
                                                     .and_then(|e| e.get("name"))
                                                     .and_then(|v| v.as_str())
                                                     .unwrap_or("Unknown error");
-                                                eprintln!(
-                                                    "[winter-app] message.updated error={} session={}",
+                                                tracing::warn!(
+                                                    "message.updated error={} session={}",
                                                     error_msg, msg_session
                                                 );
                                                 let _ = on_event.send(ChatStreamEvent::StreamEnd);
@@ -858,13 +1205,44 @@ impl OpenCodeClient {
                             }
                         }
 
+                        "question.asked" => {
+                            let props = &envelope.payload.properties;
+                            if let Some(id) = Self::question_for_session(props, session_id) {
+                                keepalive.touch();
+                                if pending_questions.insert(id.clone()) {
+                                    let _ = on_event.send(ChatStreamEvent::Question {
+                                        id,
+                                        questions: props
+                                            .get("questions")
+                                            .cloned()
+                                            .unwrap_or(Value::Null),
+                                    });
+                                }
+                            }
+                        }
+
+                        "question.replied" | "question.rejected" => {
+                            let props = &envelope.payload.properties;
+                            let id = props
+                                .get("requestID")
+                                .or_else(|| props.get("id"))
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("");
+                            if pending_questions.remove(id) {
+                                keepalive.touch();
+                                let _ = on_event.send(ChatStreamEvent::QuestionResolved {
+                                    id: id.to_string(),
+                                });
+                            }
+                        }
+
                         "session.idle" => {
                             if let Some(props) = envelope.payload.properties.as_object() {
                                 let idle_session = props.get("sessionID")
                                     .and_then(|v| v.as_str())
                                     .unwrap_or("");
                                 if idle_session == session_id {
-                                    eprintln!("[winter-app] session.idle session={}", session_id);
+                                    tracing::debug!("session.idle session={}", session_id);
                                     let _ = on_event.send(ChatStreamEvent::StreamEnd);
                                     return Ok(());
                                 }
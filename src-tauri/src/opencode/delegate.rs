@@ -0,0 +1,222 @@
+/// `delegate_to_opencode`: lets the native Claude tool loop hand a subtask to
+/// the OpenCode bridge and get its final answer back as the tool result.
+///
+/// The tool is only offered when OpenCode is enabled and the active profile's
+/// server answers its health check. Each call runs in a bridge session, a new
+/// one unless the model passes the `session_id` of an earlier delegation.
+/// OpenCode's tool calls and status are relayed to the parent stream as
+/// `Status` events; its text is collected into the result instead of being
+/// streamed into the native reply.
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use super::profiles;
+use crate::claude::types::ChatStreamEvent;
+use crate::memory::AgentRunLog;
+use crate::STORE_FILE;
+
+/// Name of the bridge delegation tool.
+pub const DELEGATE_TOOL: &str = "delegate_to_opencode";
+
+/// Added to the system prompt when the tool is offered.
+pub const PROMPT_HINT: &str = "\
+The OpenCode agent is connected. For multi-step coding work in the project, hand the job to it with \
+delegate_to_opencode and pass back its session_id to follow up on the same job.";
+
+/// Upper bound for the availability check, so a dead server never delays a chat turn.
+const AVAILABILITY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How often the relay checks the global abort flag.
+const ABORT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+pub fn tool_definition() -> Value {
+    json!({
+        "name": DELEGATE_TOOL,
+        "description": "Hand a subtask to the OpenCode agent working in the project and get its final answer. \
+It has its own tools and sees the project files, but not this conversation.",
+        "input_schema": {
+            "type": "object",
+            "properties": {
+                "task": { "type": "string", "description": "What OpenCode should do, self-contained" },
+                "session_id": { "type": "string", "description": "Session ID from an earlier delegation, to continue that job" }
+            },
+            "required": ["task"]
+        }
+    })
+}
+
+/// Whether OpenCode is enabled and the active profile's server is healthy.
+pub async fn available(app: &AppHandle) -> bool {
+    let enabled = app
+        .store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get("opencode_enabled"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    if !enabled {
+        return false;
+    }
+    let check = async {
+        match profiles::client(app, profiles::active(app)).await {
+            Ok(client) => client.health_check().await,
+            Err(_) => false,
+        }
+    };
+    tokio::time::timeout(AVAILABILITY_TIMEOUT, check).await.unwrap_or(false)
+}
+
+/// What the relay channel collected from OpenCode's event stream.
+#[derive(Default)]
+struct Relayed {
+    text: String,
+    error: Option<String>,
+}
+
+/// A channel that turns OpenCode's stream events into `Status` events on
+/// `parent` and collects its text and errors into `relayed`.
+fn relay_channel(parent: Channel<ChatStreamEvent>, relayed: Arc<Mutex<Relayed>>) -> Channel<ChatStreamEvent> {
+    Channel::new(move |body: InvokeResponseBody| {
+        let InvokeResponseBody::Json(raw) = body else { return Ok(()) };
+        let Ok(event) = serde_json::from_str::<Value>(&raw) else { return Ok(()) };
+        let data = &event["data"];
+        let status = match event["event"].as_str().unwrap_or("") {
+            "delta" => {
+                if let Some(text) = data["text"].as_str() {
+                    relayed.lock().unwrap_or_else(|e| e.into_inner()).text.push_str(text);
+                }
+                None
+            }
+            "tool_start" => data["name"].as_str().map(|n| format!("OpenCode: {}", n)),
+            "status" => data["text"].as_str().map(|t| format!("OpenCode: {}", t)),
+            "question" => Some("OpenCode is waiting for an answer in its session".to_string()),
+            "error" => {
+                relayed.lock().unwrap_or_else(|e| e.into_inner()).error =
+                    data["message"].as_str().map(str::to_string);
+                None
+            }
+            _ => None,
+        };
+        if let Some(text) = status {
+            let _ = parent.send(ChatStreamEvent::Status { text });
+        }
+        Ok(())
+    })
+}
+
+/// Executes a `delegate_to_opencode` tool call and records it in the agent
+/// history. Returns `(output, is_error)`.
+pub async fn delegate(app: &AppHandle, input: &Value, parent: &Channel<ChatStreamEvent>) -> (String, bool) {
+    let task = input["task"].as_str().unwrap_or("").trim();
+    if task.is_empty() {
+        return (format!("{} needs a task", DELEGATE_TOOL), true);
+    }
+    let started = std::time::Instant::now();
+    let mut session_id = input["session_id"].as_str().filter(|s| !s.is_empty()).map(str::to_string);
+    let result = run(app, task, &mut session_id, parent).await;
+    let cancelled = app.state::<Arc<AtomicBool>>().load(Ordering::SeqCst);
+    crate::memory::record_agent_run(
+        app,
+        AgentRunLog {
+            agent: "OpenCode".to_string(),
+            action: crate::memory::summarize_prompt(task),
+            result: match (cancelled, &result) {
+                (true, _) => "cancelled",
+                (false, Err(_)) => "failed",
+                (false, Ok(_)) => "done",
+            }
+            .to_string(),
+            session_id,
+            duration_ms: started.elapsed().as_millis() as u64,
+            input_tokens: None,
+            output_tokens: None,
+            notes: result.as_ref().err().cloned(),
+        },
+    );
+    match result {
+        Ok(output) => (output, false),
+        Err(e) => (format!("OpenCode delegation failed: {}", e), true),
+    }
+}
+
+/// Runs the task in `session_id`, creating a session first when it is `None`.
+async fn run(
+    app: &AppHandle,
+    task: &str,
+    session_id: &mut Option<String>,
+    parent: &Channel<ChatStreamEvent>,
+) -> Result<String, String> {
+    let session_id = match session_id {
+        Some(id) => id.clone(),
+        None => {
+            let profile = profiles::active(app);
+            let client = profiles::client(app, profile.clone()).await?;
+            let session = client.create_session().await?;
+            profiles::bind_session(app, &session.id, Some(&profile))?;
+            session_id.insert(session.id).clone()
+        }
+    };
+    let _ = parent.send(ChatStreamEvent::Status {
+        text: "Delegating to OpenCode...".to_string(),
+    });
+
+    let client = profiles::client(app, profiles::for_session(app, &session_id)).await?;
+    let known_msg_ids = client.get_known_message_ids(&session_id).await;
+    let relayed = Arc::new(Mutex::new(Relayed::default()));
+    let relay = relay_channel(parent.clone(), relayed.clone());
+
+    // The delegation has its own stop flag: a failed prompt must end the event
+    // stream without aborting the parent turn, while a user abort of the
+    // parent turn is forwarded to it by the watcher below.
+    let global_abort = app.state::<Arc<AtomicBool>>().inner().clone();
+    let stop = AtomicBool::new(false);
+    let done = AtomicBool::new(false);
+
+    let stream = async {
+        let result = client.subscribe_sse(&session_id, &relay, &stop, known_msg_ids).await;
+        done.store(true, Ordering::SeqCst);
+        result
+    };
+    let prompt = async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let result = client.prompt_async(&session_id, task, &[], None).await;
+        if result.is_err() {
+            stop.store(true, Ordering::SeqCst);
+        }
+        result
+    };
+    let watcher = async {
+        while !done.load(Ordering::SeqCst) {
+            if global_abort.load(Ordering::SeqCst) {
+                stop.store(true, Ordering::SeqCst);
+                break;
+            }
+            tokio::time::sleep(ABORT_POLL_INTERVAL).await;
+        }
+    };
+    let (streamed, prompted, ()) = tokio::join!(stream, prompt, watcher);
+    prompted?;
+
+    if global_abort.load(Ordering::SeqCst) {
+        let _ = client.abort(&session_id).await;
+        return Err("cancelled".to_string());
+    }
+    streamed?;
+
+    let relayed = std::mem::take(&mut *relayed.lock().unwrap_or_else(|e| e.into_inner()));
+    if let Some(error) = relayed.error {
+        return Err(error);
+    }
+    let _ = parent.send(ChatStreamEvent::Status {
+        text: "OpenCode finished".to_string(),
+    });
+    Ok(format!(
+        "[OpenCode, session_id {}]\n{}",
+        session_id,
+        relayed.text.trim()
+    ))
+}
@@ -1,5 +1,8 @@
 /// OpenCode server client module — session management, SSE streaming, and file proxying.
 pub mod client;
+pub mod delegate;
+pub mod profiles;
+pub mod tunnel;
 pub mod types;
 
 pub use client::OpenCodeClient;
@@ -0,0 +1,342 @@
+/// Named OpenCode endpoints (server URL + workspace directory).
+///
+/// The "default" profile is always present and is backed by the original
+/// `opencode_url` / `opencode_directory` store keys, so existing settings keep
+/// working. Extra profiles live in the `opencode_profiles` store key. New
+/// conversations use the active profile; each OpenCode session remembers the
+/// profile and workspace directory it was created on, since a session only
+/// exists on that server and project. Profiles with an `ssh` section are
+/// reached through an SSH tunnel.
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_store::StoreExt;
+
+use super::tunnel::{self, SshTunnelConfig, SshTunnelStatus, SshTunnels};
+use super::OpenCodeClient;
+use crate::error::AppError;
+use crate::{default_opencode_dir, DEFAULT_OPENCODE_URL, STORE_FILE};
+
+/// ID of the built-in profile backed by the legacy store keys.
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+const STORE_KEY_PROFILES: &str = "opencode_profiles";
+const STORE_KEY_ACTIVE: &str = "opencode_active_profile";
+const STORE_KEY_SESSION_PROFILES: &str = "opencode_session_profiles";
+const STORE_KEY_SESSION_DIRS: &str = "opencode_session_directories";
+
+/// A named OpenCode server endpoint.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OpenCodeProfile {
+    pub id: String,
+    pub name: String,
+    /// Server base URL, e.g. "http://127.0.0.1:6096".
+    pub base_url: String,
+    /// Workspace directory on the machine running the server.
+    pub directory: String,
+    /// When set, the server is reached through an SSH port-forward and
+    /// `base_url` is ignored.
+    #[serde(default)]
+    pub ssh: Option<SshTunnelConfig>,
+}
+
+/// All profiles plus the ID of the active one.
+#[derive(Debug, Serialize, Clone)]
+pub struct OpenCodeProfiles {
+    pub profiles: Vec<OpenCodeProfile>,
+    pub active: String,
+}
+
+fn store_string(app: &AppHandle, key: &str) -> Option<String> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(key))
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.is_empty())
+}
+
+fn default_profile(app: &AppHandle) -> OpenCodeProfile {
+    OpenCodeProfile {
+        id: DEFAULT_PROFILE_ID.to_string(),
+        name: "Local".to_string(),
+        base_url: store_string(app, "opencode_url")
+            .unwrap_or_else(|| DEFAULT_OPENCODE_URL.to_string()),
+        directory: store_string(app, "opencode_directory").unwrap_or_else(default_opencode_dir),
+        ssh: None,
+    }
+}
+
+fn custom_profiles(app: &AppHandle) -> Vec<OpenCodeProfile> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_PROFILES))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn store_map(app: &AppHandle, key: &str) -> HashMap<String, String> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(key))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn session_profiles(app: &AppHandle) -> HashMap<String, String> {
+    store_map(app, STORE_KEY_SESSION_PROFILES)
+}
+
+/// All profiles, the default one first.
+pub fn list(app: &AppHandle) -> Vec<OpenCodeProfile> {
+    let mut profiles = vec![default_profile(app)];
+    profiles.extend(custom_profiles(app));
+    profiles
+}
+
+fn find(app: &AppHandle, id: &str) -> Option<OpenCodeProfile> {
+    list(app).into_iter().find(|p| p.id == id)
+}
+
+/// The active profile; falls back to the default one if the stored ID is gone.
+pub fn active(app: &AppHandle) -> OpenCodeProfile {
+    store_string(app, STORE_KEY_ACTIVE)
+        .and_then(|id| find(app, &id))
+        .unwrap_or_else(|| default_profile(app))
+}
+
+/// The profile an OpenCode session was created on, or the active profile for
+/// sessions that were never bound (e.g. created before profiles existed). The
+/// directory is the one the session was created in, even if the profile's
+/// workspace has been switched since.
+pub fn for_session(app: &AppHandle, oc_session_id: &str) -> OpenCodeProfile {
+    let mut profile = session_profiles(app)
+        .get(oc_session_id)
+        .and_then(|id| find(app, id))
+        .unwrap_or_else(|| active(app));
+    if let Some(dir) = store_map(app, STORE_KEY_SESSION_DIRS).remove(oc_session_id) {
+        profile.directory = dir;
+    }
+    profile
+}
+
+/// Looks up a profile by ID, or the active profile when `id` is `None`.
+pub fn resolve(app: &AppHandle, id: Option<&str>) -> Result<OpenCodeProfile, String> {
+    match id {
+        Some(id) => find(app, id).ok_or_else(|| format!("OpenCode profile '{}' not found", id)),
+        None => Ok(active(app)),
+    }
+}
+
+/// Records which profile and directory an OpenCode session lives on. `None` forgets the binding.
+pub fn bind_session(app: &AppHandle, oc_session_id: &str, profile: Option<&OpenCodeProfile>) -> Result<(), String> {
+    let mut bindings = session_profiles(app);
+    let mut dirs = store_map(app, STORE_KEY_SESSION_DIRS);
+    match profile {
+        Some(p) => {
+            bindings.insert(oc_session_id.to_string(), p.id.clone());
+            dirs.insert(oc_session_id.to_string(), p.directory.clone());
+        }
+        None => {
+            bindings.remove(oc_session_id);
+            dirs.remove(oc_session_id);
+        }
+    }
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_SESSION_PROFILES, json!(bindings));
+    store.set(STORE_KEY_SESSION_DIRS, json!(dirs));
+    store.save().map_err(|e| e.to_string())
+}
+
+fn validate(profile: &OpenCodeProfile) -> Result<(), String> {
+    if profile.id.trim().is_empty() {
+        return Err("Profile ID cannot be empty".to_string());
+    }
+    if !profile
+        .id
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Profile ID may only contain letters, digits, '-' and '_'".to_string());
+    }
+    if profile.name.trim().is_empty() {
+        return Err("Profile name cannot be empty".to_string());
+    }
+    match &profile.ssh {
+        Some(ssh) => tunnel::validate(ssh)?,
+        None if !(profile.base_url.starts_with("http://")
+            || profile.base_url.starts_with("https://")) =>
+        {
+            return Err("Server URL must start with http:// or https://".to_string());
+        }
+        None => {}
+    }
+    if profile.directory.trim().is_empty() {
+        return Err("Workspace directory cannot be empty".to_string());
+    }
+    Ok(())
+}
+
+/// Builds a client for a profile, opening its SSH tunnel first if it has one.
+pub async fn client(app: &AppHandle, profile: OpenCodeProfile) -> Result<OpenCodeClient, String> {
+    let base_url = match &profile.ssh {
+        Some(ssh) => {
+            let tunnels = app.state::<SshTunnels>();
+            let port = tunnel::ensure(&tunnels, &profile.id, ssh).await?;
+            format!("http://127.0.0.1:{}", port)
+        }
+        None => profile.base_url,
+    };
+    Ok(OpenCodeClient::new(base_url, profile.directory))
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn list_opencode_profiles(app: AppHandle) -> OpenCodeProfiles {
+    OpenCodeProfiles {
+        profiles: list(&app),
+        active: active(&app).id,
+    }
+}
+
+/// Adds or replaces a profile. Saving the default profile updates the
+/// `opencode_url` / `opencode_directory` settings.
+#[tauri::command]
+pub async fn save_opencode_profile(app: AppHandle, mut profile: OpenCodeProfile) -> Result<(), AppError> {
+    profile.base_url = profile.base_url.trim().trim_end_matches('/').to_string();
+    validate(&profile)?;
+    let id = profile.id.clone();
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    if profile.id == DEFAULT_PROFILE_ID {
+        if profile.ssh.is_some() {
+            return Err(AppError::InvalidInput("Add a separate profile for SSH access".to_string()));
+        }
+        store.set("opencode_url", json!(profile.base_url));
+        store.set("opencode_directory", json!(profile.directory));
+    } else {
+        let mut profiles = custom_profiles(&app);
+        match profiles.iter_mut().find(|p| p.id == profile.id) {
+            Some(existing) => *existing = profile,
+            None => profiles.push(profile),
+        }
+        store.set(STORE_KEY_PROFILES, json!(profiles));
+    }
+    store.save().map_err(|e| e.to_string())?;
+    // Drop a tunnel opened with the old settings; it is reopened on next use.
+    tunnel::close(&app.state::<SshTunnels>(), &id).await;
+    Ok(())
+}
+
+/// Removes a profile. Sessions bound to it fall back to the active profile.
+#[tauri::command]
+pub async fn delete_opencode_profile(app: AppHandle, id: String) -> Result<(), AppError> {
+    if id == DEFAULT_PROFILE_ID {
+        return Err(AppError::InvalidInput("The default profile cannot be deleted".to_string()));
+    }
+    let mut profiles = custom_profiles(&app);
+    let before = profiles.len();
+    profiles.retain(|p| p.id != id);
+    if profiles.len() == before {
+        return Err(AppError::NotFound(format!("OpenCode profile '{}' not found", id)));
+    }
+    let mut bindings = session_profiles(&app);
+    bindings.retain(|_, p| *p != id);
+    let mut dirs = store_map(&app, STORE_KEY_SESSION_DIRS);
+    dirs.retain(|session, _| bindings.contains_key(session));
+
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_PROFILES, json!(profiles));
+    store.set(STORE_KEY_SESSION_PROFILES, json!(bindings));
+    store.set(STORE_KEY_SESSION_DIRS, json!(dirs));
+    if store_string(&app, STORE_KEY_ACTIVE).as_deref() == Some(id.as_str()) {
+        store.delete(STORE_KEY_ACTIVE);
+    }
+    store.save().map_err(|e| e.to_string())?;
+    tunnel::close(&app.state::<SshTunnels>(), &id).await;
+    Ok(())
+}
+
+/// Sets the profile used for new conversations.
+#[tauri::command]
+pub fn set_active_opencode_profile(app: AppHandle, id: String) -> Result<(), AppError> {
+    find(&app, &id).ok_or_else(|| AppError::NotFound(format!("OpenCode profile '{}' not found", id)))?;
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_ACTIVE, json!(id));
+    Ok(store.save()?)
+}
+
+/// Payload of the `opencode-workspace-changed` event.
+#[derive(Debug, Serialize, Clone)]
+pub struct WorkspaceChanged {
+    pub profile_id: String,
+    pub directory: String,
+}
+
+/// Switches the workspace directory of a profile (the active one by default).
+/// New sessions are created in the new directory; existing sessions keep the
+/// one they were created in. Local directories must exist; for SSH profiles
+/// the remote server is asked to resolve the path instead.
+#[tauri::command]
+pub async fn set_opencode_workspace(
+    app: AppHandle,
+    path: String,
+    profile: Option<String>,
+) -> Result<(), AppError> {
+    let mut profile = resolve(&app, profile.as_deref())?;
+    let path = path.trim().trim_end_matches(['/', '\\']).to_string();
+    if path.is_empty() {
+        return Err(AppError::InvalidInput("Workspace directory cannot be empty".to_string()));
+    }
+    if profile.ssh.is_none() {
+        let dir = std::path::Path::new(&path);
+        if !dir.is_absolute() {
+            return Err(AppError::InvalidInput("Path must be absolute".to_string()));
+        }
+        if !dir.is_dir() {
+            return Err(AppError::InvalidInput(format!("Not a directory: {}", path)));
+        }
+    }
+    profile.directory = path.clone();
+    if profile.ssh.is_some() {
+        client(&app, profile.clone()).await?.get_path_info().await?;
+    }
+    let profile_id = profile.id.clone();
+    save_opencode_profile(app.clone(), profile).await?;
+    let _ = app.emit(
+        "opencode-workspace-changed",
+        WorkspaceChanged {
+            profile_id,
+            directory: path,
+        },
+    );
+    Ok(())
+}
+
+/// Returns the profile a conversation's OpenCode session is bound to.
+#[tauri::command]
+pub fn get_opencode_session_profile(app: AppHandle, oc_session_id: String) -> OpenCodeProfile {
+    for_session(&app, &oc_session_id)
+}
+
+/// Lists the SSH tunnels opened by the app.
+#[tauri::command]
+pub async fn get_ssh_tunnels(app: AppHandle) -> Vec<SshTunnelStatus> {
+    tunnel::status(&app.state::<SshTunnels>()).await
+}
+
+/// Opens the tunnel of an SSH profile now, e.g. to test its settings. Returns the local port.
+#[tauri::command]
+pub async fn connect_ssh_tunnel(app: AppHandle, profile_id: String) -> Result<u16, AppError> {
+    let profile = resolve(&app, Some(&profile_id))?;
+    let ssh = profile
+        .ssh
+        .ok_or_else(|| AppError::InvalidInput(format!("Profile '{}' does not use SSH", profile_id)))?;
+    Ok(tunnel::ensure(&app.state::<SshTunnels>(), &profile.id, &ssh).await?)
+}
+
+/// Closes the SSH tunnel of a profile. It is reopened the next time the profile is used.
+#[tauri::command]
+pub async fn close_ssh_tunnel(app: AppHandle, profile_id: String) {
+    tunnel::close(&app.state::<SshTunnels>(), &profile_id).await
+}
@@ -0,0 +1,208 @@
+/// SSH local port-forwards to remote OpenCode servers.
+///
+/// A profile with an `ssh` section is reached through `ssh -N -L` run with the
+/// system `ssh` binary, so the user's agent, known_hosts and config apply as
+/// usual. Tunnels are opened on first use, reused while the ssh process is
+/// alive and reopened when it has died or the profile's settings changed.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+
+/// How long to wait for the forwarded port to accept connections.
+const TUNNEL_READY_TIMEOUT: Duration = Duration::from_secs(15);
+const TUNNEL_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+fn default_remote_port() -> u16 {
+    6096
+}
+
+/// SSH connection settings of an OpenCode profile.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SshTunnelConfig {
+    pub host: String,
+    #[serde(default)]
+    pub user: Option<String>,
+    /// SSH port; the ssh default (or ~/.ssh/config) when unset.
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Private key passed as `-i`. May start with `~/`.
+    #[serde(default)]
+    pub identity_file: Option<String>,
+    /// Port OpenCode listens on, on the remote machine's loopback.
+    #[serde(default = "default_remote_port")]
+    pub remote_port: u16,
+    /// Local port to forward from; a free one is picked when unset.
+    #[serde(default)]
+    pub local_port: Option<u16>,
+}
+
+struct Tunnel {
+    config: SshTunnelConfig,
+    local_port: u16,
+    child: tokio::process::Child,
+}
+
+/// Status of one open tunnel, for the settings UI.
+#[derive(Debug, Serialize, Clone)]
+pub struct SshTunnelStatus {
+    pub profile_id: String,
+    pub host: String,
+    pub local_port: u16,
+    pub alive: bool,
+}
+
+/// Tauri state holding the ssh processes spawned for tunnels, keyed by profile ID.
+#[derive(Default, Clone)]
+pub struct SshTunnels {
+    tunnels: Arc<tokio::sync::Mutex<HashMap<String, Tunnel>>>,
+}
+
+pub fn validate(config: &SshTunnelConfig) -> Result<(), String> {
+    let valid = |s: &str| !s.is_empty() && !s.starts_with('-') && !s.chars().any(char::is_whitespace);
+    if !valid(config.host.trim()) {
+        return Err("SSH host is invalid".to_string());
+    }
+    if let Some(user) = &config.user {
+        if !user.is_empty() && !valid(user) {
+            return Err("SSH user is invalid".to_string());
+        }
+    }
+    if config.remote_port == 0 {
+        return Err("Remote port must not be 0".to_string());
+    }
+    Ok(())
+}
+
+fn free_local_port() -> Result<u16, String> {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|l| l.local_addr())
+        .map(|a| a.port())
+        .map_err(|e| format!("Failed to find a free local port: {}", e))
+}
+
+fn spawn_ssh(config: &SshTunnelConfig, local_port: u16) -> Result<tokio::process::Child, String> {
+    let mut cmd = tokio::process::Command::new("ssh");
+    cmd.args([
+        "-N",
+        "-o",
+        "ExitOnForwardFailure=yes",
+        "-o",
+        "BatchMode=yes",
+        "-o",
+        "ServerAliveInterval=30",
+        "-L",
+    ])
+    .arg(format!("127.0.0.1:{}:127.0.0.1:{}", local_port, config.remote_port));
+    if let Some(port) = config.port {
+        cmd.arg("-p").arg(port.to_string());
+    }
+    if let Some(key) = config.identity_file.as_deref().filter(|k| !k.is_empty()) {
+        cmd.arg("-i").arg(crate::backup::expand_home(key));
+    }
+    let target = match config.user.as_deref().filter(|u| !u.is_empty()) {
+        Some(user) => format!("{}@{}", user, config.host.trim()),
+        None => config.host.trim().to_string(),
+    };
+    cmd.arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("Failed to start ssh: {}", e))
+}
+
+/// Returns the local port forwarding to the profile's remote OpenCode server,
+/// opening (or reopening) the tunnel if needed.
+pub async fn ensure(state: &SshTunnels, profile_id: &str, config: &SshTunnelConfig) -> Result<u16, String> {
+    let mut tunnels = state.tunnels.lock().await;
+    if let Some(tunnel) = tunnels.get_mut(profile_id) {
+        if tunnel.config == *config && matches!(tunnel.child.try_wait(), Ok(None)) {
+            return Ok(tunnel.local_port);
+        }
+        if let Some(mut old) = tunnels.remove(profile_id) {
+            let _ = old.child.kill().await;
+        }
+    }
+
+    validate(config)?;
+    let local_port = match config.local_port {
+        Some(p) => p,
+        None => free_local_port()?,
+    };
+    let mut child = spawn_ssh(config, local_port)?;
+
+    let deadline = tokio::time::Instant::now() + TUNNEL_READY_TIMEOUT;
+    loop {
+        if let Ok(Some(status)) = child.try_wait() {
+            let mut stderr = String::new();
+            if let Some(mut pipe) = child.stderr.take() {
+                let _ = pipe.read_to_string(&mut stderr).await;
+            }
+            return Err(format!(
+                "SSH tunnel to {} failed (exit {}): {}",
+                config.host,
+                status.code().unwrap_or(-1),
+                stderr.trim()
+            ));
+        }
+        if tokio::net::TcpStream::connect(("127.0.0.1", local_port)).await.is_ok() {
+            break;
+        }
+        if tokio::time::Instant::now() >= deadline {
+            let _ = child.kill().await;
+            return Err(format!(
+                "SSH tunnel to {} did not open within {}s",
+                config.host,
+                TUNNEL_READY_TIMEOUT.as_secs()
+            ));
+        }
+        tokio::time::sleep(TUNNEL_POLL_INTERVAL).await;
+    }
+
+    tracing::info!(
+        "SSH tunnel for profile {} open on 127.0.0.1:{}",
+        profile_id, local_port
+    );
+    tunnels.insert(
+        profile_id.to_string(),
+        Tunnel {
+            config: config.clone(),
+            local_port,
+            child,
+        },
+    );
+    Ok(local_port)
+}
+
+/// Closes the tunnel of a profile, if one is open.
+pub async fn close(state: &SshTunnels, profile_id: &str) {
+    if let Some(mut tunnel) = state.tunnels.lock().await.remove(profile_id) {
+        let _ = tunnel.child.kill().await;
+    }
+}
+
+/// Local port of the profile's tunnel if it is open and its ssh process alive.
+pub async fn local_port(state: &SshTunnels, profile_id: &str) -> Option<u16> {
+    let mut tunnels = state.tunnels.lock().await;
+    let tunnel = tunnels.get_mut(profile_id)?;
+    matches!(tunnel.child.try_wait(), Ok(None)).then_some(tunnel.local_port)
+}
+
+pub async fn status(state: &SshTunnels) -> Vec<SshTunnelStatus> {
+    let mut tunnels = state.tunnels.lock().await;
+    let mut list: Vec<SshTunnelStatus> = tunnels
+        .iter_mut()
+        .map(|(id, t)| SshTunnelStatus {
+            profile_id: id.clone(),
+            host: t.config.host.clone(),
+            local_port: t.local_port,
+            alive: matches!(t.child.try_wait(), Ok(None)),
+        })
+        .collect();
+    list.sort_by(|a, b| a.profile_id.cmp(&b.profile_id));
+    list
+}
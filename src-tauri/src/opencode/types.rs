@@ -33,6 +33,28 @@ pub struct OcSession {
     pub time: Option<OcSessionTime>,
 }
 
+/// A session together with its full message history, used to resume it in the UI.
+#[derive(Debug, Clone, Serialize)]
+pub struct OcResumedSession {
+    /// The session metadata.
+    pub session: OcSession,
+    /// All messages in the session, as returned by `/session/{id}/message`.
+    pub messages: Value,
+}
+
+// ── Prompt Parts ──────────────────────────────────────────────────
+
+/// A file attached to a prompt, sent as an OpenCode "file" part with a data URL.
+#[derive(Debug, Clone)]
+pub struct OcFilePart {
+    /// MIME type of the file (e.g. "image/png", "text/plain").
+    pub mime: String,
+    /// File name shown to the model.
+    pub filename: String,
+    /// Base64-encoded file content.
+    pub data: String,
+}
+
 // ── SSE Event Parts ────────────────────────────────────────────────
 
 /// A single message part from the OpenCode SSE event stream.
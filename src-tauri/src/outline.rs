@@ -0,0 +1,387 @@
+/// Symbol outlines (functions, classes, sections) of source files.
+///
+/// Code files are parsed with tree-sitter and named definitions are picked
+/// out by node kind; function bodies are not descended into, so local
+/// helpers and callbacks stay out of the outline. Markdown is outlined by its
+/// headings. Lines are 1-based and inclusive.
+use serde::Serialize;
+use std::path::Path;
+use tree_sitter::{Language, Node, Parser};
+
+use crate::error::AppError;
+
+/// Larger files are not outlined (5 MB).
+const MAX_OUTLINE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Syntax tree depth explored at most, as a guard against pathological input.
+const MAX_NESTING: usize = 128;
+
+/// Symbol names longer than this are cut.
+const MAX_NAME_CHARS: usize = 120;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SymbolKind {
+    Function,
+    Method,
+    Class,
+    Struct,
+    Enum,
+    Interface,
+    Trait,
+    Impl,
+    Module,
+    Type,
+    Constant,
+    Macro,
+    Heading,
+}
+
+impl SymbolKind {
+    fn is_container(self) -> bool {
+        matches!(self, Self::Class | Self::Struct | Self::Interface | Self::Trait | Self::Impl)
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Function => "fn",
+            Self::Method => "method",
+            Self::Class => "class",
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Interface => "interface",
+            Self::Trait => "trait",
+            Self::Impl => "impl",
+            Self::Module => "mod",
+            Self::Type => "type",
+            Self::Constant => "const",
+            Self::Macro => "macro",
+            Self::Heading => "#",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OutlineSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub children: Vec<OutlineSymbol>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FileOutline {
+    pub path: String,
+    /// Language the file was parsed as; `None` when it is not supported.
+    pub language: Option<&'static str>,
+    pub symbols: Vec<OutlineSymbol>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Lang {
+    Rust,
+    Python,
+    JavaScript,
+    TypeScript,
+    Tsx,
+    Go,
+    Java,
+    C,
+    Cpp,
+    Markdown,
+}
+
+impl Lang {
+    fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        Some(match ext.as_str() {
+            "rs" => Self::Rust,
+            "py" | "pyi" => Self::Python,
+            "js" | "jsx" | "mjs" | "cjs" => Self::JavaScript,
+            "ts" | "mts" | "cts" => Self::TypeScript,
+            "tsx" => Self::Tsx,
+            "go" => Self::Go,
+            "java" => Self::Java,
+            "c" | "h" => Self::C,
+            "cpp" | "cc" | "cxx" | "hpp" | "hh" | "hxx" => Self::Cpp,
+            "md" | "markdown" => Self::Markdown,
+            _ => return None,
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::Python => "python",
+            Self::JavaScript => "javascript",
+            Self::TypeScript => "typescript",
+            Self::Tsx => "tsx",
+            Self::Go => "go",
+            Self::Java => "java",
+            Self::C => "c",
+            Self::Cpp => "cpp",
+            Self::Markdown => "markdown",
+        }
+    }
+
+    fn grammar(self) -> Option<Language> {
+        Some(match self {
+            Self::Rust => tree_sitter_rust::LANGUAGE.into(),
+            Self::Python => tree_sitter_python::LANGUAGE.into(),
+            Self::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+            Self::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Self::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
+            Self::Go => tree_sitter_go::LANGUAGE.into(),
+            Self::Java => tree_sitter_java::LANGUAGE.into(),
+            Self::C => tree_sitter_c::LANGUAGE.into(),
+            Self::Cpp => tree_sitter_cpp::LANGUAGE.into(),
+            Self::Markdown => return None,
+        })
+    }
+}
+
+/// Single-line, length-capped text of a node.
+fn text(node: Node, src: &[u8]) -> String {
+    let raw = node.utf8_text(src).unwrap_or_default();
+    let line = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    match line.char_indices().nth(MAX_NAME_CHARS) {
+        Some((end, _)) => format!("{}…", &line[..end]),
+        None => line,
+    }
+}
+
+/// Name of a C/C++ function from its (possibly nested) declarator.
+fn declarator_name(node: Node, src: &[u8]) -> Option<String> {
+    let mut current = node.child_by_field_name("declarator")?;
+    while let Some(inner) = current.child_by_field_name("declarator") {
+        current = inner;
+    }
+    Some(text(current, src))
+}
+
+/// Symbol kind and name of `node`, if it is a definition worth listing.
+fn classify(lang: Lang, node: Node, src: &[u8]) -> Option<(SymbolKind, String)> {
+    let name = || node.child_by_field_name("name").map(|n| text(n, src));
+    let kind = match (lang, node.kind()) {
+        (Lang::Rust, "function_item" | "function_signature_item") => SymbolKind::Function,
+        (Lang::Rust, "struct_item" | "union_item") => SymbolKind::Struct,
+        (Lang::Rust, "enum_item") => SymbolKind::Enum,
+        (Lang::Rust, "trait_item") => SymbolKind::Trait,
+        (Lang::Rust, "mod_item") => SymbolKind::Module,
+        (Lang::Rust, "const_item" | "static_item") => SymbolKind::Constant,
+        (Lang::Rust, "type_item") => SymbolKind::Type,
+        (Lang::Rust, "macro_definition") => SymbolKind::Macro,
+        (Lang::Rust, "impl_item") => {
+            let ty = text(node.child_by_field_name("type")?, src);
+            let name = match node.child_by_field_name("trait") {
+                Some(t) => format!("{} for {}", text(t, src), ty),
+                None => ty,
+            };
+            return Some((SymbolKind::Impl, name));
+        }
+
+        (Lang::Python, "function_definition") => SymbolKind::Function,
+        (Lang::Python, "class_definition") => SymbolKind::Class,
+
+        (Lang::JavaScript | Lang::TypeScript | Lang::Tsx, kind) => match kind {
+            "function_declaration" | "generator_function_declaration" | "function_signature" => {
+                SymbolKind::Function
+            }
+            "class_declaration" | "abstract_class_declaration" => SymbolKind::Class,
+            "method_definition" | "method_signature" | "abstract_method_signature" => SymbolKind::Method,
+            "interface_declaration" => SymbolKind::Interface,
+            "type_alias_declaration" => SymbolKind::Type,
+            "enum_declaration" => SymbolKind::Enum,
+            "internal_module" => SymbolKind::Module,
+            // `const f = () => {}` and friends.
+            "variable_declarator" => {
+                let value = node.child_by_field_name("value")?;
+                match value.kind() {
+                    "arrow_function" | "function_expression" | "function" | "generator_function" => {
+                        SymbolKind::Function
+                    }
+                    "class" => SymbolKind::Class,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        },
+
+        (Lang::Go, "function_declaration") => SymbolKind::Function,
+        (Lang::Go, "method_declaration") => SymbolKind::Method,
+        (Lang::Go, "type_spec") => match node.child_by_field_name("type").map(|t| t.kind()) {
+            Some("struct_type") => SymbolKind::Struct,
+            Some("interface_type") => SymbolKind::Interface,
+            _ => SymbolKind::Type,
+        },
+
+        (Lang::Java, "class_declaration" | "record_declaration") => SymbolKind::Class,
+        (Lang::Java, "interface_declaration" | "annotation_type_declaration") => SymbolKind::Interface,
+        (Lang::Java, "enum_declaration") => SymbolKind::Enum,
+        (Lang::Java, "method_declaration" | "constructor_declaration") => SymbolKind::Method,
+
+        (Lang::C | Lang::Cpp, "function_definition") => {
+            return Some((SymbolKind::Function, declarator_name(node, src)?));
+        }
+        // Only definitions with a body, not every `struct foo *` in a signature.
+        (Lang::C | Lang::Cpp, "struct_specifier" | "union_specifier") if node.child_by_field_name("body").is_some() => {
+            SymbolKind::Struct
+        }
+        (Lang::C | Lang::Cpp, "enum_specifier") if node.child_by_field_name("body").is_some() => SymbolKind::Enum,
+        (Lang::Cpp, "class_specifier") if node.child_by_field_name("body").is_some() => SymbolKind::Class,
+        (Lang::Cpp, "namespace_definition") => {
+            let name = name().unwrap_or_else(|| "(anonymous)".to_string());
+            return Some((SymbolKind::Module, name));
+        }
+
+        _ => return None,
+    };
+    Some((kind, name()?))
+}
+
+fn collect(lang: Lang, node: Node, src: &[u8], parent: Option<SymbolKind>, depth: usize) -> Vec<OutlineSymbol> {
+    if depth > MAX_NESTING {
+        return Vec::new();
+    }
+    let mut symbols = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let Some((mut kind, name)) = classify(lang, child, src) else {
+            symbols.extend(collect(lang, child, src, parent, depth + 1));
+            continue;
+        };
+        if kind == SymbolKind::Function && parent.is_some_and(SymbolKind::is_container) {
+            kind = SymbolKind::Method;
+        }
+        let children = if matches!(kind, SymbolKind::Function | SymbolKind::Method) {
+            Vec::new()
+        } else {
+            collect(lang, child, src, Some(kind), depth + 1)
+        };
+        symbols.push(OutlineSymbol {
+            name,
+            kind,
+            start_line: child.start_position().row + 1,
+            end_line: child.end_position().row + 1,
+            children,
+        });
+    }
+    symbols
+}
+
+/// Pops the open headings at `level` or deeper, ending them at line `end`.
+fn close_headings(open: &mut Vec<(usize, OutlineSymbol)>, roots: &mut Vec<OutlineSymbol>, level: usize, end: usize) {
+    while open.last().is_some_and(|(l, _)| *l >= level) {
+        let Some((_, mut symbol)) = open.pop() else { break };
+        symbol.end_line = end.max(symbol.start_line);
+        match open.last_mut() {
+            Some((_, parent)) => parent.children.push(symbol),
+            None => roots.push(symbol),
+        }
+    }
+}
+
+/// Outline of ATX (`#`) headings, nested by level. Fenced code blocks are skipped.
+fn markdown_outline(src: &str) -> Vec<OutlineSymbol> {
+    // (level, symbol) of the headings still open, outermost first.
+    let mut open: Vec<(usize, OutlineSymbol)> = Vec::new();
+    let mut roots = Vec::new();
+    let mut in_fence = false;
+    let mut last_line = 0;
+
+    for (i, line) in src.lines().enumerate() {
+        let line_no = i + 1;
+        last_line = line_no;
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence || line.len() - trimmed.len() > 3 {
+            continue;
+        }
+        let level = trimmed.bytes().take_while(|&b| b == b'#').count();
+        let rest = &trimmed[level..];
+        if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+            continue;
+        }
+        let title = rest.trim().trim_end_matches('#').trim();
+        close_headings(&mut open, &mut roots, level, line_no - 1);
+        open.push((level, OutlineSymbol {
+            name: title.chars().take(MAX_NAME_CHARS).collect(),
+            kind: SymbolKind::Heading,
+            start_line: line_no,
+            end_line: line_no,
+            children: Vec::new(),
+        }));
+    }
+    close_headings(&mut open, &mut roots, 1, last_line);
+    roots
+}
+
+/// Outline of the file at `path`.
+pub fn outline(path: &Path) -> Result<FileOutline, String> {
+    let meta = std::fs::metadata(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    if !meta.is_file() {
+        return Err(format!("Not a file: {}", path.display()));
+    }
+    let display = path.to_string_lossy().to_string();
+    let Some(lang) = Lang::from_path(path) else {
+        return Ok(FileOutline { path: display, language: None, symbols: Vec::new() });
+    };
+    if meta.len() > MAX_OUTLINE_BYTES {
+        return Err(format!("{} is too large to outline", path.display()));
+    }
+    let src = std::fs::read(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+
+    let symbols = match lang.grammar() {
+        None => markdown_outline(&String::from_utf8_lossy(&src)),
+        Some(grammar) => {
+            let mut parser = Parser::new();
+            parser.set_language(&grammar).map_err(|e| e.to_string())?;
+            let tree = parser
+                .parse(&src, None)
+                .ok_or_else(|| format!("Failed to parse {}", path.display()))?;
+            collect(lang, tree.root_node(), &src, None, 0)
+        }
+    };
+    Ok(FileOutline { path: display, language: Some(lang.name()), symbols })
+}
+
+/// Plain-text rendering for the `file_outline` tool: one symbol per line,
+/// indented by nesting, with its line range.
+pub fn render(outline: &FileOutline) -> String {
+    fn walk(symbols: &[OutlineSymbol], indent: usize, out: &mut String) {
+        for s in symbols {
+            out.push_str(&format!(
+                "{}{} {} (L{}-{})\n",
+                "  ".repeat(indent),
+                s.kind.label(),
+                s.name,
+                s.start_line,
+                s.end_line
+            ));
+            walk(&s.children, indent + 1, out);
+        }
+    }
+    match outline.language {
+        None => format!("Outline is not supported for {}", outline.path),
+        Some(_) if outline.symbols.is_empty() => format!("No symbols found in {}", outline.path),
+        Some(_) => {
+            let mut out = String::new();
+            walk(&outline.symbols, 0, &mut out);
+            out
+        }
+    }
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Functions, classes and sections of a source or Markdown file, with line ranges.
+#[tauri::command]
+pub async fn get_file_outline(path: String) -> Result<FileOutline, AppError> {
+    Ok(tokio::task::spawn_blocking(move || outline(Path::new(&path)))
+        .await
+        .map_err(|e| e.to_string())??)
+}
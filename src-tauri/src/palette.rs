@@ -0,0 +1,180 @@
+/// Actions for the frontend's command palette.
+///
+/// `list_commands` returns every action the palette can offer: a fixed set of
+/// app commands plus entries generated from the current state (scheduled
+/// tasks, services, message modes, personality presets, webhooks). Each action names the
+/// Tauri command to invoke, the arguments it already carries, and a JSON schema
+/// for the arguments the user still has to supply. Argument names are
+/// camelCase, as passed from JavaScript.
+use serde::Serialize;
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager};
+
+use crate::modes::MessageMode;
+use crate::scheduler::{self, SharedSchedulerState};
+use crate::services;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PaletteAction {
+    /// Stable ID, e.g. `task:run:backup` or `command:abort_stream`.
+    pub id: String,
+    pub title: String,
+    /// "command", "task", "service", "mode", "personality" or "webhook".
+    pub category: &'static str,
+    /// Tauri command that performs the action.
+    pub command: &'static str,
+    /// Arguments already bound by the action.
+    pub args: Value,
+    /// JSON schema of the arguments the user must add before invoking.
+    pub schema: Value,
+}
+
+fn no_args() -> Value {
+    json!({ "type": "object", "properties": {} })
+}
+
+fn string_arg(name: &str, description: &str) -> Value {
+    json!({
+        "type": "object",
+        "properties": { name: { "type": "string", "description": description } },
+        "required": [name]
+    })
+}
+
+fn conversation_arg() -> Value {
+    string_arg("conversationId", "Conversation to apply it to; the palette fills in the active one")
+}
+
+/// App commands that make sense on their own, with the arguments they need.
+fn app_commands() -> Vec<(&'static str, &'static str, Value)> {
+    vec![
+        ("abort_stream", "Abort the current reply", no_args()),
+        ("scheduler_pause", "Pause the scheduler", no_args()),
+        ("scheduler_resume", "Resume the scheduler", no_args()),
+        ("check_for_updates", "Check for updates", no_args()),
+        ("install_update", "Install the latest update", no_args()),
+        ("winter_db_recover", "Recover the memory database", no_args()),
+        ("clear_crash_reports", "Clear crash reports", no_args()),
+        (
+            "set_working_directory",
+            "Change the working directory",
+            string_arg("directory", "Absolute path of an existing directory"),
+        ),
+        (
+            "set_response_language",
+            "Set the response language",
+            string_arg("language", "\"auto\" to mirror the user, a language code or a language name"),
+        ),
+        (
+            "set_auto_review",
+            "Toggle automatic review of written files",
+            json!({
+                "type": "object",
+                "properties": { "enabled": { "type": "boolean" } },
+                "required": ["enabled"]
+            }),
+        ),
+        ("speak", "Read text aloud", string_arg("text", "Text to speak")),
+    ]
+}
+
+fn mode_name(mode: &MessageMode) -> String {
+    json!(mode).as_str().unwrap_or_default().to_string()
+}
+
+/// Every action the palette can offer right now.
+#[tauri::command]
+pub async fn list_commands(app: AppHandle) -> Vec<PaletteAction> {
+    let mut actions: Vec<PaletteAction> = app_commands()
+        .into_iter()
+        .map(|(command, title, schema)| PaletteAction {
+            id: format!("command:{}", command),
+            title: title.to_string(),
+            category: "command",
+            command,
+            args: json!({}),
+            schema,
+        })
+        .collect();
+
+    // The scheduler may still be initializing; its tasks show up once it is ready.
+    let state = app.state::<SharedSchedulerState>();
+    let tasks = scheduler::with_scheduler(&state, |s| {
+        Ok(s.registry
+            .tasks
+            .iter()
+            .map(|t| (t.id.clone(), t.name.clone(), t.enabled))
+            .collect::<Vec<_>>())
+    })
+    .await
+    .unwrap_or_default();
+    for (id, name, enabled) in tasks {
+        actions.push(PaletteAction {
+            id: format!("task:run:{}", id),
+            title: format!("Run task: {}", name),
+            category: "task",
+            command: "run_task_now",
+            args: json!({ "id": id }),
+            schema: no_args(),
+        });
+        actions.push(PaletteAction {
+            id: format!("task:toggle:{}", id),
+            title: format!("{} task: {}", if enabled { "Disable" } else { "Enable" }, name),
+            category: "task",
+            command: "toggle_task",
+            args: json!({ "id": id, "enabled": !enabled }),
+            schema: no_args(),
+        });
+    }
+
+    for svc in services::read_service_registry(&app).unwrap_or_default() {
+        for action in ["start", "stop", "restart"] {
+            let mut verb = action.to_string();
+            verb[..1].make_ascii_uppercase();
+            actions.push(PaletteAction {
+                id: format!("service:{}:{}", action, svc.id),
+                title: format!("{} service: {}", verb, svc.name),
+                category: "service",
+                command: "control_service",
+                args: json!({ "id": svc.id, "action": action }),
+                schema: no_args(),
+            });
+        }
+    }
+
+    for mode in [MessageMode::Normal, MessageMode::Search, MessageMode::Analyze] {
+        let name = mode_name(&mode);
+        actions.push(PaletteAction {
+            id: format!("mode:{}", name),
+            title: format!("Switch to {} mode", name),
+            category: "mode",
+            command: "set_conversation_mode",
+            args: json!({ "mode": mode }),
+            schema: conversation_arg(),
+        });
+    }
+
+    for preset in crate::personality::list(&app) {
+        actions.push(PaletteAction {
+            id: format!("personality:{}", preset.id),
+            title: format!("Use personality: {}", preset.name),
+            category: "personality",
+            command: "apply_personality_preset",
+            args: json!({ "id": preset.id }),
+            schema: no_args(),
+        });
+    }
+
+    for webhook in crate::webhooks::list(&app).into_iter().filter(|w| w.enabled) {
+        actions.push(PaletteAction {
+            id: format!("webhook:{}", webhook.id),
+            title: format!("Fire webhook: {}", webhook.name),
+            category: "webhook",
+            command: "test_webhook",
+            args: json!({ "id": webhook.id }),
+            schema: no_args(),
+        });
+    }
+
+    actions
+}
@@ -0,0 +1,222 @@
+/// Personality presets: named prompt modifiers that change how Winter talks.
+///
+/// Built-in presets ship with the app; user presets are saved in the
+/// `personality_presets` store key. Applying a preset writes its modifier to
+/// `mbti_prompt_modifier`, the key the MBTI picker also uses, so it becomes the
+/// default for every conversation. A conversation can also pin its own preset,
+/// which then wins over the default.
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_PRESETS: &str = "personality_presets";
+const STORE_KEY_ACTIVE_PRESET: &str = "personality_preset";
+const STORE_KEY_CONVERSATION_PRESETS: &str = "conversation_personalities";
+const STORE_KEY_MBTI_MODIFIER: &str = "mbti_prompt_modifier";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PersonalityPreset {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Text appended to the system prompt; empty for Winter's plain personality.
+    pub prompt_modifier: String,
+    /// Set for presets that ship with the app; those cannot be changed or removed.
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+/// All presets plus the ID of the applied one, if any.
+#[derive(Debug, Serialize, Clone)]
+pub struct PersonalityPresets {
+    pub presets: Vec<PersonalityPreset>,
+    pub active: Option<String>,
+}
+
+fn builtin(id: &str, name: &str, description: &str, prompt_modifier: &str) -> PersonalityPreset {
+    PersonalityPreset {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: description.to_string(),
+        prompt_modifier: prompt_modifier.to_string(),
+        builtin: true,
+    }
+}
+
+fn builtin_presets() -> Vec<PersonalityPreset> {
+    vec![
+        builtin("winter", "Winter", "Plain Winter, no modifier", ""),
+        builtin(
+            "terse",
+            "Terse",
+            "Answers only, no small talk",
+            "Be even more terse than usual: answer in as few words as possible, skip greetings, \
+jokes and explanations unless asked.",
+        ),
+        builtin(
+            "mentor",
+            "Mentor",
+            "Patient, explains the why",
+            "Act as a patient mentor: explain the reasoning behind your answers, point out what the user \
+can learn from the problem, and check their understanding without being condescending.",
+        ),
+        builtin(
+            "cheerful",
+            "Cheerful",
+            "Warm and upbeat, still to the point",
+            "Your personality is warm and upbeat: encourage the user and celebrate progress, \
+while staying concise and honest.",
+        ),
+        builtin(
+            "critic",
+            "Critic",
+            "Challenges assumptions and finds flaws",
+            "Act as a critical reviewer: challenge the user's assumptions, point out flaws, risks and \
+edge cases first, and say plainly when an idea is bad.",
+        ),
+    ]
+}
+
+fn store_get<T: serde::de::DeserializeOwned>(app: &AppHandle, key: &str) -> Option<T> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(key))
+        .and_then(|v| serde_json::from_value(v).ok())
+}
+
+fn store_set(app: &AppHandle, key: &str, value: serde_json::Value) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(key, value);
+    store.save().map_err(|e| e.to_string())
+}
+
+fn user_presets(app: &AppHandle) -> Vec<PersonalityPreset> {
+    store_get(app, STORE_KEY_PRESETS).unwrap_or_default()
+}
+
+fn conversation_presets(app: &AppHandle) -> HashMap<String, String> {
+    store_get(app, STORE_KEY_CONVERSATION_PRESETS).unwrap_or_default()
+}
+
+/// All presets, built-in ones first.
+pub fn list(app: &AppHandle) -> Vec<PersonalityPreset> {
+    let mut presets = builtin_presets();
+    presets.extend(user_presets(app).into_iter().map(|p| PersonalityPreset { builtin: false, ..p }));
+    presets
+}
+
+fn find(app: &AppHandle, id: &str) -> Option<PersonalityPreset> {
+    list(app).into_iter().find(|p| p.id == id)
+}
+
+/// Prompt modifier for a conversation: its pinned preset if it has one, else
+/// the app-wide modifier. `None` when there is nothing to add.
+pub fn modifier_for(app: &AppHandle, conversation_id: Option<&str>) -> Option<String> {
+    let pinned = conversation_id
+        .and_then(|id| conversation_presets(app).remove(id))
+        .and_then(|preset_id| find(app, &preset_id));
+    match pinned {
+        Some(preset) => Some(preset.prompt_modifier),
+        None => store_get::<String>(app, STORE_KEY_MBTI_MODIFIER),
+    }
+    .filter(|m| !m.trim().is_empty())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn list_personality_presets(app: AppHandle) -> PersonalityPresets {
+    let presets = list(&app);
+    // The MBTI picker writes the modifier directly; only report the applied
+    // preset while the modifier still matches it.
+    let modifier = store_get::<String>(&app, STORE_KEY_MBTI_MODIFIER).unwrap_or_default();
+    let active = store_get::<String>(&app, STORE_KEY_ACTIVE_PRESET)
+        .filter(|id| presets.iter().any(|p| &p.id == id && p.prompt_modifier == modifier));
+    PersonalityPresets { presets, active }
+}
+
+/// Makes a preset the default personality for all conversations.
+#[tauri::command]
+pub fn apply_personality_preset(app: AppHandle, id: String) -> Result<(), AppError> {
+    let preset = find(&app, &id).ok_or_else(|| AppError::NotFound(format!("Preset '{}' not found", id)))?;
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_MBTI_MODIFIER, json!(preset.prompt_modifier));
+    store.set(STORE_KEY_ACTIVE_PRESET, json!(preset.id));
+    Ok(store.save()?)
+}
+
+/// Adds or updates a user preset.
+#[tauri::command]
+pub fn save_personality_preset(app: AppHandle, mut preset: PersonalityPreset) -> Result<(), AppError> {
+    preset.id = preset.id.trim().to_string();
+    if preset.id.is_empty() || preset.name.trim().is_empty() {
+        return Err(AppError::InvalidInput("Preset ID and name cannot be empty".to_string()));
+    }
+    if builtin_presets().iter().any(|p| p.id == preset.id) {
+        return Err(AppError::InvalidInput(format!("'{}' is a built-in preset", preset.id)));
+    }
+    preset.builtin = false;
+    let mut presets = user_presets(&app);
+    match presets.iter_mut().find(|p| p.id == preset.id) {
+        Some(existing) => *existing = preset,
+        None => presets.push(preset),
+    }
+    Ok(store_set(&app, STORE_KEY_PRESETS, json!(presets))?)
+}
+
+#[tauri::command]
+pub fn delete_personality_preset(app: AppHandle, id: String) -> Result<(), AppError> {
+    if builtin_presets().iter().any(|p| p.id == id) {
+        return Err(AppError::InvalidInput(format!("'{}' is a built-in preset", id)));
+    }
+    let mut presets = user_presets(&app);
+    let before = presets.len();
+    presets.retain(|p| p.id != id);
+    if presets.len() == before {
+        return Err(AppError::NotFound(format!("Preset '{}' not found", id)));
+    }
+    Ok(store_set(&app, STORE_KEY_PRESETS, json!(presets))?)
+}
+
+/// Preset pinned to a conversation, if any.
+#[tauri::command]
+pub fn get_conversation_personality(app: AppHandle, conversation_id: String) -> Option<String> {
+    conversation_presets(&app).remove(&conversation_id)
+}
+
+/// Pins a preset to a conversation; `None` makes it follow the default again.
+#[tauri::command]
+pub fn set_conversation_personality(
+    app: AppHandle,
+    conversation_id: String,
+    preset_id: Option<String>,
+) -> Result<(), AppError> {
+    let mut pinned = conversation_presets(&app);
+    match preset_id {
+        Some(id) => {
+            if find(&app, &id).is_none() {
+                return Err(AppError::NotFound(format!("Preset '{}' not found", id)));
+            }
+            pinned.insert(conversation_id, id);
+        }
+        None => {
+            pinned.remove(&conversation_id);
+        }
+    }
+    Ok(store_set(&app, STORE_KEY_CONVERSATION_PRESETS, json!(pinned))?)
+}
+
+/// Drops the pinned preset of a deleted conversation.
+pub fn forget_conversation(app: &AppHandle, conversation_id: &str) -> Result<(), String> {
+    let mut pinned = conversation_presets(app);
+    if pinned.remove(conversation_id).is_some() {
+        store_set(app, STORE_KEY_CONVERSATION_PRESETS, json!(pinned))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,421 @@
+//! Phoenix: the in-app replacement for the `phoenix.sh` watchdog cron.
+//!
+//! Watches a configurable list of targets (registry services or plain
+//! processes), restarts the ones that are down, and stops retrying a target
+//! that keeps dying (flapping) until the flap window has passed. Every action
+//! is appended to `<app_data_dir>/logs/phoenix.log` and emitted as a
+//! `phoenix` event. Services stopped from the app, or with their own
+//! watchdog policy, are left alone.
+//!
+//! Config stored at: <app_data_dir>/phoenix.json
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::error::AppError;
+use crate::services::{self, ServiceStatus};
+
+/// How long a restart command may run before it is treated as the
+/// long-running process itself and left alone.
+const RESTART_COMMAND_GRACE: Duration = Duration::from_secs(10);
+
+/// Lines returned by `get_phoenix_log` when no count is given.
+const DEFAULT_LOG_LINES: usize = 200;
+
+fn default_interval() -> u64 {
+    60
+}
+
+fn default_flap_window() -> u64 {
+    600
+}
+
+fn default_flap_max_restarts() -> u32 {
+    3
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// What a target points at.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum PhoenixCheck {
+    /// An entry of the services registry, checked and restarted through its manager.
+    Service { service_id: String },
+    /// Any process whose name contains `process_name`.
+    Process { process_name: String },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PhoenixTarget {
+    pub id: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub check: PhoenixCheck,
+    /// Shell command run instead of the default restart. Required for processes.
+    #[serde(default)]
+    pub restart_command: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PhoenixConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_interval")]
+    pub interval_secs: u64,
+    /// A target restarted `flap_max_restarts` times within this window is
+    /// considered flapping and left down until the window has passed.
+    #[serde(default = "default_flap_window")]
+    pub flap_window_secs: u64,
+    #[serde(default = "default_flap_max_restarts")]
+    pub flap_max_restarts: u32,
+    #[serde(default)]
+    pub targets: Vec<PhoenixTarget>,
+}
+
+impl Default for PhoenixConfig {
+    fn default() -> Self {
+        let service = |id: &str, name: &str| PhoenixTarget {
+            id: id.into(),
+            name: name.into(),
+            check: PhoenixCheck::Service { service_id: id.into() },
+            restart_command: None,
+            enabled: true,
+        };
+        PhoenixConfig {
+            enabled: false,
+            interval_secs: default_interval(),
+            flap_window_secs: default_flap_window(),
+            flap_max_restarts: default_flap_max_restarts(),
+            targets: vec![
+                service("winter-opencode", "Winter Agent"),
+                service("winter-proxy", "Winter Proxy"),
+            ],
+        }
+    }
+}
+
+/// Payload of the `phoenix` event.
+#[derive(Debug, Serialize, Clone)]
+pub struct PhoenixEvent {
+    pub target_id: String,
+    /// "down", "restarted", "restart-failed", "flapping" or "recovered".
+    pub action: String,
+    pub message: String,
+}
+
+/// Restart times per target, for flap detection.
+#[derive(Default, Clone)]
+pub struct PhoenixState {
+    restarts: Arc<std::sync::Mutex<HashMap<String, VecDeque<Instant>>>>,
+    flapping: Arc<std::sync::Mutex<HashMap<String, Instant>>>,
+}
+
+// ── Config and log I/O ───────────────────────────────────────────────
+
+fn data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Cannot get app data dir: {}", e))
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(data_dir(app)?.join("phoenix.json"))
+}
+
+fn log_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(data_dir(app)?.join("logs").join("phoenix.log"))
+}
+
+fn read_config(app: &AppHandle) -> Result<PhoenixConfig, String> {
+    let path = config_path(app)?;
+    if !path.exists() {
+        return Ok(PhoenixConfig::default());
+    }
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read phoenix config: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse phoenix config: {}", e))
+}
+
+fn write_config(app: &AppHandle, config: &PhoenixConfig) -> Result<(), String> {
+    let path = config_path(app)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config dir: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize phoenix config: {}", e))?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, &json).map_err(|e| format!("Failed to write temp config: {}", e))?;
+    std::fs::rename(&tmp, &path).map_err(|e| format!("Failed to commit config: {}", e))
+}
+
+/// Logs and emits one watchdog action.
+fn report(app: &AppHandle, target: &PhoenixTarget, action: &str, message: String) {
+    if let Ok(path) = log_path(app) {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        if let Ok(mut f) = std::fs::OpenOptions::new().create(true).append(true).open(&path) {
+            let ts = Local::now().format("%Y-%m-%d %H:%M:%S");
+            let _ = writeln!(f, "[{}] {} {}: {}", ts, target.id, action, message);
+        }
+    }
+    let _ = app.emit("phoenix", PhoenixEvent {
+        target_id: target.id.clone(),
+        action: action.to_string(),
+        message,
+    });
+}
+
+fn validate_config(config: &PhoenixConfig) -> Result<(), String> {
+    if config.interval_secs < 10 {
+        return Err("Check interval must be at least 10 seconds".to_string());
+    }
+    let mut seen = std::collections::HashSet::new();
+    for target in &config.targets {
+        if target.id.trim().is_empty() {
+            return Err("Target ID cannot be empty".to_string());
+        }
+        if !seen.insert(target.id.as_str()) {
+            return Err(format!("Duplicate target '{}'", target.id));
+        }
+        let restart = target.restart_command.as_deref().unwrap_or("").trim();
+        match &target.check {
+            PhoenixCheck::Service { service_id } if service_id.trim().is_empty() => {
+                return Err(format!("Target '{}' needs a service ID", target.id));
+            }
+            PhoenixCheck::Process { process_name } if process_name.trim().is_empty() => {
+                return Err(format!("Target '{}' needs a process name", target.id));
+            }
+            PhoenixCheck::Process { .. } if restart.is_empty() => {
+                return Err(format!("Process target '{}' needs a restart command", target.id));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+// ── Checks and restarts ──────────────────────────────────────────────
+
+/// `Some(true)` when up, `Some(false)` when down, `None` when it cannot be told.
+async fn is_up(target: &PhoenixTarget, registry: &[services::ServiceEntry]) -> Option<bool> {
+    match &target.check {
+        PhoenixCheck::Service { service_id } => {
+            let svc = registry.iter().find(|s| &s.id == service_id)?;
+            let status = tokio::time::timeout(
+                Duration::from_secs(5),
+                services::manager_for(svc).status(svc),
+            )
+            .await
+            .ok()?;
+            match status {
+                ServiceStatus::Running => Some(true),
+                ServiceStatus::Stopped => Some(false),
+                _ => None,
+            }
+        }
+        PhoenixCheck::Process { process_name } => {
+            let name = process_name.clone();
+            tokio::task::spawn_blocking(move || {
+                use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+                let mut sys = System::new();
+                sys.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::new());
+                let mut matches = sys.processes_by_name(std::ffi::OsStr::new(&name));
+                matches.next().is_some()
+            })
+            .await
+            .ok()
+        }
+    }
+}
+
+/// Runs a restart command through the platform shell. A command still running
+/// after the grace period is assumed to be the restarted process itself.
+async fn run_restart_command(command_line: &str) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    let mut cmd = {
+        let mut c = tokio::process::Command::new("cmd");
+        c.arg("/C");
+        c
+    };
+    #[cfg(not(target_os = "windows"))]
+    let mut cmd = {
+        let mut c = tokio::process::Command::new("sh");
+        c.arg("-c");
+        c
+    };
+    let mut child = cmd
+        .arg(command_line)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("exec error: {}", e))?;
+    match tokio::time::timeout(RESTART_COMMAND_GRACE, child.wait()).await {
+        Ok(Ok(status)) if status.success() => Ok(()),
+        Ok(Ok(status)) => {
+            let mut stderr = String::new();
+            if let Some(mut pipe) = child.stderr.take() {
+                use tokio::io::AsyncReadExt;
+                let _ = pipe.read_to_string(&mut stderr).await;
+            }
+            Err(format!("exit {}: {}", status.code().unwrap_or(-1), stderr.trim()))
+        }
+        Ok(Err(e)) => Err(format!("exec error: {}", e)),
+        Err(_) => {
+            // Still running: reap it in the background.
+            tokio::spawn(async move {
+                let _ = child.wait().await;
+            });
+            Ok(())
+        }
+    }
+}
+
+async fn restart(target: &PhoenixTarget, registry: &[services::ServiceEntry]) -> Result<(), String> {
+    if let Some(command) = target.restart_command.as_deref().filter(|c| !c.trim().is_empty()) {
+        return run_restart_command(command).await;
+    }
+    match &target.check {
+        PhoenixCheck::Service { service_id } => {
+            let svc = registry
+                .iter()
+                .find(|s| &s.id == service_id)
+                .ok_or_else(|| format!("Service '{}' not found", service_id))?;
+            services::manager_for(svc).restart(svc).await
+        }
+        PhoenixCheck::Process { .. } => Err("No restart command configured".to_string()),
+    }
+}
+
+/// Records a restart and reports whether the target is now flapping.
+fn note_restart(state: &PhoenixState, id: &str, config: &PhoenixConfig) -> bool {
+    let window = Duration::from_secs(config.flap_window_secs);
+    let now = Instant::now();
+    let mut restarts = state.restarts.lock().unwrap_or_else(|e| e.into_inner());
+    let times = restarts.entry(id.to_string()).or_default();
+    times.push_back(now);
+    while times.front().is_some_and(|t| now.duration_since(*t) > window) {
+        times.pop_front();
+    }
+    times.len() as u32 >= config.flap_max_restarts
+}
+
+async fn check_target(
+    app: &AppHandle,
+    state: &PhoenixState,
+    config: &PhoenixConfig,
+    target: &PhoenixTarget,
+    registry: &[services::ServiceEntry],
+) {
+    if let PhoenixCheck::Service { service_id } = &target.check {
+        // Stopped on purpose, or already covered by the per-service watchdog.
+        if services::is_manually_stopped(app, service_id)
+            || registry.iter().any(|s| &s.id == service_id && s.watchdog.is_some())
+        {
+            return;
+        }
+    }
+    let Some(up) = is_up(target, registry).await else { return };
+    let window = Duration::from_secs(config.flap_window_secs);
+
+    let flapping_since = state
+        .flapping
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&target.id)
+        .copied();
+    if let Some(since) = flapping_since {
+        if up || since.elapsed() >= window {
+            state.flapping.lock().unwrap_or_else(|e| e.into_inner()).remove(&target.id);
+            state.restarts.lock().unwrap_or_else(|e| e.into_inner()).remove(&target.id);
+            if up {
+                report(app, target, "recovered", "Up again after flapping".to_string());
+            }
+        } else {
+            return;
+        }
+    }
+    if up {
+        return;
+    }
+
+    report(app, target, "down", format!("{} is not running", target.name));
+    match restart(target, registry).await {
+        Ok(()) => report(app, target, "restarted", format!("Restarted {}", target.name)),
+        Err(e) => report(app, target, "restart-failed", e),
+    }
+    if note_restart(state, &target.id, config) {
+        state
+            .flapping
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(target.id.clone(), Instant::now());
+        report(
+            app,
+            target,
+            "flapping",
+            format!(
+                "Restarted {} times within {}s; pausing restarts for the flap window",
+                config.flap_max_restarts, config.flap_window_secs
+            ),
+        );
+    }
+}
+
+/// Spawns the background loop. The config is re-read every round, so changes
+/// from the UI apply without a restart.
+pub fn spawn_phoenix(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let config = read_config(&app).unwrap_or_default();
+            if config.enabled {
+                let state = app.state::<PhoenixState>().inner().clone();
+                let registry = services::read_service_registry(&app).unwrap_or_default();
+                for target in config.targets.iter().filter(|t| t.enabled) {
+                    check_target(&app, &state, &config, target, &registry).await;
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(config.interval_secs.max(10))).await;
+        }
+    });
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_phoenix_config(app: AppHandle) -> Result<PhoenixConfig, AppError> {
+    Ok(read_config(&app)?)
+}
+
+#[tauri::command]
+pub fn set_phoenix_config(app: AppHandle, config: PhoenixConfig) -> Result<(), AppError> {
+    validate_config(&config).map_err(AppError::InvalidInput)?;
+    Ok(write_config(&app, &config)?)
+}
+
+/// Last `lines` lines of the phoenix log, oldest first.
+#[tauri::command]
+pub fn get_phoenix_log(app: AppHandle, lines: Option<usize>) -> Result<String, AppError> {
+    let path = log_path(&app)?;
+    let content = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(String::new()),
+        Err(e) => return Err(AppError::Io(format!("Failed to read phoenix log: {}", e))),
+    };
+    let all: Vec<&str> = content.lines().collect();
+    let n = lines.unwrap_or(DEFAULT_LOG_LINES);
+    Ok(all[all.len().saturating_sub(n)..].join("\n"))
+}
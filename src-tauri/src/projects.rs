@@ -0,0 +1,268 @@
+/// Projects: a named root directory with its own preferred model, system
+/// prompt additions and tool policy, stored under the `projects` store key.
+///
+/// `switch_project` makes a project active (`active_project` store key): it
+/// sets the working directory, points the active OpenCode profile at the
+/// project root and selects the preferred model. While a project is active,
+/// its prompt additions are appended to the system prompt and its tool
+/// policy restricts which tools Claude is offered and may run.
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::Path;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_PROJECTS: &str = "projects";
+const STORE_KEY_ACTIVE: &str = "active_project";
+
+/// Tools allowed under [`ToolPolicy::ReadOnly`].
+const READ_ONLY_TOOLS: &[&str] = &["file_read", "file_list", "file_outline"];
+
+/// Which tools Claude may use while a project is active.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ToolPolicy {
+    /// Every tool, as without a project.
+    #[default]
+    All,
+    /// Only reading and listing files.
+    ReadOnly,
+    /// Only the named tools (built-in, delegate or webhook tools).
+    Allowlist { tools: Vec<String> },
+    /// No tools at all.
+    None,
+}
+
+impl ToolPolicy {
+    pub fn allows(&self, tool: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::ReadOnly => READ_ONLY_TOOLS.contains(&tool),
+            Self::Allowlist { tools } => tools.iter().any(|t| t == tool),
+            Self::None => false,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Project {
+    /// Generated when empty on save.
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    /// Absolute path of the project's root directory.
+    pub root: String,
+    /// Model selected when switching to the project; the current one is kept when unset.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Text appended to the system prompt while the project is active.
+    #[serde(default)]
+    pub prompt: String,
+    #[serde(default)]
+    pub tool_policy: ToolPolicy,
+    /// RFC 3339 time the project was created.
+    #[serde(default)]
+    pub created_at: String,
+    /// RFC 3339 time the project was last switched to.
+    #[serde(default)]
+    pub last_opened_at: Option<String>,
+}
+
+fn stored(app: &AppHandle) -> Vec<Project> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_PROJECTS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn write_stored(app: &AppHandle, projects: &[Project]) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_PROJECTS, json!(projects));
+    store.save().map_err(|e| e.to_string())
+}
+
+fn active_id(app: &AppHandle) -> Option<String> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_ACTIVE))
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.is_empty())
+}
+
+/// The active project, if any.
+pub fn active(app: &AppHandle) -> Option<Project> {
+    let id = active_id(app)?;
+    stored(app).into_iter().find(|p| p.id == id)
+}
+
+/// The active project's system prompt additions, with a header naming it.
+pub fn prompt_additions(app: &AppHandle) -> Option<String> {
+    let project = active(app)?;
+    let prompt = project.prompt.trim();
+    if prompt.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "## Project: {}\nProject root: {}\n\n{}",
+        project.name, project.root, prompt
+    ))
+}
+
+/// Tool policies in effect: the active project's and the active settings profile's.
+fn active_policies(app: &AppHandle) -> Vec<ToolPolicy> {
+    active(app)
+        .map(|p| p.tool_policy)
+        .into_iter()
+        .chain(crate::settings::active_profile(app).map(|p| p.tool_policy))
+        .collect()
+}
+
+/// Whether the active tool policies let Claude run `tool`.
+pub fn tool_allowed(app: &AppHandle, tool: &str) -> bool {
+    active_policies(app).iter().all(|p| p.allows(tool))
+}
+
+/// Removes the tools the active policies do not allow from a tool definition list.
+pub fn restrict_tools(app: &AppHandle, tools: &mut Value) {
+    let policies = active_policies(app);
+    if let Some(list) = tools.as_array_mut() {
+        list.retain(|t| t["name"].as_str().is_some_and(|n| policies.iter().all(|p| p.allows(n))));
+    }
+}
+
+/// Sets the working directory and the active OpenCode profile's workspace to
+/// `dir`. Remote (SSH) profiles are left alone, as `dir` is a local path.
+pub async fn switch_workspace(app: &AppHandle, dir: &str) -> Result<(), String> {
+    crate::set_working_directory(app.clone(), dir.to_string()).await?;
+    let profile = crate::opencode::profiles::active(app);
+    if profile.ssh.is_some() {
+        tracing::warn!(
+            "Active OpenCode profile '{}' is remote; its workspace was not changed",
+            profile.name
+        );
+        return Ok(());
+    }
+    Ok(crate::opencode::profiles::set_opencode_workspace(app.clone(), dir.to_string(), Some(profile.id)).await?)
+}
+
+fn validate(project: &Project) -> Result<(), String> {
+    if project.name.trim().is_empty() {
+        return Err("Project name cannot be empty".to_string());
+    }
+    let root = Path::new(&project.root);
+    if !root.is_absolute() {
+        return Err("Project root must be an absolute path".to_string());
+    }
+    if !root.is_dir() {
+        return Err(format!("Not a directory: {}", project.root));
+    }
+    Ok(())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Projects in the order they were created.
+#[tauri::command]
+pub fn list_projects(app: AppHandle) -> Vec<Project> {
+    stored(&app)
+}
+
+#[tauri::command]
+pub fn get_active_project(app: AppHandle) -> Option<Project> {
+    active(&app)
+}
+
+/// Adds or replaces a project. Returns it with its ID filled in.
+///
+/// Saving the active project does not re-apply its directory or model; switch
+/// to it again for that.
+#[tauri::command]
+pub fn save_project(app: AppHandle, mut project: Project) -> Result<Project, AppError> {
+    project.name = project.name.trim().to_string();
+    project.root = project.root.trim().trim_end_matches(['/', '\\']).to_string();
+    project.model = project.model.map(|m| m.trim().to_string()).filter(|m| !m.is_empty());
+    validate(&project)?;
+
+    let mut projects = stored(&app);
+    match projects.iter_mut().find(|p| !project.id.is_empty() && p.id == project.id) {
+        Some(existing) => {
+            project.created_at = existing.created_at.clone();
+            project.last_opened_at = existing.last_opened_at.clone();
+            *existing = project.clone();
+        }
+        None => {
+            if project.id.is_empty() {
+                project.id = uuid::Uuid::new_v4().to_string();
+            }
+            project.created_at = chrono::Local::now().to_rfc3339();
+            project.last_opened_at = None;
+            projects.push(project.clone());
+        }
+    }
+    write_stored(&app, &projects)?;
+    Ok(project)
+}
+
+/// Deletes a project. Deleting the active project leaves project mode; the
+/// working directory and model stay as they are.
+#[tauri::command]
+pub fn delete_project(app: AppHandle, id: String) -> Result<(), AppError> {
+    let mut projects = stored(&app);
+    let before = projects.len();
+    projects.retain(|p| p.id != id);
+    if projects.len() == before {
+        return Err(AppError::NotFound(format!("Project '{}' not found", id)));
+    }
+    write_stored(&app, &projects)?;
+    if active_id(&app).as_deref() == Some(id.as_str()) {
+        let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+        store.delete(STORE_KEY_ACTIVE);
+        store.save().map_err(|e| e.to_string())?;
+        let _ = app.emit("project-switched", Option::<Project>::None);
+    }
+    Ok(())
+}
+
+/// Makes a project active, or leaves project mode when `id` is `None`.
+///
+/// Switching sets the working directory to the project root, points the
+/// active OpenCode profile's workspace at it (SSH profiles are left alone,
+/// as the root is a local path) and selects the preferred model. Emits
+/// `project-switched` with the new active project.
+#[tauri::command]
+pub async fn switch_project(app: AppHandle, id: Option<String>) -> Result<Option<Project>, AppError> {
+    let Some(id) = id else {
+        let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+        store.delete(STORE_KEY_ACTIVE);
+        store.save().map_err(|e| e.to_string())?;
+        let _ = app.emit("project-switched", Option::<Project>::None);
+        return Ok(None);
+    };
+
+    let mut projects = stored(&app);
+    let project = projects
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Project '{}' not found", id))?;
+    validate(project)?;
+
+    switch_workspace(&app, &project.root).await?;
+
+    project.last_opened_at = Some(chrono::Local::now().to_rfc3339());
+    let project = project.clone();
+    write_stored(&app, &projects)?;
+
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_ACTIVE, json!(project.id));
+    if let Some(model) = &project.model {
+        store.set(crate::claude::client::STORE_KEY_MODEL, json!(model));
+    }
+    store.save().map_err(|e| e.to_string())?;
+
+    let _ = app.emit("project-switched", Some(&project));
+    Ok(Some(project))
+}
@@ -0,0 +1,123 @@
+/// Quick-prompt window summoned by a global hotkey.
+///
+/// The hotkey (stored under `quick_prompt_hotkey`) toggles a small
+/// always-on-top window. Its submission is handed to the main window as a
+/// `quick-prompt` event, which sends it through `chat_send` into the active
+/// conversation like any typed message.
+use serde_json::json;
+use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_HOTKEY: &str = "quick_prompt_hotkey";
+const DEFAULT_HOTKEY: &str = "CommandOrControl+Shift+Space";
+
+const WINDOW_LABEL: &str = "quick-prompt";
+
+/// Event carrying the submitted text to the main window.
+const SUBMIT_EVENT: &str = "quick-prompt";
+
+fn hotkey(app: &AppHandle) -> String {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_HOTKEY))
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_HOTKEY.to_string())
+}
+
+fn register(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|e| format!("Invalid hotkey '{}': {}", accelerator, e))?;
+    app.global_shortcut()
+        .on_shortcut(shortcut, |app, _, event| {
+            if event.state == ShortcutState::Pressed {
+                if let Err(e) = toggle_window(app) {
+                    tracing::warn!("Quick prompt window failed: {}", e);
+                }
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Registers the stored hotkey. Called once from `setup`; a hotkey another
+/// app already holds is logged instead of failing startup.
+pub fn init(app: &AppHandle) {
+    let accelerator = hotkey(app);
+    if let Err(e) = register(app, &accelerator) {
+        tracing::warn!("Quick prompt hotkey not registered: {}", e);
+    }
+}
+
+fn toggle_window(app: &AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(WINDOW_LABEL) {
+        if window.is_visible().unwrap_or(false) {
+            return window.hide().map_err(|e| e.to_string());
+        }
+        window.show().map_err(|e| e.to_string())?;
+        return window.set_focus().map_err(|e| e.to_string());
+    }
+    WebviewWindowBuilder::new(app, WINDOW_LABEL, WebviewUrl::App("index.html?window=quick-prompt".into()))
+        .title("Winter")
+        .inner_size(640.0, 64.0)
+        .resizable(false)
+        .decorations(false)
+        .transparent(true)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .center()
+        .focused(true)
+        .build()
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_quick_prompt_hotkey(app: AppHandle) -> String {
+    hotkey(&app)
+}
+
+/// Replaces the hotkey. The old one stays registered if the new one is rejected.
+#[tauri::command]
+pub fn set_quick_prompt_hotkey(app: AppHandle, hotkey: String) -> Result<(), AppError> {
+    let hotkey = hotkey.trim().to_string();
+    let previous = self::hotkey(&app);
+    if let Ok(shortcut) = previous.parse::<Shortcut>() {
+        let _ = app.global_shortcut().unregister(shortcut);
+    }
+    if let Err(e) = register(&app, &hotkey) {
+        let _ = register(&app, &previous);
+        return Err(AppError::InvalidInput(e));
+    }
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_HOTKEY, json!(hotkey));
+    Ok(store.save()?)
+}
+
+/// Hides the quick-prompt window and passes `text` to the main window.
+#[tauri::command]
+pub fn submit_quick_prompt(app: AppHandle, text: String) -> Result<(), AppError> {
+    hide_quick_prompt(app.clone())?;
+    if text.trim().is_empty() {
+        return Ok(());
+    }
+    let main = app.get_webview_window("main").ok_or("Main window not found")?;
+    let _ = main.show();
+    let _ = main.set_focus();
+    main.emit(SUBMIT_EVENT, text).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn hide_quick_prompt(app: AppHandle) -> Result<(), AppError> {
+    match app.get_webview_window(WINDOW_LABEL) {
+        Some(window) => Ok(window.hide().map_err(|e| e.to_string())?),
+        None => Ok(()),
+    }
+}
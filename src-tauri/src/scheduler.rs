@@ -1,22 +1,88 @@
 /// Tauri-native cron scheduler with persistent registry.
 /// Registry stored at: <app_data_dir>/scheduler-registry.json
 /// Logs stored at:     <app_data_dir>/logs/<task-id>.log
-use chrono::Local;
+use crate::app_backup::AppBackupConfig;
+use crate::backup::BackupConfig;
+use crate::error::AppError;
+use crate::log_digest::LogDigestConfig;
+use chrono::{Local, Utc};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tauri::{AppHandle, Manager};
-use tokio::sync::Mutex;
+use tauri::{ipc::Channel, AppHandle, Emitter, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::{broadcast, Mutex};
+use std::future::Future;
+use std::pin::Pin;
 use tokio_cron_scheduler::{Job, JobScheduler};
 use uuid::Uuid;
 
 // ── Types ────────────────────────────────────────────────────────────
 
+/// What a task runs. Untagged so registries written before `command_line`
+/// existed still deserialize as `Script`.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct TaskCommand {
-    pub script: String,
-    pub args: Vec<String>,
+#[serde(untagged)]
+pub enum TaskCommand {
+    /// A plain script filename resolved from ~/bin or ~/infra.
+    Script { script: String, args: Vec<String> },
+    /// A full command line handed to the platform shell.
+    CommandLine { command_line: String },
+    /// An AI prompt. Claude runs it with the native tool loop; Ollama answers without tools.
+    Prompt {
+        prompt: String,
+        /// "claude" (default) or "ollama".
+        #[serde(default)]
+        provider: Option<String>,
+        /// Overrides the configured model for this task only.
+        #[serde(default)]
+        model: Option<String>,
+        /// File the result is appended to, in addition to the task log. `~` is expanded.
+        #[serde(default)]
+        output_file: Option<String>,
+        /// Emit a `scheduler-prompt-result` event so the UI can notify the user.
+        #[serde(default)]
+        notify: bool,
+    },
+    /// A built-in archive of one or more directories; see `backup.rs`.
+    Backup { backup: BackupConfig },
+    /// A built-in encrypted archive of Winter's own data; see `app_backup.rs`.
+    AppBackup { app_backup: AppBackupConfig },
+    /// A built-in digest of new errors and warnings in log files; see `log_digest.rs`.
+    LogDigest { log_digest: LogDigestConfig },
+    /// Fires a configured webhook; see `webhooks.rs`.
+    Webhook {
+        webhook: String,
+        /// Values for the webhook's `{{placeholders}}`.
+        #[serde(default)]
+        vars: HashMap<String, String>,
+    },
+}
+
+/// Payload of the `task-started`, `task-finished` and `task-failed` events.
+/// `task-finished` fires after every run; `task-failed` additionally fires
+/// when the run failed or timed out.
+#[derive(Debug, Serialize, Clone)]
+pub struct TaskEvent {
+    pub task_id: String,
+    /// "schedule", "chain" or "manual".
+    pub trigger: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Payload of the `scheduler-prompt-result` event.
+#[derive(Debug, Serialize, Clone)]
+pub struct PromptResult {
+    pub task_id: String,
+    pub output: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -28,11 +94,75 @@ pub struct TaskEntry {
     pub log_file: String,
     pub enabled: bool,
     pub created_by_user: bool,
+    /// Kill the run after this many seconds. `None` means no limit.
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Random delay of 0..=N seconds before each scheduled run, to spread out
+    /// tasks that share a cron slot.
+    #[serde(default)]
+    pub jitter_secs: Option<u64>,
+    /// Automatic retries for failed or timed-out scheduled runs.
+    #[serde(default)]
+    pub retry: Option<RetryPolicy>,
+    /// Interval schedule such as "15m" or "1h30m". Replaces `schedule` when set.
+    #[serde(default)]
+    pub every: Option<String>,
+    /// One-shot run time ("2025-06-01T09:00:00", or RFC 3339). The task is
+    /// disabled after it fires. Replaces `schedule` when set.
+    #[serde(default)]
+    pub at: Option<String>,
+    /// Run this task whenever one of these tasks finishes a scheduled run.
+    /// With no `schedule`, `every` or `at`, the task runs only when chained.
+    #[serde(default)]
+    pub run_after: Vec<String>,
+    /// With `run_after`, only run when the upstream task succeeded.
+    #[serde(default)]
+    pub only_on_success: bool,
+    /// IANA zone the schedule is evaluated in (e.g. "Asia/Seoul"). Defaults to system local time.
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Alerts fired when a scheduled run fails or times out.
+    #[serde(default)]
+    pub on_failure: Option<FailureAlert>,
 }
 
+/// Per-task failure alerting.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FailureAlert {
+    /// Show a desktop notification.
+    #[serde(default)]
+    pub desktop: bool,
+    /// POST the failure (task name, error, last log lines) to this URL.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+}
+
+/// Log lines included in failure alerts.
+const ALERT_LOG_LINES: usize = 20;
+
+/// Retry settings for a task. The delay doubles after every failed attempt.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts per run, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    #[serde(default = "default_backoff_secs")]
+    pub backoff_secs: u64,
+}
+
+fn default_backoff_secs() -> u64 {
+    30
+}
+
+/// Upper bound for a single retry delay.
+const MAX_BACKOFF_SECS: u64 = 3600;
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct TaskRegistry {
     pub tasks: Vec<TaskEntry>,
+    /// Maintenance mode: no jobs are scheduled while set. Survives restarts.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub paused: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -57,168 +187,163 @@ pub struct SchedulerState {
     pub data_dir: PathBuf,
     pub last_run: HashMap<String, String>, // task_id → ISO timestamp
     pub running: HashMap<String, bool>,    // task_id → running flag
+    pub live_output: HashMap<String, broadcast::Sender<LogLine>>, // task_id → output of the current run
+    pub log_streams: HashMap<String, Arc<AtomicBool>>, // stream id → stop flag
 }
 
 pub type SharedSchedulerState = Arc<Mutex<Option<SchedulerState>>>;
 
 /// Helper to extract the inner state or return an error if scheduler hasn't initialized yet.
-pub async fn with_scheduler<F, R>(state: &SharedSchedulerState, f: F) -> Result<R, String>
+pub async fn with_scheduler<F, R>(state: &SharedSchedulerState, f: F) -> Result<R, AppError>
 where
-    F: FnOnce(&mut SchedulerState) -> Result<R, String>,
+    F: FnOnce(&mut SchedulerState) -> Result<R, AppError>,
 {
     let mut guard = state.lock().await;
     match guard.as_mut() {
         Some(s) => f(s),
-        None => Err("Scheduler is still initializing. Please try again.".to_string()),
+        None => Err(not_initialized()),
     }
 }
 
-pub async fn start_enabled_jobs(state: &SharedSchedulerState) {
+fn not_initialized() -> AppError {
+    AppError::Unavailable("Scheduler is still initializing. Please try again.".to_string())
+}
+
+pub async fn start_enabled_jobs(app: &AppHandle, state: &SharedSchedulerState) {
     let mut guard = state.lock().await;
     let Some(s) = guard.as_mut() else { return };
-    let enabled: Vec<TaskEntry> = s.registry.tasks.iter().filter(|t| t.enabled).cloned().collect();
+    let enabled: Vec<TaskEntry> = s.registry
+        .tasks
+        .iter()
+        .filter(|t| t.enabled && !s.job_map.contains_key(&t.id))
+        .cloned()
+        .collect();
     let sched = s.scheduler.clone();
     let d_dir = s.data_dir.clone();
     drop(guard);
 
     for task in &enabled {
         let state_clone = state.clone();
-        match add_job_to_scheduler(&sched, task, &d_dir, Some(&state_clone)).await {
-            Ok(uuid) => {
+        match add_job_to_scheduler(app, &sched, task, &d_dir, Some(&state_clone)).await {
+            Ok(Some(uuid)) => {
                 let mut g = state.lock().await;
                 if let Some(s) = g.as_mut() {
                     s.job_map.insert(task.id.clone(), uuid);
                 }
             }
-            Err(e) => eprintln!("[scheduler] Failed to add job '{}' on init: {}", task.id, e),
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to add job '{}' on init: {}", task.id, e),
         }
     }
 }
 
-// ── Default task seeds (13 crons from TaskInfo.md) ──────────────────
+// ── Default task seeds (crons from TaskInfo.md) ─────────────────────
+
+/// A disabled, built-in task.
+fn seed(id: &str, name: &str, schedule: &str, command: TaskCommand, log_file: &str) -> TaskEntry {
+    TaskEntry {
+        id: id.into(),
+        name: name.into(),
+        schedule: schedule.into(),
+        command,
+        log_file: log_file.into(),
+        enabled: false,
+        created_by_user: false,
+        timeout_secs: None,
+        jitter_secs: None,
+        retry: None,
+        every: None,
+        at: None,
+        run_after: vec![],
+        only_on_success: false,
+        timezone: None,
+        on_failure: None,
+    }
+}
 
-fn default_tasks() -> Vec<TaskEntry> {
-    vec![
-        TaskEntry {
-            id: "phoenix".into(),
-            name: "Phoenix Watchdog".into(),
-            schedule: "* * * * *".into(),
-            command: TaskCommand { script: "phoenix.sh".into(), args: vec![] },
-            log_file: "phoenix-watchdog.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "log-digest".into(),
-            name: "Log Digest".into(),
-            schedule: "*/30 * * * *".into(),
-            command: TaskCommand { script: "log-digest.sh".into(), args: vec![] },
-            log_file: "log-digest.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "cleanup-sessions".into(),
-            name: "Session Cleanup".into(),
-            schedule: "*/30 * * * *".into(),
-            command: TaskCommand { script: "cleanup-sessions.sh".into(), args: vec![] },
-            log_file: "cleanup-sessions.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "incremental-backup".into(),
-            name: "Incremental Backup".into(),
-            schedule: "*/10 * * * *".into(),
-            command: TaskCommand { script: "incremental-backup.sh".into(), args: vec![] },
-            log_file: "incremental-backup.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "audit-collect".into(),
-            name: "Audit Collector".into(),
-            schedule: "0 * * * *".into(),
-            command: TaskCommand { script: "collect-logs.sh".into(), args: vec![] },
-            log_file: "audit-collect.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "rag-indexer".into(),
-            name: "RAG Indexer".into(),
-            schedule: "0 */6 * * *".into(),
-            command: TaskCommand { script: "rag-indexer.py".into(), args: vec![] },
-            log_file: "rag-indexer.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "daily-backup".into(),
-            name: "Daily Backup".into(),
-            schedule: "0 4 * * *".into(),
-            command: TaskCommand { script: "openclaw-backup.sh".into(), args: vec![] },
-            log_file: "daily-backup.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "daily-cleanup".into(),
-            name: "Disk Cleanup".into(),
-            schedule: "0 5 * * *".into(),
-            command: TaskCommand { script: "daily-cleanup.sh".into(), args: vec![] },
-            log_file: "daily-cleanup.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "daily-avatar".into(),
-            name: "Avatar Update".into(),
-            schedule: "0 9 * * *".into(),
-            command: TaskCommand { script: "daily-avatar.sh".into(), args: vec![] },
-            log_file: "daily-avatar.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "daily-obsidian".into(),
-            name: "Obsidian Log".into(),
-            schedule: "59 23 * * *".into(),
-            command: TaskCommand { script: "daily-obsidian-log.sh".into(), args: vec![] },
-            log_file: "daily-obsidian.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "deadline-checker".into(),
-            name: "Deadline Checker".into(),
-            schedule: "0 8-22/2 * * *".into(),
-            command: TaskCommand { script: "deadline-checker.py".into(), args: vec![] },
-            log_file: "deadline-checker.log".into(),
-            enabled: false,
-            created_by_user: false,
-        },
-        TaskEntry {
-            id: "ai-upgrade-scanner".into(),
-            name: "Upgrade Scanner".into(),
-            schedule: "0 9,21 * * *".into(),
-            command: TaskCommand { script: "ai-upgrade-scanner.py".into(), args: vec![] },
-            log_file: "ai-upgrade-scanner.log".into(),
-            enabled: false,
-            created_by_user: false,
+fn script(name: &str) -> TaskCommand {
+    TaskCommand::Script { script: name.into(), args: vec![] }
+}
+
+/// Native backup of the Winter workspace into ~/.winter/backups.
+fn workspace_backup(incremental: bool) -> TaskCommand {
+    TaskCommand::Backup {
+        backup: BackupConfig {
+            sources: vec!["~/.winter/workspace".into()],
+            destination: "~/.winter/backups".into(),
+            retention: if incremental { 48 } else { 7 },
+            compression: Default::default(),
+            incremental,
+            exclude: vec!["node_modules".into(), ".git".into(), "target".into()],
         },
-        TaskEntry {
-            id: "study-sync".into(),
-            name: "Study Sync".into(),
-            schedule: "0 8-22/2 * * *".into(),
-            command: TaskCommand { script: "sync_to_cloud.sh".into(), args: vec![] },
-            log_file: "study-sync.log".into(),
-            enabled: false,
-            created_by_user: false,
+    }
+}
+
+/// Encrypted backup of settings, conversations, registries and the memory DB.
+fn app_data_backup() -> TaskCommand {
+    TaskCommand::AppBackup {
+        app_backup: AppBackupConfig { destination: "~/.winter/backups/app".into(), retention: 14 },
+    }
+}
+
+/// Native digest of ~/.winter/logs, notifying on new errors.
+fn winter_log_digest() -> TaskCommand {
+    TaskCommand::LogDigest {
+        log_digest: LogDigestConfig {
+            paths: vec!["~/.winter/logs".into()],
+            error_patterns: crate::log_digest::default_error_patterns(),
+            warning_patterns: crate::log_digest::default_warning_patterns(),
+            max_entries: 20,
+            output_file: Some("~/.winter/log-digest.md".into()),
+            notify: true,
         },
+    }
+}
+
+fn default_tasks() -> Vec<TaskEntry> {
+    vec![
+        seed("log-digest", "Log Digest", "*/30 * * * *", winter_log_digest(), "log-digest.log"),
+        seed("cleanup-sessions", "Session Cleanup", "*/30 * * * *", script("cleanup-sessions.sh"), "cleanup-sessions.log"),
+        seed("incremental-backup", "Incremental Backup", "*/10 * * * *", workspace_backup(true), "incremental-backup.log"),
+        seed("audit-collect", "Audit Collector", "0 * * * *", script("collect-logs.sh"), "audit-collect.log"),
+        seed("rag-indexer", "RAG Indexer", "0 */6 * * *", script("rag-indexer.py"), "rag-indexer.log"),
+        seed("daily-backup", "Daily Backup", "0 4 * * *", workspace_backup(false), "daily-backup.log"),
+        seed("app-backup", "App Data Backup", "30 4 * * *", app_data_backup(), "app-backup.log"),
+        seed("daily-cleanup", "Disk Cleanup", "0 5 * * *", script("daily-cleanup.sh"), "daily-cleanup.log"),
+        seed("daily-avatar", "Avatar Update", "0 9 * * *", script("daily-avatar.sh"), "daily-avatar.log"),
+        seed("daily-obsidian", "Obsidian Log", "59 23 * * *", script("daily-obsidian-log.sh"), "daily-obsidian.log"),
+        seed("deadline-checker", "Deadline Checker", "0 8-22/2 * * *", script("deadline-checker.py"), "deadline-checker.log"),
+        seed("ai-upgrade-scanner", "Upgrade Scanner", "0 9,21 * * *", script("ai-upgrade-scanner.py"), "ai-upgrade-scanner.log"),
+        seed("study-sync", "Study Sync", "0 8-22/2 * * *", script("sync_to_cloud.sh"), "study-sync.log"),
     ]
 }
 
+/// Scripts built-in tasks ran before they had a native command, by task id.
+/// Untouched seeds are migrated and matching crontab lines enable the task.
+const LEGACY_SCRIPTS: &[(&str, &str)] = &[
+    ("log-digest", "log-digest.sh"),
+    ("incremental-backup", "incremental-backup.sh"),
+    ("daily-backup", "openclaw-backup.sh"),
+];
+
+/// Switches built-in tasks still running their legacy script (no extra args)
+/// to the native command from [`default_tasks`], keeping schedule and enabled
+/// state. Returns whether anything changed.
+fn migrate_legacy_seeds(tasks: &mut [TaskEntry]) -> bool {
+    let defaults = default_tasks();
+    let mut changed = false;
+    for task in tasks.iter_mut().filter(|t| !t.created_by_user) {
+        let TaskCommand::Script { script, args } = &task.command else { continue };
+        let untouched = args.is_empty()
+            && LEGACY_SCRIPTS.iter().any(|(id, legacy)| *id == task.id && legacy == script);
+        if let Some(native) = defaults.iter().find(|d| untouched && d.id == task.id) {
+            task.command = native.command.clone();
+            changed = true;
+        }
+    }
+    changed
+}
+
 // ── Registry I/O ─────────────────────────────────────────────────────
 
 fn registry_path(app: &AppHandle) -> Result<PathBuf, String> {
@@ -240,7 +365,7 @@ fn read_registry(path: &PathBuf) -> TaskRegistry {
         Ok(s) => match serde_json::from_str(&s) {
             Ok(r) => r,
             Err(e) => {
-                eprintln!("[scheduler] Corrupt registry at {:?}: {}. Backing up and resetting.", path, e);
+                tracing::error!("Corrupt registry at {:?}: {}. Backing up and resetting.", path, e);
                 let bak = path.with_extension("json.corrupt");
                 let _ = std::fs::rename(path, &bak);
                 TaskRegistry::default()
@@ -290,6 +415,441 @@ fn resolve_script(script_name: &str) -> Result<PathBuf, String> {
     ))
 }
 
+/// Builds the process for a task command. Command lines go through `sh -c`
+/// (`cmd /C` on Windows) so pipes, redirects and env expansion work.
+fn build_command(command: &TaskCommand) -> Result<tokio::process::Command, String> {
+    match command {
+        TaskCommand::Script { script, args } => {
+            let mut cmd = tokio::process::Command::new(resolve_script(script)?);
+            cmd.args(args);
+            Ok(cmd)
+        }
+        TaskCommand::CommandLine { command_line } => {
+            if command_line.trim().is_empty() {
+                return Err("Command line cannot be empty".to_string());
+            }
+            #[cfg(target_os = "windows")]
+            let mut cmd = {
+                let mut c = tokio::process::Command::new("cmd");
+                c.arg("/C");
+                c
+            };
+            #[cfg(not(target_os = "windows"))]
+            let mut cmd = {
+                let mut c = tokio::process::Command::new("sh");
+                c.arg("-c");
+                c
+            };
+            cmd.arg(command_line);
+            Ok(cmd)
+        }
+        TaskCommand::Prompt { .. } => Err("Prompt tasks do not spawn a process".to_string()),
+        TaskCommand::Backup { .. } | TaskCommand::AppBackup { .. } => {
+            Err("Backup tasks do not spawn a process".to_string())
+        }
+        TaskCommand::LogDigest { .. } => Err("Log digest tasks do not spawn a process".to_string()),
+        TaskCommand::Webhook { .. } => Err("Webhook tasks do not spawn a process".to_string()),
+    }
+}
+
+// ── Task execution ────────────────────────────────────────────────────
+
+/// How a single run of a task ended.
+enum TaskOutcome {
+    Success(String),
+    Failed { exit_code: Option<i32>, output: String },
+    TimedOut(u64),
+}
+
+impl TaskOutcome {
+    fn status(&self) -> &'static str {
+        match self {
+            TaskOutcome::Success(_) => "success",
+            TaskOutcome::Failed { .. } => "failed",
+            TaskOutcome::TimedOut(_) => "timeout",
+        }
+    }
+
+    /// One-line failure description for logs and command errors.
+    fn describe(&self) -> String {
+        match self {
+            TaskOutcome::Success(_) => "completed OK".to_string(),
+            TaskOutcome::Failed { exit_code: Some(code), output } => {
+                format!("exited with {}: {}", code, output.trim())
+            }
+            TaskOutcome::Failed { exit_code: None, output } => output.trim().to_string(),
+            TaskOutcome::TimedOut(secs) => format!("timed out after {}s", secs),
+        }
+    }
+}
+
+fn emit_task_started(app: &AppHandle, task_id: &str, trigger: &str) {
+    let _ = app.emit("task-started", TaskEvent {
+        task_id: task_id.to_string(),
+        trigger: trigger.to_string(),
+        status: None,
+        exit_code: None,
+        error: None,
+    });
+}
+
+fn emit_task_finished(app: &AppHandle, task_id: &str, trigger: &str, outcome: &TaskOutcome) {
+    let exit_code = match outcome {
+        TaskOutcome::Success(_) => Some(0),
+        TaskOutcome::Failed { exit_code, .. } => *exit_code,
+        TaskOutcome::TimedOut(_) => None,
+    };
+    let failed = !matches!(outcome, TaskOutcome::Success(_));
+    let event = TaskEvent {
+        task_id: task_id.to_string(),
+        trigger: trigger.to_string(),
+        status: Some(outcome.status().to_string()),
+        exit_code,
+        error: failed.then(|| outcome.describe()),
+    };
+    let vars = HashMap::from([
+        ("task_id".to_string(), task_id.to_string()),
+        ("trigger".to_string(), trigger.to_string()),
+        ("status".to_string(), outcome.status().to_string()),
+        ("error".to_string(), event.error.clone().unwrap_or_default()),
+    ]);
+    crate::webhooks::fire_event(app, "task_finished", vars.clone());
+    let _ = app.emit("task-finished", event.clone());
+    if failed {
+        crate::webhooks::fire_event(app, "task_failed", vars);
+        let _ = app.emit("task-failed", event);
+    }
+}
+
+/// Runs a task, enforcing its timeout. On expiry the run future is dropped,
+/// which kills the child process (`kill_on_drop`).
+async fn run_task(app: &AppHandle, task: &TaskEntry, live: Option<&broadcast::Sender<LogLine>>) -> TaskOutcome {
+    let run = execute_task(app, &task.id, &task.command, live);
+    match task.timeout_secs.filter(|s| *s > 0) {
+        Some(secs) => tokio::time::timeout(std::time::Duration::from_secs(secs), run)
+            .await
+            .unwrap_or(TaskOutcome::TimedOut(secs)),
+        None => run.await,
+    }
+}
+
+/// Runs a task once and appends the attempt to its run history.
+async fn run_and_record(
+    app: &AppHandle,
+    task: &TaskEntry,
+    data_dir: &Path,
+    attempt: u32,
+    trigger: &str,
+    live: Option<&broadcast::Sender<LogLine>>,
+) -> TaskOutcome {
+    let started = Local::now();
+    let timer = std::time::Instant::now();
+    let outcome = run_task(app, task, live).await;
+
+    let (exit_code, output) = match &outcome {
+        TaskOutcome::Success(out) => (Some(0), out.clone()),
+        TaskOutcome::Failed { exit_code, output } => (*exit_code, output.clone()),
+        TaskOutcome::TimedOut(_) => (None, outcome.describe()),
+    };
+    record_run(data_dir, &task.id, RunRecord {
+        started_at: started.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        finished_at: Local::now().format("%Y-%m-%dT%H:%M:%S").to_string(),
+        duration_ms: timer.elapsed().as_millis() as u64,
+        status: outcome.status().to_string(),
+        exit_code,
+        output: truncate_tail(&output, HISTORY_OUTPUT_MAX),
+        attempt,
+        trigger: trigger.to_string(),
+    });
+    outcome
+}
+
+/// Runs a task, retrying failed attempts according to its retry policy.
+/// Every failed attempt is written to the task log and the run history.
+async fn run_task_with_retries(
+    app: &AppHandle,
+    task: &TaskEntry,
+    data_dir: &Path,
+    trigger: &str,
+    live: Option<&broadcast::Sender<LogLine>>,
+) -> TaskOutcome {
+    let log_file = log_path(data_dir, &task.id);
+    let (max_attempts, backoff) = match &task.retry {
+        Some(r) => (r.max_attempts.max(1), r.backoff_secs),
+        None => (1, 0),
+    };
+
+    let mut attempt = 1;
+    loop {
+        let outcome = run_and_record(app, task, data_dir, attempt, trigger, live).await;
+        if matches!(outcome, TaskOutcome::Success(_)) || attempt >= max_attempts {
+            return outcome;
+        }
+
+        let delay = backoff.saturating_mul(1 << (attempt - 1).min(16)).min(MAX_BACKOFF_SECS);
+        append_log(&log_file, &format!(
+            "Attempt {}/{} of task '{}' failed: {}. Retrying in {}s",
+            attempt, max_attempts, task.id, outcome.describe(), delay
+        ));
+        tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+        attempt += 1;
+    }
+}
+
+/// Runs a task command to completion, without a timeout. Process output is
+/// forwarded line by line to `live` while the command runs.
+async fn execute_task(
+    app: &AppHandle,
+    task_id: &str,
+    command: &TaskCommand,
+    live: Option<&broadcast::Sender<LogLine>>,
+) -> TaskOutcome {
+    if let TaskCommand::Prompt { .. } = command {
+        return match run_prompt_task(app, task_id, command).await {
+            Ok(output) => TaskOutcome::Success(output),
+            Err(e) => TaskOutcome::Failed { exit_code: None, output: e },
+        };
+    }
+    if let TaskCommand::Backup { backup } = command {
+        let (id, cfg) = (task_id.to_string(), backup.clone());
+        return match tokio::task::spawn_blocking(move || crate::backup::run(&id, &cfg)).await {
+            Ok(Ok(summary)) => TaskOutcome::Success(summary),
+            Ok(Err(e)) => TaskOutcome::Failed { exit_code: None, output: e },
+            Err(e) => TaskOutcome::Failed { exit_code: None, output: format!("backup panicked: {}", e) },
+        };
+    }
+    if let TaskCommand::AppBackup { app_backup } = command {
+        return match crate::app_backup::run(app, task_id, app_backup).await {
+            Ok(summary) => TaskOutcome::Success(summary),
+            Err(e) => TaskOutcome::Failed { exit_code: None, output: e },
+        };
+    }
+    if let TaskCommand::LogDigest { log_digest } = command {
+        let state_path = match data_dir(app) {
+            Ok(d) => digest_state_path(&d, task_id),
+            Err(e) => return TaskOutcome::Failed { exit_code: None, output: e },
+        };
+        let (id, cfg) = (task_id.to_string(), log_digest.clone());
+        return match tokio::task::spawn_blocking(move || crate::log_digest::run(&id, &state_path, &cfg)).await {
+            Ok(Ok(digest)) => TaskOutcome::Success(digest),
+            Ok(Err(e)) => TaskOutcome::Failed { exit_code: None, output: e },
+            Err(e) => TaskOutcome::Failed { exit_code: None, output: format!("log digest panicked: {}", e) },
+        };
+    }
+    if let TaskCommand::Webhook { webhook, vars } = command {
+        let mut vars = vars.clone();
+        vars.entry("task_id".to_string()).or_insert_with(|| task_id.to_string());
+        return match crate::webhooks::run_for_task(app, webhook, &vars).await {
+            Ok(response) => TaskOutcome::Success(response),
+            Err(e) => TaskOutcome::Failed { exit_code: None, output: e },
+        };
+    }
+
+    let mut cmd = match build_command(command) {
+        Ok(c) => c,
+        Err(e) => return TaskOutcome::Failed { exit_code: None, output: e },
+    };
+    let mut child = match cmd
+        .kill_on_drop(true)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+    {
+        Ok(c) => c,
+        Err(e) => return TaskOutcome::Failed { exit_code: None, output: format!("exec error: {}", e) },
+    };
+
+    let (stdout, stderr, status) = tokio::join!(
+        collect_output(child.stdout.take(), "stdout", live),
+        collect_output(child.stderr.take(), "stderr", live),
+        child.wait(),
+    );
+    let output = format!("{}{}", stdout, stderr);
+    match status {
+        Ok(status) if status.success() => TaskOutcome::Success(output),
+        Ok(status) => TaskOutcome::Failed { exit_code: status.code(), output },
+        Err(e) => TaskOutcome::Failed { exit_code: None, output: format!("exec error: {}", e) },
+    }
+}
+
+/// Reads a child's pipe to the end, forwarding each line to `live`.
+async fn collect_output<R: AsyncRead + Unpin>(
+    pipe: Option<R>,
+    source: &str,
+    live: Option<&broadcast::Sender<LogLine>>,
+) -> String {
+    let Some(pipe) = pipe else { return String::new() };
+    let mut lines = BufReader::new(pipe).lines();
+    let mut collected = String::new();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if let Some(tx) = live {
+            let _ = tx.send(LogLine { source: source.to_string(), line: line.clone() });
+        }
+        collected.push_str(&line);
+        collected.push('\n');
+    }
+    collected
+}
+
+async fn run_prompt_task(app: &AppHandle, task_id: &str, command: &TaskCommand) -> Result<String, String> {
+    let TaskCommand::Prompt { prompt, provider, model, output_file, notify } = command else {
+        return Err("Not a prompt task".to_string());
+    };
+    if prompt.trim().is_empty() {
+        return Err("Prompt cannot be empty".to_string());
+    }
+
+    let output = match provider.as_deref().unwrap_or("claude") {
+        "claude" => crate::run_headless_prompt(app, prompt, model.as_deref()).await?,
+        "ollama" => {
+            let settings = crate::ollama::get_settings(app);
+            let model = model.clone().filter(|m| !m.is_empty()).unwrap_or(settings.model);
+            let options = crate::ollama::get_generation_options(app);
+            crate::ollama::generate(&settings.base_url, &model, &options, prompt).await?
+        }
+        other => return Err(format!("Unknown prompt provider '{}'", other)),
+    };
+
+    if let Some(path) = output_file.as_deref().filter(|p| !p.is_empty()) {
+        use std::io::Write;
+        let path = crate::backup::expand_home(path);
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let mut f = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("Failed to open output file: {}", e))?;
+        let ts = Local::now().format("%Y-%m-%d %H:%M:%S");
+        writeln!(f, "## {}\n\n{}\n", ts, output)
+            .map_err(|e| format!("Failed to write output file: {}", e))?;
+    }
+
+    if *notify {
+        let _ = app.emit("scheduler-prompt-result", PromptResult {
+            task_id: task_id.to_string(),
+            output: output.clone(),
+        });
+    }
+
+    Ok(output)
+}
+
+// ── Failure alerts ────────────────────────────────────────────────────
+
+async fn send_failure_alert(task: &TaskEntry, alert: &FailureAlert, log_file: &Path, error: &str) {
+    if alert.desktop {
+        crate::notifications::send(&format!("Task failed: {}", task.name), error);
+    }
+
+    let Some(url) = alert.webhook_url.as_deref().filter(|u| !u.is_empty()) else { return };
+    let log_tail = std::fs::read_to_string(log_file)
+        .map(|c| tail_lines(&c, ALERT_LOG_LINES))
+        .unwrap_or_default();
+    let summary = format!("Task '{}' failed: {}", task.name, error);
+    // `content` and `text` let Discord and Slack webhooks render the summary as-is.
+    let payload = serde_json::json!({
+        "content": format!("{}\n```\n{}\n```", summary, truncate_tail(&log_tail, 1500)),
+        "text": summary,
+        "task_id": task.id,
+        "task_name": task.name,
+        "error": error,
+        "log_tail": log_tail,
+    });
+    let result = reqwest::Client::new()
+        .post(url)
+        .timeout(std::time::Duration::from_secs(10))
+        .json(&payload)
+        .send()
+        .await;
+    match result {
+        Ok(resp) if !resp.status().is_success() => {
+            tracing::warn!("Failure webhook for '{}' returned {}", task.id, resp.status());
+        }
+        Err(e) => tracing::warn!("Failure webhook for '{}' failed: {}", task.id, e),
+        _ => {}
+    }
+}
+
+// ── Run history ───────────────────────────────────────────────────────
+
+/// Runs kept per task in `<app_data_dir>/history/<task-id>.json`.
+const HISTORY_MAX_RECORDS: usize = 100;
+
+/// Serializes history updates from runs finishing at the same time.
+static HISTORY: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Bytes of output kept per run (the tail, where errors usually are).
+const HISTORY_OUTPUT_MAX: usize = 4000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RunRecord {
+    pub started_at: String,
+    pub finished_at: String,
+    pub duration_ms: u64,
+    /// "success", "failed" or "timeout".
+    pub status: String,
+    pub exit_code: Option<i32>,
+    pub output: String,
+    /// 1-based attempt number within a retried run.
+    pub attempt: u32,
+    /// "schedule", "chain" or "manual".
+    pub trigger: String,
+}
+
+fn history_path(data_dir: &Path, task_id: &str) -> PathBuf {
+    data_dir.join("history").join(format!("{}.json", task_id))
+}
+
+/// Read offsets of a log digest task, kept next to its history.
+fn digest_state_path(data_dir: &Path, task_id: &str) -> PathBuf {
+    data_dir.join("digest").join(format!("{}.json", task_id))
+}
+
+fn read_history(data_dir: &Path, task_id: &str) -> Vec<RunRecord> {
+    std::fs::read_to_string(history_path(data_dir, task_id))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn record_run(data_dir: &Path, task_id: &str, record: RunRecord) {
+    let _guard = HISTORY.lock().unwrap_or_else(|e| e.into_inner());
+    let path = history_path(data_dir, task_id);
+    let mut history = read_history(data_dir, task_id);
+    history.push(record);
+    if history.len() > HISTORY_MAX_RECORDS {
+        history.drain(..history.len() - HISTORY_MAX_RECORDS);
+    }
+
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let result = serde_json::to_string(&history)
+        .map_err(|e| e.to_string())
+        .and_then(|json| {
+            let tmp = path.with_extension("json.tmp");
+            std::fs::write(&tmp, json).map_err(|e| e.to_string())?;
+            std::fs::rename(&tmp, &path).map_err(|e| e.to_string())
+        });
+    if let Err(e) = result {
+        tracing::warn!("Failed to record run for '{}': {}", task_id, e);
+    }
+}
+
+/// Keeps the last `max` bytes of `text`, cut at a char boundary.
+fn truncate_tail(text: &str, max: usize) -> String {
+    if text.len() <= max {
+        return text.to_string();
+    }
+    let mut start = text.len() - max;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    format!("…{}", &text[start..])
+}
+
 // ── Linux crontab migration ───────────────────────────────────────────
 
 #[cfg(target_os = "linux")]
@@ -306,8 +866,15 @@ fn read_active_cron_ids() -> Vec<String> {
                     continue;
                 }
                 for task in &defaults {
-                    if trimmed.contains(&*task.command.script) {
-                        ids.push(task.id.clone());
+                    if let TaskCommand::Script { script, .. } = &task.command {
+                        if trimmed.contains(script.as_str()) {
+                            ids.push(task.id.clone());
+                        }
+                    }
+                }
+                for (id, legacy) in LEGACY_SCRIPTS {
+                    if trimmed.contains(legacy) {
+                        ids.push(id.to_string());
                     }
                 }
             }
@@ -322,16 +889,150 @@ fn read_active_cron_ids() -> Vec<String> {
     vec![]
 }
 
+// ── Live output ───────────────────────────────────────────────────────
+
+/// A line pushed to `stream_task_log` subscribers.
+#[derive(Debug, Serialize, Clone)]
+pub struct LogLine {
+    /// "log" for task log lines, "stdout"/"stderr" for live process output.
+    pub source: String,
+    pub line: String,
+}
+
+/// How often `stream_task_log` checks the log file for new lines.
+const LOG_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Creates (or reuses) the broadcast channel carrying a task's live output.
+fn open_live_output(s: &mut SchedulerState, task_id: &str) -> broadcast::Sender<LogLine> {
+    s.live_output
+        .entry(task_id.to_string())
+        .or_insert_with(|| broadcast::channel(256).0)
+        .clone()
+}
+
+/// Sends lines appended to `log_file` since `offset`, returning the new offset.
+/// A file shorter than `offset` was rotated or cleared, so reading restarts at 0.
+/// `None` when the channel is closed.
+fn send_new_log_lines(log_file: &Path, offset: u64, on_line: &Channel<LogLine>) -> Option<u64> {
+    use std::io::{Read, Seek, SeekFrom};
+    let Ok(mut f) = std::fs::File::open(log_file) else { return Some(0) };
+    let len = f.metadata().map(|m| m.len()).unwrap_or(0);
+    let start = if len < offset { 0 } else { offset };
+    if len == start {
+        return Some(start);
+    }
+    let mut buf = Vec::new();
+    if f.seek(SeekFrom::Start(start)).is_err() || f.read_to_end(&mut buf).is_err() {
+        return Some(start);
+    }
+    // Only forward complete lines; a partial trailing line is picked up next time.
+    let complete = buf.iter().rposition(|b| *b == b'\n').map(|i| i + 1).unwrap_or(0);
+    for line in String::from_utf8_lossy(&buf[..complete]).lines() {
+        on_line.send(LogLine { source: "log".to_string(), line: line.to_string() }).ok()?;
+    }
+    Some(start + complete as u64)
+}
+
 // ── Logging ───────────────────────────────────────────────────────────
 
+fn tail_lines(content: &str, n: usize) -> String {
+    let tail: Vec<&str> = content.lines().rev().take(n).collect();
+    let result: Vec<&str> = tail.into_iter().rev().collect();
+    result.join("\n")
+}
+
 fn log_path(data_dir: &Path, task_id: &str) -> PathBuf {
     data_dir.join("logs").join(format!("{}.log", task_id))
 }
 
+/// Rotate a task log once it grows past this size.
+const LOG_MAX_BYTES: u64 = 1024 * 1024;
+
+/// Rotate a task log once its first entry is older than this many days.
+const LOG_MAX_AGE_DAYS: i64 = 7;
+
+/// Number of gzipped rotations kept per task (`<id>.log.1.gz` is the newest).
+const LOG_KEEP_ROTATED: usize = 5;
+
+/// A log is checked for rotation at most this often, not on every line.
+const LOG_ROTATION_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// When each log was last checked for rotation.
+static LOG_ROTATION_CHECKS: std::sync::Mutex<Option<HashMap<PathBuf, std::time::Instant>>> =
+    std::sync::Mutex::new(None);
+
+/// Whether `log_file` is due for a rotation check; records the check if so.
+fn rotation_check_due(log_file: &Path) -> bool {
+    let mut checks = LOG_ROTATION_CHECKS.lock().unwrap_or_else(|e| e.into_inner());
+    let checks = checks.get_or_insert_with(HashMap::new);
+    let now = std::time::Instant::now();
+    match checks.get(log_file) {
+        Some(last) if now.duration_since(*last) < LOG_ROTATION_CHECK_INTERVAL => false,
+        _ => {
+            checks.insert(log_file.to_path_buf(), now);
+            true
+        }
+    }
+}
+
+fn rotated_path(log_file: &Path, n: usize) -> PathBuf {
+    let mut name = log_file.as_os_str().to_owned();
+    name.push(format!(".{}.gz", n));
+    PathBuf::from(name)
+}
+
+/// Timestamp of the first `[YYYY-mm-dd HH:MM:SS]` entry in a log.
+fn first_entry_time(log_file: &Path) -> Option<chrono::NaiveDateTime> {
+    use std::io::BufRead;
+    let f = std::fs::File::open(log_file).ok()?;
+    let mut line = String::new();
+    std::io::BufReader::new(f).read_line(&mut line).ok()?;
+    let ts = line.strip_prefix('[')?.get(..19)?;
+    chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S").ok()
+}
+
+fn needs_rotation(log_file: &Path) -> bool {
+    let Ok(meta) = std::fs::metadata(log_file) else { return false };
+    if meta.len() >= LOG_MAX_BYTES {
+        return true;
+    }
+    meta.len() > 0
+        && first_entry_time(log_file).is_some_and(|t| {
+            Local::now().naive_local() - t > chrono::Duration::days(LOG_MAX_AGE_DAYS)
+        })
+}
+
+/// Shifts `<log>.N.gz` up by one, compresses the live log into `<log>.1.gz`
+/// and drops anything past [`LOG_KEEP_ROTATED`].
+fn rotate_log(log_file: &Path) -> Result<(), String> {
+    use flate2::{write::GzEncoder, Compression};
+
+    let _ = std::fs::remove_file(rotated_path(log_file, LOG_KEEP_ROTATED));
+    for n in (1..LOG_KEEP_ROTATED).rev() {
+        let from = rotated_path(log_file, n);
+        if from.exists() {
+            let _ = std::fs::rename(&from, rotated_path(log_file, n + 1));
+        }
+    }
+
+    let mut input = std::fs::File::open(log_file).map_err(|e| format!("Failed to open log: {}", e))?;
+    let output = std::fs::File::create(rotated_path(log_file, 1))
+        .map_err(|e| format!("Failed to create rotated log: {}", e))?;
+    let mut encoder = GzEncoder::new(output, Compression::default());
+    std::io::copy(&mut input, &mut encoder).map_err(|e| format!("Failed to compress log: {}", e))?;
+    encoder.finish().map_err(|e| format!("Failed to compress log: {}", e))?;
+    std::fs::remove_file(log_file).map_err(|e| format!("Failed to remove rotated log: {}", e))
+}
+
 fn append_log(log_file: &PathBuf, message: &str) {
     if let Some(parent) = log_file.parent() {
         let _ = std::fs::create_dir_all(parent);
     }
+    if rotation_check_due(log_file) && needs_rotation(log_file) {
+        if let Err(e) = rotate_log(log_file) {
+            tracing::warn!("Log rotation failed for {:?}: {}", log_file, e);
+        }
+    }
     use std::io::Write;
     if let Ok(mut f) = std::fs::OpenOptions::new()
         .create(true)
@@ -343,6 +1044,244 @@ fn append_log(log_file: &PathBuf, message: &str) {
     }
 }
 
+// ── Schedule parsing ──────────────────────────────────────────────────
+
+/// tokio-cron-scheduler requires a leading seconds field; 5-field crontab
+/// expressions fire at second 0.
+fn normalize_schedule(schedule: &str) -> String {
+    if schedule.split_whitespace().count() == 5 {
+        format!("0 {}", schedule)
+    } else {
+        schedule.to_string()
+    }
+}
+
+/// Parses a schedule exactly the way tokio-cron-scheduler does.
+fn parse_schedule(schedule: &str) -> Result<croner::Cron, String> {
+    croner::Cron::new(&normalize_schedule(schedule))
+        .with_seconds_required()
+        .with_dom_and_dow()
+        .parse()
+        .map_err(|e| format!("Invalid schedule '{}': {}", schedule, e))
+}
+
+/// Parses an optional IANA zone name. `None` means system local time.
+fn resolve_timezone(tz: Option<&str>) -> Result<Option<chrono_tz::Tz>, String> {
+    match tz.map(str::trim).filter(|t| !t.is_empty()) {
+        Some(name) => name
+            .parse::<chrono_tz::Tz>()
+            .map(Some)
+            .map_err(|_| format!("Unknown timezone '{}'", name)),
+        None => Ok(None),
+    }
+}
+
+/// The next `count` fire times of a schedule evaluated in `tz`, rendered in local time.
+fn upcoming_runs(schedule: &str, tz: Option<&str>, count: usize) -> Result<Vec<String>, String> {
+    let cron = parse_schedule(schedule)?;
+    let to_local = |t: chrono::DateTime<Local>| t.format("%Y-%m-%dT%H:%M:%S").to_string();
+    Ok(match resolve_timezone(tz)? {
+        Some(zone) => cron
+            .iter_after(Utc::now().with_timezone(&zone))
+            .take(count)
+            .map(|t| to_local(t.with_timezone(&Local)))
+            .collect(),
+        None => cron.iter_after(Local::now()).take(count).map(to_local).collect(),
+    })
+}
+
+/// Next fire time for cron and one-shot tasks. Interval tasks are answered
+/// by the scheduler itself in `get_scheduler_status`.
+fn next_run_time(task: &TaskEntry) -> Option<String> {
+    match task_trigger(task).ok()? {
+        Trigger::Cron(_) => upcoming_runs(&task.schedule, task.timezone.as_deref(), 1).ok()?.pop(),
+        Trigger::At(t) => Some(t.with_timezone(&Local).format("%Y-%m-%dT%H:%M:%S").to_string()),
+        Trigger::Every(_) | Trigger::Chained => None,
+    }
+}
+
+/// When a task fires: a cron expression, a fixed interval, once at a time,
+/// or only after other tasks (`run_after`).
+enum Trigger {
+    Cron(String),
+    Every(std::time::Duration),
+    At(chrono::DateTime<Utc>),
+    Chained,
+}
+
+fn task_trigger(task: &TaskEntry) -> Result<Trigger, String> {
+    let every = task.every.as_deref().map(str::trim).filter(|e| !e.is_empty());
+    let at = task.at.as_deref().map(str::trim).filter(|a| !a.is_empty());
+    match (every, at) {
+        (Some(_), Some(_)) => Err(format!("Task '{}' cannot set both 'every' and 'at'", task.id)),
+        (Some(e), None) => parse_interval(e).map(Trigger::Every),
+        (None, Some(a)) => parse_at(a, task.timezone.as_deref()).map(Trigger::At),
+        (None, None) if task.schedule.trim().is_empty() && !task.run_after.is_empty() => Ok(Trigger::Chained),
+        (None, None) => Ok(Trigger::Cron(normalize_schedule(&task.schedule))),
+    }
+}
+
+/// Checks the scheduling fields of a task before it is saved.
+fn validate_task(task: &TaskEntry) -> Result<(), String> {
+    resolve_timezone(task.timezone.as_deref())?;
+    if task.run_after.contains(&task.id) {
+        return Err(format!("Task '{}' cannot run after itself", task.id));
+    }
+    task_trigger(task).map(|_| ())
+}
+
+/// Checks `run_after` across the whole registry: every upstream id must exist
+/// and the chain must not loop back on itself (A -> B -> A).
+fn validate_chain(tasks: &[TaskEntry]) -> Result<(), String> {
+    let by_id: HashMap<&str, &TaskEntry> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+    for task in tasks {
+        if let Some(missing) = task.run_after.iter().find(|id| !by_id.contains_key(id.as_str())) {
+            return Err(format!("Task '{}' runs after unknown task '{}'", task.id, missing));
+        }
+    }
+
+    // Depth-first walk over upstream edges; a task met again while still on
+    // the path closes a cycle.
+    fn visit<'a>(
+        id: &'a str,
+        by_id: &HashMap<&str, &'a TaskEntry>,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Result<(), String> {
+        if done.contains(id) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|p| *p == id) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(id);
+            return Err(format!("run_after cycle: {}", cycle.join(" -> ")));
+        }
+        path.push(id);
+        for upstream in &by_id[id].run_after {
+            visit(upstream, by_id, path, done)?;
+        }
+        path.pop();
+        done.insert(id);
+        Ok(())
+    }
+
+    let mut done = HashSet::new();
+    for task in tasks {
+        visit(&task.id, &by_id, &mut Vec::new(), &mut done)?;
+    }
+    Ok(())
+}
+
+/// Parses intervals like "90s", "15m", "1h30m" or "2d".
+pub(crate) fn parse_interval(text: &str) -> Result<std::time::Duration, String> {
+    let invalid = || format!("Invalid interval '{}': use e.g. 30s, 15m, 1h30m, 2d", text);
+    let mut total: u64 = 0;
+    let mut digits = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let n: u64 = digits.parse().map_err(|_| invalid())?;
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => return Err(invalid()),
+        };
+        total = total.saturating_add(n.saturating_mul(unit));
+        digits.clear();
+    }
+    if !digits.is_empty() || total == 0 {
+        return Err(invalid());
+    }
+    Ok(std::time::Duration::from_secs(total))
+}
+
+/// Parses a one-shot time. Timestamps without an offset are read in `tz`
+/// (or system local time).
+fn parse_at(text: &str, tz: Option<&str>) -> Result<chrono::DateTime<Utc>, String> {
+    use chrono::TimeZone;
+
+    if let Ok(t) = chrono::DateTime::parse_from_rfc3339(text) {
+        return Ok(t.with_timezone(&Utc));
+    }
+    let naive = chrono::NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M"))
+        .map_err(|_| format!("Invalid time '{}': use YYYY-MM-DDTHH:MM[:SS]", text))?;
+    let resolved = match resolve_timezone(tz)? {
+        Some(zone) => zone.from_local_datetime(&naive).earliest().map(|t| t.with_timezone(&Utc)),
+        None => Local.from_local_datetime(&naive).earliest().map(|t| t.with_timezone(&Utc)),
+    };
+    resolved.ok_or_else(|| format!("Time '{}' does not exist in that timezone", text))
+}
+
+fn is_number(field: &str) -> bool {
+    !field.is_empty() && field.chars().all(|c| c.is_ascii_digit())
+}
+
+fn name_list(field: &str, names: &[&str]) -> String {
+    let name = |v: &str| -> String {
+        v.parse::<usize>()
+            .ok()
+            .and_then(|i| names.get(i % names.len()))
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| v.to_string())
+    };
+    field
+        .split(',')
+        .map(|part| match part.split_once('-') {
+            Some((a, b)) => format!("{} through {}", name(a), name(b)),
+            None => name(part),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Plain-English description of a 6-field cron expression. Common shapes get
+/// a natural phrasing; anything else falls back to a field-by-field reading.
+fn describe_schedule(expr: &str) -> String {
+    const WEEKDAYS: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+    const MONTHS: [&str; 13] = ["", "January", "February", "March", "April", "May", "June",
+        "July", "August", "September", "October", "November", "December"];
+
+    let fields: Vec<&str> = expr.split_whitespace().collect();
+    let [sec, min, hour, dom, month, dow] = fields[..] else {
+        return expr.to_string();
+    };
+
+    let mut text = match (min, hour) {
+        ("*", "*") => "Every minute".to_string(),
+        (m, "*") if m.starts_with("*/") => format!("Every {} minutes", &m[2..]),
+        (m, "*") if is_number(m) => format!("Every hour at minute {}", m),
+        (m, h) if is_number(m) && h.starts_with("*/") => format!("Every {} hours at minute {}", &h[2..], m),
+        (m, h) if is_number(m) && h.split(',').all(is_number) => {
+            let minute: u32 = m.parse().unwrap_or(0);
+            let times: Vec<String> = h
+                .split(',')
+                .map(|h| format!("{:02}:{:02}", h.parse::<u32>().unwrap_or(0), minute))
+                .collect();
+            format!("At {}", times.join(" and "))
+        }
+        (m, h) => format!("At minute {} past hour {}", m, h),
+    };
+
+    if sec != "0" {
+        text.push_str(&format!(", second {}", sec));
+    }
+    if dom != "*" {
+        text.push_str(&format!(", on day {} of the month", dom));
+    }
+    if month != "*" {
+        text.push_str(&format!(", in {}", name_list(month, &MONTHS)));
+    }
+    if dow != "*" {
+        text.push_str(&format!(", on {}", name_list(dow, &WEEKDAYS)));
+    }
+    text
+}
+
 // ── Scheduler initialization ──────────────────────────────────────────
 
 pub async fn init_scheduler(app: &AppHandle) -> Result<SchedulerState, String> {
@@ -350,9 +1289,21 @@ pub async fn init_scheduler(app: &AppHandle) -> Result<SchedulerState, String> {
     let d_dir = data_dir(app)?;
 
     let registry = if reg_path.exists() {
-        read_registry(&reg_path)
+        let mut r = read_registry(&reg_path);
+        let mut dirty = migrate_legacy_seeds(&mut r.tasks);
+        // Registries created before the app data backup existed get it added, disabled.
+        if !r.tasks.iter().any(|t| t.id == "app-backup") {
+            if let Some(task) = default_tasks().into_iter().find(|t| t.id == "app-backup") {
+                r.tasks.push(task);
+                dirty = true;
+            }
+        }
+        if dirty {
+            write_registry(&reg_path, &r)?;
+        }
+        r
     } else {
-        let mut r = TaskRegistry { tasks: default_tasks() };
+        let mut r = TaskRegistry { tasks: default_tasks(), ..Default::default() };
         let active = read_active_cron_ids();
         for task in &mut r.tasks {
             if active.contains(&task.id) {
@@ -369,88 +1320,209 @@ pub async fn init_scheduler(app: &AppHandle) -> Result<SchedulerState, String> {
 
     sched.start().await.map_err(|e| format!("Failed to start scheduler: {}", e))?;
 
+    let last_run = registry
+        .tasks
+        .iter()
+        .filter_map(|t| read_history(&d_dir, &t.id).pop().map(|r| (t.id.clone(), r.finished_at)))
+        .collect();
+
     Ok(SchedulerState {
         scheduler: sched,
         registry,
         job_map: HashMap::new(),
         registry_path: reg_path,
         data_dir: d_dir,
-        last_run: HashMap::new(),
+        last_run,
         running: HashMap::new(),
+        live_output: HashMap::new(),
+        log_streams: HashMap::new(),
     })
 }
 
-async fn add_job_to_scheduler(
-    sched: &JobScheduler,
-    task: &TaskEntry,
-    data_dir: &Path,
-    shared_state: Option<&SharedSchedulerState>,
-) -> Result<Uuid, String> {
-    let task_id = task.id.clone();
-    let script_name = task.command.script.clone();
-    let args = task.command.args.clone();
-    let log_file = log_path(data_dir, &task_id);
-    let state_ref = shared_state.cloned();
+/// Chained runs deeper than this are dropped. Cycles are rejected when tasks are
+/// saved ([`validate_chain`]); this is only a backstop for registries edited by hand.
+const MAX_CHAIN_DEPTH: u32 = 8;
 
-    let schedule_str = if task.schedule.split_whitespace().count() == 5 {
-        format!("0 {}", task.schedule)
-    } else {
-        task.schedule.clone()
-    };
-    let job = Job::new_async(schedule_str.as_str(), move |_uuid, _lock| {
-        let script_name = script_name.clone();
-        let args = args.clone();
-        let log_file = log_file.clone();
-        let task_id = task_id.clone();
-        let state_ref = state_ref.clone();
-        Box::pin(async move {
-            if let Some(ref st) = state_ref {
-                let mut g = st.lock().await;
-                if let Some(s) = g.as_mut() { s.running.insert(task_id.clone(), true); }
+/// One scheduled (or chained) run: executes with retries, logs, alerts, updates
+/// state, then starts any tasks chained after this one.
+fn run_scheduled(
+    app: AppHandle,
+    task: TaskEntry,
+    data_dir: PathBuf,
+    state_ref: Option<SharedSchedulerState>,
+    depth: u32,
+) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+    Box::pin(async move {
+        let task_id = task.id.clone();
+        let log_file = log_path(&data_dir, &task_id);
+
+        // Jitter applies to timer-triggered runs, not to chained ones.
+        if let Some(max) = task.jitter_secs.filter(|j| depth == 0 && *j > 0) {
+            let delay = rand::thread_rng().gen_range(0..=max);
+            append_log(&log_file, &format!("Delaying start by {}s (jitter)", delay));
+            tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+        }
+
+        let mut live = None;
+        if let Some(ref st) = state_ref {
+            let mut g = st.lock().await;
+            if let Some(s) = g.as_mut() {
+                s.running.insert(task_id.clone(), true);
+                live = Some(open_live_output(s, &task_id));
             }
+        }
 
-            append_log(&log_file, &format!("Starting task '{}'", task_id));
-            match resolve_script(&script_name) {
-                Ok(script_path) => {
-                    match tokio::process::Command::new(&script_path)
-                        .args(&args)
-                        .kill_on_drop(true)
-                        .output()
-                        .await
-                    {
-                        Ok(out) => {
-                            if out.status.success() {
-                                let stdout = String::from_utf8_lossy(&out.stdout);
-                                if !stdout.trim().is_empty() {
-                                    append_log(&log_file, &format!("stdout: {}", stdout.trim()));
-                                }
-                                append_log(&log_file, &format!("Task '{}' completed OK", task_id));
-                            } else {
-                                let stderr = String::from_utf8_lossy(&out.stderr);
-                                append_log(&log_file, &format!("Task '{}' failed (exit {:?}): {}", task_id, out.status.code(), stderr.trim()));
-                            }
-                        }
-                        Err(e) => append_log(&log_file, &format!("Task '{}' exec error: {}", task_id, e)),
-                    }
+        let trigger = if depth == 0 { "schedule" } else { "chain" };
+        append_log(&log_file, &format!("Starting task '{}'", task_id));
+        emit_task_started(&app, &task_id, trigger);
+        let outcome = run_task_with_retries(&app, &task, &data_dir, trigger, live.as_ref()).await;
+        emit_task_finished(&app, &task_id, trigger, &outcome);
+        match &outcome {
+            TaskOutcome::Success(output) => {
+                if !output.trim().is_empty() {
+                    append_log(&log_file, &format!("output: {}", output.trim()));
                 }
-                Err(e) => append_log(&log_file, &format!("Task '{}' script not found: {}", task_id, e)),
+                append_log(&log_file, &format!("Task '{}' completed OK", task_id));
+            }
+            TaskOutcome::TimedOut(secs) => append_log(&log_file, &format!("TIMEOUT: task '{}' exceeded {}s and was killed", task_id, secs)),
+            failed => append_log(&log_file, &format!("Task '{}' failed: {}", task_id, failed.describe())),
+        }
+        let succeeded = matches!(outcome, TaskOutcome::Success(_));
+        if !succeeded {
+            if let Some(alert) = &task.on_failure {
+                send_failure_alert(&task, alert, &log_file, &outcome.describe()).await;
             }
+        }
 
-            let ts = Local::now().format("%Y-%m-%dT%H:%M:%S").to_string();
-            if let Some(ref st) = state_ref {
-                let mut g = st.lock().await;
-                if let Some(s) = g.as_mut() {
-                    s.running.insert(task_id.clone(), false);
-                    s.last_run.insert(task_id, ts);
-                }
+        let ts = Local::now().format("%Y-%m-%dT%H:%M:%S").to_string();
+        let Some(st) = state_ref else { return };
+        let dependents: Vec<TaskEntry> = {
+            let mut g = st.lock().await;
+            let Some(s) = g.as_mut() else { return };
+            s.running.insert(task_id.clone(), false);
+            s.live_output.remove(&task_id);
+            s.last_run.insert(task_id.clone(), ts);
+            if task.at.as_deref().is_some_and(|a| !a.trim().is_empty()) {
+                disable_fired_one_shot(s, &task_id);
             }
-        })
+            if s.registry.paused {
+                return;
+            }
+            s.registry
+                .tasks
+                .iter()
+                .filter(|t| t.enabled && t.run_after.contains(&task_id) && (succeeded || !t.only_on_success))
+                .cloned()
+                .collect()
+        };
+
+        for next in dependents {
+            if depth >= MAX_CHAIN_DEPTH {
+                append_log(&log_file, &format!("Not chaining '{}': run_after depth limit reached", next.id));
+                continue;
+            }
+            append_log(&log_path(&data_dir, &next.id), &format!("Triggered by '{}'", task_id));
+            tokio::spawn(run_scheduled(app.clone(), next, data_dir.clone(), Some(st.clone()), depth + 1));
+        }
     })
+}
+
+/// Registers a task's job. Returns `None` for chained-only tasks, which have no job
+/// of their own, and while the scheduler is paused (resuming schedules them).
+async fn add_job_to_scheduler(
+    app: &AppHandle,
+    sched: &JobScheduler,
+    task: &TaskEntry,
+    data_dir: &Path,
+    shared_state: Option<&SharedSchedulerState>,
+) -> Result<Option<Uuid>, String> {
+    if let Some(st) = shared_state {
+        if st.lock().await.as_ref().is_some_and(|s| s.registry.paused) {
+            task_trigger(task)?;
+            return Ok(None);
+        }
+    }
+
+    let job_task = task.clone();
+    let data_dir = data_dir.to_path_buf();
+    let state_ref = shared_state.cloned();
+    let app = app.clone();
+
+    let trigger = task_trigger(task)?;
+    let zone = resolve_timezone(task.timezone.as_deref())?;
+    let run = move |_uuid: Uuid, _lock: JobScheduler| -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        run_scheduled(app.clone(), job_task.clone(), data_dir.clone(), state_ref.clone(), 0)
+    };
+    let job = match trigger {
+        Trigger::Cron(expr) => match zone {
+            Some(tz) => Job::new_async_tz(expr.as_str(), tz, run),
+            None => Job::new_async_tz(expr.as_str(), Local, run),
+        },
+        Trigger::Every(interval) => Job::new_repeated_async(interval, run),
+        // A time already in the past (e.g. the app was closed) fires right away.
+        Trigger::At(at) => Job::new_one_shot_async((at - Utc::now()).to_std().unwrap_or_default(), run),
+        Trigger::Chained => return Ok(None),
+    }
     .map_err(|e| format!("Failed to build job '{}': {}", task.id, e))?;
 
     let uuid = job.guid();
     sched.add(job).await.map_err(|e| format!("Failed to add job '{}': {}", task.id, e))?;
-    Ok(uuid)
+    Ok(Some(uuid))
+}
+
+/// Marks a one-shot task disabled once it has fired.
+fn disable_fired_one_shot(s: &mut SchedulerState, task_id: &str) {
+    s.job_map.remove(task_id);
+    if let Some(t) = s.registry.tasks.iter_mut().find(|t| t.id == task_id) {
+        t.enabled = false;
+    }
+    if let Err(e) = write_registry(&s.registry_path, &s.registry) {
+        tracing::warn!("Failed to disable one-shot task '{}': {}", task_id, e);
+    }
+}
+
+/// Scheduler summary for `get_system_health`.
+#[derive(Debug, Serialize, Clone)]
+pub struct SchedulerHealth {
+    pub initialized: bool,
+    pub paused: bool,
+    pub tasks: usize,
+    pub enabled_tasks: usize,
+    pub running_tasks: usize,
+    /// Names of enabled tasks whose most recent run did not succeed.
+    pub failing_tasks: Vec<String>,
+}
+
+pub(crate) async fn health(state: &SharedSchedulerState) -> SchedulerHealth {
+    let guard = state.lock().await;
+    let Some(s) = guard.as_ref() else {
+        return SchedulerHealth {
+            initialized: false,
+            paused: false,
+            tasks: 0,
+            enabled_tasks: 0,
+            running_tasks: 0,
+            failing_tasks: Vec::new(),
+        };
+    };
+    let enabled: Vec<&TaskEntry> = s.registry.tasks.iter().filter(|t| t.enabled).collect();
+    let failing_tasks = enabled
+        .iter()
+        .filter(|t| {
+            read_history(&s.data_dir, &t.id)
+                .last()
+                .is_some_and(|run| run.status != "success")
+        })
+        .map(|t| t.name.clone())
+        .collect();
+    SchedulerHealth {
+        initialized: true,
+        paused: s.registry.paused,
+        tasks: s.registry.tasks.len(),
+        enabled_tasks: enabled.len(),
+        running_tasks: s.running.values().filter(|r| **r).count(),
+        failing_tasks,
+    }
 }
 
 // ── Tauri Commands ────────────────────────────────────────────────────
@@ -458,24 +1530,49 @@ async fn add_job_to_scheduler(
 #[tauri::command]
 pub async fn get_scheduler_status(
     state: tauri::State<'_, SharedSchedulerState>,
-) -> Result<Vec<TaskStatus>, String> {
-    with_scheduler(&state, |s| {
-        Ok(s.registry
+) -> Result<Vec<TaskStatus>, AppError> {
+    let (mut statuses, interval_jobs, mut sched) = with_scheduler(&state, |s| {
+        let mut interval_jobs = Vec::new();
+        let statuses = s.registry
             .tasks
             .iter()
-            .map(|t| TaskStatus {
-                id: t.id.clone(),
-                name: t.name.clone(),
-                schedule: t.schedule.clone(),
-                enabled: t.enabled,
-                created_by_user: t.created_by_user,
-                last_run: s.last_run.get(&t.id).cloned(),
-                next_run: None,
-                running: s.running.get(&t.id).copied().unwrap_or(false),
+            .enumerate()
+            .map(|(i, t)| {
+                let schedule = match (&t.every, &t.at) {
+                    (Some(e), _) if !e.trim().is_empty() => format!("every {}", e.trim()),
+                    (_, Some(a)) if !a.trim().is_empty() => format!("at {}", a.trim()),
+                    _ if t.schedule.trim().is_empty() && !t.run_after.is_empty() => {
+                        format!("after {}", t.run_after.join(", "))
+                    }
+                    _ => t.schedule.clone(),
+                };
+                if t.enabled && matches!(task_trigger(t), Ok(Trigger::Every(_))) {
+                    if let Some(uuid) = s.job_map.get(&t.id) {
+                        interval_jobs.push((i, *uuid));
+                    }
+                }
+                TaskStatus {
+                    id: t.id.clone(),
+                    name: t.name.clone(),
+                    schedule,
+                    enabled: t.enabled,
+                    created_by_user: t.created_by_user,
+                    last_run: s.last_run.get(&t.id).cloned(),
+                    next_run: if t.enabled && !s.registry.paused { next_run_time(t) } else { None },
+                    running: s.running.get(&t.id).copied().unwrap_or(false),
+                }
             })
-            .collect())
+            .collect::<Vec<_>>();
+        Ok((statuses, interval_jobs, s.scheduler.clone()))
     })
-    .await
+    .await?;
+
+    for (i, uuid) in interval_jobs {
+        if let Ok(Some(next)) = sched.next_tick_for_job(uuid).await {
+            statuses[i].next_run = Some(next.with_timezone(&Local).format("%Y-%m-%dT%H:%M:%S").to_string());
+        }
+    }
+    Ok(statuses)
 }
 
 #[tauri::command]
@@ -484,14 +1581,14 @@ pub async fn toggle_task(
     id: String,
     enabled: bool,
     state: tauri::State<'_, SharedSchedulerState>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let data_dir_path = data_dir(&app)?;
 
     let (task_clone, old_uuid, sched) = {
         let mut guard = state.lock().await;
-        let s = guard.as_mut().ok_or("Scheduler not initialized")?;
+        let s = guard.as_mut().ok_or_else(not_initialized)?;
         let idx = s.registry.tasks.iter().position(|t| t.id == id)
-            .ok_or_else(|| format!("Task '{}' not found", id))?;
+            .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", id)))?;
         s.registry.tasks[idx].enabled = enabled;
         let task = s.registry.tasks[idx].clone();
         let uuid = if !enabled { s.job_map.remove(&id) } else { None };
@@ -504,11 +1601,13 @@ pub async fn toggle_task(
     }
 
     if enabled {
-        let uuid = add_job_to_scheduler(&sched, &task_clone, &data_dir_path, Some(&state.inner().clone())).await
+        let uuid = add_job_to_scheduler(&app, &sched, &task_clone, &data_dir_path, Some(&state.inner().clone())).await
             .map_err(|e| format!("Failed to enable task '{}': {}", id, e))?;
-        let mut guard = state.lock().await;
-        if let Some(s) = guard.as_mut() {
-            s.job_map.insert(id, uuid);
+        if let Some(uuid) = uuid {
+            let mut guard = state.lock().await;
+            if let Some(s) = guard.as_mut() {
+                s.job_map.insert(id, uuid);
+            }
         }
     }
 
@@ -520,43 +1619,46 @@ pub async fn run_task_now(
     app: AppHandle,
     id: String,
     state: tauri::State<'_, SharedSchedulerState>,
-) -> Result<String, String> {
-    let (script_name, args, log_file_path) = {
-        let guard = state.lock().await;
-        let s = guard.as_ref().ok_or("Scheduler not initialized")?;
+) -> Result<String, AppError> {
+    let d = data_dir(&app)?;
+    let (task, log_file_path, live) = {
+        let mut guard = state.lock().await;
+        let s = guard.as_mut().ok_or_else(not_initialized)?;
         let task = s.registry.tasks.iter().find(|t| t.id == id)
-            .ok_or_else(|| format!("Task '{}' not found", id))?;
-        let d = data_dir(&app)?;
-        (task.command.script.clone(), task.command.args.clone(), log_path(&d, &task.id))
+            .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", id)))?
+            .clone();
+        let live = open_live_output(s, &id);
+        (task, log_path(&d, &id), live)
     };
 
-    let script_path = resolve_script(&script_name)?;
     append_log(&log_file_path, &format!("Manual run of task '{}'", id));
-
-    let out = tokio::process::Command::new(&script_path)
-        .args(&args)
-        .kill_on_drop(true)
-        .output()
-        .await
-        .map_err(|e| format!("Failed to spawn task '{}': {}", id, e))?;
+    emit_task_started(&app, &id, "manual");
+    let outcome = run_and_record(&app, &task, &d, 1, "manual", Some(&live)).await;
+    emit_task_finished(&app, &id, "manual", &outcome);
 
     let ts = Local::now().format("%Y-%m-%dT%H:%M:%S").to_string();
     {
         let mut guard = state.lock().await;
         if let Some(s) = guard.as_mut() {
             s.last_run.insert(id.clone(), ts);
+            s.live_output.remove(&id);
         }
     }
 
-    let stdout = String::from_utf8_lossy(&out.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&out.stderr).to_string();
-
-    if out.status.success() {
-        append_log(&log_file_path, &format!("Manual run of '{}' succeeded", id));
-        Ok(format!("{}{}", stdout, stderr))
-    } else {
-        append_log(&log_file_path, &format!("Manual run of '{}' failed: {}{}", id, stdout, stderr));
-        Err(format!("Task '{}' exited with {:?}: {}{}", id, out.status.code(), stdout, stderr))
+    match outcome {
+        TaskOutcome::Success(output) => {
+            append_log(&log_file_path, &format!("Manual run of '{}' succeeded", id));
+            Ok(output)
+        }
+        TaskOutcome::TimedOut(secs) => {
+            append_log(&log_file_path, &format!("TIMEOUT: manual run of '{}' exceeded {}s and was killed", id, secs));
+            Err(AppError::Timeout(format!("Task '{}' timed out after {}s", id, secs)))
+        }
+        failed => {
+            let e = failed.describe();
+            append_log(&log_file_path, &format!("Manual run of '{}' failed: {}", id, e));
+            Err(AppError::Failed(format!("Task '{}' {}", id, e)))
+        }
     }
 }
 
@@ -566,14 +1668,14 @@ pub async fn get_task_log(
     id: String,
     lines: Option<u32>,
     state: tauri::State<'_, SharedSchedulerState>,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     let n = lines.unwrap_or(50) as usize;
     let d = data_dir(&app)?;
     let log_file = {
         let guard = state.lock().await;
-        let s = guard.as_ref().ok_or("Scheduler not initialized")?;
+        let s = guard.as_ref().ok_or_else(not_initialized)?;
         let task = s.registry.tasks.iter().find(|t| t.id == id)
-            .ok_or_else(|| format!("Task '{}' not found", id))?;
+            .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", id)))?;
         log_path(&d, &task.id)
     };
 
@@ -584,9 +1686,79 @@ pub async fn get_task_log(
     let content = tokio::fs::read_to_string(&log_file).await
         .map_err(|e| format!("Failed to read log: {}", e))?;
 
-    let tail: Vec<&str> = content.lines().rev().take(n).collect();
-    let result: Vec<&str> = tail.into_iter().rev().collect();
-    Ok(result.join("\n"))
+    Ok(tail_lines(&content, n))
+}
+
+/// Result of [`validate_schedule`].
+#[derive(Debug, Serialize, Clone)]
+pub struct ScheduleInfo {
+    /// The expression as the scheduler sees it (6 fields, seconds first).
+    pub normalized: String,
+    pub description: String,
+    pub next_runs: Vec<String>,
+}
+
+/// Checks a cron expression the same way jobs are built and describes it.
+/// Upcoming runs are evaluated in `timezone` when given.
+#[tauri::command]
+pub fn validate_schedule(expr: String, timezone: Option<String>) -> Result<ScheduleInfo, AppError> {
+    let expr = expr.trim();
+    if expr.is_empty() {
+        return Err(AppError::InvalidInput("Schedule cannot be empty".to_string()));
+    }
+    let normalized = normalize_schedule(expr);
+    Ok(ScheduleInfo {
+        next_runs: upcoming_runs(expr, timezone.as_deref(), 3)?,
+        description: describe_schedule(&normalized),
+        normalized,
+    })
+}
+
+/// Returns the persisted run history for a task, newest first.
+#[tauri::command]
+pub async fn get_task_history(
+    app: AppHandle,
+    id: String,
+    limit: Option<u32>,
+    state: tauri::State<'_, SharedSchedulerState>,
+) -> Result<Vec<RunRecord>, AppError> {
+    let d = data_dir(&app)?;
+    with_scheduler(&state, |s| {
+        s.registry.tasks.iter().find(|t| t.id == id)
+            .map(|_| ())
+            .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", id)))
+    })
+    .await?;
+
+    let mut history = read_history(&d, &id);
+    history.reverse();
+    history.truncate(limit.unwrap_or(HISTORY_MAX_RECORDS as u32) as usize);
+    Ok(history)
+}
+
+/// Deletes a task's log and all of its rotated archives.
+#[tauri::command]
+pub async fn clear_task_log(
+    app: AppHandle,
+    id: String,
+    state: tauri::State<'_, SharedSchedulerState>,
+) -> Result<(), AppError> {
+    let d = data_dir(&app)?;
+    let log_file = {
+        let guard = state.lock().await;
+        let s = guard.as_ref().ok_or_else(not_initialized)?;
+        let task = s.registry.tasks.iter().find(|t| t.id == id)
+            .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", id)))?;
+        log_path(&d, &task.id)
+    };
+
+    if log_file.exists() {
+        std::fs::remove_file(&log_file).map_err(|e| format!("Failed to clear log: {}", e))?;
+    }
+    for n in 1..=LOG_KEEP_ROTATED {
+        let _ = std::fs::remove_file(rotated_path(&log_file, n));
+    }
+    Ok(())
 }
 
 #[tauri::command]
@@ -594,7 +1766,7 @@ pub async fn create_task(
     entry: TaskEntry,
     state: tauri::State<'_, SharedSchedulerState>,
     app: AppHandle,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let d = data_dir(&app)?;
     let task = TaskEntry {
         created_by_user: true,
@@ -602,29 +1774,33 @@ pub async fn create_task(
     };
 
     if task.id.is_empty() {
-        return Err("Task ID cannot be empty".to_string());
+        return Err(AppError::InvalidInput("Task ID cannot be empty".to_string()));
     }
+    validate_task(&task)?;
 
     let (enabled, sched) = {
         let guard = state.lock().await;
-        let s = guard.as_ref().ok_or("Scheduler not initialized")?;
+        let s = guard.as_ref().ok_or_else(not_initialized)?;
         if s.registry.tasks.iter().any(|t| t.id == task.id) {
-            return Err(format!("Task '{}' already exists", task.id));
+            return Err(AppError::InvalidInput(format!("Task '{}' already exists", task.id)));
         }
+        let mut prospective = s.registry.tasks.clone();
+        prospective.push(task.clone());
+        validate_chain(&prospective).map_err(AppError::InvalidInput)?;
         (task.enabled, s.scheduler.clone())
     };
 
     let maybe_uuid = if enabled {
-        Some(add_job_to_scheduler(&sched, &task, &d, Some(&state.inner().clone())).await
-            .map_err(|e| format!("Failed to schedule new task: {}", e))?)
+        add_job_to_scheduler(&app, &sched, &task, &d, Some(&state.inner().clone())).await
+            .map_err(|e| format!("Failed to schedule new task: {}", e))?
     } else {
         None
     };
 
     let mut guard = state.lock().await;
-    let s = guard.as_mut().ok_or("Scheduler not initialized")?;
+    let s = guard.as_mut().ok_or_else(not_initialized)?;
     if s.registry.tasks.iter().any(|t| t.id == task.id) {
-        return Err(format!("Task '{}' already exists (concurrent create)", task.id));
+        return Err(AppError::InvalidInput(format!("Task '{}' already exists (concurrent create)", task.id)));
     }
     if let Some(uuid) = maybe_uuid {
         s.job_map.insert(task.id.clone(), uuid);
@@ -638,12 +1814,12 @@ pub async fn create_task(
 pub async fn delete_task(
     id: String,
     state: tauri::State<'_, SharedSchedulerState>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let (old_uuid, sched) = {
         let mut guard = state.lock().await;
-        let s = guard.as_mut().ok_or("Scheduler not initialized")?;
+        let s = guard.as_mut().ok_or_else(not_initialized)?;
         let idx = s.registry.tasks.iter().position(|t| t.id == id)
-            .ok_or_else(|| format!("Task '{}' not found", id))?;
+            .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", id)))?;
         let uuid = s.job_map.remove(&id);
         let sched = s.scheduler.clone();
         s.registry.tasks.remove(idx);
@@ -664,16 +1840,20 @@ pub async fn update_task(
     entry: TaskEntry,
     state: tauri::State<'_, SharedSchedulerState>,
     app: AppHandle,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let d = data_dir(&app)?;
+    validate_task(&entry)?;
 
     let (old_uuid, sched, was_user_created) = {
         let guard = state.lock().await;
-        let s = guard.as_ref().ok_or("Scheduler not initialized")?;
+        let s = guard.as_ref().ok_or_else(not_initialized)?;
         let idx = s.registry.tasks.iter().position(|t| t.id == id)
-            .ok_or_else(|| format!("Task '{}' not found", id))?;
+            .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", id)))?;
         let uuid = s.job_map.get(&id).copied();
         let was_user = s.registry.tasks[idx].created_by_user;
+        let mut prospective = s.registry.tasks.clone();
+        prospective[idx] = entry.clone();
+        validate_chain(&prospective).map_err(AppError::InvalidInput)?;
         (uuid, s.scheduler.clone(), was_user)
     };
 
@@ -684,14 +1864,14 @@ pub async fn update_task(
     let updated = TaskEntry { created_by_user: was_user_created, ..entry };
 
     let maybe_uuid = if updated.enabled {
-        Some(add_job_to_scheduler(&sched, &updated, &d, Some(&state.inner().clone())).await
-            .map_err(|e| format!("Failed to reschedule task: {}", e))?)
+        add_job_to_scheduler(&app, &sched, &updated, &d, Some(&state.inner().clone())).await
+            .map_err(|e| format!("Failed to reschedule task: {}", e))?
     } else {
         None
     };
 
     let mut guard = state.lock().await;
-    let s = guard.as_mut().ok_or("Scheduler not initialized")?;
+    let s = guard.as_mut().ok_or_else(not_initialized)?;
     let idx = s.registry.tasks.iter().position(|t| t.id == id)
         .ok_or_else(|| format!("Task '{}' vanished during update", id))?;
     s.job_map.remove(&id);
@@ -702,3 +1882,275 @@ pub async fn update_task(
     write_registry(&s.registry_path, &s.registry)?;
     Ok(())
 }
+
+/// Outcome of [`import_tasks`], by task id.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ImportReport {
+    pub added: Vec<String>,
+    pub overwritten: Vec<String>,
+    /// "old-id -> new-id" for tasks imported under a fresh id.
+    pub renamed: Vec<String>,
+    pub skipped: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Writes the task registry to `path`. With `only_custom`, built-in tasks are left out.
+/// Returns the number of exported tasks.
+#[tauri::command]
+pub async fn export_tasks(
+    path: String,
+    only_custom: Option<bool>,
+    state: tauri::State<'_, SharedSchedulerState>,
+) -> Result<usize, AppError> {
+    let registry = with_scheduler(&state, |s| {
+        Ok(TaskRegistry {
+            paused: false,
+            tasks: s.registry
+                .tasks
+                .iter()
+                .filter(|t| !only_custom.unwrap_or(false) || t.created_by_user)
+                .cloned()
+                .collect(),
+        })
+    })
+    .await?;
+
+    let json = serde_json::to_string_pretty(&registry)
+        .map_err(|e| format!("Failed to serialize tasks: {}", e))?;
+    tokio::fs::write(&path, json).await
+        .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    Ok(registry.tasks.len())
+}
+
+/// Imports tasks from a file written by [`export_tasks`] (or a raw registry).
+/// `merge_strategy` decides what happens when an id already exists:
+/// "skip" (default) keeps the existing task, "overwrite" replaces it and
+/// "rename" imports the task under a new id.
+#[tauri::command]
+pub async fn import_tasks(
+    app: AppHandle,
+    path: String,
+    merge_strategy: Option<String>,
+    state: tauri::State<'_, SharedSchedulerState>,
+) -> Result<ImportReport, AppError> {
+    let strategy = merge_strategy.as_deref().unwrap_or("skip");
+    if !matches!(strategy, "skip" | "overwrite" | "rename") {
+        return Err(AppError::InvalidInput(format!("Unknown merge strategy '{}'", strategy)));
+    }
+    let content = tokio::fs::read_to_string(&path).await
+        .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let incoming: TaskRegistry = serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid task file: {}", e))?;
+    let d = data_dir(&app)?;
+
+    let mut report = ImportReport::default();
+    let (planned, replaced_uuids, sched) = {
+        let guard = state.lock().await;
+        let s = guard.as_ref().ok_or_else(not_initialized)?;
+        let mut ids: HashSet<String> = s.registry.tasks.iter().map(|t| t.id.clone()).collect();
+        let mut planned = Vec::new();
+        let mut replaced_uuids = Vec::new();
+
+        for mut task in incoming.tasks {
+            if task.id.is_empty() {
+                report.errors.push("Task with empty id".to_string());
+                continue;
+            }
+            if let Err(e) = validate_task(&task) {
+                report.errors.push(format!("{}: {}", task.id, e));
+                continue;
+            }
+            if ids.contains(&task.id) {
+                match strategy {
+                    "overwrite" => {
+                        replaced_uuids.extend(s.job_map.get(&task.id).copied());
+                        report.overwritten.push(task.id.clone());
+                    }
+                    "rename" => {
+                        let new_id = (2..)
+                            .map(|n| format!("{}-{}", task.id, n))
+                            .find(|candidate| !ids.contains(candidate))
+                            .unwrap_or_default();
+                        report.renamed.push(format!("{} -> {}", task.id, new_id));
+                        task.id = new_id;
+                    }
+                    _ => {
+                        report.skipped.push(task.id.clone());
+                        continue;
+                    }
+                }
+            } else {
+                report.added.push(task.id.clone());
+            }
+            ids.insert(task.id.clone());
+            planned.push(task);
+        }
+        let mut prospective: Vec<TaskEntry> = s.registry.tasks.iter()
+            .filter(|t| !planned.iter().any(|p| p.id == t.id))
+            .cloned()
+            .collect();
+        prospective.extend(planned.iter().cloned());
+        validate_chain(&prospective).map_err(AppError::InvalidInput)?;
+        (planned, replaced_uuids, s.scheduler.clone())
+    };
+
+    for uuid in replaced_uuids {
+        sched.remove(&uuid).await.ok();
+    }
+
+    let mut scheduled = Vec::with_capacity(planned.len());
+    for mut task in planned {
+        let mut uuid = None;
+        if task.enabled {
+            match add_job_to_scheduler(&app, &sched, &task, &d, Some(&state.inner().clone())).await {
+                Ok(u) => uuid = u,
+                Err(e) => {
+                    report.errors.push(format!("{}: imported disabled ({})", task.id, e));
+                    task.enabled = false;
+                }
+            }
+        }
+        scheduled.push((task, uuid));
+    }
+
+    let mut guard = state.lock().await;
+    let s = guard.as_mut().ok_or_else(not_initialized)?;
+    for (task, uuid) in scheduled {
+        s.job_map.remove(&task.id);
+        if let Some(uuid) = uuid {
+            s.job_map.insert(task.id.clone(), uuid);
+        }
+        match s.registry.tasks.iter().position(|t| t.id == task.id) {
+            Some(idx) => s.registry.tasks[idx] = task,
+            None => s.registry.tasks.push(task),
+        }
+    }
+    write_registry(&s.registry_path, &s.registry)?;
+    Ok(report)
+}
+
+/// Suspends every job without touching the tasks' `enabled` flags.
+/// The paused state is persisted in the registry.
+#[tauri::command]
+pub async fn scheduler_pause(state: tauri::State<'_, SharedSchedulerState>) -> Result<(), AppError> {
+    let (uuids, sched) = {
+        let mut guard = state.lock().await;
+        let s = guard.as_mut().ok_or_else(not_initialized)?;
+        s.registry.paused = true;
+        write_registry(&s.registry_path, &s.registry)?;
+        let uuids: Vec<Uuid> = s.job_map.drain().map(|(_, u)| u).collect();
+        (uuids, s.scheduler.clone())
+    };
+    for uuid in uuids {
+        sched.remove(&uuid).await.ok();
+    }
+    Ok(())
+}
+
+/// Leaves maintenance mode and reschedules all enabled tasks.
+#[tauri::command]
+pub async fn scheduler_resume(
+    app: AppHandle,
+    state: tauri::State<'_, SharedSchedulerState>,
+) -> Result<(), AppError> {
+    {
+        let mut guard = state.lock().await;
+        let s = guard.as_mut().ok_or_else(not_initialized)?;
+        s.registry.paused = false;
+        write_registry(&s.registry_path, &s.registry)?;
+    }
+    start_enabled_jobs(&app, state.inner()).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn scheduler_is_paused(state: tauri::State<'_, SharedSchedulerState>) -> Result<bool, AppError> {
+    with_scheduler(&state, |s| Ok(s.registry.paused)).await
+}
+
+/// Streams a task's log over `on_line`: the last `lines` lines first, then new
+/// log lines and the live stdout/stderr of any running run. Returns a stream id
+/// for [`stop_task_log_stream`].
+#[tauri::command]
+pub async fn stream_task_log(
+    app: AppHandle,
+    id: String,
+    lines: Option<u32>,
+    on_line: Channel<LogLine>,
+    state: tauri::State<'_, SharedSchedulerState>,
+) -> Result<String, AppError> {
+    let d = data_dir(&app)?;
+    let stream_id = Uuid::new_v4().to_string();
+    let stop = Arc::new(AtomicBool::new(false));
+    let log_file = {
+        let mut guard = state.lock().await;
+        let s = guard.as_mut().ok_or_else(not_initialized)?;
+        if !s.registry.tasks.iter().any(|t| t.id == id) {
+            return Err(AppError::NotFound(format!("Task '{}' not found", id)));
+        }
+        s.log_streams.insert(stream_id.clone(), stop.clone());
+        log_path(&d, &id)
+    };
+
+    let content = std::fs::read_to_string(&log_file).unwrap_or_default();
+    for line in tail_lines(&content, lines.unwrap_or(50) as usize).lines() {
+        let _ = on_line.send(LogLine { source: "log".to_string(), line: line.to_string() });
+    }
+    let mut offset = content.len() as u64;
+
+    let shared = state.inner().clone();
+    let sid = stream_id.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut live: Option<broadcast::Receiver<LogLine>> = None;
+        let mut ticker = tokio::time::interval(LOG_POLL_INTERVAL);
+        // A failed send means the window closed the channel; stop streaming.
+        while !stop.load(Ordering::SeqCst) {
+            match live.as_mut() {
+                Some(rx) => tokio::select! {
+                    msg = rx.recv() => match msg {
+                        Ok(line) => {
+                            if on_line.send(line).is_err() {
+                                break;
+                            }
+                        }
+                        Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => live = None,
+                    },
+                    _ = ticker.tick() => match send_new_log_lines(&log_file, offset, &on_line) {
+                        Some(next) => offset = next,
+                        None => break,
+                    },
+                },
+                None => {
+                    ticker.tick().await;
+                    match send_new_log_lines(&log_file, offset, &on_line) {
+                        Some(next) => offset = next,
+                        None => break,
+                    }
+                    let g = shared.lock().await;
+                    live = g.as_ref().and_then(|s| s.live_output.get(&id)).map(|tx| tx.subscribe());
+                }
+            }
+        }
+        if let Some(s) = shared.lock().await.as_mut() {
+            s.log_streams.remove(&sid);
+        }
+    });
+
+    Ok(stream_id)
+}
+
+/// Stops a stream started by [`stream_task_log`].
+#[tauri::command]
+pub async fn stop_task_log_stream(
+    stream_id: String,
+    state: tauri::State<'_, SharedSchedulerState>,
+) -> Result<(), AppError> {
+    with_scheduler(&state, |s| {
+        if let Some(stop) = s.log_streams.remove(&stream_id) {
+            stop.store(true, Ordering::SeqCst);
+        }
+        Ok(())
+    })
+    .await
+}
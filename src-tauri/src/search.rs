@@ -0,0 +1,375 @@
+/// File, directory and content search for the folder browser.
+///
+/// Walks are built on the `ignore` crate: they run in parallel and honor
+/// `.gitignore`, `.ignore` and the global git excludes (even outside a git
+/// repository), and skip hidden entries. Names are matched fuzzily and
+/// results ranked by score. Content search runs the ripgrep engine
+/// (`grep-searcher`) over the same walk and skips binary files.
+///
+/// A search started with a `search_id` cancels the previous search with the
+/// same ID, and `cancel_search` stops it explicitly, so abandoned searches
+/// don't keep walking the disk.
+use grep_matcher::Matcher;
+use grep_regex::{RegexMatcher, RegexMatcherBuilder};
+use grep_searcher::{sinks, BinaryDetection, SearcherBuilder};
+use ignore::overrides::OverrideBuilder;
+use ignore::{WalkBuilder, WalkState};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::error::AppError;
+
+/// Directory levels searched below the root.
+const MAX_DEPTH: usize = 12;
+/// Entries visited before a search gives up.
+const MAX_VISITED: usize = 300_000;
+/// Candidates gathered per requested result before ranking stops the walk.
+const CANDIDATES_PER_RESULT: usize = 20;
+
+/// Longest snippet returned per matching line, in bytes.
+const MAX_SNIPPET: usize = 240;
+/// Context kept before the first match when a long line is cut.
+const SNIPPET_LEAD: usize = 80;
+
+/// Cancellation flags of running searches (and disk usage scans), by ID.
+#[derive(Default)]
+pub struct SearchRegistry(Mutex<HashMap<String, Arc<AtomicBool>>>);
+
+impl SearchRegistry {
+    /// Cancels any search running under `id` and registers a new one.
+    pub fn start(&self, id: &str) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        let mut searches = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(previous) = searches.insert(id.to_string(), flag.clone()) {
+            previous.store(true, Ordering::SeqCst);
+        }
+        flag
+    }
+
+    pub fn cancel(&self, id: &str) {
+        let searches = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(flag) = searches.get(id) {
+            flag.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Forgets `id` if it still belongs to the search holding `flag`.
+    pub fn finish(&self, id: &str, flag: &Arc<AtomicBool>) {
+        let mut searches = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if searches.get(id).is_some_and(|f| Arc::ptr_eq(f, flag)) {
+            searches.remove(id);
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PathMatch {
+    pub name: String,
+    pub absolute: String,
+    /// "directory" or "file".
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub score: i64,
+}
+
+/// Scores `name` against a lowercase `query`; `None` when it doesn't match.
+///
+/// Substring matches rank above scattered ones, with bonuses for exact and
+/// prefix matches. Otherwise every query character must appear in order;
+/// consecutive characters and word starts (after `-`, `_`, `.`, space, or a
+/// lower-to-upper case change) score higher, and skipped characters cost a point.
+fn fuzzy_score(name: &str, query: &str) -> Option<i64> {
+    let lower = name.to_lowercase();
+    let length_penalty = lower.chars().count() as i64;
+    if let Some(pos) = lower.find(query) {
+        let mut score = 1000 - length_penalty;
+        if lower == query {
+            score += 1000;
+        } else if pos == 0 {
+            score += 500;
+        }
+        return Some(score);
+    }
+
+    let chars: Vec<char> = name.chars().collect();
+    let mut wanted = query.chars().peekable();
+    let mut score = 0;
+    let mut previous_matched = false;
+    for (i, c) in chars.iter().enumerate() {
+        let Some(&q) = wanted.peek() else { break };
+        if c.to_lowercase().eq(q.to_lowercase()) {
+            let boundary = i == 0
+                || matches!(chars[i - 1], '-' | '_' | '.' | ' ')
+                || (chars[i - 1].is_lowercase() && c.is_uppercase());
+            score += 10;
+            if previous_matched {
+                score += 15;
+            }
+            if boundary {
+                score += 20;
+            }
+            previous_matched = true;
+            wanted.next();
+        } else {
+            score -= 1;
+            previous_matched = false;
+        }
+    }
+    wanted.peek().is_none().then_some(score)
+}
+
+fn walk(root: PathBuf, query: &str, limit: usize, include_files: bool, cancelled: &AtomicBool) -> Vec<PathMatch> {
+    let found = Mutex::new(Vec::new());
+    let visited = AtomicUsize::new(0);
+    let max_candidates = limit.saturating_mul(CANDIDATES_PER_RESULT);
+
+    WalkBuilder::new(&root)
+        .max_depth(Some(MAX_DEPTH))
+        .require_git(false)
+        .build_parallel()
+        .run(|| {
+            Box::new(|entry| {
+                if cancelled.load(Ordering::Relaxed)
+                    || visited.fetch_add(1, Ordering::Relaxed) >= MAX_VISITED
+                {
+                    return WalkState::Quit;
+                }
+                let Ok(entry) = entry else { return WalkState::Continue };
+                if entry.depth() == 0 {
+                    return WalkState::Continue;
+                }
+                let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+                if !is_dir && !include_files {
+                    return WalkState::Continue;
+                }
+                let name = entry.file_name().to_string_lossy();
+                let Some(score) = fuzzy_score(&name, query) else { return WalkState::Continue };
+                let mut found = found.lock().unwrap_or_else(|e| e.into_inner());
+                found.push(PathMatch {
+                    name: name.to_string(),
+                    absolute: entry.path().to_string_lossy().to_string(),
+                    kind: if is_dir { "directory" } else { "file" },
+                    score,
+                });
+                if found.len() >= max_candidates {
+                    WalkState::Quit
+                } else {
+                    WalkState::Continue
+                }
+            })
+        });
+
+    let mut found = found.into_inner().unwrap_or_else(|e| e.into_inner());
+    // Best score first; among equals, shallower paths first.
+    found.sort_by_cached_key(|m| (std::cmp::Reverse(m.score), m.absolute.matches(std::path::MAIN_SEPARATOR).count()));
+    found.truncate(limit);
+    found
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ContentMatch {
+    pub file: String,
+    /// 1-based line number.
+    pub line: u64,
+    /// The matching line, trimmed and cut around the first match when long.
+    pub snippet: String,
+    /// `[start, end)` of each match in `snippet`, in UTF-16 code units so
+    /// they index JavaScript strings directly.
+    pub highlights: Vec<[usize; 2]>,
+}
+
+fn floor_boundary(s: &str, mut i: usize) -> usize {
+    while !s.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+fn utf16_len(s: &str) -> usize {
+    s.encode_utf16().count()
+}
+
+/// Builds the snippet for `line` with the match byte ranges `ranges`.
+fn snippet(line: &str, ranges: &[(usize, usize)]) -> (String, Vec<[usize; 2]>) {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let first = ranges.first().map_or(0, |r| r.0);
+    let (mut start, mut end) = (line.len() - line.trim_start().len(), line.len());
+    if end - start > MAX_SNIPPET {
+        start = floor_boundary(line, first.saturating_sub(SNIPPET_LEAD).max(start));
+        end = floor_boundary(line, (start + MAX_SNIPPET).min(line.len()));
+    }
+    let body = &line[start..end];
+    let prefix = if line[..start].trim().is_empty() { "" } else { "…" };
+    let suffix = if end < line.len() { "…" } else { "" };
+    let offset = utf16_len(prefix);
+
+    let highlights = ranges
+        .iter()
+        .filter(|(s, e)| *s < end && *e > start)
+        .map(|&(s, e)| {
+            let s = s.max(start);
+            let e = floor_boundary(line, e.min(end));
+            let from = offset + utf16_len(&line[start..s]);
+            [from, from + utf16_len(&line[s..e])]
+        })
+        .collect();
+    (format!("{}{}{}", prefix, body, suffix), highlights)
+}
+
+fn content_walk(
+    root: PathBuf,
+    matcher: RegexMatcher,
+    globs: Vec<String>,
+    limit: usize,
+    cancelled: &AtomicBool,
+) -> Result<Vec<ContentMatch>, String> {
+    let mut builder = WalkBuilder::new(&root);
+    builder.require_git(false);
+    if !globs.is_empty() {
+        let mut overrides = OverrideBuilder::new(&root);
+        for glob in &globs {
+            overrides.add(glob).map_err(|e| format!("Invalid glob '{}': {}", glob, e))?;
+        }
+        builder.overrides(overrides.build().map_err(|e| e.to_string())?);
+    }
+
+    let found = Mutex::new(Vec::new());
+    let full = AtomicBool::new(false);
+    builder.build_parallel().run(|| {
+        let mut searcher = SearcherBuilder::new()
+            .line_number(true)
+            .binary_detection(BinaryDetection::quit(b'\x00'))
+            .build();
+        let matcher = matcher.clone();
+        let found = &found;
+        let full = &full;
+        Box::new(move |entry| {
+            if cancelled.load(Ordering::Relaxed) || full.load(Ordering::Relaxed) {
+                return WalkState::Quit;
+            }
+            let Ok(entry) = entry else { return WalkState::Continue };
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                return WalkState::Continue;
+            }
+            let file = entry.path().to_string_lossy().to_string();
+            let _ = searcher.search_path(
+                &matcher,
+                entry.path(),
+                sinks::Lossy(|line_number, line| {
+                    let mut ranges = Vec::new();
+                    let _ = matcher.find_iter(line.as_bytes(), |m| {
+                        ranges.push((m.start(), m.end()));
+                        true
+                    });
+                    let (snippet, highlights) = snippet(line, &ranges);
+                    let mut found = found.lock().unwrap_or_else(|e| e.into_inner());
+                    found.push(ContentMatch { file: file.clone(), line: line_number, snippet, highlights });
+                    if found.len() >= limit {
+                        full.store(true, Ordering::Relaxed);
+                        return Ok(false);
+                    }
+                    Ok(!cancelled.load(Ordering::Relaxed))
+                }),
+            );
+            WalkState::Continue
+        })
+    });
+
+    let mut found = found.into_inner().unwrap_or_else(|e| e.into_inner());
+    found.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+    found.truncate(limit);
+    Ok(found)
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Fuzzy-searches directory names (and file names with `include_files`) under
+/// `root`, best matches first. Returns an empty list when cancelled.
+#[tauri::command]
+pub async fn search_directories(
+    registry: tauri::State<'_, SearchRegistry>,
+    root: String,
+    query: String,
+    max_results: Option<usize>,
+    include_files: Option<bool>,
+    search_id: Option<String>,
+) -> Result<Vec<PathMatch>, AppError> {
+    let root_path = PathBuf::from(&root);
+    if !root_path.is_dir() {
+        return Err(AppError::InvalidInput("Root is not a directory".to_string()));
+    }
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let limit = max_results.unwrap_or(20).max(1);
+    let include_files = include_files.unwrap_or(false);
+
+    let id = search_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let cancelled = registry.start(&id);
+    let flag = cancelled.clone();
+    let results = tokio::task::spawn_blocking(move || walk(root_path, &query, limit, include_files, &flag))
+        .await
+        .map_err(|e| e.to_string());
+    registry.finish(&id, &cancelled);
+    if cancelled.load(Ordering::SeqCst) {
+        return Ok(Vec::new());
+    }
+    Ok(results?)
+}
+
+/// Searches file contents under `root` for `query` (a literal, or a regex with
+/// `regex`), case-insensitively unless the query has uppercase letters.
+/// `glob` limits the files searched, e.g. `*.rs` or `src/**/*.{ts,tsx}`;
+/// several globs can be separated by commas, and `!` excludes.
+#[tauri::command]
+pub async fn search_file_contents(
+    registry: tauri::State<'_, SearchRegistry>,
+    root: String,
+    query: String,
+    glob: Option<String>,
+    max_results: Option<usize>,
+    regex: Option<bool>,
+    search_id: Option<String>,
+) -> Result<Vec<ContentMatch>, AppError> {
+    let root_path = PathBuf::from(&root);
+    if !root_path.is_dir() {
+        return Err(AppError::InvalidInput("Root is not a directory".to_string()));
+    }
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let matcher = RegexMatcherBuilder::new()
+        .case_smart(true)
+        .fixed_strings(!regex.unwrap_or(false))
+        .build(&query)
+        .map_err(|e| format!("Invalid pattern: {}", e))?;
+    let globs: Vec<String> = glob
+        .unwrap_or_default()
+        .split(',')
+        .map(|g| g.trim().to_string())
+        .filter(|g| !g.is_empty())
+        .collect();
+    let limit = max_results.unwrap_or(200).max(1);
+
+    let id = search_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let cancelled = registry.start(&id);
+    let flag = cancelled.clone();
+    let results = tokio::task::spawn_blocking(move || content_walk(root_path, matcher, globs, limit, &flag))
+        .await
+        .map_err(|e| e.to_string());
+    registry.finish(&id, &cancelled);
+    if cancelled.load(Ordering::SeqCst) {
+        return Ok(Vec::new());
+    }
+    Ok(results??)
+}
+
+/// Stops the search running under `search_id`, if any.
+#[tauri::command]
+pub fn cancel_search(registry: tauri::State<'_, SearchRegistry>, search_id: String) {
+    registry.cancel(&search_id);
+}
@@ -1,9 +1,15 @@
 /// Cross-platform service manager.
-/// Registry stored alongside scheduler-registry.json in Tauri app data dir.
+/// Registry stored at: <app_data_dir>/services-registry.json
 /// Platform dispatch: Linux→systemctl --user, macOS→launchctl, Windows→sc.exe, mobile→noop.
+/// Entries with a `container` go through the Docker API on every platform.
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::error::AppError;
 
 // ── Types ────────────────────────────────────────────────────────────
 
@@ -28,7 +34,38 @@ pub struct ServiceEntry {
     pub id: String,
     pub name: String,
     pub category: String,
+    /// False for the built-in defaults, true for services added from the app.
+    #[serde(default)]
+    pub created_by_user: bool,
     pub platform: ServicePlatformMap,
+    /// Docker container name or ID. When set the service is managed through
+    /// the Docker API instead of the platform service manager.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    /// Probed with GET during status checks; any 2xx counts as healthy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_url: Option<String>,
+    /// Opt-in auto-restart when the service stops unexpectedly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watchdog: Option<WatchdogPolicy>,
+}
+
+fn default_max_restarts() -> u32 {
+    3
+}
+
+fn default_watchdog_backoff() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WatchdogPolicy {
+    /// Restarts attempted before giving up until the service is healthy again.
+    #[serde(default = "default_max_restarts")]
+    pub max_restarts: u32,
+    /// Delay before the second attempt; doubles after each failed one.
+    #[serde(default = "default_watchdog_backoff")]
+    pub backoff_secs: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -54,6 +91,26 @@ pub enum ServiceStatus {
     Unsupported,
 }
 
+/// Result of a service's HTTP health probe.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthProbe {
+    pub healthy: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Resource usage of a running service's main process.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServiceResources {
+    pub pid: u32,
+    /// Percent of one core, so multi-threaded services can exceed 100.
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    pub uptime_secs: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ServiceStatusInfo {
     pub id: String,
@@ -61,6 +118,55 @@ pub struct ServiceStatusInfo {
     pub category: String,
     pub status: ServiceStatus,
     pub supported: bool,
+    /// Starts at boot/login. `None` when the manager cannot tell.
+    pub enabled: Option<bool>,
+    pub created_by_user: bool,
+    /// Only set while running and the main PID is known.
+    pub resources: Option<ServiceResources>,
+    /// Only set when the service declares a `health_url`.
+    pub health: Option<HealthProbe>,
+}
+
+fn default_restart() -> String {
+    "on-failure".to_string()
+}
+
+fn default_restart_sec() -> u64 {
+    5
+}
+
+/// Input of `install_service`: what to run and how the manager should supervise it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServiceDefinition {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub category: String,
+    /// Absolute path of the executable.
+    pub exec: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub working_dir: Option<String>,
+    #[serde(default)]
+    pub env: std::collections::BTreeMap<String, String>,
+    /// "always", "on-failure" or "no".
+    #[serde(default = "default_restart")]
+    pub restart: String,
+    #[serde(default = "default_restart_sec")]
+    pub restart_sec: u64,
+    #[serde(default)]
+    pub health_url: Option<String>,
+}
+
+/// One log line from the platform's service log.
+#[derive(Debug, Serialize, Clone)]
+pub struct ServiceLogLine {
+    /// RFC 3339 when the platform reports one.
+    pub timestamp: Option<String>,
+    /// "error", "warning", "info" or "debug".
+    pub level: Option<String>,
+    pub message: String,
 }
 
 // ── Default services (6 from TaskInfo.md) ────────────────────────────
@@ -71,6 +177,7 @@ fn default_services() -> Vec<ServiceEntry> {
             id: "winter-opencode".into(),
             name: "Winter Agent".into(),
             category: "agent".into(),
+            created_by_user: false,
             platform: ServicePlatformMap {
                 linux: Some(PlatformServiceConfig {
                     svc_type: "systemd".into(),
@@ -91,11 +198,15 @@ fn default_services() -> Vec<ServiceEntry> {
                     name: Some("WinterOpenCode".into()),
                 }),
             },
+            container: None,
+            health_url: Some("http://127.0.0.1:6096/global/health".into()),
+            watchdog: None,
         },
         ServiceEntry {
             id: "winter-proxy".into(),
             name: "Winter Proxy".into(),
             category: "proxy".into(),
+            created_by_user: false,
             platform: ServicePlatformMap {
                 linux: Some(PlatformServiceConfig {
                     svc_type: "systemd".into(),
@@ -116,11 +227,15 @@ fn default_services() -> Vec<ServiceEntry> {
                     name: Some("WinterProxy".into()),
                 }),
             },
+            container: None,
+            health_url: None,
+            watchdog: None,
         },
         ServiceEntry {
             id: "frost-opencode".into(),
             name: "Frost Agent".into(),
             category: "agent".into(),
+            created_by_user: false,
             platform: ServicePlatformMap {
                 linux: Some(PlatformServiceConfig {
                     svc_type: "systemd".into(),
@@ -141,11 +256,15 @@ fn default_services() -> Vec<ServiceEntry> {
                     name: Some("FrostOpenCode".into()),
                 }),
             },
+            container: None,
+            health_url: None,
+            watchdog: None,
         },
         ServiceEntry {
             id: "frost-proxy".into(),
             name: "Frost Proxy".into(),
             category: "proxy".into(),
+            created_by_user: false,
             platform: ServicePlatformMap {
                 linux: Some(PlatformServiceConfig {
                     svc_type: "systemd".into(),
@@ -166,11 +285,15 @@ fn default_services() -> Vec<ServiceEntry> {
                     name: Some("FrostProxy".into()),
                 }),
             },
+            container: None,
+            health_url: None,
+            watchdog: None,
         },
         ServiceEntry {
             id: "gai-api".into(),
             name: "GAI API".into(),
             category: "ai-service".into(),
+            created_by_user: false,
             platform: ServicePlatformMap {
                 linux: Some(PlatformServiceConfig {
                     svc_type: "systemd".into(),
@@ -191,11 +314,15 @@ fn default_services() -> Vec<ServiceEntry> {
                     name: Some("GaiApi".into()),
                 }),
             },
+            container: None,
+            health_url: None,
+            watchdog: None,
         },
         ServiceEntry {
             id: "gpt-sovits".into(),
             name: "TTS Engine".into(),
             category: "ai-service".into(),
+            created_by_user: false,
             platform: ServicePlatformMap {
                 linux: Some(PlatformServiceConfig {
                     svc_type: "systemd".into(),
@@ -216,6 +343,9 @@ fn default_services() -> Vec<ServiceEntry> {
                     name: Some("GptSovits".into()),
                 }),
             },
+            container: None,
+            health_url: None,
+            watchdog: None,
         },
     ]
 }
@@ -229,6 +359,22 @@ pub trait ServiceManager: Send + Sync {
     async fn stop(&self, svc: &ServiceEntry) -> Result<(), String>;
     async fn restart(&self, svc: &ServiceEntry) -> Result<(), String>;
     async fn is_installed(&self, svc: &ServiceEntry) -> bool;
+    /// Whether the service starts at boot/login; `None` when unknown.
+    async fn is_enabled(&self, svc: &ServiceEntry) -> Option<bool>;
+    async fn set_enabled(&self, svc: &ServiceEntry, enabled: bool) -> Result<(), String>;
+    /// PID of the service's main process while it runs.
+    async fn main_pid(&self, svc: &ServiceEntry) -> Option<u32>;
+    /// Writes the platform's service definition, registers it with the
+    /// manager and returns the config to store in the registry entry.
+    async fn install(&self, def: &ServiceDefinition) -> Result<PlatformServiceConfig, String>;
+    /// Most recent `lines` log lines, oldest first. `since` is local time as
+    /// `%Y-%m-%d %H:%M:%S`.
+    async fn logs(
+        &self,
+        svc: &ServiceEntry,
+        lines: usize,
+        since: Option<&str>,
+    ) -> Result<Vec<ServiceLogLine>, String>;
 }
 
 // ── Linux: systemctl --user ───────────────────────────────────────────
@@ -324,6 +470,126 @@ impl ServiceManager for LinuxServiceManager {
                 && String::from_utf8_lossy(&out.stdout).contains(&unit)
         )
     }
+
+    async fn is_enabled(&self, svc: &ServiceEntry) -> Option<bool> {
+        let unit = Self::unit_name(svc)?;
+        let out = Self::run_systemctl(&["--user", "is-enabled", &unit]).await.ok()?;
+        // is-enabled exits non-zero for "disabled" too, so read the state word.
+        match String::from_utf8_lossy(&out.stdout).trim() {
+            "enabled" | "enabled-runtime" | "alias" => Some(true),
+            "disabled" | "static" | "indirect" | "masked" | "masked-runtime" => Some(false),
+            _ => None,
+        }
+    }
+
+    async fn set_enabled(&self, svc: &ServiceEntry, enabled: bool) -> Result<(), String> {
+        let unit = Self::unit_name(svc)
+            .ok_or_else(|| format!("No Linux unit configured for '{}'", svc.id))?;
+        let verb = if enabled { "enable" } else { "disable" };
+        let out = Self::run_systemctl(&["--user", verb, &unit]).await?;
+        if out.status.success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "systemctl {} {} failed: {}",
+                verb,
+                unit,
+                String::from_utf8_lossy(&out.stderr)
+            ))
+        }
+    }
+
+    async fn main_pid(&self, svc: &ServiceEntry) -> Option<u32> {
+        let unit = Self::unit_name(svc)?;
+        let out = Self::run_systemctl(&["--user", "show", "-p", "MainPID", "--value", &unit]).await.ok()?;
+        String::from_utf8_lossy(&out.stdout).trim().parse().ok().filter(|&pid| pid != 0)
+    }
+
+    async fn install(&self, def: &ServiceDefinition) -> Result<PlatformServiceConfig, String> {
+        let home = std::env::var("HOME").map_err(|_| "HOME is not set".to_string())?;
+        let dir = PathBuf::from(home).join(".config/systemd/user");
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        let unit = format!("{}.service", def.id);
+        std::fs::write(dir.join(&unit), systemd_unit(def))
+            .map_err(|e| format!("Failed to write {}: {}", unit, e))?;
+        let out = Self::run_systemctl(&["--user", "daemon-reload"]).await?;
+        if !out.status.success() {
+            return Err(format!(
+                "systemctl daemon-reload failed: {}",
+                String::from_utf8_lossy(&out.stderr)
+            ));
+        }
+        Ok(PlatformServiceConfig {
+            svc_type: "systemd".into(),
+            unit: Some(unit),
+            label: None,
+            name: None,
+        })
+    }
+
+    async fn logs(
+        &self,
+        svc: &ServiceEntry,
+        lines: usize,
+        since: Option<&str>,
+    ) -> Result<Vec<ServiceLogLine>, String> {
+        let unit = Self::unit_name(svc)
+            .ok_or_else(|| format!("No Linux unit configured for '{}'", svc.id))?;
+        let count = lines.to_string();
+        let mut args = vec!["--user", "-u", &unit, "-n", &count, "-o", "json", "--no-pager"];
+        if let Some(since) = since {
+            args.extend(["--since", since]);
+        }
+        let out = tokio::process::Command::new("journalctl")
+            .args(&args)
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|e| format!("journalctl error: {}", e))?;
+        if !out.status.success() {
+            return Err(format!(
+                "journalctl -u {} failed: {}",
+                unit,
+                String::from_utf8_lossy(&out.stderr)
+            ));
+        }
+        Ok(String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter_map(|l| serde_json::from_str::<serde_json::Value>(l).ok())
+            .map(|entry| parse_journal_entry(&entry))
+            .collect())
+    }
+}
+
+/// Converts one `journalctl -o json` record. MESSAGE is a byte array when it
+/// is not valid UTF-8.
+fn parse_journal_entry(entry: &serde_json::Value) -> ServiceLogLine {
+    let message = match entry.get("MESSAGE") {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(serde_json::Value::Array(bytes)) => {
+            let bytes: Vec<u8> = bytes.iter().filter_map(|b| b.as_u64().map(|b| b as u8)).collect();
+            String::from_utf8_lossy(&bytes).into_owned()
+        }
+        _ => String::new(),
+    };
+    let timestamp = entry
+        .get("__REALTIME_TIMESTAMP")
+        .and_then(|t| t.as_str())
+        .and_then(|t| t.parse::<i64>().ok())
+        .and_then(chrono::DateTime::from_timestamp_micros)
+        .map(|t| t.with_timezone(&chrono::Local).to_rfc3339());
+    let level = entry
+        .get("PRIORITY")
+        .and_then(|p| p.as_str())
+        .and_then(|p| p.parse::<u8>().ok())
+        .map(|p| match p {
+            0..=3 => "error",
+            4 => "warning",
+            5 | 6 => "info",
+            _ => "debug",
+        }.to_string());
+    ServiceLogLine { timestamp, level, message }
 }
 
 // ── macOS: launchctl ──────────────────────────────────────────────────
@@ -424,6 +690,188 @@ impl ServiceManager for MacOSServiceManager {
             Ok(out) if out.status.success()
         )
     }
+
+    async fn is_enabled(&self, svc: &ServiceEntry) -> Option<bool> {
+        let label = Self::label(svc)?;
+        let domain = launchd_gui_domain().await?;
+        let out = tokio::process::Command::new("launchctl")
+            .args(["print-disabled", &domain])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .ok()?;
+        let listing = String::from_utf8_lossy(&out.stdout);
+        let needle = format!("\"{}\" =>", label);
+        // Older macOS prints `=> true` for disabled jobs; unlisted jobs are enabled.
+        match listing.lines().find(|l| l.trim_start().starts_with(&needle)) {
+            Some(line) => Some(!(line.contains("disabled") || line.contains("true"))),
+            None => Some(self.is_installed(svc).await),
+        }
+    }
+
+    async fn set_enabled(&self, svc: &ServiceEntry, enabled: bool) -> Result<(), String> {
+        let label = Self::label(svc)
+            .ok_or_else(|| format!("No macOS label configured for '{}'", svc.id))?;
+        let domain = launchd_gui_domain()
+            .await
+            .ok_or("Could not determine the launchd user domain")?;
+        let verb = if enabled { "enable" } else { "disable" };
+        let out = tokio::process::Command::new("launchctl")
+            .args([verb, &format!("{}/{}", domain, label)])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|e| format!("launchctl error: {}", e))?;
+        if out.status.success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "launchctl {} {} failed: {}",
+                verb,
+                label,
+                String::from_utf8_lossy(&out.stderr)
+            ))
+        }
+    }
+
+    async fn main_pid(&self, svc: &ServiceEntry) -> Option<u32> {
+        let label = Self::label(svc)?;
+        let out = tokio::process::Command::new("launchctl")
+            .args(["list", &label])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .ok()?;
+        String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .find_map(|l| l.trim().strip_prefix("\"PID\" = "))
+            .and_then(|pid| pid.trim_end_matches(';').parse().ok())
+    }
+
+    async fn install(&self, def: &ServiceDefinition) -> Result<PlatformServiceConfig, String> {
+        let home = std::env::var("HOME").map_err(|_| "HOME is not set".to_string())?;
+        let dir = PathBuf::from(home).join("Library/LaunchAgents");
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        let label = format!("com.winter.{}", def.id);
+        let plist = dir.join(format!("{}.plist", label));
+        let plist_path = plist.to_string_lossy().into_owned();
+        // Reinstalling over a loaded job: unload first so the new plist is read.
+        let _ = tokio::process::Command::new("launchctl")
+            .args(["unload", &plist_path])
+            .kill_on_drop(true)
+            .output()
+            .await;
+        std::fs::write(&plist, launchd_plist(&label, def))
+            .map_err(|e| format!("Failed to write {}: {}", plist.display(), e))?;
+        let out = tokio::process::Command::new("launchctl")
+            .args(["load", &plist_path])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|e| format!("launchctl error: {}", e))?;
+        if !out.status.success() {
+            return Err(format!(
+                "launchctl load {} failed: {}",
+                plist.display(),
+                String::from_utf8_lossy(&out.stderr)
+            ));
+        }
+        Ok(PlatformServiceConfig {
+            svc_type: "launchd".into(),
+            unit: None,
+            label: Some(label),
+            name: None,
+        })
+    }
+
+    async fn logs(
+        &self,
+        svc: &ServiceEntry,
+        lines: usize,
+        since: Option<&str>,
+    ) -> Result<Vec<ServiceLogLine>, String> {
+        let label = Self::label(svc)
+            .ok_or_else(|| format!("No macOS label configured for '{}'", svc.id))?;
+        // Unified log entries carry the process name, not the launchd label;
+        // take it from the job's Program / ProgramArguments.
+        let process = tokio::process::Command::new("launchctl")
+            .args(["list", &label])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .ok()
+            .and_then(|out| launchd_process_name(&String::from_utf8_lossy(&out.stdout)));
+        let predicate = match process {
+            Some(p) => format!("subsystem == \"{}\" OR process == \"{}\"", label, p),
+            None => format!("subsystem == \"{}\"", label),
+        };
+        let mut cmd = tokio::process::Command::new("log");
+        cmd.args(["show", "--style", "ndjson", "--info", "--predicate", &predicate]);
+        match since {
+            Some(since) => cmd.args(["--start", since]),
+            None => cmd.args(["--last", "1d"]),
+        };
+        let out = cmd
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|e| format!("log show error: {}", e))?;
+        if !out.status.success() {
+            return Err(format!(
+                "log show for {} failed: {}",
+                label,
+                String::from_utf8_lossy(&out.stderr)
+            ));
+        }
+        let mut entries: Vec<ServiceLogLine> = String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter_map(|l| serde_json::from_str::<serde_json::Value>(l).ok())
+            .filter(|e| e.get("eventMessage").is_some())
+            .map(|e| ServiceLogLine {
+                timestamp: e.get("timestamp").and_then(|t| t.as_str()).map(String::from),
+                level: e.get("messageType").and_then(|t| t.as_str()).map(|t| match t {
+                    "Error" | "Fault" => "error".to_string(),
+                    "Debug" => "debug".to_string(),
+                    _ => "info".to_string(),
+                }),
+                message: e["eventMessage"].as_str().unwrap_or_default().to_string(),
+            })
+            .collect();
+        let skip = entries.len().saturating_sub(lines);
+        entries.drain(..skip);
+        Ok(entries)
+    }
+}
+
+/// `gui/<uid>`, the launchd domain of the logged-in user's agents.
+async fn launchd_gui_domain() -> Option<String> {
+    let out = tokio::process::Command::new("id")
+        .arg("-u")
+        .kill_on_drop(true)
+        .output()
+        .await
+        .ok()?;
+    let uid = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    (!uid.is_empty()).then(|| format!("gui/{}", uid))
+}
+
+/// Executable name from `launchctl list <label>` output.
+fn launchd_process_name(listing: &str) -> Option<String> {
+    let mut lines = listing.lines().map(str::trim);
+    let path = loop {
+        let line = lines.next()?;
+        if let Some(rest) = line.strip_prefix("\"Program\" = ") {
+            break rest.to_string();
+        }
+        if line.starts_with("\"ProgramArguments\"") {
+            break lines.next()?.to_string();
+        }
+    };
+    let path = path.trim_end_matches([';', ',']).trim_matches('"');
+    std::path::Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
 }
 
 // ── Windows: sc.exe ───────────────────────────────────────────────────
@@ -520,6 +968,359 @@ impl ServiceManager for WindowsServiceManager {
     async fn is_installed(&self, svc: &ServiceEntry) -> bool {
         matches!(self.status(svc).await, ServiceStatus::Running | ServiceStatus::Stopped)
     }
+
+    async fn is_enabled(&self, svc: &ServiceEntry) -> Option<bool> {
+        let name = Self::svc_name(svc)?;
+        let out = tokio::process::Command::new("sc.exe")
+            .args(["qc", &name])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .ok()?;
+        if !out.status.success() {
+            return None;
+        }
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        let start_type = stdout.lines().find(|l| l.contains("START_TYPE"))?;
+        Some(start_type.contains("AUTO_START"))
+    }
+
+    async fn set_enabled(&self, svc: &ServiceEntry, enabled: bool) -> Result<(), String> {
+        let name = Self::svc_name(svc)
+            .ok_or_else(|| format!("No Windows service name for '{}'", svc.id))?;
+        let start = if enabled { "auto" } else { "demand" };
+        let out = tokio::process::Command::new("sc.exe")
+            .args(["config", &name, "start=", start])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|e| format!("sc.exe error: {}", e))?;
+        if out.status.success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "sc config {} failed: {}",
+                name,
+                String::from_utf8_lossy(&out.stdout)
+            ))
+        }
+    }
+
+    async fn main_pid(&self, svc: &ServiceEntry) -> Option<u32> {
+        let name = Self::svc_name(svc)?;
+        let out = tokio::process::Command::new("sc.exe")
+            .args(["queryex", &name])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .ok()?;
+        String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .find(|l| l.trim_start().starts_with("PID"))
+            .and_then(|l| l.split(':').nth(1))
+            .and_then(|pid| pid.trim().parse().ok())
+            .filter(|&pid| pid != 0)
+    }
+
+    /// Registers with `sc.exe create` (needs an elevated app). The executable
+    /// must implement the Windows service protocol; `working_dir` is ignored.
+    async fn install(&self, def: &ServiceDefinition) -> Result<PlatformServiceConfig, String> {
+        let name = def.id.clone();
+        let bin_path = std::iter::once(def.exec.as_str())
+            .chain(def.args.iter().map(String::as_str))
+            .map(quote_windows_arg)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let sc = |args: Vec<String>| async move {
+            let out = tokio::process::Command::new("sc.exe")
+                .args(&args)
+                .kill_on_drop(true)
+                .output()
+                .await
+                .map_err(|e| format!("sc.exe error: {}", e))?;
+            if out.status.success() {
+                Ok(())
+            } else {
+                Err(format!(
+                    "sc {} failed: {}",
+                    args[0],
+                    String::from_utf8_lossy(&out.stdout).trim()
+                ))
+            }
+        };
+        sc(vec![
+            "create".into(), name.clone(),
+            "binPath=".into(), bin_path,
+            "start=".into(), "demand".into(),
+            "DisplayName=".into(), def.name.clone(),
+        ])
+        .await?;
+        if def.restart != "no" {
+            let delay_ms = (def.restart_sec * 1000).to_string();
+            sc(vec![
+                "failure".into(), name.clone(),
+                "reset=".into(), "86400".into(),
+                "actions=".into(), format!("restart/{}", delay_ms),
+            ])
+            .await?;
+        }
+        if !def.env.is_empty() {
+            let vars: Vec<String> = def.env.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            let key = format!(r"HKLM\SYSTEM\CurrentControlSet\Services\{}", name);
+            let out = tokio::process::Command::new("reg")
+                .args(["add", &key, "/v", "Environment", "/t", "REG_MULTI_SZ", "/d", &vars.join("\\0"), "/f"])
+                .kill_on_drop(true)
+                .output()
+                .await
+                .map_err(|e| format!("reg error: {}", e))?;
+            if !out.status.success() {
+                return Err(format!(
+                    "Failed to set service environment: {}",
+                    String::from_utf8_lossy(&out.stderr)
+                ));
+            }
+        }
+        Ok(PlatformServiceConfig {
+            svc_type: "windows-service".into(),
+            unit: None,
+            label: None,
+            name: Some(name),
+        })
+    }
+
+    async fn logs(
+        &self,
+        svc: &ServiceEntry,
+        lines: usize,
+        since: Option<&str>,
+    ) -> Result<Vec<ServiceLogLine>, String> {
+        // Events written by the service itself plus Service Control Manager
+        // entries that mention it. Inputs go through env vars to avoid quoting.
+        const SCRIPT: &str = "\
+$f = @{ LogName = 'Application','System' }; \
+if ($env:WINTER_LOG_SINCE) { $f.StartTime = [datetime]$env:WINTER_LOG_SINCE }; \
+$n = $env:WINTER_LOG_SERVICE; \
+@(Get-WinEvent -FilterHashtable $f -MaxEvents 5000 -ErrorAction SilentlyContinue | \
+  Where-Object { $_.ProviderName -eq $n -or ($_.ProviderName -eq 'Service Control Manager' -and $_.Message -like \"*$n*\") } | \
+  Select-Object -First ([int]$env:WINTER_LOG_LINES) | \
+  ForEach-Object { @{ t = $_.TimeCreated.ToString('o'); l = [int]$_.Level; m = $_.Message } }) | ConvertTo-Json -Compress";
+        let name = Self::svc_name(svc)
+            .ok_or_else(|| format!("No Windows service name for '{}'", svc.id))?;
+        let out = tokio::process::Command::new("powershell")
+            .args(["-NoProfile", "-NonInteractive", "-Command", SCRIPT])
+            .env("WINTER_LOG_SERVICE", &name)
+            .env("WINTER_LOG_LINES", lines.to_string())
+            .env("WINTER_LOG_SINCE", since.unwrap_or_default())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|e| format!("powershell error: {}", e))?;
+        if !out.status.success() {
+            return Err(format!(
+                "Get-WinEvent for {} failed: {}",
+                name,
+                String::from_utf8_lossy(&out.stderr)
+            ));
+        }
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        if stdout.trim().is_empty() {
+            return Ok(vec![]);
+        }
+        // ConvertTo-Json emits a bare object for a single event.
+        let events = match serde_json::from_str::<serde_json::Value>(&stdout)
+            .map_err(|e| format!("Failed to parse event log output: {}", e))?
+        {
+            serde_json::Value::Array(items) => items,
+            other => vec![other],
+        };
+        // Get-WinEvent returns newest first.
+        Ok(events
+            .iter()
+            .rev()
+            .map(|e| ServiceLogLine {
+                timestamp: e.get("t").and_then(|t| t.as_str()).map(String::from),
+                level: e.get("l").and_then(|l| l.as_u64()).map(|l| match l {
+                    1 | 2 => "error",
+                    3 => "warning",
+                    5 => "debug",
+                    _ => "info",
+                }.to_string()),
+                message: e.get("m").and_then(|m| m.as_str()).unwrap_or_default().to_string(),
+            })
+            .collect())
+    }
+}
+
+// ── Docker: bollard ───────────────────────────────────────────────────
+
+pub struct DockerServiceManager;
+
+impl DockerServiceManager {
+    fn container(svc: &ServiceEntry) -> Result<String, String> {
+        svc.container
+            .clone()
+            .filter(|c| !c.is_empty())
+            .ok_or_else(|| format!("No container configured for '{}'", svc.id))
+    }
+
+    fn client() -> Result<bollard::Docker, String> {
+        bollard::Docker::connect_with_local_defaults()
+            .map_err(|e| format!("Cannot connect to Docker: {}", e))
+    }
+
+    async fn inspect(
+        svc: &ServiceEntry,
+    ) -> Result<bollard::models::ContainerInspectResponse, bollard::errors::Error> {
+        let name = svc.container.clone().unwrap_or_default();
+        let docker = bollard::Docker::connect_with_local_defaults()?;
+        docker.inspect_container(&name, None).await
+    }
+}
+
+fn is_not_found(e: &bollard::errors::Error) -> bool {
+    matches!(
+        e,
+        bollard::errors::Error::DockerResponseServerError { status_code: 404, .. }
+    )
+}
+
+#[async_trait::async_trait]
+impl ServiceManager for DockerServiceManager {
+    async fn status(&self, svc: &ServiceEntry) -> ServiceStatus {
+        match Self::inspect(svc).await {
+            Ok(info) if info.state.as_ref().and_then(|s| s.running) == Some(true) => ServiceStatus::Running,
+            Ok(_) => ServiceStatus::Stopped,
+            Err(e) if is_not_found(&e) => ServiceStatus::NotInstalled,
+            Err(_) => ServiceStatus::Unknown,
+        }
+    }
+
+    async fn start(&self, svc: &ServiceEntry) -> Result<(), String> {
+        let name = Self::container(svc)?;
+        Self::client()?
+            .start_container::<String>(&name, None)
+            .await
+            .map_err(|e| format!("docker start {} failed: {}", name, e))
+    }
+
+    async fn stop(&self, svc: &ServiceEntry) -> Result<(), String> {
+        let name = Self::container(svc)?;
+        Self::client()?
+            .stop_container(&name, None)
+            .await
+            .map_err(|e| format!("docker stop {} failed: {}", name, e))
+    }
+
+    async fn restart(&self, svc: &ServiceEntry) -> Result<(), String> {
+        let name = Self::container(svc)?;
+        Self::client()?
+            .restart_container(&name, None)
+            .await
+            .map_err(|e| format!("docker restart {} failed: {}", name, e))
+    }
+
+    async fn is_installed(&self, svc: &ServiceEntry) -> bool {
+        Self::inspect(svc).await.is_ok()
+    }
+
+    /// A container "starts at boot" when its restart policy brings it back
+    /// with the Docker daemon.
+    async fn is_enabled(&self, svc: &ServiceEntry) -> Option<bool> {
+        use bollard::models::RestartPolicyNameEnum;
+        let info = Self::inspect(svc).await.ok()?;
+        let policy = info.host_config?.restart_policy?.name?;
+        Some(matches!(
+            policy,
+            RestartPolicyNameEnum::ALWAYS | RestartPolicyNameEnum::UNLESS_STOPPED
+        ))
+    }
+
+    async fn set_enabled(&self, svc: &ServiceEntry, enabled: bool) -> Result<(), String> {
+        use bollard::models::{RestartPolicy, RestartPolicyNameEnum};
+        let name = Self::container(svc)?;
+        let policy = RestartPolicy {
+            name: Some(if enabled {
+                RestartPolicyNameEnum::UNLESS_STOPPED
+            } else {
+                RestartPolicyNameEnum::NO
+            }),
+            maximum_retry_count: None,
+        };
+        let options = bollard::container::UpdateContainerOptions::<String> {
+            restart_policy: Some(policy),
+            ..Default::default()
+        };
+        Self::client()?
+            .update_container(&name, options)
+            .await
+            .map_err(|e| format!("docker update {} failed: {}", name, e))
+    }
+
+    /// Host PID of the container's init process. Under Docker Desktop this is
+    /// a PID inside the VM, so resource sampling finds nothing.
+    async fn main_pid(&self, svc: &ServiceEntry) -> Option<u32> {
+        let pid = Self::inspect(svc).await.ok()?.state?.pid?;
+        u32::try_from(pid).ok().filter(|&p| p != 0)
+    }
+
+    async fn install(&self, def: &ServiceDefinition) -> Result<PlatformServiceConfig, String> {
+        Err(format!(
+            "Containers are not created by Winter; add '{}' with an existing container name instead",
+            def.id
+        ))
+    }
+
+    async fn logs(
+        &self,
+        svc: &ServiceEntry,
+        lines: usize,
+        since: Option<&str>,
+    ) -> Result<Vec<ServiceLogLine>, String> {
+        use bollard::container::{LogOutput, LogsOptions};
+        use futures::StreamExt;
+
+        let name = Self::container(svc)?;
+        let since = since
+            .and_then(|s| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok())
+            .and_then(|t| t.and_local_timezone(chrono::Local).earliest())
+            .map(|t| t.timestamp())
+            .unwrap_or(0);
+        let options = LogsOptions {
+            stdout: true,
+            stderr: true,
+            timestamps: true,
+            since,
+            tail: lines.to_string(),
+            ..Default::default()
+        };
+        let docker = Self::client()?;
+        let mut stream = docker.logs(&name, Some(options));
+        let mut entries = Vec::new();
+        while let Some(item) = stream.next().await {
+            let output = item.map_err(|e| format!("docker logs {} failed: {}", name, e))?;
+            let (bytes, is_stderr) = match output {
+                LogOutput::StdOut { message } | LogOutput::Console { message } => (message, false),
+                LogOutput::StdErr { message } => (message, true),
+                LogOutput::StdIn { .. } => continue,
+            };
+            let text = String::from_utf8_lossy(&bytes);
+            for line in text.lines() {
+                // With `timestamps` every line starts with an RFC 3339 time and a space.
+                let (timestamp, message) = match line.split_once(' ') {
+                    Some((ts, rest)) if chrono::DateTime::parse_from_rfc3339(ts).is_ok() => {
+                        (Some(ts.to_string()), rest)
+                    }
+                    _ => (None, line),
+                };
+                entries.push(ServiceLogLine {
+                    timestamp,
+                    level: is_stderr.then(|| "error".to_string()),
+                    message: message.to_string(),
+                });
+            }
+        }
+        Ok(entries)
+    }
 }
 
 // ── Noop: iOS/Android ─────────────────────────────────────────────────
@@ -543,6 +1344,174 @@ impl ServiceManager for NoopServiceManager {
     async fn is_installed(&self, _svc: &ServiceEntry) -> bool {
         false
     }
+    async fn is_enabled(&self, _svc: &ServiceEntry) -> Option<bool> {
+        None
+    }
+    async fn set_enabled(&self, svc: &ServiceEntry, _enabled: bool) -> Result<(), String> {
+        Err(format!("Service management not supported on this platform ({})", svc.id))
+    }
+
+    async fn main_pid(&self, _svc: &ServiceEntry) -> Option<u32> {
+        None
+    }
+
+    async fn install(&self, def: &ServiceDefinition) -> Result<PlatformServiceConfig, String> {
+        Err(format!("Service management not supported on this platform ({})", def.id))
+    }
+
+    async fn logs(
+        &self,
+        svc: &ServiceEntry,
+        _lines: usize,
+        _since: Option<&str>,
+    ) -> Result<Vec<ServiceLogLine>, String> {
+        Err(format!("Service management not supported on this platform ({})", svc.id))
+    }
+}
+
+// ── Service definition templates ──────────────────────────────────────
+
+/// Escapes systemd specifiers (`%n`, `%h`, ...) so `%` is taken literally.
+fn escape_specifiers(text: &str) -> String {
+    text.replace('%', "%%")
+}
+
+/// Quotes an ExecStart/Environment word for systemd; `%` is a specifier there.
+fn quote_systemd(word: &str) -> String {
+    let escaped = escape_specifiers(word);
+    if !escaped.is_empty() && !escaped.contains(|c: char| c.is_whitespace() || c == '"' || c == '\\' || c == '\'') {
+        return escaped;
+    }
+    format!("\"{}\"", escaped.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn systemd_unit(def: &ServiceDefinition) -> String {
+    // ExecStart also expands `$VAR`/`${VAR}`, so `$` is doubled there (not in Environment=).
+    let exec = std::iter::once(def.exec.as_str())
+        .chain(def.args.iter().map(String::as_str))
+        .map(|word| quote_systemd(&word.replace('$', "$$")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut unit = format!(
+        "# Generated by Winter. Edits are overwritten on reinstall.\n\
+         [Unit]\nDescription={}\n\n[Service]\nType=simple\nExecStart={}\n",
+        escape_specifiers(&def.name),
+        exec
+    );
+    if let Some(dir) = def.working_dir.as_deref().filter(|d| !d.is_empty()) {
+        unit.push_str(&format!("WorkingDirectory={}\n", escape_specifiers(dir)));
+    }
+    for (key, value) in &def.env {
+        unit.push_str(&format!("Environment={}\n", quote_systemd(&format!("{}={}", key, value))));
+    }
+    unit.push_str(&format!(
+        "Restart={}\nRestartSec={}\n\n[Install]\nWantedBy=default.target\n",
+        def.restart, def.restart_sec
+    ));
+    unit
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn launchd_plist(label: &str, def: &ServiceDefinition) -> String {
+    let string = |s: &str| format!("<string>{}</string>", xml_escape(s));
+    let mut body = format!("  <key>Label</key>\n  {}\n  <key>ProgramArguments</key>\n  <array>\n", string(label));
+    for arg in std::iter::once(&def.exec).chain(def.args.iter()) {
+        body.push_str(&format!("    {}\n", string(arg)));
+    }
+    body.push_str("  </array>\n");
+    if let Some(dir) = def.working_dir.as_deref().filter(|d| !d.is_empty()) {
+        body.push_str(&format!("  <key>WorkingDirectory</key>\n  {}\n", string(dir)));
+    }
+    if !def.env.is_empty() {
+        body.push_str("  <key>EnvironmentVariables</key>\n  <dict>\n");
+        for (key, value) in &def.env {
+            body.push_str(&format!("    <key>{}</key>\n    {}\n", xml_escape(key), string(value)));
+        }
+        body.push_str("  </dict>\n");
+    }
+    let keep_alive = match def.restart.as_str() {
+        "always" => "<true/>".to_string(),
+        "on-failure" => "<dict>\n    <key>SuccessfulExit</key>\n    <false/>\n  </dict>".to_string(),
+        _ => "<false/>".to_string(),
+    };
+    body.push_str(&format!(
+        "  <key>KeepAlive</key>\n  {}\n  <key>ThrottleInterval</key>\n  <integer>{}</integer>\n  <key>RunAtLoad</key>\n  <true/>\n",
+        keep_alive, def.restart_sec
+    ));
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+         <plist version=\"1.0\">\n<dict>\n{}</dict>\n</plist>\n",
+        body
+    )
+}
+
+/// Quotes a word for a Windows command line (`binPath=`).
+fn quote_windows_arg(word: &str) -> String {
+    if !word.is_empty() && !word.contains(|c: char| c.is_whitespace() || c == '"') {
+        return word.to_string();
+    }
+    format!("\"{}\"", word.replace('"', "\\\""))
+}
+
+fn validate_definition(def: &ServiceDefinition) -> Result<(), String> {
+    if def.id.is_empty()
+        || !def.id.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err("Service ID may only contain letters, digits, '-', '_' and '.'".to_string());
+    }
+    if def.name.trim().is_empty() {
+        return Err("Service name cannot be empty".to_string());
+    }
+    if !std::path::Path::new(&def.exec).is_absolute() {
+        return Err(format!("Executable must be an absolute path: '{}'", def.exec));
+    }
+    if !["always", "on-failure", "no"].contains(&def.restart.as_str()) {
+        return Err(format!(
+            "Invalid restart policy '{}'. Must be always, on-failure, or no",
+            def.restart
+        ));
+    }
+    if def.env.keys().any(|k| k.is_empty() || k.contains('=')) {
+        return Err("Environment variable names cannot be empty or contain '='".to_string());
+    }
+    // Unit files and plists are line/markup based; a newline would inject directives.
+    let has_control = |s: &str| s.chars().any(char::is_control);
+    if has_control(&def.name)
+        || has_control(&def.exec)
+        || def.args.iter().any(|a| has_control(a))
+        || def.working_dir.as_deref().is_some_and(has_control)
+        || def.env.iter().any(|(k, v)| has_control(k) || has_control(v))
+    {
+        return Err("Service name, command, arguments, working directory and environment cannot contain control characters".to_string());
+    }
+    Ok(())
+}
+
+/// Stores `config` in the slot for the platform the app is running on.
+fn set_current_platform(map: &mut ServicePlatformMap, config: PlatformServiceConfig) {
+    #[cfg(target_os = "linux")]
+    {
+        map.linux = Some(config);
+    }
+    #[cfg(target_os = "macos")]
+    {
+        map.macos = Some(config);
+    }
+    #[cfg(target_os = "windows")]
+    {
+        map.windows = Some(config);
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        let _ = (map, config);
+    }
 }
 
 // ── Factory ───────────────────────────────────────────────────────────
@@ -566,79 +1535,551 @@ pub fn create_service_manager() -> Box<dyn ServiceManager> {
     }
 }
 
+// ── Resource usage ────────────────────────────────────────────────────
+
+/// Samples CPU, memory and uptime for `pids`. CPU usage needs two refreshes
+/// apart, so this blocks for about 200ms; call from `spawn_blocking`.
+fn sample_resources(pids: &[u32]) -> HashMap<u32, ServiceResources> {
+    use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+    if pids.is_empty() {
+        return HashMap::new();
+    }
+    let targets: Vec<Pid> = pids.iter().map(|&p| Pid::from_u32(p)).collect();
+    let kind = ProcessRefreshKind::new().with_cpu().with_memory();
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(ProcessesToUpdate::Some(&targets), true, kind);
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    sys.refresh_processes_specifics(ProcessesToUpdate::Some(&targets), true, kind);
+
+    pids.iter()
+        .filter_map(|&pid| {
+            let process = sys.process(Pid::from_u32(pid))?;
+            Some((pid, ServiceResources {
+                pid,
+                cpu_percent: process.cpu_usage(),
+                memory_bytes: process.memory(),
+                uptime_secs: process.run_time(),
+            }))
+        })
+        .collect()
+}
+
+// ── Watchdog ──────────────────────────────────────────────────────────
+
+/// Interval between watchdog status checks.
+const WATCHDOG_INTERVAL: Duration = Duration::from_secs(20);
+
+/// A service that stays up this long after a restart gets its restart budget back.
+const WATCHDOG_STABLE_AFTER: Duration = Duration::from_secs(300);
+
+/// Upper bound for the doubling backoff between restart attempts.
+const WATCHDOG_MAX_BACKOFF: Duration = Duration::from_secs(3600);
+
+/// What the watchdog last saw of one service.
+#[derive(Default)]
+struct Tracked {
+    last_status: Option<ServiceStatus>,
+    restarts: u32,
+    last_restart: Option<Instant>,
+    next_attempt: Option<Instant>,
+    gave_up: bool,
+    /// Stopped from the app; the watchdog leaves it alone until started again.
+    manually_stopped: bool,
+}
+
+#[derive(Default, Clone)]
+pub struct WatchdogState {
+    tracked: Arc<std::sync::Mutex<HashMap<String, Tracked>>>,
+}
+
+/// Payload of the `service-watchdog` event.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchdogEvent {
+    pub service_id: String,
+    /// "restarting", "restarted", "restart-failed", "gave-up" or "recovered".
+    pub action: String,
+    pub attempt: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn emit_watchdog(app: &AppHandle, service_id: &str, action: &str, attempt: u32, error: Option<String>) {
+    let _ = app.emit("service-watchdog", WatchdogEvent {
+        service_id: service_id.to_string(),
+        action: action.to_string(),
+        attempt,
+        error,
+    });
+}
+
+/// Records a start/stop issued from the app so a deliberate stop is not
+/// mistaken for a crash, and a manual start resets the restart budget.
+fn note_manual_action(app: &AppHandle, id: &str, stopped: bool) {
+    let Some(state) = app.try_state::<WatchdogState>() else { return };
+    let mut tracked = state.tracked.lock().unwrap_or_else(|e| e.into_inner());
+    let entry = tracked.entry(id.to_string()).or_default();
+    entry.manually_stopped = stopped;
+    if !stopped {
+        entry.restarts = 0;
+        entry.gave_up = false;
+        entry.next_attempt = None;
+    }
+}
+
+/// Whether `id` was last stopped from the app and not started again since.
+pub fn is_manually_stopped(app: &AppHandle, id: &str) -> bool {
+    let Some(state) = app.try_state::<WatchdogState>() else { return false };
+    let tracked = state.tracked.lock().unwrap_or_else(|e| e.into_inner());
+    tracked.get(id).is_some_and(|t| t.manually_stopped)
+}
+
+/// Decides whether `svc` needs a restart now and updates its bookkeeping.
+/// Returns the attempt number to make, if any.
+fn watchdog_step(
+    app: &AppHandle,
+    state: &WatchdogState,
+    svc: &ServiceEntry,
+    policy: &WatchdogPolicy,
+    status: ServiceStatus,
+) -> Option<u32> {
+    let mut tracked = state.tracked.lock().unwrap_or_else(|e| e.into_inner());
+    let entry = tracked.entry(svc.id.clone()).or_default();
+    let previous = entry.last_status.replace(status.clone());
+    let now = Instant::now();
+
+    match status {
+        ServiceStatus::Running => {
+            entry.manually_stopped = false;
+            if entry.last_restart.is_some_and(|t| now.duration_since(t) >= WATCHDOG_STABLE_AFTER) {
+                if entry.gave_up {
+                    emit_watchdog(app, &svc.id, "recovered", entry.restarts, None);
+                }
+                entry.restarts = 0;
+                entry.gave_up = false;
+                entry.last_restart = None;
+                entry.next_attempt = None;
+            }
+            None
+        }
+        ServiceStatus::Stopped => {
+            // Only a service seen running (or already being recovered) counts as crashed.
+            let crashed = !entry.manually_stopped
+                && (previous == Some(ServiceStatus::Running) || entry.restarts > 0);
+            if !crashed || entry.gave_up || entry.next_attempt.is_some_and(|t| now < t) {
+                return None;
+            }
+            if entry.restarts >= policy.max_restarts {
+                entry.gave_up = true;
+                emit_watchdog(app, &svc.id, "gave-up", entry.restarts, None);
+                return None;
+            }
+            entry.restarts += 1;
+            entry.last_restart = Some(now);
+            let backoff = Duration::from_secs(policy.backoff_secs)
+                .saturating_mul(2u32.saturating_pow(entry.restarts - 1))
+                .min(WATCHDOG_MAX_BACKOFF);
+            entry.next_attempt = Some(now + backoff);
+            Some(entry.restarts)
+        }
+        _ => None,
+    }
+}
+
+/// Spawns the background loop that restarts crashed services which have a
+/// `watchdog` policy, emitting `service-watchdog` for every action taken.
+/// Stops made outside the app (e.g. `systemctl stop`) look like crashes.
+pub fn spawn_watchdog(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(WATCHDOG_INTERVAL).await;
+            let state = app.state::<WatchdogState>().inner().clone();
+            let Ok(services) = read_service_registry(&app) else { continue };
+            for svc in &services {
+                let Some(policy) = &svc.watchdog else { continue };
+                let manager = manager_for(svc);
+                let status = tokio::time::timeout(STATUS_CHECK_TIMEOUT, manager.status(svc))
+                    .await
+                    .unwrap_or(ServiceStatus::Unknown);
+                let Some(attempt) = watchdog_step(&app, &state, svc, policy, status) else {
+                    continue;
+                };
+                emit_watchdog(&app, &svc.id, "restarting", attempt, None);
+                match manager.restart(svc).await {
+                    Ok(()) => emit_watchdog(&app, &svc.id, "restarted", attempt, None),
+                    Err(e) => {
+                        tracing::warn!("Watchdog restart of '{}' failed: {}", svc.id, e);
+                        emit_watchdog(&app, &svc.id, "restart-failed", attempt, Some(e));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// The manager responsible for `svc`: Docker for container-backed entries,
+/// the platform service manager otherwise.
+pub fn manager_for(svc: &ServiceEntry) -> Box<dyn ServiceManager> {
+    if svc.container.as_deref().is_some_and(|c| !c.is_empty()) {
+        Box::new(DockerServiceManager)
+    } else {
+        create_service_manager()
+    }
+}
+
 // ── Registry I/O ─────────────────────────────────────────────────────
 
-fn registry_path(app: &AppHandle) -> Result<PathBuf, String> {
-    let data_dir = app
-        .path()
+fn data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
         .app_data_dir()
-        .map_err(|e| format!("Cannot get app data dir: {}", e))?;
-    Ok(data_dir.join("scheduler-registry.json"))
+        .map_err(|e| format!("Cannot get app data dir: {}", e))
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
-struct CombinedRegistry {
-    #[serde(default)]
-    tasks: Vec<serde_json::Value>,
+fn registry_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(data_dir(app)?.join("services-registry.json"))
+}
+
+/// Services used to live in scheduler-registry.json under a `services` key.
+/// `None` means that key was never written.
+#[derive(Debug, Deserialize, Default)]
+struct LegacyCombinedRegistry {
     #[serde(default)]
-    services: Vec<ServiceEntry>,
+    services: Option<Vec<ServiceEntry>>,
 }
 
-fn read_service_registry(app: &AppHandle) -> Result<Vec<ServiceEntry>, String> {
+/// Copies services out of a pre-split scheduler-registry.json, if it has any.
+/// The scheduler drops the stale key on its next write.
+fn migrate_legacy_registry(app: &AppHandle) -> Result<Option<Vec<ServiceEntry>>, String> {
+    let legacy = data_dir(app)?.join("scheduler-registry.json");
+    let Ok(content) = std::fs::read_to_string(&legacy) else {
+        return Ok(None);
+    };
+    let Some(services) = serde_json::from_str::<LegacyCombinedRegistry>(&content)
+        .ok()
+        .and_then(|r| r.services)
+    else {
+        return Ok(None);
+    };
+    write_services_to_registry(app, &services)?;
+    Ok(Some(services))
+}
+
+pub(crate) fn read_service_registry(app: &AppHandle) -> Result<Vec<ServiceEntry>, String> {
     let path = registry_path(app)?;
     if !path.exists() {
-        return Ok(default_services());
+        return Ok(migrate_legacy_registry(app)?.unwrap_or_else(default_services));
     }
     let content = std::fs::read_to_string(&path)
-        .map_err(|e| format!("Failed to read registry: {}", e))?;
-    let combined: CombinedRegistry = serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse registry: {}", e))?;
-    if combined.services.is_empty() {
-        Ok(default_services())
-    } else {
-        Ok(combined.services)
-    }
+        .map_err(|e| format!("Failed to read services registry: {}", e))?;
+    let registry: ServiceRegistry = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse services registry: {}", e))?;
+    Ok(registry.services)
 }
 
-#[allow(dead_code)]
 fn write_services_to_registry(app: &AppHandle, services: &[ServiceEntry]) -> Result<(), String> {
     let path = registry_path(app)?;
-    let mut combined: CombinedRegistry = if path.exists() {
-        let content = std::fs::read_to_string(&path)
-            .map_err(|e| format!("Failed to read registry: {}", e))?;
-        serde_json::from_str(&content).unwrap_or_default()
-    } else {
-        CombinedRegistry::default()
-    };
-    combined.services = services.to_vec();
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)
             .map_err(|e| format!("Failed to create registry dir: {}", e))?;
     }
-    let json = serde_json::to_string_pretty(&combined)
-        .map_err(|e| format!("Failed to serialize registry: {}", e))?;
-    std::fs::write(&path, json).map_err(|e| format!("Failed to write registry: {}", e))
+    let registry = ServiceRegistry { services: services.to_vec() };
+    let json = serde_json::to_string_pretty(&registry)
+        .map_err(|e| format!("Failed to serialize services registry: {}", e))?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, &json).map_err(|e| format!("Failed to write temp registry: {}", e))?;
+    std::fs::rename(&tmp, &path).map_err(|e| format!("Failed to commit registry: {}", e))
+}
+
+fn validate_service(svc: &ServiceEntry) -> Result<(), String> {
+    if svc.id.trim().is_empty() {
+        return Err("Service ID cannot be empty".to_string());
+    }
+    if svc.name.trim().is_empty() {
+        return Err("Service name cannot be empty".to_string());
+    }
+    let p = &svc.platform;
+    let configured = svc.container.as_deref().is_some_and(|c| !c.is_empty())
+        || p.linux.as_ref().is_some_and(|c| c.unit.as_deref().is_some_and(|u| !u.is_empty()))
+        || p.macos.as_ref().is_some_and(|c| c.label.as_deref().is_some_and(|l| !l.is_empty()))
+        || p.windows.as_ref().is_some_and(|c| c.name.as_deref().is_some_and(|n| !n.is_empty()));
+    if !configured {
+        return Err(format!(
+            "Service '{}' needs a container, systemd unit, launchd label or Windows service name",
+            svc.id
+        ));
+    }
+    Ok(())
 }
 
 // ── Tauri Commands ────────────────────────────────────────────────────
 
-#[tauri::command]
-pub async fn get_services_status(app: AppHandle) -> Result<Vec<ServiceStatusInfo>, String> {
-    let services = read_service_registry(&app)?;
-    let manager = create_service_manager();
+/// Per-service budget for the status, enabled and PID checks together.
+const STATUS_CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Status, boot-enabled state and main PID of one service.
+async fn probe_service(
+    manager: &dyn ServiceManager,
+    svc: &ServiceEntry,
+) -> (ServiceStatus, Option<bool>, Option<u32>) {
+    let status = manager.status(svc).await;
+    let enabled = if status != ServiceStatus::Unsupported {
+        manager.is_enabled(svc).await
+    } else {
+        None
+    };
+    let pid = if status == ServiceStatus::Running {
+        manager.main_pid(svc).await
+    } else {
+        None
+    };
+    (status, enabled, pid)
+}
+
+/// Timeout of one HTTP health probe.
+const HEALTH_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+async fn probe_health(client: &reqwest::Client, url: &str) -> HealthProbe {
+    let started = Instant::now();
+    let result = client.get(url).send().await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+    match result {
+        Ok(resp) => {
+            let status_code = resp.status().as_u16();
+            let mut healthy = resp.status().is_success();
+            // Endpoints like OpenCode's `/global/health` answer 200 with `{"healthy": false}`.
+            if healthy {
+                if let Ok(body) = resp.json::<serde_json::Value>().await {
+                    if let Some(flag) = body.get("healthy").and_then(|v| v.as_bool()) {
+                        healthy = flag;
+                    }
+                }
+            }
+            HealthProbe {
+                healthy,
+                status_code: Some(status_code),
+                latency_ms,
+                error: None,
+            }
+        }
+        Err(e) => HealthProbe {
+            healthy: false,
+            status_code: None,
+            latency_ms,
+            error: Some(if e.is_timeout() { "timed out".to_string() } else { e.to_string() }),
+        },
+    }
+}
+
+/// Status, enabled state and health of every service, checked concurrently.
+/// Resource usage is left empty; callers that want it sample it afterwards.
+async fn probe_all(services: &[ServiceEntry]) -> Result<(Vec<ServiceStatusInfo>, Vec<Option<u32>>), String> {
+    let client = reqwest::Client::builder()
+        .timeout(HEALTH_PROBE_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+
+    // A slow manager call only costs that service its status, not the whole panel.
+    let probes = futures::future::join_all(services.iter().map(|svc| async {
+        let manager = manager_for(svc);
+        let status = tokio::time::timeout(STATUS_CHECK_TIMEOUT, probe_service(manager.as_ref(), svc));
+        let health = async {
+            match svc.health_url.as_deref().filter(|u| !u.is_empty()) {
+                Some(url) => Some(probe_health(&client, url).await),
+                None => None,
+            }
+        };
+        let (status, health) = tokio::join!(status, health);
+        (status.unwrap_or((ServiceStatus::Unknown, None, None)), health)
+    }))
+    .await;
 
     let mut result = Vec::new();
-    for svc in &services {
-        let status = manager.status(svc).await;
-        let supported = status != ServiceStatus::Unsupported;
+    let mut pids = Vec::new();
+    for (svc, ((status, enabled, pid), health)) in services.iter().zip(probes) {
+        pids.push(pid);
         result.push(ServiceStatusInfo {
             id: svc.id.clone(),
             name: svc.name.clone(),
             category: svc.category.clone(),
+            supported: status != ServiceStatus::Unsupported,
             status,
-            supported,
+            enabled,
+            created_by_user: svc.created_by_user,
+            resources: None,
+            health,
         });
     }
+    Ok((result, pids))
+}
+
+/// Registry services that should be up but are not: a failing health probe,
+/// or stopped while set to start at boot or watched by the watchdog.
+pub(crate) async fn failing_services(app: &AppHandle) -> Result<Vec<ServiceStatusInfo>, String> {
+    let services = read_service_registry(app)?;
+    let (infos, _) = probe_all(&services).await?;
+    Ok(services
+        .iter()
+        .zip(infos)
+        .filter(|(svc, info)| {
+            let unhealthy = info.health.as_ref().is_some_and(|h| !h.healthy);
+            let expected = info.enabled == Some(true) || svc.watchdog.is_some();
+            unhealthy || (expected && info.status == ServiceStatus::Stopped)
+        })
+        .map(|(_, info)| info)
+        .collect())
+}
+
+// ── OpenCode bridge ──────────────────────────────────────────────────
+
+/// ID of the panel entry for the OpenCode server the chat bridge talks to.
+pub const OPENCODE_BRIDGE_ID: &str = "opencode-bridge";
+
+/// Status of the active OpenCode profile's server, so a dead bridge shows up in
+/// the panel even when nothing in the registry manages it. `None` when a
+/// registry service already health-checks the same URL.
+async fn opencode_bridge_status(app: &AppHandle, services: &[ServiceEntry]) -> Option<ServiceStatusInfo> {
+    let profile = crate::opencode::profiles::active(app);
+    let health_url = opencode_health_url(app, &profile).await;
+    if profile.ssh.is_none()
+        && services
+            .iter()
+            .any(|s| s.health_url.is_some() && s.health_url == health_url)
+    {
+        return None;
+    }
+
+    let health = probe_opencode_url(health_url.as_deref()).await;
+    Some(ServiceStatusInfo {
+        id: OPENCODE_BRIDGE_ID.to_string(),
+        name: format!("OpenCode ({})", profile.name),
+        category: "agent".to_string(),
+        status: if health.healthy { ServiceStatus::Running } else { ServiceStatus::Stopped },
+        // Only an SSH bridge can be (re)connected from here.
+        supported: profile.ssh.is_some(),
+        enabled: None,
+        created_by_user: false,
+        resources: None,
+        health: Some(health),
+    })
+}
+
+/// Health endpoint of `profile`'s server; `None` for an SSH profile whose
+/// tunnel is not open. Probing never opens a tunnel; that happens when the
+/// bridge is used or restarted.
+async fn opencode_health_url(app: &AppHandle, profile: &crate::opencode::profiles::OpenCodeProfile) -> Option<String> {
+    use crate::opencode::tunnel;
+
+    let base_url = match &profile.ssh {
+        Some(_) => tunnel::local_port(&app.state::<tunnel::SshTunnels>(), &profile.id)
+            .await
+            .map(|port| format!("http://127.0.0.1:{}", port)),
+        None => Some(profile.base_url.trim_end_matches('/').to_string()),
+    };
+    base_url.map(|base| format!("{}/global/health", base))
+}
+
+async fn probe_opencode_url(health_url: Option<&str>) -> HealthProbe {
+    match (health_url, reqwest::Client::builder().timeout(HEALTH_PROBE_TIMEOUT).build()) {
+        (Some(url), Ok(client)) => probe_health(&client, url).await,
+        (None, _) => HealthProbe {
+            healthy: false,
+            status_code: None,
+            latency_ms: 0,
+            error: Some("SSH tunnel is not open".to_string()),
+        },
+        (_, Err(e)) => HealthProbe {
+            healthy: false,
+            status_code: None,
+            latency_ms: 0,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Health of the active OpenCode profile's server, without opening a tunnel.
+pub(crate) async fn opencode_health(app: &AppHandle) -> HealthProbe {
+    let profile = crate::opencode::profiles::active(app);
+    probe_opencode_url(opencode_health_url(app, &profile).await.as_deref()).await
+}
+
+/// `control_service` for the bridge entry: reconnects or closes an SSH tunnel.
+async fn control_opencode_bridge(app: &AppHandle, action: &str) -> Result<(), String> {
+    use crate::opencode::{profiles, tunnel};
+
+    let profile = profiles::active(app);
+    if profile.ssh.is_none() {
+        return Err(format!(
+            "OpenCode at {} is not managed by Winter; add it as a service to control it",
+            profile.base_url
+        ));
+    }
+    tunnel::close(&app.state::<tunnel::SshTunnels>(), &profile.id).await;
+    if action != "stop" {
+        profiles::client(app, profile).await?;
+    }
+    Ok(())
+}
+
+/// Interval between background status polls.
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Payload of the `service-status-changed` event.
+#[derive(Debug, Serialize, Clone)]
+pub struct ServiceStatusChanged {
+    #[serde(flatten)]
+    pub info: ServiceStatusInfo,
+    pub previous_status: ServiceStatus,
+    /// Health before the change; `None` when the service has no health URL.
+    pub previous_healthy: Option<bool>,
+}
+
+/// Spawns the background loop that polls every service and emits
+/// `service-status-changed` when its status or health flips. The first poll
+/// only records a baseline.
+pub fn spawn_status_poller(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last: HashMap<String, (ServiceStatus, Option<bool>)> = HashMap::new();
+        loop {
+            if let Ok(services) = read_service_registry(&app) {
+                if let Ok((mut infos, _)) = probe_all(&services).await {
+                    infos.extend(opencode_bridge_status(&app, &services).await);
+                    let mut seen = HashMap::new();
+                    for info in infos {
+                        let current = (info.status.clone(), info.health.as_ref().map(|h| h.healthy));
+                        seen.insert(info.id.clone(), current.clone());
+                        match last.get(&info.id) {
+                            Some(previous) if *previous != current => {
+                                let (previous_status, previous_healthy) = previous.clone();
+                                let _ = app.emit("service-status-changed", ServiceStatusChanged {
+                                    info,
+                                    previous_status,
+                                    previous_healthy,
+                                });
+                            }
+                            _ => {}
+                        }
+                    }
+                    last = seen;
+                }
+            }
+            tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_services_status(app: AppHandle) -> Result<Vec<ServiceStatusInfo>, AppError> {
+    let services = read_service_registry(&app)?;
+    let (mut result, pids) = probe_all(&services).await?;
+
+    let running: Vec<u32> = pids.iter().flatten().copied().collect();
+    let mut usage = tokio::task::spawn_blocking(move || sample_resources(&running))
+        .await
+        .unwrap_or_default();
+    for (info, pid) in result.iter_mut().zip(pids) {
+        info.resources = pid.and_then(|p| usage.remove(&p));
+    }
+    result.extend(opencode_bridge_status(&app, &services).await);
     Ok(result)
 }
 
@@ -647,26 +2088,178 @@ pub async fn control_service(
     app: AppHandle,
     id: String,
     action: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let valid_actions = ["start", "stop", "restart"];
     if !valid_actions.contains(&action.as_str()) {
-        return Err(format!(
+        return Err(AppError::InvalidInput(format!(
             "Invalid action '{}'. Must be start, stop, or restart",
             action
-        ));
+        )));
+    }
+
+    if id == OPENCODE_BRIDGE_ID {
+        return Ok(control_opencode_bridge(&app, &action).await?);
     }
 
     let services = read_service_registry(&app)?;
     let svc = services
         .iter()
         .find(|s| s.id == id)
-        .ok_or_else(|| format!("Service '{}' not found", id))?;
+        .ok_or_else(|| AppError::NotFound(format!("Service '{}' not found", id)))?;
 
-    let manager = create_service_manager();
-    match action.as_str() {
+    let manager = manager_for(svc);
+    let result = match action.as_str() {
         "start" => manager.start(svc).await,
         "stop" => manager.stop(svc).await,
         "restart" => manager.restart(svc).await,
         _ => unreachable!(),
+    };
+    if result.is_ok() {
+        note_manual_action(&app, &id, action == "stop");
+    }
+    Ok(result?)
+}
+
+/// Turns the auto-restart watchdog on (with `policy`) or off (`None`) for a service.
+#[tauri::command]
+pub async fn set_service_watchdog(
+    app: AppHandle,
+    id: String,
+    policy: Option<WatchdogPolicy>,
+) -> Result<(), AppError> {
+    let mut services = read_service_registry(&app)?;
+    let svc = services
+        .iter_mut()
+        .find(|s| s.id == id)
+        .ok_or_else(|| AppError::NotFound(format!("Service '{}' not found", id)))?;
+    svc.watchdog = policy;
+    write_services_to_registry(&app, &services)?;
+    note_manual_action(&app, &id, false);
+    Ok(())
+}
+
+const DEFAULT_LOG_LINES: usize = 200;
+const MAX_LOG_LINES: usize = 5000;
+
+/// `since` is either a relative interval ("30m", "2h", "1d") or a local
+/// timestamp ("2024-05-01 09:00:00"); normalized to the latter.
+fn resolve_since(since: &str) -> Result<String, String> {
+    let since = since.trim();
+    if chrono::NaiveDateTime::parse_from_str(since, "%Y-%m-%d %H:%M:%S").is_ok() {
+        return Ok(since.to_string());
     }
+    let ago = crate::scheduler::parse_interval(since)
+        .map_err(|_| format!("Invalid since '{}': use e.g. 30m, 2h or 2024-05-01 09:00:00", since))?;
+    let ago = chrono::Duration::from_std(ago).map_err(|e| e.to_string())?;
+    Ok((chrono::Local::now() - ago).format("%Y-%m-%d %H:%M:%S").to_string())
+}
+
+#[tauri::command]
+pub async fn get_service_logs(
+    app: AppHandle,
+    id: String,
+    lines: Option<usize>,
+    since: Option<String>,
+) -> Result<Vec<ServiceLogLine>, AppError> {
+    let services = read_service_registry(&app)?;
+    let svc = services
+        .iter()
+        .find(|s| s.id == id)
+        .ok_or_else(|| AppError::NotFound(format!("Service '{}' not found", id)))?;
+    let lines = lines.unwrap_or(DEFAULT_LOG_LINES).clamp(1, MAX_LOG_LINES);
+    let since = since
+        .as_deref()
+        .filter(|s| !s.trim().is_empty())
+        .map(resolve_since)
+        .transpose()?;
+    Ok(manager_for(svc).logs(svc, lines, since.as_deref()).await?)
+}
+
+#[tauri::command]
+pub async fn set_service_enabled(app: AppHandle, id: String, enabled: bool) -> Result<(), AppError> {
+    let services = read_service_registry(&app)?;
+    let svc = services
+        .iter()
+        .find(|s| s.id == id)
+        .ok_or_else(|| AppError::NotFound(format!("Service '{}' not found", id)))?;
+    Ok(manager_for(svc).set_enabled(svc, enabled).await?)
+}
+
+/// Writes the platform service definition and adds (or updates) the registry entry.
+#[tauri::command]
+pub async fn install_service(app: AppHandle, definition: ServiceDefinition) -> Result<ServiceEntry, AppError> {
+    validate_definition(&definition)?;
+    let config = create_service_manager().install(&definition).await?;
+
+    let mut services = read_service_registry(&app)?;
+    let entry = match services.iter_mut().find(|s| s.id == definition.id) {
+        Some(existing) => {
+            set_current_platform(&mut existing.platform, config);
+            if definition.health_url.is_some() {
+                existing.health_url = definition.health_url.clone();
+            }
+            existing.clone()
+        }
+        None => {
+            let mut platform = ServicePlatformMap { linux: None, macos: None, windows: None };
+            set_current_platform(&mut platform, config);
+            let entry = ServiceEntry {
+                id: definition.id.clone(),
+                name: definition.name.clone(),
+                category: if definition.category.is_empty() {
+                    "custom".to_string()
+                } else {
+                    definition.category.clone()
+                },
+                created_by_user: true,
+                platform,
+                container: None,
+                health_url: definition.health_url.clone(),
+                watchdog: None,
+            };
+            services.push(entry.clone());
+            entry
+        }
+    };
+    write_services_to_registry(&app, &services)?;
+    Ok(entry)
+}
+
+#[tauri::command]
+pub async fn add_service(app: AppHandle, service: ServiceEntry) -> Result<(), AppError> {
+    let service = ServiceEntry { created_by_user: true, ..service };
+    validate_service(&service)?;
+    let mut services = read_service_registry(&app)?;
+    if services.iter().any(|s| s.id == service.id) {
+        return Err(AppError::InvalidInput(format!("Service '{}' already exists", service.id)));
+    }
+    services.push(service);
+    Ok(write_services_to_registry(&app, &services)?)
+}
+
+#[tauri::command]
+pub async fn update_service(app: AppHandle, id: String, service: ServiceEntry) -> Result<(), AppError> {
+    validate_service(&service)?;
+    let mut services = read_service_registry(&app)?;
+    let idx = services
+        .iter()
+        .position(|s| s.id == id)
+        .ok_or_else(|| AppError::NotFound(format!("Service '{}' not found", id)))?;
+    if service.id != id && services.iter().any(|s| s.id == service.id) {
+        return Err(AppError::InvalidInput(format!("Service '{}' already exists", service.id)));
+    }
+    let was_user_created = services[idx].created_by_user;
+    services[idx] = ServiceEntry { created_by_user: was_user_created, ..service };
+    Ok(write_services_to_registry(&app, &services)?)
+}
+
+#[tauri::command]
+pub async fn remove_service(app: AppHandle, id: String) -> Result<(), AppError> {
+    let mut services = read_service_registry(&app)?;
+    let idx = services
+        .iter()
+        .position(|s| s.id == id)
+        .ok_or_else(|| AppError::NotFound(format!("Service '{}' not found", id)))?;
+    services.remove(idx);
+    Ok(write_services_to_registry(&app, &services)?)
 }
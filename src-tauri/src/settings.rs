@@ -0,0 +1,345 @@
+/// Export and import of the settings store, for setting up another machine,
+/// and named settings profiles.
+///
+/// Secrets are never written to an export: sign-in tokens and API keys are
+/// left out entirely, and secrets nested in otherwise portable settings
+/// (bridge bot tokens, webhook auth headers, the feedback relay token) are
+/// blanked. On import, blanked secrets keep the value already configured on
+/// this machine. Per-machine state such as session bindings and the app's
+/// trash records is not exported either.
+///
+/// A settings profile ("work", "personal") bundles a model, system prompt
+/// additions, a tool policy and a working directory, stored under
+/// `settings_profiles`. Applying one writes its model and working directory
+/// and makes it the active profile (`active_settings_profile`), whose prompt
+/// additions and tool policy then apply alongside the active project's.
+/// `settings-profile-applied` is emitted so every view reloads.
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::projects::ToolPolicy;
+use crate::STORE_FILE;
+
+const STORE_KEY_PROFILES: &str = "settings_profiles";
+const STORE_KEY_ACTIVE_PROFILE: &str = "active_settings_profile";
+
+/// `format` field identifying an export file.
+const EXPORT_FORMAT: &str = "winter-settings";
+const EXPORT_VERSION: u32 = 1;
+
+/// Credentials, never exported or imported.
+const SECRET_KEYS: &[&str] = &[
+    "oauth_access_token",
+    "oauth_refresh_token",
+    "oauth_expires",
+    "claude_session_key",
+    "compaction_openai_key",
+    "backup_passphrase",
+];
+
+/// State that only makes sense on this machine.
+const LOCAL_KEYS: &[&str] = &[
+    "opencode_session_profiles",
+    "opencode_session_directories",
+    "conversation_modes",
+    "conversation_personalities",
+    "trashed_by_app",
+    "recent_directories",
+    "sync_state",
+    "remote_bridge_telegram_offset",
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SettingsExport {
+    format: String,
+    version: u32,
+    /// RFC 3339 export time.
+    exported_at: String,
+    app_version: String,
+    settings: Map<String, Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportSummary {
+    /// Settings written.
+    pub imported: usize,
+    /// Keys in the file that were ignored (secrets and per-machine state).
+    pub skipped: Vec<String>,
+}
+
+fn portable(key: &str) -> bool {
+    !SECRET_KEYS.contains(&key) && !LOCAL_KEYS.contains(&key)
+}
+
+/// Pointers (RFC 6901) of the secrets nested in a setting; `*` matches each array item.
+fn nested_secrets(key: &str) -> &'static [&'static str] {
+    match key {
+        "remote_bridge" => &["/telegram/token", "/discord/token"],
+        "webhooks" => &["/*/auth_header"],
+        "feedback" => &["/destination/token"],
+        "sync" => &["/password"],
+        _ => &[],
+    }
+}
+
+/// Expands a `/*/` pointer over the items of the array at its prefix.
+fn expand(value: &Value, pointer: &str) -> Vec<String> {
+    match pointer.split_once("/*") {
+        Some((prefix, rest)) => {
+            let len = value.pointer(prefix).and_then(Value::as_array).map_or(0, Vec::len);
+            (0..len).map(|i| format!("{}/{}{}", prefix, i, rest)).collect()
+        }
+        None => vec![pointer.to_string()],
+    }
+}
+
+fn is_blank(value: &Value) -> bool {
+    value.is_null() || value.as_str().is_some_and(str::is_empty)
+}
+
+/// Blanks the secrets nested in `value`.
+fn strip_secrets(key: &str, value: &mut Value) {
+    for pattern in nested_secrets(key) {
+        for pointer in expand(value, pattern) {
+            if let Some(secret) = value.pointer_mut(&pointer) {
+                *secret = if secret.is_string() { Value::String(String::new()) } else { Value::Null };
+            }
+        }
+    }
+}
+
+/// Fills secrets blanked in an imported `value` from the current setting.
+/// Array items are matched by their `id` field.
+fn restore_secrets(key: &str, value: &mut Value, current: Option<&Value>) {
+    let Some(current) = current else { return };
+    for pattern in nested_secrets(key) {
+        for pointer in expand(value, pattern) {
+            let current_pointer = match pattern.split_once("/*") {
+                Some((prefix, rest)) => {
+                    let item = pointer[prefix.len() + 1..].split('/').next().unwrap_or_default();
+                    let id = value.pointer(&format!("{}/{}/id", prefix, item)).cloned();
+                    let position = current
+                        .pointer(prefix)
+                        .and_then(Value::as_array)
+                        .and_then(|items| items.iter().position(|i| id.is_some() && i.get("id") == id.as_ref()));
+                    match position {
+                        Some(i) => format!("{}/{}{}", prefix, i, rest),
+                        None => continue,
+                    }
+                }
+                None => pointer.clone(),
+            };
+            let Some(existing) = current.pointer(&current_pointer).filter(|v| !is_blank(v)) else { continue };
+            if let Some(secret) = value.pointer_mut(&pointer).filter(|v| is_blank(v)) {
+                *secret = existing.clone();
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SettingsProfile {
+    /// Unique, and how the profile is referred to.
+    pub name: String,
+    /// Model selected on apply; the current one is kept when unset.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Text appended to the system prompt while the profile is active.
+    #[serde(default)]
+    pub prompt: String,
+    #[serde(default)]
+    pub tool_policy: ToolPolicy,
+    /// Working directory set on apply; the current one is kept when unset.
+    #[serde(default)]
+    pub working_directory: Option<String>,
+}
+
+/// All profiles plus the name of the active one.
+#[derive(Debug, Serialize)]
+pub struct SettingsProfiles {
+    pub profiles: Vec<SettingsProfile>,
+    pub active: Option<String>,
+}
+
+fn stored_profiles(app: &AppHandle) -> Vec<SettingsProfile> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_PROFILES))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn active_profile_name(app: &AppHandle) -> Option<String> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_ACTIVE_PROFILE))
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.is_empty())
+}
+
+/// The active settings profile, if any.
+pub fn active_profile(app: &AppHandle) -> Option<SettingsProfile> {
+    let name = active_profile_name(app)?;
+    stored_profiles(app).into_iter().find(|p| p.name == name)
+}
+
+/// The active profile's system prompt additions.
+pub fn profile_prompt(app: &AppHandle) -> Option<String> {
+    active_profile(app).map(|p| p.prompt.trim().to_string()).filter(|p| !p.is_empty())
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Writes the portable settings to `path` as JSON, without secrets.
+/// Returns the number of settings exported.
+#[tauri::command]
+pub fn export_settings(app: AppHandle, path: String) -> Result<usize, AppError> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    let mut settings = Map::new();
+    for (key, mut value) in store.entries() {
+        if !portable(&key) {
+            continue;
+        }
+        strip_secrets(&key, &mut value);
+        settings.insert(key, value);
+    }
+    let count = settings.len();
+    let export = SettingsExport {
+        format: EXPORT_FORMAT.to_string(),
+        version: EXPORT_VERSION,
+        exported_at: chrono::Local::now().to_rfc3339(),
+        app_version: app.package_info().version.to_string(),
+        settings,
+    };
+    let json = serde_json::to_string_pretty(&export).map_err(|e| e.to_string())?;
+    std::fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    tracing::info!("Exported {} settings to {}", count, path);
+    Ok(count)
+}
+
+/// Restores settings exported by `export_settings`. Settings not in the file
+/// are left as they are. Emits `settings-imported` so the UI reloads them.
+#[tauri::command]
+pub fn import_settings(app: AppHandle, path: String) -> Result<ImportSummary, AppError> {
+    let raw = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let export: SettingsExport =
+        serde_json::from_str(&raw).map_err(|e| format!("Not a settings export: {}", e))?;
+    if export.format != EXPORT_FORMAT {
+        return Err(AppError::InvalidInput("Not a settings export".to_string()));
+    }
+    if export.version > EXPORT_VERSION {
+        return Err(AppError::InvalidInput(format!(
+            "This export is from a newer version of the app ({}); update before importing it",
+            export.app_version
+        )));
+    }
+
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    let mut summary = ImportSummary { imported: 0, skipped: Vec::new() };
+    for (key, mut value) in export.settings {
+        if !portable(&key) {
+            summary.skipped.push(key);
+            continue;
+        }
+        restore_secrets(&key, &mut value, store.get(&key).as_ref());
+        store.set(key, value);
+        summary.imported += 1;
+    }
+    store.save().map_err(|e| e.to_string())?;
+    tracing::info!("Imported {} settings from {}", summary.imported, path);
+
+    crate::bridge::restart(&app);
+    let _ = app.emit("settings-imported", summary.imported);
+    Ok(summary)
+}
+
+/// Settings profiles in the order they were saved, and the active one.
+#[tauri::command]
+pub fn profile_list(app: AppHandle) -> SettingsProfiles {
+    SettingsProfiles {
+        profiles: stored_profiles(&app),
+        active: active_profile_name(&app),
+    }
+}
+
+/// Adds a profile, or replaces the one with the same name. Saving the active
+/// profile does not re-apply it.
+#[tauri::command]
+pub fn profile_save(app: AppHandle, mut profile: SettingsProfile) -> Result<(), AppError> {
+    profile.name = profile.name.trim().to_string();
+    if profile.name.is_empty() {
+        return Err(AppError::InvalidInput("Profile name cannot be empty".to_string()));
+    }
+    profile.model = profile.model.map(|m| m.trim().to_string()).filter(|m| !m.is_empty());
+    profile.working_directory = profile
+        .working_directory
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty());
+    if let Some(dir) = &profile.working_directory {
+        let path = std::path::Path::new(dir);
+        if !path.is_absolute() || !path.is_dir() {
+            return Err(AppError::InvalidInput(format!("Not an absolute directory path: {}", dir)));
+        }
+    }
+
+    let mut profiles = stored_profiles(&app);
+    match profiles.iter_mut().find(|p| p.name == profile.name) {
+        Some(existing) => *existing = profile,
+        None => profiles.push(profile),
+    }
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_PROFILES, json!(profiles));
+    Ok(store.save()?)
+}
+
+/// Deletes a profile. Deleting the active profile deactivates it; the model
+/// and working directory it set stay.
+#[tauri::command]
+pub fn profile_delete(app: AppHandle, name: String) -> Result<(), AppError> {
+    let mut profiles = stored_profiles(&app);
+    let before = profiles.len();
+    profiles.retain(|p| p.name != name);
+    if profiles.len() == before {
+        return Err(AppError::NotFound(format!("Profile '{}' not found", name)));
+    }
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_PROFILES, json!(profiles));
+    if active_profile_name(&app).as_deref() == Some(name.as_str()) {
+        store.delete(STORE_KEY_ACTIVE_PROFILE);
+        let _ = app.emit("settings-profile-applied", Option::<SettingsProfile>::None);
+    }
+    Ok(store.save()?)
+}
+
+/// Applies a profile, or deactivates the active one when `name` is `None`.
+/// Emits `settings-profile-applied` with the new active profile.
+#[tauri::command]
+pub async fn profile_apply(app: AppHandle, name: Option<String>) -> Result<Option<SettingsProfile>, AppError> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    let Some(name) = name else {
+        store.delete(STORE_KEY_ACTIVE_PROFILE);
+        store.save().map_err(|e| e.to_string())?;
+        let _ = app.emit("settings-profile-applied", Option::<SettingsProfile>::None);
+        return Ok(None);
+    };
+    let profile = stored_profiles(&app)
+        .into_iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| format!("Profile '{}' not found", name))?;
+
+    if let Some(dir) = &profile.working_directory {
+        crate::projects::switch_workspace(&app, dir).await?;
+    }
+    store.set(STORE_KEY_ACTIVE_PROFILE, json!(profile.name));
+    if let Some(model) = &profile.model {
+        store.set(crate::claude::client::STORE_KEY_MODEL, json!(model));
+    }
+    store.save().map_err(|e| e.to_string())?;
+    tracing::info!("Applied profile '{}'", profile.name);
+
+    let _ = app.emit("settings-profile-applied", Some(&profile));
+    Ok(Some(profile))
+}
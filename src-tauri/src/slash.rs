@@ -0,0 +1,141 @@
+/// Slash commands typed into the chat box.
+///
+/// `chat_send` checks the last user message before calling Claude. When it is
+/// one of the commands below, the app runs it itself and streams the outcome
+/// back as a `command_result` event; the message never reaches the model.
+/// Anything else starting with `/` (a path, an unknown command) is sent as
+/// normal text.
+///
+/// - `/model [name]`: show or change the Claude model
+/// - `/compact`: compress this conversation's history from now on
+/// - `/cwd [path]`: show or change the working directory
+/// - `/mode [normal|search|analyze]`: show or change this conversation's mode
+/// - `/usage`: show the Claude rate limit windows
+use serde_json::json;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::claude::types::{ChatMessage, ContentBlock, MessageContent};
+use crate::compaction::ForcedCompaction;
+use crate::modes::MessageMode;
+use crate::STORE_FILE;
+
+/// A recognized slash command and its argument (empty when none was given).
+pub struct SlashCommand {
+    pub name: &'static str,
+    pub arg: String,
+}
+
+/// Parses the text of the last user message in `messages`, if it is a command.
+pub fn parse(messages: &[ChatMessage]) -> Option<SlashCommand> {
+    let last = messages.iter().rev().find(|m| m.role == "user")?;
+    let text = match &last.content {
+        MessageContent::Text(s) => s.as_str(),
+        MessageContent::Blocks(blocks) => match blocks.as_slice() {
+            [ContentBlock::Text { text }] => text.as_str(),
+            _ => return None,
+        },
+    };
+    let rest = text.trim().strip_prefix('/')?;
+    let (word, arg) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let name = ["model", "compact", "cwd", "mode", "usage"]
+        .into_iter()
+        .find(|n| *n == word)?;
+    Some(SlashCommand {
+        name,
+        arg: arg.trim().to_string(),
+    })
+}
+
+/// Runs a command and returns the text to show the user.
+pub async fn execute(
+    app: &AppHandle,
+    command: &SlashCommand,
+    conversation_id: Option<&str>,
+    history_len: usize,
+) -> Result<String, String> {
+    let arg = command.arg.as_str();
+    match command.name {
+        "model" => {
+            if arg.is_empty() {
+                return Ok(format!("Model: {}", crate::claude::client::get_model(app)));
+            }
+            if !arg.starts_with("claude-") {
+                return Err(format!("Not a Claude model: {}", arg));
+            }
+            let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+            store.set(crate::claude::client::STORE_KEY_MODEL, json!(arg));
+            store.save().map_err(|e| e.to_string())?;
+            Ok(format!("Model set to {}", arg))
+        }
+        "compact" => {
+            let id = conversation_id.ok_or("/compact needs a saved conversation")?;
+            app.state::<ForcedCompaction>().force(id);
+            Ok(format!(
+                "History compaction is on for this conversation ({} messages so far). \
+Older messages are summarized from the next message on.",
+                history_len
+            ))
+        }
+        "cwd" => {
+            if arg.is_empty() {
+                return Ok(format!("Working directory: {}", crate::get_working_directory(app.clone()).await?));
+            }
+            crate::set_working_directory(app.clone(), arg.to_string()).await?;
+            Ok(format!("Working directory set to {}", arg))
+        }
+        "mode" => {
+            let id = conversation_id.ok_or("/mode needs a saved conversation")?;
+            if arg.is_empty() {
+                let mode = crate::modes::conversation_mode(app, id);
+                return Ok(format!("Mode: {}", mode_name(&mode)));
+            }
+            let mode: MessageMode = serde_json::from_value(json!(arg.to_lowercase()))
+                .map_err(|_| format!("Unknown mode '{}' (normal, search, analyze)", arg))?;
+            crate::modes::set_conversation_mode(app.clone(), id.to_string(), mode.clone())?;
+            Ok(format!("Mode set to {}", mode_name(&mode)))
+        }
+        "usage" => {
+            let usage = crate::fetch_claude_usage(app.clone()).await?;
+            let windows = [
+                ("5-hour", &usage.five_hour),
+                ("7-day", &usage.seven_day),
+                ("7-day Opus", &usage.seven_day_opus),
+            ];
+            let mut lines: Vec<String> = windows
+                .into_iter()
+                .filter_map(|(label, limit)| limit.as_ref().map(|l| (label, l)))
+                .map(|(label, limit)| {
+                    let used = limit
+                        .utilization
+                        .map(|u| format!("{:.0}%", u))
+                        .unwrap_or_else(|| "?".to_string());
+                    match &limit.resets_at {
+                        Some(at) => format!("{}: {} used, resets {}", label, used, at),
+                        None => format!("{}: {} used", label, used),
+                    }
+                })
+                .collect();
+            let budget = crate::budget::status(app);
+            if budget.enabled {
+                lines.push(format!(
+                    "Budget: {:.0}% of this {}'s limit used",
+                    budget.fraction * 100.0,
+                    budget.period.unit()
+                ));
+            }
+            if lines.is_empty() {
+                return Ok("No usage data available".to_string());
+            }
+            Ok(lines.join("\n"))
+        }
+        _ => Err(format!("Unknown command /{}", command.name)),
+    }
+}
+
+fn mode_name(mode: &MessageMode) -> String {
+    serde_json::to_value(mode)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
@@ -0,0 +1,661 @@
+/// Optional sync of the conversation store and memory database to a
+/// user-provided WebDAV folder or S3 bucket, so chats follow the user
+/// between machines.
+///
+/// Each item is stored remotely as one zstd-compressed object. The sync
+/// state remembers, per item, the local content hash and the remote version
+/// (ETag) as of the last sync, which tells which side changed:
+/// - only local changed: push, conditional on the remote still being at the
+///   remembered version;
+/// - only remote changed: pull, reload the store and emit `sync-pulled`;
+/// - both changed: a conflict. The remote copy is saved next to the app data
+///   and nothing is overwritten until `resolve_sync_conflict` picks a side.
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{Method, RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::memory::WinterMemoryDB;
+use crate::STORE_FILE;
+
+const STORE_KEY_SETTINGS: &str = "sync";
+const STORE_KEY_STATE: &str = "sync_state";
+
+/// Store file of the frontend's conversations (see `useSessionStore.ts`).
+const SESSIONS_STORE: &str = "sessions.json";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Delay before the first automatic sync after launch.
+const FIRST_SYNC_DELAY: Duration = Duration::from_secs(20);
+
+/// Remote copies kept aside on conflict, under the app data directory.
+const CONFLICTS_DIR: &str = "sync-conflicts";
+
+/// Serializes automatic and manual syncs.
+static SYNC: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+fn default_region() -> String {
+    "us-east-1".to_string()
+}
+
+fn default_prefix() -> String {
+    "winter".to_string()
+}
+
+fn default_interval() -> u32 {
+    15
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncProvider {
+    #[default]
+    Webdav,
+    S3,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SyncSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub provider: SyncProvider,
+    /// WebDAV: URL of the folder to sync into. S3: the service endpoint,
+    /// e.g. `https://s3.eu-central-1.amazonaws.com`; buckets are addressed path-style.
+    #[serde(default)]
+    pub endpoint: String,
+    /// WebDAV user name, or S3 access key ID.
+    #[serde(default)]
+    pub username: String,
+    /// WebDAV password, or S3 secret access key.
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub bucket: String,
+    #[serde(default = "default_region")]
+    pub region: String,
+    /// S3 key prefix the objects are stored under.
+    #[serde(default = "default_prefix")]
+    pub prefix: String,
+    /// Minutes between automatic syncs; 0 syncs only on request.
+    #[serde(default = "default_interval")]
+    pub interval_minutes: u32,
+}
+
+impl Default for SyncSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: SyncProvider::Webdav,
+            endpoint: String::new(),
+            username: String::new(),
+            password: String::new(),
+            bucket: String::new(),
+            region: default_region(),
+            prefix: default_prefix(),
+            interval_minutes: default_interval(),
+        }
+    }
+}
+
+/// A synced file.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncItem {
+    /// The frontend's `sessions.json` store.
+    Conversations,
+    /// `winter.db`, the SQLite memory database.
+    Memory,
+}
+
+impl SyncItem {
+    const ALL: [SyncItem; 2] = [SyncItem::Conversations, SyncItem::Memory];
+
+    fn remote_name(self) -> &'static str {
+        match self {
+            SyncItem::Conversations => "sessions.json.zst",
+            SyncItem::Memory => "winter.db.zst",
+        }
+    }
+
+    fn local_path(self, app: &AppHandle) -> Result<PathBuf, AppError> {
+        match self {
+            SyncItem::Conversations => app
+                .path()
+                .app_data_dir()
+                .map(|d| d.join(SESSIONS_STORE))
+                .map_err(|e| AppError::Io(format!("Cannot get app data dir: {}", e))),
+            SyncItem::Memory => Ok(WinterMemoryDB::new_with_app(app).db_path()),
+        }
+    }
+}
+
+/// Per-item state as of the last successful push or pull.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct ItemState {
+    local_hash: Option<String>,
+    remote_version: Option<String>,
+    /// Set while a conflict waits for `resolve_sync_conflict`.
+    #[serde(default)]
+    conflict: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct SyncState {
+    items: HashMap<SyncItem, ItemState>,
+    last_sync: Option<String>,
+    last_report: Vec<ItemReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncAction {
+    UpToDate,
+    Pushed,
+    Pulled,
+    Conflict,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ItemReport {
+    pub item: SyncItem,
+    pub action: SyncAction,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SyncStatus {
+    pub enabled: bool,
+    pub last_sync: Option<String>,
+    pub last_report: Vec<ItemReport>,
+    /// Items waiting for `resolve_sync_conflict`.
+    pub conflicts: Vec<SyncItem>,
+}
+
+pub fn get_settings(app: &AppHandle) -> SyncSettings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get(STORE_KEY_SETTINGS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn read_state(app: &AppHandle) -> SyncState {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|s| s.get(STORE_KEY_STATE))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn write_state(app: &AppHandle, state: &SyncState) -> Result<(), AppError> {
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_STATE, json!(state));
+    store.save()?;
+    Ok(())
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+// ── Remote ────────────────────────────────────────────────────────────
+
+/// A precondition on a write, so a concurrent change is never overwritten.
+enum Expect<'a> {
+    /// The object must not exist yet.
+    Absent,
+    /// The object must still be at this version.
+    Version(&'a str),
+    /// Overwrite whatever is there (resolving a conflict).
+    Any,
+}
+
+struct Remote {
+    client: reqwest::Client,
+    settings: SyncSettings,
+}
+
+impl Remote {
+    fn new(settings: SyncSettings) -> Result<Self, AppError> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self { client, settings })
+    }
+
+    fn url(&self, name: &str) -> String {
+        let endpoint = self.settings.endpoint.trim_end_matches('/');
+        match self.settings.provider {
+            SyncProvider::Webdav => format!("{}/{}", endpoint, name),
+            SyncProvider::S3 => {
+                let prefix = self.settings.prefix.trim_matches('/');
+                if prefix.is_empty() {
+                    format!("{}/{}/{}", endpoint, self.settings.bucket, name)
+                } else {
+                    format!("{}/{}/{}/{}", endpoint, self.settings.bucket, prefix, name)
+                }
+            }
+        }
+    }
+
+    /// Builds an authenticated request: basic auth for WebDAV, AWS Signature
+    /// Version 4 for S3.
+    fn request(&self, method: Method, url: &str, body: &[u8]) -> Result<RequestBuilder, AppError> {
+        let s = &self.settings;
+        let builder = self.client.request(method.clone(), url);
+        if s.provider == SyncProvider::Webdav {
+            return Ok(if s.username.is_empty() {
+                builder
+            } else {
+                builder.basic_auth(&s.username, Some(&s.password))
+            });
+        }
+
+        let parsed = reqwest::Url::parse(url).map_err(|e| AppError::InvalidInput(format!("Invalid endpoint: {}", e)))?;
+        let host = match (parsed.host_str(), parsed.port()) {
+            (Some(h), Some(p)) => format!("{}:{}", h, p),
+            (Some(h), None) => h.to_string(),
+            (None, _) => return Err(AppError::InvalidInput("Endpoint has no host".to_string())),
+        };
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = sha256_hex(body);
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "{}\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            method,
+            parsed.path(),
+            host,
+            payload_hash,
+            amz_date,
+            signed_headers,
+            payload_hash
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, s.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            sha256_hex(canonical_request.as_bytes())
+        );
+        let key = [date.as_str(), s.region.as_str(), "s3", "aws4_request"]
+            .iter()
+            .fold(format!("AWS4{}", s.password).into_bytes(), |key, part| hmac_sha256(&key, part));
+        let signature: String = hmac_sha256(&key, &string_to_sign).iter().map(|b| format!("{:02x}", b)).collect();
+
+        Ok(builder
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", payload_hash)
+            .header(
+                "Authorization",
+                format!(
+                    "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                    s.username, scope, signed_headers, signature
+                ),
+            ))
+    }
+
+    /// Version of a response: its ETag, or Last-Modified for WebDAV servers
+    /// that send no ETag.
+    fn version(resp: &reqwest::Response) -> Option<String> {
+        let header = |name| resp.headers().get(name).and_then(|v| v.to_str().ok()).map(String::from);
+        header(reqwest::header::ETAG).or_else(|| header(reqwest::header::LAST_MODIFIED))
+    }
+
+    fn check(resp: &reqwest::Response, what: &str) -> Result<(), AppError> {
+        match resp.status() {
+            s if s.is_success() => Ok(()),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Err(AppError::InvalidInput(format!(
+                "Sync server rejected the credentials ({})",
+                resp.status()
+            ))),
+            s => Err(AppError::Api { status: s.as_u16(), message: format!("{} failed", what) }),
+        }
+    }
+
+    /// Current version of `name`, or `None` if it does not exist yet.
+    async fn head(&self, name: &str) -> Result<Option<String>, AppError> {
+        let resp = self.request(Method::HEAD, &self.url(name), &[])?.send().await?;
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Self::check(&resp, "Checking the remote copy")?;
+        Ok(Self::version(&resp))
+    }
+
+    async fn get(&self, name: &str) -> Result<(Vec<u8>, Option<String>), AppError> {
+        let resp = self.request(Method::GET, &self.url(name), &[])?.send().await?;
+        Self::check(&resp, "Downloading")?;
+        let version = Self::version(&resp);
+        Ok((resp.bytes().await?.to_vec(), version))
+    }
+
+    fn put_request(&self, url: &str, body: &[u8], expect: &Expect<'_>) -> Result<RequestBuilder, AppError> {
+        let req = self.request(Method::PUT, url, body)?;
+        Ok(match expect {
+            Expect::Absent => req.header(reqwest::header::IF_NONE_MATCH, "*"),
+            // A Last-Modified date cannot be used as a precondition.
+            Expect::Version(v) if v.starts_with('"') || v.starts_with("W/") => req.header(reqwest::header::IF_MATCH, *v),
+            _ => req,
+        })
+    }
+
+    /// Uploads `body` under `expect`. Returns the new version, or `None` when
+    /// the remote changed in the meantime.
+    async fn put(&self, name: &str, body: Vec<u8>, expect: Expect<'_>) -> Result<Option<String>, AppError> {
+        let url = self.url(name);
+        let mut resp = self.put_request(&url, &body, &expect)?.body(body.clone()).send().await?;
+        if self.settings.provider == SyncProvider::Webdav && resp.status() == StatusCode::CONFLICT {
+            // The sync folder does not exist yet.
+            let folder = self.settings.endpoint.trim_end_matches('/');
+            let mkcol = Method::from_bytes(b"MKCOL").expect("valid method");
+            self.request(mkcol, folder, &[])?.send().await?;
+            resp = self.put_request(&url, &body, &expect)?.body(body).send().await?;
+        }
+        if resp.status() == StatusCode::PRECONDITION_FAILED {
+            return Ok(None);
+        }
+        Self::check(&resp, "Uploading")?;
+        match Self::version(&resp) {
+            Some(v) => Ok(Some(v)),
+            None => self.head(name).await,
+        }
+    }
+}
+
+// ── Sync ──────────────────────────────────────────────────────────────
+
+fn read_local(path: &PathBuf) -> Result<Option<Vec<u8>>, AppError> {
+    match std::fs::read(path) {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Replaces the local file with pulled content and reloads the store, if any.
+fn write_local(app: &AppHandle, item: SyncItem, path: &PathBuf, data: &[u8]) -> Result<(), AppError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("sync-partial");
+    std::fs::write(&tmp, data)?;
+    std::fs::rename(&tmp, path)?;
+    if item == SyncItem::Conversations {
+        if let Some(store) = app.get_store(SESSIONS_STORE) {
+            store.reload()?;
+        }
+    }
+    Ok(())
+}
+
+fn conflict_path(app: &AppHandle, item: SyncItem) -> Result<PathBuf, AppError> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| AppError::Io(format!("Cannot get app data dir: {}", e)))?
+        .join(CONFLICTS_DIR);
+    Ok(dir.join(item.remote_name().trim_end_matches(".zst")))
+}
+
+fn compress(data: &[u8]) -> Result<Vec<u8>, AppError> {
+    Ok(zstd::encode_all(data, 3)?)
+}
+
+fn decompress(data: &[u8]) -> Result<Vec<u8>, AppError> {
+    zstd::decode_all(data).map_err(|e| AppError::Failed(format!("Remote copy is corrupt: {}", e)))
+}
+
+async fn push(remote: &Remote, item: SyncItem, data: &[u8], expect: Expect<'_>) -> Result<Option<String>, AppError> {
+    remote.put(item.remote_name(), compress(data)?, expect).await
+}
+
+async fn sync_item(app: &AppHandle, remote: &Remote, item: SyncItem, state: &mut ItemState) -> Result<SyncAction, AppError> {
+    if state.conflict {
+        return Ok(SyncAction::Conflict);
+    }
+    if item == SyncItem::Conversations {
+        if let Some(store) = app.get_store(SESSIONS_STORE) {
+            store.save()?;
+        }
+    }
+    let path = item.local_path(app)?;
+    let local = read_local(&path)?;
+    let local_hash = local.as_deref().map(sha256_hex);
+    let remote_version = remote.head(item.remote_name()).await?;
+    let local_changed = local_hash != state.local_hash;
+    let remote_changed = remote_version != state.remote_version;
+
+    let Some(local) = local else {
+        if remote_version.is_none() {
+            return Ok(SyncAction::UpToDate);
+        }
+        return pull(app, remote, item, &path, state).await;
+    };
+    let Some(version) = remote_version else {
+        return match push(remote, item, &local, Expect::Absent).await? {
+            Some(v) => {
+                *state = ItemState { local_hash, remote_version: Some(v), conflict: false };
+                Ok(SyncAction::Pushed)
+            }
+            None => Ok(SyncAction::UpToDate),
+        };
+    };
+
+    match (local_changed, remote_changed) {
+        (false, false) => Ok(SyncAction::UpToDate),
+        (true, false) => match push(remote, item, &local, Expect::Version(&version)).await? {
+            Some(v) => {
+                *state = ItemState { local_hash, remote_version: Some(v), conflict: false };
+                Ok(SyncAction::Pushed)
+            }
+            // Changed remotely since the check; the next sync sorts it out.
+            None => Ok(SyncAction::UpToDate),
+        },
+        (false, true) => pull(app, remote, item, &path, state).await,
+        (true, true) => {
+            let (data, version) = remote.get(item.remote_name()).await?;
+            let data = decompress(&data)?;
+            if local_hash.as_deref() == Some(sha256_hex(&data).as_str()) {
+                *state = ItemState { local_hash, remote_version: version, conflict: false };
+                return Ok(SyncAction::UpToDate);
+            }
+            let copy = conflict_path(app, item)?;
+            if let Some(parent) = copy.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&copy, data)?;
+            // `remote_version` now names the saved copy; it is not compared
+            // again until the conflict is resolved.
+            *state = ItemState { local_hash: state.local_hash.take(), remote_version: version, conflict: true };
+            tracing::warn!("Sync conflict for {:?}; remote copy saved to {}", item, copy.display());
+            Ok(SyncAction::Conflict)
+        }
+    }
+}
+
+async fn pull(
+    app: &AppHandle,
+    remote: &Remote,
+    item: SyncItem,
+    path: &PathBuf,
+    state: &mut ItemState,
+) -> Result<SyncAction, AppError> {
+    let (data, version) = remote.get(item.remote_name()).await?;
+    let data = decompress(&data)?;
+    write_local(app, item, path, &data)?;
+    *state = ItemState { local_hash: Some(sha256_hex(&data)), remote_version: version, conflict: false };
+    Ok(SyncAction::Pulled)
+}
+
+/// Syncs every item and records the outcome. Emits `sync-pulled` with the
+/// pulled items, if any.
+async fn run(app: &AppHandle) -> Result<Vec<ItemReport>, AppError> {
+    let settings = get_settings(app);
+    validate(&settings)?;
+    let _guard = SYNC.lock().await;
+    let remote = Remote::new(settings)?;
+    let mut state = read_state(app);
+    let mut report = Vec::new();
+    for item in SyncItem::ALL {
+        let item_state = state.items.entry(item).or_default();
+        let (action, error) = match sync_item(app, &remote, item, item_state).await {
+            Ok(action) => (action, None),
+            Err(e) => {
+                tracing::warn!("Sync of {:?} failed: {}", item, e);
+                (SyncAction::Failed, Some(e.to_string()))
+            }
+        };
+        report.push(ItemReport { item, action, error });
+    }
+    state.last_sync = Some(chrono::Local::now().to_rfc3339());
+    state.last_report = report.clone();
+    write_state(app, &state)?;
+
+    let pulled: Vec<SyncItem> = report.iter().filter(|r| r.action == SyncAction::Pulled).map(|r| r.item).collect();
+    if !pulled.is_empty() {
+        let _ = app.emit("sync-pulled", &pulled);
+    }
+    Ok(report)
+}
+
+fn validate(settings: &SyncSettings) -> Result<(), AppError> {
+    let url = reqwest::Url::parse(settings.endpoint.trim())
+        .map_err(|_| AppError::InvalidInput("Enter the sync server URL".to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(AppError::InvalidInput("Sync server URL must start with https://".to_string()));
+    }
+    if settings.provider == SyncProvider::S3 {
+        if settings.bucket.trim().is_empty() || settings.username.is_empty() || settings.password.is_empty() {
+            return Err(AppError::InvalidInput("S3 needs a bucket, access key and secret key".to_string()));
+        }
+        let safe = |s: &str| s.chars().all(|c| c.is_ascii_alphanumeric() || "-_./".contains(c));
+        if !safe(&settings.bucket) || !safe(&settings.prefix) || !safe(&settings.region) {
+            return Err(AppError::InvalidInput(
+                "Bucket, region and prefix may only use letters, digits, '-', '_', '.' and '/'".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Syncs every `interval_minutes` while sync is enabled.
+pub fn spawn_sync_loop(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(FIRST_SYNC_DELAY).await;
+        loop {
+            let settings = get_settings(&app);
+            if settings.enabled && settings.interval_minutes > 0 {
+                if let Err(e) = run(&app).await {
+                    tracing::warn!("Sync failed: {}", e);
+                }
+            }
+            let minutes = settings.interval_minutes.max(1) as u64;
+            tokio::time::sleep(Duration::from_secs(minutes * 60)).await;
+        }
+    });
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn get_sync_settings(app: AppHandle) -> SyncSettings {
+    get_settings(&app)
+}
+
+/// Validates and saves the sync settings. Changing the server or bucket
+/// forgets the sync state, so the first sync against it compares contents.
+#[tauri::command]
+pub fn set_sync_settings(app: AppHandle, settings: SyncSettings) -> Result<SyncSettings, AppError> {
+    let settings = SyncSettings {
+        endpoint: settings.endpoint.trim().to_string(),
+        bucket: settings.bucket.trim().to_string(),
+        region: settings.region.trim().to_string(),
+        ..settings
+    };
+    if settings.enabled {
+        validate(&settings)?;
+    }
+    let previous = get_settings(&app);
+    let moved = previous.provider != settings.provider
+        || previous.endpoint != settings.endpoint
+        || previous.bucket != settings.bucket
+        || previous.prefix != settings.prefix;
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_SETTINGS, json!(settings));
+    if moved {
+        store.delete(STORE_KEY_STATE);
+    }
+    store.save()?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_sync_status(app: AppHandle) -> SyncStatus {
+    let state = read_state(&app);
+    let mut conflicts: Vec<SyncItem> = state.items.iter().filter(|(_, s)| s.conflict).map(|(i, _)| *i).collect();
+    conflicts.sort_by_key(|i| SyncItem::ALL.iter().position(|x| x == i));
+    SyncStatus {
+        enabled: get_settings(&app).enabled,
+        last_sync: state.last_sync,
+        last_report: state.last_report,
+        conflicts,
+    }
+}
+
+/// Pushes and pulls every item now.
+#[tauri::command]
+pub async fn sync_now(app: AppHandle) -> Result<Vec<ItemReport>, AppError> {
+    run(&app).await
+}
+
+/// Settles a conflict on `item`: `keep_local` overwrites the remote copy with
+/// this machine's, otherwise the remote copy saved at conflict time replaces
+/// the local one.
+#[tauri::command]
+pub async fn resolve_sync_conflict(app: AppHandle, item: SyncItem, keep_local: bool) -> Result<(), AppError> {
+    let _guard = SYNC.lock().await;
+    let mut state = read_state(&app);
+    if !state.items.get(&item).is_some_and(|s| s.conflict) {
+        return Err(AppError::NotFound(format!("No sync conflict for {:?}", item)));
+    }
+    let path = item.local_path(&app)?;
+    let copy = conflict_path(&app, item)?;
+    let resolved = if keep_local {
+        if item == SyncItem::Conversations {
+            if let Some(store) = app.get_store(SESSIONS_STORE) {
+                store.save()?;
+            }
+        }
+        let local = read_local(&path)?.ok_or_else(|| AppError::NotFound("Local copy is missing".to_string()))?;
+        let remote = Remote::new(get_settings(&app))?;
+        let version = push(&remote, item, &local, Expect::Any).await?;
+        ItemState { local_hash: Some(sha256_hex(&local)), remote_version: version, conflict: false }
+    } else {
+        let data = std::fs::read(&copy)
+            .map_err(|e| AppError::NotFound(format!("Saved remote copy is missing: {}", e)))?;
+        write_local(&app, item, &path, &data)?;
+        let _ = app.emit("sync-pulled", [item]);
+        let remote_version = state.items.get(&item).and_then(|s| s.remote_version.clone());
+        ItemState { local_hash: Some(sha256_hex(&data)), remote_version, conflict: false }
+    };
+    let _ = std::fs::remove_file(&copy);
+    state.items.insert(item, resolved);
+    write_state(&app, &state)
+}
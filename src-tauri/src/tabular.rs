@@ -0,0 +1,259 @@
+/// Paginated previews of large CSV/TSV and plain text files.
+///
+/// `native_file_content` stops at 2 MB; this reads only the requested page.
+/// CSV/TSV files (by extension) are split into quoted fields, one record per
+/// row, with the first record as the header. Any other file is paged by line.
+///
+/// Reaching row N means reading every record before it, so the byte offset of
+/// every `CHECKPOINT_EVERY`th record is remembered per file (until the file
+/// changes) and later pages start from the nearest checkpoint.
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::{AppHandle, Manager};
+
+use crate::error::AppError;
+
+/// Records between remembered byte offsets.
+const CHECKPOINT_EVERY: usize = 1000;
+
+/// Files whose checkpoints are kept; all are dropped when this is exceeded.
+const MAX_INDEXED_FILES: usize = 32;
+
+/// Rows or lines returned per page at most.
+const MAX_LIMIT: usize = 1000;
+const DEFAULT_LIMIT: usize = 100;
+
+/// A record is cut off here, e.g. at an unbalanced quote (1 MB).
+const MAX_RECORD_BYTES: usize = 1024 * 1024;
+
+/// Cells and lines longer than this are cut.
+const MAX_CELL_CHARS: usize = 4000;
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum TabularPreview {
+    Table {
+        path: String,
+        size: u64,
+        delimiter: String,
+        headers: Vec<String>,
+        rows: Vec<Vec<String>>,
+        /// Index of the first returned row, not counting the header.
+        offset: usize,
+        has_more: bool,
+        /// Data rows in the file, once a page has reached the end.
+        total: Option<usize>,
+    },
+    Lines {
+        path: String,
+        size: u64,
+        lines: Vec<String>,
+        /// Index of the first returned line (0-based).
+        offset: usize,
+        has_more: bool,
+        /// Lines in the file, once a page has reached the end.
+        total: Option<usize>,
+    },
+}
+
+#[derive(Clone)]
+struct RecordIndex {
+    size: u64,
+    modified: Option<SystemTime>,
+    /// Byte offset of record `i * CHECKPOINT_EVERY`.
+    checkpoints: Vec<u64>,
+    /// Record count, once known.
+    total: Option<usize>,
+}
+
+/// Record checkpoints of recently previewed files, by path.
+#[derive(Default)]
+pub struct TabularIndexes(Mutex<HashMap<String, RecordIndex>>);
+
+fn delimiter_for(path: &Path) -> Option<u8> {
+    match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+        "csv" => Some(b','),
+        "tsv" | "tab" => Some(b'\t'),
+        _ => None,
+    }
+}
+
+/// Reads the raw bytes of one record into `buf`: a line, extended over
+/// newlines inside quotes when `quoted`. Returns the bytes consumed (0 at EOF).
+fn read_record<R: BufRead>(reader: &mut R, buf: &mut Vec<u8>, quoted: bool) -> std::io::Result<usize> {
+    buf.clear();
+    let mut consumed = 0;
+    loop {
+        let n = reader.read_until(b'\n', buf)?;
+        consumed += n;
+        let open_quote = quoted && buf.iter().filter(|&&b| b == b'"').count() % 2 == 1;
+        if n == 0 || !open_quote || buf.len() > MAX_RECORD_BYTES {
+            return Ok(consumed);
+        }
+    }
+}
+
+fn cap(mut s: String) -> String {
+    if let Some((end, _)) = s.char_indices().nth(MAX_CELL_CHARS) {
+        s.truncate(end);
+        s.push('…');
+    }
+    s
+}
+
+fn trim_newline(raw: &[u8]) -> &[u8] {
+    let raw = raw.strip_suffix(b"\n").unwrap_or(raw);
+    raw.strip_suffix(b"\r").unwrap_or(raw)
+}
+
+/// Splits a record into fields, unquoting `"..."` fields and `""` escapes.
+fn split_fields(raw: &[u8], delimiter: u8) -> Vec<String> {
+    let raw = trim_newline(raw);
+    let mut fields = Vec::new();
+    let mut field = Vec::new();
+    let mut in_quotes = false;
+    let mut i = 0;
+    while i < raw.len() {
+        let b = raw[i];
+        if in_quotes {
+            if b == b'"' && raw.get(i + 1) == Some(&b'"') {
+                field.push(b'"');
+                i += 1;
+            } else if b == b'"' {
+                in_quotes = false;
+            } else {
+                field.push(b);
+            }
+        } else if b == b'"' && field.is_empty() {
+            in_quotes = true;
+        } else if b == delimiter {
+            fields.push(cap(String::from_utf8_lossy(&field).to_string()));
+            field.clear();
+        } else {
+            field.push(b);
+        }
+        i += 1;
+    }
+    fields.push(cap(String::from_utf8_lossy(&field).to_string()));
+    fields
+}
+
+fn cached_index(indexes: &TabularIndexes, key: &str, size: u64, modified: Option<SystemTime>) -> RecordIndex {
+    let map = indexes.0.lock().unwrap_or_else(|e| e.into_inner());
+    map.get(key)
+        .filter(|i| i.size == size && i.modified == modified)
+        .cloned()
+        .unwrap_or(RecordIndex { size, modified, checkpoints: vec![0], total: None })
+}
+
+fn store_index(indexes: &TabularIndexes, key: String, index: RecordIndex) {
+    let mut map = indexes.0.lock().unwrap_or_else(|e| e.into_inner());
+    if map.len() >= MAX_INDEXED_FILES && !map.contains_key(&key) {
+        map.clear();
+    }
+    map.insert(key, index);
+}
+
+/// Raw records `first..first + count` (fewer at EOF), and whether more follow.
+fn read_records(
+    reader: &mut BufReader<File>,
+    index: &mut RecordIndex,
+    quoted: bool,
+    first: usize,
+    count: usize,
+) -> Result<(Vec<Vec<u8>>, bool), String> {
+    let checkpoint = (first / CHECKPOINT_EVERY).min(index.checkpoints.len() - 1);
+    let mut pos = index.checkpoints[checkpoint];
+    let mut record_no = checkpoint * CHECKPOINT_EVERY;
+    reader.seek(SeekFrom::Start(pos)).map_err(|e| e.to_string())?;
+
+    let mut records = Vec::new();
+    let mut buf = Vec::new();
+    loop {
+        if record_no.is_multiple_of(CHECKPOINT_EVERY) && record_no / CHECKPOINT_EVERY == index.checkpoints.len() {
+            index.checkpoints.push(pos);
+        }
+        let n = read_record(reader, &mut buf, quoted).map_err(|e| e.to_string())?;
+        if n == 0 {
+            index.total = Some(record_no);
+            return Ok((records, false));
+        }
+        if record_no >= first + count {
+            // One record past the page: there is more.
+            return Ok((records, true));
+        }
+        if record_no >= first {
+            records.push(buf.clone());
+        }
+        pos += n as u64;
+        record_no += 1;
+    }
+}
+
+fn preview(indexes: &TabularIndexes, path: &Path, offset: usize, limit: usize) -> Result<TabularPreview, String> {
+    let meta = std::fs::metadata(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    if !meta.is_file() {
+        return Err(format!("Not a file: {}", path.display()));
+    }
+    let file = File::open(path).map_err(|e| format!("Cannot open {}: {}", path.display(), e))?;
+    let mut reader = BufReader::with_capacity(256 * 1024, file);
+    let key = path.to_string_lossy().to_string();
+    let mut index = cached_index(indexes, &key, meta.len(), meta.modified().ok());
+    let delimiter = delimiter_for(path);
+
+    let result = match delimiter {
+        Some(delimiter) => {
+            let (header, _) = read_records(&mut reader, &mut index, true, 0, 1)?;
+            let (records, has_more) = read_records(&mut reader, &mut index, true, offset + 1, limit)?;
+            TabularPreview::Table {
+                path: key.clone(),
+                size: meta.len(),
+                delimiter: (delimiter as char).to_string(),
+                headers: header.first().map(|h| split_fields(h, delimiter)).unwrap_or_default(),
+                rows: records.iter().map(|r| split_fields(r, delimiter)).collect(),
+                offset,
+                has_more,
+                total: index.total.map(|t| t.saturating_sub(1)),
+            }
+        }
+        None => {
+            let (records, has_more) = read_records(&mut reader, &mut index, false, offset, limit)?;
+            TabularPreview::Lines {
+                path: key.clone(),
+                size: meta.len(),
+                lines: records
+                    .iter()
+                    .map(|r| cap(String::from_utf8_lossy(trim_newline(r)).to_string()))
+                    .collect(),
+                offset,
+                has_more,
+                total: index.total,
+            }
+        }
+    };
+    store_index(indexes, key, index);
+    Ok(result)
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// A page of rows of a CSV/TSV file (with its header), or of lines of any
+/// other text file. `limit` defaults to 100 and is capped at 1000.
+#[tauri::command]
+pub async fn preview_tabular(
+    app: AppHandle,
+    path: String,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Result<TabularPreview, AppError> {
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    Ok(tokio::task::spawn_blocking(move || preview(&app.state::<TabularIndexes>(), Path::new(&path), offset, limit))
+        .await
+        .map_err(|e| e.to_string())??)
+}
@@ -0,0 +1,160 @@
+/// Image thumbnails for the file browser's grid view.
+///
+/// Images are decoded and downscaled once, then cached in
+/// `<app_data_dir>/thumbnails/`, keyed by path, size, modification time and
+/// thumbnail size, so an edited image gets a new thumbnail. Thumbnails with
+/// transparency are PNG, the rest JPEG.
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use image::{DynamicImage, ImageFormat, ImageReader};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tauri::{AppHandle, Manager};
+
+use crate::error::AppError;
+
+const DEFAULT_MAX_PX: u32 = 256;
+const MIN_MAX_PX: u32 = 16;
+const MAX_MAX_PX: u32 = 1024;
+
+/// Larger images are not decoded (100 MB).
+const MAX_SOURCE_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Cached thumbnails kept; the oldest are removed past this.
+const MAX_CACHED: usize = 5000;
+
+/// The cache is checked for pruning after one in this many writes.
+const PRUNE_EVERY: u32 = 64;
+
+const JPEG_QUALITY: u8 = 80;
+
+#[derive(Debug, Serialize)]
+pub struct Thumbnail {
+    pub mime: &'static str,
+    /// Base64-encoded image data.
+    pub data: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+fn cache_dir(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_data_dir().ok().map(|d| d.join("thumbnails"))
+}
+
+fn cache_key(path: &Path, meta: &std::fs::Metadata, max_px: u32) -> String {
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let mut hasher = Sha256::new();
+    hasher.update(path.to_string_lossy().as_bytes());
+    hasher.update(format!("\0{}\0{}\0{}", meta.len(), modified, max_px));
+    hasher
+        .finalize()
+        .iter()
+        .take(16)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Cached thumbnail for `key` and its MIME type.
+fn find_cached(dir: &Path, key: &str) -> Option<(PathBuf, &'static str)> {
+    [("png", "image/png"), ("jpg", "image/jpeg")]
+        .into_iter()
+        .map(|(ext, mime)| (dir.join(format!("{}.{}", key, ext)), mime))
+        .find(|(path, _)| path.is_file())
+}
+
+/// Removes the least recently written thumbnails beyond `MAX_CACHED`.
+fn prune(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .collect();
+    if files.len() <= MAX_CACHED {
+        return;
+    }
+    files.sort_by_key(|(modified, _)| *modified);
+    let excess = files.len() - MAX_CACHED;
+    for (_, path) in files.into_iter().take(excess) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+fn encode(thumb: &DynamicImage) -> Result<(Vec<u8>, &'static str, &'static str), String> {
+    let mut bytes = Vec::new();
+    if thumb.color().has_alpha() {
+        thumb
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .map_err(|e| e.to_string())?;
+        Ok((bytes, "image/png", "png"))
+    } else {
+        let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY);
+        thumb.to_rgb8().write_with_encoder(encoder).map_err(|e| e.to_string())?;
+        Ok((bytes, "image/jpeg", "jpg"))
+    }
+}
+
+fn thumbnail(cache: Option<PathBuf>, path: &Path, max_px: u32) -> Result<Thumbnail, String> {
+    let meta = std::fs::metadata(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    if !meta.is_file() {
+        return Err(format!("Not a file: {}", path.display()));
+    }
+    if meta.len() > MAX_SOURCE_BYTES {
+        return Err(format!("{} is too large for a thumbnail", path.display()));
+    }
+    let key = cache_key(path, &meta, max_px);
+
+    if let Some((cached, mime)) = cache.as_deref().and_then(|d| find_cached(d, &key)) {
+        if let (Ok(bytes), Ok((width, height))) = (std::fs::read(&cached), image::image_dimensions(&cached)) {
+            return Ok(Thumbnail { mime, data: STANDARD.encode(bytes), width, height });
+        }
+    }
+
+    let source = ImageReader::open(path)
+        .and_then(|r| r.with_guessed_format())
+        .map_err(|e| format!("Cannot open {}: {}", path.display(), e))?
+        .decode()
+        .map_err(|e| format!("Cannot decode {}: {}", path.display(), e))?;
+    let thumb = if source.width() > max_px || source.height() > max_px {
+        source.thumbnail(max_px, max_px)
+    } else {
+        source
+    };
+    let (bytes, mime, ext) = encode(&thumb)?;
+
+    if let Some(dir) = cache {
+        let file = dir.join(format!("{}.{}", key, ext));
+        match std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(&file, &bytes)) {
+            Ok(()) if rand::random::<u32>().is_multiple_of(PRUNE_EVERY) => prune(&dir),
+            Ok(()) => {}
+            Err(e) => tracing::warn!("Failed to cache {}: {}", file.display(), e),
+        }
+    }
+
+    Ok(Thumbnail {
+        mime,
+        data: STANDARD.encode(&bytes),
+        width: thumb.width(),
+        height: thumb.height(),
+    })
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+/// Thumbnail of an image, at most `max_px` pixels on its longer side (256 by
+/// default, 16 to 1024). Smaller images are not scaled up.
+#[tauri::command]
+pub async fn get_thumbnail(app: AppHandle, path: String, max_px: Option<u32>) -> Result<Thumbnail, AppError> {
+    let max_px = max_px.unwrap_or(DEFAULT_MAX_PX).clamp(MIN_MAX_PX, MAX_MAX_PX);
+    let cache = cache_dir(&app);
+    Ok(tokio::task::spawn_blocking(move || thumbnail(cache, Path::new(&path), max_px))
+        .await
+        .map_err(|e| e.to_string())??)
+}
@@ -0,0 +1,133 @@
+/// System tray icon: shows whether Winter is idle, streaming or running a
+/// tool, and offers quick actions (new chat, abort, pause scheduler, quit).
+///
+/// "New chat" has no backend counterpart, so it shows the main window and
+/// emits `tray-new-chat` for the frontend to open a session.
+use tauri::menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{App, AppHandle, Emitter, Manager, Wry};
+
+use crate::scheduler;
+
+const TRAY_ID: &str = "main";
+
+/// Event emitted to the frontend when "New chat" is picked.
+const NEW_CHAT_EVENT: &str = "tray-new-chat";
+
+/// What the native chat loop is doing, shown in the tray tooltip and menu.
+#[derive(Clone, Copy)]
+pub enum TrayState {
+    Idle,
+    Streaming,
+    RunningTool,
+}
+
+impl TrayState {
+    fn label(self) -> &'static str {
+        match self {
+            TrayState::Idle => "Idle",
+            TrayState::Streaming => "Streaming...",
+            TrayState::RunningTool => "Running a tool...",
+        }
+    }
+}
+
+/// Menu items that change after the tray is built.
+struct TrayMenu {
+    status: MenuItem<Wry>,
+    pause: CheckMenuItem<Wry>,
+}
+
+/// Creates the tray icon. Called once from `setup`.
+pub fn init(app: &App) -> tauri::Result<()> {
+    let status = MenuItem::with_id(app, "status", TrayState::Idle.label(), false, None::<&str>)?;
+    let new_chat = MenuItem::with_id(app, "new_chat", "New chat", true, None::<&str>)?;
+    let abort = MenuItem::with_id(app, "abort", "Abort", true, None::<&str>)?;
+    let pause = CheckMenuItem::with_id(app, "pause_scheduler", "Pause scheduler", true, false, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(
+        app,
+        &[
+            &status,
+            &PredefinedMenuItem::separator(app)?,
+            &new_chat,
+            &abort,
+            &pause,
+            &PredefinedMenuItem::separator(app)?,
+            &quit,
+        ],
+    )?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip("Winter")
+        .menu(&menu)
+        .on_menu_event(on_menu_event);
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+    app.manage(TrayMenu { status, pause });
+    Ok(())
+}
+
+fn on_menu_event(app: &AppHandle, event: MenuEvent) {
+    match event.id.as_ref() {
+        "new_chat" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+            let _ = app.emit(NEW_CHAT_EVENT, ());
+        }
+        "abort" => crate::abort_stream(app.clone()),
+        "pause_scheduler" => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let paused = scheduler::scheduler_is_paused(app.state()).await.unwrap_or(false);
+                let result = if paused {
+                    scheduler::scheduler_resume(app.clone(), app.state()).await
+                } else {
+                    scheduler::scheduler_pause(app.state()).await
+                };
+                if let Err(e) = result {
+                    tracing::warn!("Tray scheduler toggle failed: {}", e);
+                }
+                sync_scheduler(&app).await;
+            });
+        }
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
+
+/// Updates the tray tooltip and status line.
+pub fn set_state(app: &AppHandle, state: TrayState) {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let _ = tray.set_tooltip(Some(format!("Winter: {}", state.label())));
+    }
+    if let Some(menu) = app.try_state::<TrayMenu>() {
+        let _ = menu.status.set_text(state.label());
+    }
+}
+
+/// Makes the "Pause scheduler" check mark match the scheduler's paused flag.
+pub async fn sync_scheduler(app: &AppHandle) {
+    let paused = scheduler::scheduler_is_paused(app.state()).await.unwrap_or(false);
+    if let Some(menu) = app.try_state::<TrayMenu>() {
+        let _ = menu.pause.set_checked(paused);
+    }
+}
+
+/// Shows `Streaming` until dropped, then `Idle`.
+pub struct BusyGuard(AppHandle);
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        set_state(&self.0, TrayState::Idle);
+    }
+}
+
+pub fn busy(app: &AppHandle) -> BusyGuard {
+    set_state(app, TrayState::Streaming);
+    BusyGuard(app.clone())
+}
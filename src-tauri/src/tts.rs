@@ -0,0 +1,304 @@
+//! Text-to-speech through the local GPT-SoVITS service (the "TTS Engine" entry
+//! in the service registry).
+//!
+//! `speak` sends text to the service's `/tts` endpoint (api_v2), stores the
+//! WAV under `<app_cache_dir>/tts/` and plays it with the platform's own
+//! player. A voice is a reference clip plus its transcript, as GPT-SoVITS
+//! needs for zero-shot synthesis. With auto-speak on, finished chat replies
+//! are read aloud.
+//!
+//! Settings stored at: `tts_settings` in settings.json
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::claude::types::ChatStreamEvent;
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_SETTINGS: &str = "tts_settings";
+const DEFAULT_URL: &str = "http://127.0.0.1:9880";
+
+/// Synthesis of long replies can take a while on CPU.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Longest text sent to the engine; longer replies are cut at a sentence end.
+const MAX_SPEAK_CHARS: usize = 1000;
+
+/// Audio files kept in the cache directory; older ones are deleted.
+const MAX_CACHED_CLIPS: usize = 20;
+
+/// A reference voice for zero-shot synthesis.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TtsVoice {
+    pub id: String,
+    /// Reference audio path on the machine running GPT-SoVITS.
+    pub ref_audio_path: String,
+    /// Transcript of the reference audio.
+    #[serde(default)]
+    pub prompt_text: String,
+    /// Language of the reference audio (e.g. "en", "ko", "ja", "zh").
+    pub prompt_lang: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TtsSettings {
+    /// Base URL of the GPT-SoVITS API server.
+    pub url: String,
+    pub voices: Vec<TtsVoice>,
+    /// Voice used when `speak` gets none; the first voice when unset.
+    #[serde(default)]
+    pub default_voice: Option<String>,
+    /// Language of the text to speak; "auto" lets the engine detect it.
+    pub text_lang: String,
+    /// Read finished chat replies aloud.
+    #[serde(default)]
+    pub auto_speak: bool,
+}
+
+impl Default for TtsSettings {
+    fn default() -> Self {
+        TtsSettings {
+            url: DEFAULT_URL.to_string(),
+            voices: Vec::new(),
+            default_voice: None,
+            text_lang: "auto".to_string(),
+            auto_speak: false,
+        }
+    }
+}
+
+fn settings(app: &AppHandle) -> TtsSettings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_SETTINGS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn clip_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| format!("Cannot resolve cache dir: {}", e))?
+        .join("tts");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Cannot create {}: {}", dir.display(), e))?;
+    Ok(dir)
+}
+
+/// Deletes all but the newest `MAX_CACHED_CLIPS` clips.
+fn prune_clips(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    let mut clips: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .collect();
+    clips.sort_by_key(|c| std::cmp::Reverse(c.0));
+    for (_, path) in clips.into_iter().skip(MAX_CACHED_CLIPS) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Turns markdown into something worth hearing: code blocks are skipped and
+/// formatting characters dropped. Cut to `MAX_SPEAK_CHARS` at a sentence end.
+fn speakable(text: &str) -> String {
+    let mut out = String::new();
+    let mut in_code = false;
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        let line: String = line.chars().filter(|c| !matches!(c, '*' | '#' | '`' | '>' | '|')).collect();
+        let line = line.trim();
+        if !line.is_empty() {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    if out.chars().count() <= MAX_SPEAK_CHARS {
+        return out.trim().to_string();
+    }
+    let cut: String = out.chars().take(MAX_SPEAK_CHARS).collect();
+    let sentence_end = cut
+        .char_indices()
+        .rev()
+        .find(|(_, c)| matches!(c, '.' | '!' | '?' | '。' | '\n'));
+    match sentence_end {
+        Some((i, c)) => cut[..i + c.len_utf8()].trim().to_string(),
+        None => cut.trim().to_string(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn player(path: &Path) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", "paplay \"$1\" 2>/dev/null || aplay -q \"$1\"", "sh"]).arg(path);
+    cmd
+}
+
+#[cfg(target_os = "macos")]
+fn player(path: &Path) -> Command {
+    let mut cmd = Command::new("afplay");
+    cmd.arg(path);
+    cmd
+}
+
+#[cfg(target_os = "windows")]
+fn player(path: &Path) -> Command {
+    let mut cmd = Command::new("powershell");
+    cmd.args([
+        "-NoProfile",
+        "-NonInteractive",
+        "-Command",
+        "(New-Object Media.SoundPlayer $env:WINTER_TTS_FILE).PlaySync()",
+    ])
+    .env("WINTER_TTS_FILE", path);
+    cmd
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn player(_path: &Path) -> Command {
+    Command::new("true")
+}
+
+/// Plays a clip in the background. Failures are only logged.
+fn play(path: &Path) {
+    let result = player(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    match result {
+        Ok(mut child) => {
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+        }
+        Err(e) => tracing::warn!("Failed to play audio: {}", e),
+    }
+}
+
+/// Synthesizes `text` and stores the audio. Returns the WAV path.
+async fn synthesize(app: &AppHandle, text: &str, voice: Option<&str>) -> Result<PathBuf, String> {
+    let settings = settings(app);
+    let voice_id = voice.map(str::to_string).or(settings.default_voice.clone());
+    let voice = match &voice_id {
+        Some(id) => settings.voices.iter().find(|v| &v.id == id),
+        None => settings.voices.first(),
+    }
+    .ok_or_else(|| match &voice_id {
+        Some(id) => format!("Voice '{}' not found", id),
+        None => "No TTS voice configured".to_string(),
+    })?;
+
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("HTTP client error: {}", e))?;
+    let resp = client
+        .post(format!("{}/tts", settings.url.trim_end_matches('/')))
+        .json(&json!({
+            "text": text,
+            "text_lang": settings.text_lang,
+            "ref_audio_path": voice.ref_audio_path,
+            "prompt_text": voice.prompt_text,
+            "prompt_lang": voice.prompt_lang,
+            "media_type": "wav",
+            "streaming_mode": false,
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("TTS request failed (is the TTS Engine running?): {}", e))?;
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("TTS engine returned {}: {}", status, body));
+    }
+    let audio = resp.bytes().await.map_err(|e| format!("TTS read failed: {}", e))?;
+
+    let dir = clip_dir(app)?;
+    let path = dir.join(format!("{}.wav", uuid::Uuid::new_v4()));
+    std::fs::write(&path, &audio).map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    prune_clips(&dir);
+    Ok(path)
+}
+
+/// Wraps a chat stream channel so finished replies are read aloud when
+/// auto-speak is on. Returns `on_event` itself when it is off.
+pub fn watch_stream(app: &AppHandle, on_event: Channel<ChatStreamEvent>) -> Channel<ChatStreamEvent> {
+    if !settings(app).auto_speak {
+        return on_event;
+    }
+    let app = app.clone();
+    let reply = Mutex::new(String::new());
+    Channel::new(move |body: InvokeResponseBody| {
+        let InvokeResponseBody::Json(raw) = body else { return Ok(()) };
+        let Ok(event) = serde_json::from_str::<ChatStreamEvent>(&raw) else { return Ok(()) };
+        let mut reply = reply.lock().unwrap_or_else(|e| e.into_inner());
+        match &event {
+            ChatStreamEvent::StreamStart => reply.clear(),
+            ChatStreamEvent::Delta { text } => reply.push_str(text),
+            ChatStreamEvent::StreamEnd => {
+                let text = speakable(&std::mem::take(&mut *reply));
+                if !text.is_empty() {
+                    let app = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        match synthesize(&app, &text, None).await {
+                            Ok(path) => play(&path),
+                            Err(e) => tracing::warn!("Auto-speak failed: {}", e),
+                        }
+                    });
+                }
+            }
+            _ => {}
+        }
+        drop(reply);
+        on_event.send(event)
+    })
+}
+
+// ── Tauri Commands ──────────────────────────────────────────────────
+
+/// Speaks `text` with `voice` (the default voice when `None`) and returns the
+/// path of the stored WAV file.
+#[tauri::command]
+pub async fn speak(app: AppHandle, text: String, voice: Option<String>) -> Result<String, AppError> {
+    let text = speakable(&text);
+    if text.is_empty() {
+        return Err(AppError::InvalidInput("Nothing to speak".to_string()));
+    }
+    let path = synthesize(&app, &text, voice.as_deref()).await?;
+    play(&path);
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub fn get_tts_settings(app: AppHandle) -> TtsSettings {
+    settings(&app)
+}
+
+#[tauri::command]
+pub fn set_tts_settings(app: AppHandle, settings: TtsSettings) -> Result<(), AppError> {
+    if !settings.url.starts_with("http://") && !settings.url.starts_with("https://") {
+        return Err(AppError::InvalidInput("TTS URL must start with http:// or https://".to_string()));
+    }
+    if let Some(id) = &settings.default_voice {
+        if !settings.voices.iter().any(|v| &v.id == id) {
+            return Err(AppError::InvalidInput(format!("Voice '{}' not found", id)));
+        }
+    }
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_SETTINGS, json!(settings));
+    Ok(store.save()?)
+}
@@ -0,0 +1,126 @@
+//! Update checks and in-app self-update through the Tauri updater plugin.
+//!
+//! Releases publish a signed `latest.json` next to the installers (see the
+//! endpoint in tauri.conf.json). The signing public key is baked in at build
+//! time from `WINTER_UPDATER_PUBKEY`; builds without it cannot update.
+//!
+//! A background task checks every few hours and emits `update-available` once
+//! per new version (setting `auto_update_check`, on by default).
+
+use serde::Serialize;
+use serde_json::json;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_AUTO_CHECK: &str = "auto_update_check";
+
+/// Event emitted when a newer version is published.
+const UPDATE_EVENT: &str = "update-available";
+
+const PUBKEY: Option<&str> = option_env!("WINTER_UPDATER_PUBKEY");
+
+/// Delay before the first background check, so it doesn't compete with startup.
+const FIRST_CHECK_DELAY: Duration = Duration::from_secs(60);
+const CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+#[derive(Debug, Serialize, Clone)]
+pub struct UpdateInfo {
+    pub current_version: String,
+    pub version: String,
+    /// Release notes.
+    pub notes: Option<String>,
+    /// Publish date, RFC 3339.
+    pub date: Option<String>,
+}
+
+impl From<&Update> for UpdateInfo {
+    fn from(update: &Update) -> Self {
+        UpdateInfo {
+            current_version: update.current_version.clone(),
+            version: update.version.clone(),
+            notes: update.body.clone(),
+            date: update.date.map(|d| d.to_string()),
+        }
+    }
+}
+
+async fn fetch_update(app: &AppHandle) -> Result<Option<Update>, String> {
+    let pubkey = PUBKEY
+        .filter(|k| !k.is_empty())
+        .ok_or("Updates are not configured in this build")?;
+    app.updater_builder()
+        .pubkey(pubkey)
+        .build()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| format!("Update check failed: {}", e))
+}
+
+fn auto_check_enabled(app: &AppHandle) -> bool {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_AUTO_CHECK))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true)
+}
+
+/// Spawns the periodic background check. Does nothing in builds without a key.
+pub fn spawn_update_checker(app: AppHandle) {
+    if PUBKEY.is_none_or(str::is_empty) {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(FIRST_CHECK_DELAY).await;
+        let mut announced: Option<String> = None;
+        loop {
+            if auto_check_enabled(&app) {
+                match fetch_update(&app).await {
+                    Ok(Some(update)) if announced.as_deref() != Some(update.version.as_str()) => {
+                        announced = Some(update.version.clone());
+                        let _ = app.emit(UPDATE_EVENT, UpdateInfo::from(&update));
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("{}", e),
+                }
+            }
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
+
+// ── Tauri Commands ──────────────────────────────────────────────────
+
+/// Returns the available update, or `None` when this build is the latest.
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<Option<UpdateInfo>, AppError> {
+    Ok(fetch_update(&app).await?.as_ref().map(UpdateInfo::from))
+}
+
+/// Downloads and installs the latest update, then restarts the app.
+#[tauri::command]
+pub async fn install_update(app: AppHandle) -> Result<(), AppError> {
+    let update = fetch_update(&app).await?.ok_or("Already up to date")?;
+    update
+        .download_and_install(|_, _| {}, || {})
+        .await
+        .map_err(|e| format!("Update install failed: {}", e))?;
+    app.restart()
+}
+
+#[tauri::command]
+pub fn get_auto_update_check(app: AppHandle) -> bool {
+    auto_check_enabled(&app)
+}
+
+#[tauri::command]
+pub fn set_auto_update_check(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let store = app.store(STORE_FILE)?;
+    store.set(STORE_KEY_AUTO_CHECK, json!(enabled));
+    Ok(store.save()?)
+}
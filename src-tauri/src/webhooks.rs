@@ -0,0 +1,353 @@
+/// Outbound webhooks: HTTP requests Winter can fire for home automation, CI
+/// triggers and the like.
+///
+/// A webhook is a URL and optional body template with `{{name}}` placeholders,
+/// plus an optional `Authorization` header (e.g. a Home Assistant long-lived
+/// token as `Bearer ...`). Webhooks can be:
+/// - exposed to Claude as tools named after their ID (e.g. `turn_on_lights`),
+///   with their parameters as tool inputs;
+/// - run by scheduled tasks (`{"webhook": "<id>", "vars": {...}}`);
+/// - subscribed to app events: `task_finished`, `task_failed`, `stream_finished`.
+///
+/// Placeholders are URL-encoded in the URL and JSON-escaped in JSON bodies.
+/// Definitions live in the `webhooks` store key.
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::claude::types::ChatStreamEvent;
+use crate::error::AppError;
+use crate::STORE_FILE;
+
+const STORE_KEY_WEBHOOKS: &str = "webhooks";
+
+/// Events a webhook can subscribe to.
+pub const EVENTS: &[&str] = &["task_finished", "task_failed", "stream_finished"];
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Response body returned to the caller (and to Claude) at most.
+const MAX_RESPONSE: usize = 4000;
+
+/// Reply text passed to `stream_finished` webhooks at most.
+const MAX_REPLY_VAR: usize = 2000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebhookParam {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Webhook {
+    /// Lowercase ID; also the tool name when exposed to Claude.
+    pub id: String,
+    pub name: String,
+    /// What the webhook does, shown to Claude as the tool description.
+    #[serde(default)]
+    pub description: String,
+    /// HTTP method, "POST" by default.
+    #[serde(default = "default_method")]
+    pub method: String,
+    /// URL template, e.g. `http://homeassistant.local:8123/api/services/light/turn_on`.
+    pub url: String,
+    /// Value of the `Authorization` header, e.g. `Bearer <token>`.
+    #[serde(default)]
+    pub auth_header: Option<String>,
+    /// Body template. Without one, non-GET requests send the variables as a JSON object.
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Parameters Claude fills in when the webhook is used as a tool.
+    #[serde(default)]
+    pub params: Vec<WebhookParam>,
+    /// Offer the webhook to Claude as a tool.
+    #[serde(default)]
+    pub expose_as_tool: bool,
+    /// App events that fire the webhook; see [`EVENTS`].
+    #[serde(default)]
+    pub events: Vec<String>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_method() -> String {
+    "POST".to_string()
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+pub fn list(app: &AppHandle) -> Vec<Webhook> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY_WEBHOOKS))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn write_list(app: &AppHandle, webhooks: &[Webhook]) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY_WEBHOOKS, json!(webhooks));
+    store.save().map_err(|e| e.to_string())
+}
+
+fn find(app: &AppHandle, id: &str) -> Result<Webhook, String> {
+    list(app)
+        .into_iter()
+        .find(|w| w.id == id)
+        .ok_or_else(|| format!("Webhook '{}' not found", id))
+}
+
+fn validate(webhook: &Webhook) -> Result<(), String> {
+    let id_ok = !webhook.id.is_empty()
+        && webhook.id.len() <= 64
+        && webhook
+            .id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !id_ok {
+        return Err("Webhook ID must be lowercase letters, digits or '_'".to_string());
+    }
+    if webhook.name.trim().is_empty() {
+        return Err("Webhook name cannot be empty".to_string());
+    }
+    if !webhook.url.starts_with("http://") && !webhook.url.starts_with("https://") {
+        return Err("Webhook URL must start with http:// or https://".to_string());
+    }
+    if reqwest::Method::from_bytes(webhook.method.as_bytes()).is_err() {
+        return Err(format!("Invalid HTTP method '{}'", webhook.method));
+    }
+    let reserved = [crate::claude::subagent::DELEGATE_TOOL, crate::opencode::delegate::DELEGATE_TOOL];
+    if webhook.expose_as_tool
+        && (crate::claude::tools::tool_names().contains(&webhook.id) || reserved.contains(&webhook.id.as_str()))
+    {
+        return Err(format!("'{}' is already a built-in tool name", webhook.id));
+    }
+    if let Some(event) = webhook.events.iter().find(|e| !EVENTS.contains(&e.as_str())) {
+        return Err(format!("Unknown event '{}'", event));
+    }
+    Ok(())
+}
+
+/// Replaces every `{{name}}` in `template`, passing values through `escape`.
+/// Unknown placeholders become empty.
+fn render(template: &str, vars: &HashMap<String, String>, escape: impl Fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else { break };
+        out.push_str(&rest[..start]);
+        let name = rest[start + 2..start + 2 + len].trim();
+        out.push_str(&escape(vars.get(name).map(String::as_str).unwrap_or("")));
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn json_escape(value: &str) -> String {
+    let quoted = Value::String(value.to_string()).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((i, _)) => format!("{}…", &text[..i]),
+        None => text.to_string(),
+    }
+}
+
+/// Sends the webhook and returns the status line and response body.
+/// Non-2xx responses are errors.
+pub async fn fire(webhook: &Webhook, vars: &HashMap<String, String>) -> Result<String, String> {
+    let url = render(&webhook.url, vars, |v| urlencoding::encode(v).into_owned());
+    let method = reqwest::Method::from_bytes(webhook.method.to_uppercase().as_bytes())
+        .map_err(|_| format!("Invalid HTTP method '{}'", webhook.method))?;
+    let mut req = reqwest::Client::new()
+        .request(method.clone(), &url)
+        .timeout(REQUEST_TIMEOUT);
+    if let Some(auth) = webhook.auth_header.as_deref().filter(|a| !a.is_empty()) {
+        req = req.header("Authorization", auth);
+    }
+    match webhook.body.as_deref().filter(|b| !b.trim().is_empty()) {
+        Some(template) => {
+            let trimmed = template.trim_start();
+            if trimmed.starts_with('{') || trimmed.starts_with('[') {
+                req = req
+                    .header("Content-Type", "application/json")
+                    .body(render(template, vars, json_escape));
+            } else {
+                req = req.body(render(template, vars, str::to_string));
+            }
+        }
+        None if method != reqwest::Method::GET => req = req.json(vars),
+        None => {}
+    }
+
+    let resp = req
+        .send()
+        .await
+        .map_err(|e| format!("Webhook '{}' failed: {}", webhook.id, e))?;
+    let status = resp.status();
+    let text = resp.text().await.unwrap_or_default();
+    let summary = format!("{}\n{}", status, truncate(text.trim(), MAX_RESPONSE));
+    if status.is_success() {
+        Ok(summary)
+    } else {
+        Err(format!("Webhook '{}' returned {}", webhook.id, summary))
+    }
+}
+
+/// Fires every enabled webhook subscribed to `event`, in the background.
+pub fn fire_event(app: &AppHandle, event: &str, vars: HashMap<String, String>) {
+    let hooks: Vec<Webhook> = list(app)
+        .into_iter()
+        .filter(|w| w.enabled && w.events.iter().any(|e| e == event))
+        .collect();
+    if hooks.is_empty() {
+        return;
+    }
+    let mut vars = vars;
+    vars.insert("event".to_string(), event.to_string());
+    tauri::async_runtime::spawn(async move {
+        for hook in hooks {
+            if let Err(e) = fire(&hook, &vars).await {
+                tracing::warn!("{}", e);
+            }
+        }
+    });
+}
+
+/// Fires `stream_finished` webhooks with the reply text when a stream ends.
+pub fn watch_stream(app: &AppHandle, on_event: Channel<ChatStreamEvent>) -> Channel<ChatStreamEvent> {
+    let subscribed = list(app)
+        .iter()
+        .any(|w| w.enabled && w.events.iter().any(|e| e == "stream_finished"));
+    if !subscribed {
+        return on_event;
+    }
+    let app = app.clone();
+    let reply = Mutex::new(String::new());
+    Channel::new(move |body: InvokeResponseBody| {
+        let InvokeResponseBody::Json(raw) = body else { return Ok(()) };
+        let Ok(event) = serde_json::from_str::<ChatStreamEvent>(&raw) else { return Ok(()) };
+        let mut reply = reply.lock().unwrap_or_else(|e| e.into_inner());
+        match &event {
+            ChatStreamEvent::StreamStart => reply.clear(),
+            ChatStreamEvent::Delta { text } => reply.push_str(text),
+            ChatStreamEvent::StreamEnd => {
+                let text = truncate(reply.trim(), MAX_REPLY_VAR);
+                fire_event(&app, "stream_finished", HashMap::from([("reply".to_string(), text)]));
+            }
+            _ => {}
+        }
+        drop(reply);
+        on_event.send(event)
+    })
+}
+
+/// Tool definitions for the webhooks exposed to Claude.
+pub fn tool_definitions(app: &AppHandle) -> Vec<Value> {
+    list(app)
+        .into_iter()
+        .filter(|w| w.enabled && w.expose_as_tool)
+        .map(|w| {
+            let properties: serde_json::Map<String, Value> = w
+                .params
+                .iter()
+                .map(|p| (p.name.clone(), json!({ "type": "string", "description": p.description })))
+                .collect();
+            let required: Vec<&str> = w.params.iter().map(|p| p.name.as_str()).collect();
+            let description = match w.description.trim() {
+                "" => format!("Fire the '{}' webhook.", w.name),
+                d => d.to_string(),
+            };
+            json!({
+                "name": w.id,
+                "description": description,
+                "input_schema": { "type": "object", "properties": properties, "required": required }
+            })
+        })
+        .collect()
+}
+
+/// Runs a webhook tool call, or returns `None` when `name` is not a webhook tool.
+pub async fn execute_tool(app: &AppHandle, name: &str, input: &Value) -> Option<(String, bool)> {
+    let webhook = list(app)
+        .into_iter()
+        .find(|w| w.enabled && w.expose_as_tool && w.id == name)?;
+    let vars: HashMap<String, String> = webhook
+        .params
+        .iter()
+        .map(|p| {
+            let value = match &input[&p.name] {
+                Value::String(s) => s.clone(),
+                Value::Null => String::new(),
+                other => other.to_string(),
+            };
+            (p.name.clone(), value)
+        })
+        .collect();
+    Some(match fire(&webhook, &vars).await {
+        Ok(summary) => (summary, false),
+        Err(e) => (e, true),
+    })
+}
+
+/// Fires a webhook for a scheduled task.
+pub async fn run_for_task(app: &AppHandle, id: &str, vars: &HashMap<String, String>) -> Result<String, String> {
+    let webhook = find(app, id)?;
+    if !webhook.enabled {
+        return Err(format!("Webhook '{}' is disabled", id));
+    }
+    fire(&webhook, vars).await
+}
+
+// ── Tauri Commands ────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn list_webhooks(app: AppHandle) -> Vec<Webhook> {
+    list(&app)
+}
+
+/// Adds or updates a webhook.
+#[tauri::command]
+pub fn save_webhook(app: AppHandle, mut webhook: Webhook) -> Result<(), AppError> {
+    webhook.id = webhook.id.trim().to_string();
+    webhook.method = webhook.method.trim().to_uppercase();
+    validate(&webhook).map_err(AppError::InvalidInput)?;
+    let mut webhooks = list(&app);
+    match webhooks.iter_mut().find(|w| w.id == webhook.id) {
+        Some(existing) => *existing = webhook,
+        None => webhooks.push(webhook),
+    }
+    Ok(write_list(&app, &webhooks)?)
+}
+
+#[tauri::command]
+pub fn delete_webhook(app: AppHandle, id: String) -> Result<(), AppError> {
+    let mut webhooks = list(&app);
+    let before = webhooks.len();
+    webhooks.retain(|w| w.id != id);
+    if webhooks.len() == before {
+        return Err(AppError::NotFound(format!("Webhook '{}' not found", id)));
+    }
+    Ok(write_list(&app, &webhooks)?)
+}
+
+/// Fires a webhook by hand, e.g. to try it out from settings.
+#[tauri::command]
+pub async fn test_webhook(
+    app: AppHandle,
+    id: String,
+    vars: Option<HashMap<String, String>>,
+) -> Result<String, AppError> {
+    Ok(fire(&find(&app, &id)?, &vars.unwrap_or_default()).await?)
+}
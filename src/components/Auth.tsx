@@ -7,6 +7,7 @@
  */
 import { useState } from 'react';
 import { isTauri } from '../utils/platform';
+import { errorMessage } from '../utils/errors';
 
 async function openUrl(url: string) {
   if (isTauri) {
@@ -42,7 +43,7 @@ export function Auth({ getAuthorizeUrl, onExchangeCode, onSkip }: AuthProps) {
       const url = await getAuthorizeUrl();
       await openUrl(url);
     } catch (e) {
-      setError(String(e));
+      setError(errorMessage(e));
     }
   };
 
@@ -55,7 +56,7 @@ export function Auth({ getAuthorizeUrl, onExchangeCode, onSkip }: AuthProps) {
     try {
       await onExchangeCode(trimmed);
     } catch (e) {
-      setError(String(e));
+      setError(errorMessage(e));
       setSubmitting(false);
     }
   };
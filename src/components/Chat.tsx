@@ -34,9 +34,10 @@ import type { TranslationKey } from '../i18n';
 import type { MessageMode } from '../types';
 import { QuestionDock } from './QuestionDock';
 import { AgentBar } from './AgentBar';
+import { isTauri } from '../utils/platform';
 import '../styles/chat.css';
 
-export type SettingsPageId = 'shortcuts' | 'personalize' | 'language' | 'feedback' | 'archive' | 'ollama' | 'folder' | 'automation';
+export type SettingsPageId = 'shortcuts' | 'personalize' | 'language' | 'feedback' | 'archive' | 'ollama' | 'folder' | 'automation' | 'logs' | 'budget' | 'sync';
 
 interface ChatProps {
   onReauth?: () => void;
@@ -67,6 +68,7 @@ export function Chat({ onReauth, onShowReadme }: ChatProps) {
     activeSession,
     activeSessionId,
     isDraft,
+    activeMode,
     sendMessage,
     isStreaming,
     streamingSessionId,
@@ -195,6 +197,65 @@ export function Chat({ onReauth, onShowReadme }: ChatProps) {
     activeSessionId,
   }), [addSession, sessions, activeSessionId, switchSession, deleteSession, archiveSession, isStreaming, showToast, toggleSearch, abortOpencode]);
 
+  // Tray menu "New chat"
+  useEffect(() => {
+    if (!isTauri) return;
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+    import('@tauri-apps/api/event').then(({ listen }) =>
+      listen('tray-new-chat', () => shortcutActions.onNewSession()).then((fn) => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+    );
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, [shortcutActions]);
+
+  // Spending budget nearly or fully used (see budget settings)
+  useEffect(() => {
+    if (!isTauri) return;
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+    import('@tauri-apps/api/event').then(({ listen }) =>
+      listen<{ state: 'ok' | 'warning' | 'exceeded' }>('budget-warning', (event) => {
+        showToast(event.payload.state === 'exceeded' ? 'toastBudgetExceeded' : 'toastBudgetWarning');
+      }).then((fn) => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+    );
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, [showToast]);
+
+  // Applying a settings profile or switching projects may change the working directory
+  useEffect(() => {
+    if (!isTauri) return;
+    const unlisteners: (() => void)[] = [];
+    let cancelled = false;
+    const refresh = () => {
+      invoke<string>('get_working_directory').then(setWorkingDirectory).catch(() => {});
+      reloadSessions();
+    };
+    import('@tauri-apps/api/event').then(({ listen }) => {
+      for (const event of ['settings-profile-applied', 'project-switched']) {
+        listen(event, refresh).then((fn) => {
+          if (cancelled) fn();
+          else unlisteners.push(fn);
+        });
+      }
+    });
+    return () => {
+      cancelled = true;
+      unlisteners.forEach((fn) => fn());
+    };
+  }, [reloadSessions]);
+
   const { addToHistory, getPreviousSent, getNextSent, resetHistoryIndex } = useShortcuts(shortcutActions);
   const { pending: pendingQuestion, reply: replyQuestion, reject: rejectQuestion } = useQuestion(
     activeSession.ocSessionId,
@@ -211,6 +272,23 @@ export function Chat({ onReauth, onShowReadme }: ChatProps) {
     sendMessage(text, images, mode);
   }, [sendMessage, addToHistory, resetHistoryIndex]);
 
+  // Text submitted from the global-hotkey quick prompt window
+  useEffect(() => {
+    if (!isTauri) return;
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+    import('@tauri-apps/api/event').then(({ listen }) =>
+      listen<string>('quick-prompt', (e) => handleSendMessage(e.payload)).then((fn) => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+    );
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, [handleSendMessage]);
+
   const agentState = useAgents();
 
   const triggerDiamondGlow = useCallback(() => {
@@ -462,6 +540,7 @@ export function Chat({ onReauth, onShowReadme }: ChatProps) {
             ) : (
               <MessageInput
                 onSend={handleSendMessage}
+                mode={activeMode}
                 disabled={false}
                 isStreaming={isStreaming && streamingSessionId === activeSessionId}
                 onStop={abortOpencode}
@@ -5,17 +5,25 @@
  * - Auto-growing textarea (max 120px height)
  * - Image attachments via paste, drag-drop, or file picker
  * - Send on Enter (Shift+Enter = newline), Ctrl+↑/↓ for message history
+ * - Mode selector (normal / search / analyze), synced with the conversation's remembered mode
  * - Stop button while streaming
  */
 import { useState, useRef, useCallback, useEffect } from 'react';
 import { useClickFlash } from '../hooks/useClickFlash';
-import { useI18n } from '../i18n';
+import { useI18n, type TranslationKey } from '../i18n';
+import { invoke } from '../utils/invoke-shim';
+import { isTauri } from '../utils/platform';
 import type { ImageAttachment, MessageMode } from '../types';
 import '../styles/input.css';
 
 interface MessageInputProps {
-  /** Called when the user submits a message */
+  /**
+   * Called when the user submits a message. `mode` is only set when the user
+   * changed the selector; otherwise the conversation's remembered mode applies.
+   */
   onSend: (text: string, images?: ImageAttachment[], mode?: MessageMode) => void;
+  /** Mode remembered for the active conversation */
+  mode?: MessageMode;
   /** When true, the input is read-only (AI is responding) */
   disabled?: boolean;
   /** True while the AI is streaming — shows stop button instead of send */
@@ -32,6 +40,12 @@ interface MessageInputProps {
   onFocusReady?: (fn: () => void) => void;
 }
 
+const MODE_LABELS: Record<MessageMode, TranslationKey> = {
+  normal: 'modeNormal',
+  search: 'modeSearch',
+  analyze: 'modeAnalyze',
+};
+
 const MAX_IMAGE_SIZE = 5 * 1024 * 1024;
 const MAX_TEXT_SIZE = 500 * 1024;
 
@@ -70,11 +84,13 @@ function readAsText(file: File): Promise<string | null> {
   });
 }
 
-export function MessageInput({ onSend, disabled, isStreaming, onStop: _onStop, onHistoryUp, onHistoryDown, fileInputRef: externalFileRef, onFocusReady }: MessageInputProps) {
+export function MessageInput({ onSend, mode = 'normal', disabled, isStreaming, onStop: _onStop, onHistoryUp, onHistoryDown, fileInputRef: externalFileRef, onFocusReady }: MessageInputProps) {
   const onFlash = useClickFlash();
   const { t } = useI18n();
   const [text, setText] = useState('');
   const [attachedImages, setAttachedImages] = useState<ImageAttachment[]>([]);
+  const [selectedMode, setSelectedMode] = useState<MessageMode>(mode);
+  const [modeChanged, setModeChanged] = useState(false);
   const textareaRef = useRef<HTMLTextAreaElement>(null);
   const internalFileRef = useRef<HTMLInputElement>(null);
   const fileInputRef = externalFileRef ?? internalFileRef;
@@ -85,6 +101,11 @@ export function MessageInput({ onSend, disabled, isStreaming, onStop: _onStop, o
     }
   }, [onFocusReady]);
 
+  useEffect(() => {
+    setSelectedMode(mode);
+    setModeChanged(false);
+  }, [mode]);
+
   useEffect(() => {
     const el = textareaRef.current;
     if (!el) return;
@@ -95,13 +116,14 @@ export function MessageInput({ onSend, disabled, isStreaming, onStop: _onStop, o
   const handleSend = useCallback(() => {
     const trimmed = text.trim();
     if ((!trimmed && attachedImages.length === 0) || disabled) return;
-    onSend(trimmed, attachedImages.length > 0 ? attachedImages : undefined, 'normal');
+    onSend(trimmed, attachedImages.length > 0 ? attachedImages : undefined, modeChanged ? selectedMode : undefined);
     setText('');
     setAttachedImages([]);
+    setModeChanged(false);
     if (textareaRef.current) {
       textareaRef.current.style.height = 'auto';
     }
-  }, [text, attachedImages, onSend, disabled]);
+  }, [text, attachedImages, onSend, disabled, modeChanged, selectedMode]);
 
   const handleKeyDown = (e: React.KeyboardEvent) => {
     if (e.nativeEvent.isComposing || e.keyCode === 229) return;
@@ -160,7 +182,17 @@ export function MessageInput({ onSend, disabled, isStreaming, onStop: _onStop, o
     const items = e.clipboardData?.items;
     if (!items) return;
     const imageItems = Array.from(items).filter((item) => item.type.startsWith('image/'));
-    if (imageItems.length === 0) return;
+    if (imageItems.length === 0) {
+      // Some webviews (WebKitGTK) don't expose pasted screenshots; ask the backend.
+      if (isTauri && !e.clipboardData.getData('text')) {
+        invoke<{ media_type: string; data: string } | null>('get_clipboard_image')
+          .then((img) => {
+            if (img) setAttachedImages((prev) => [...prev, { mediaType: img.media_type, data: img.data }]);
+          })
+          .catch(() => {});
+      }
+      return;
+    }
     e.preventDefault();
     const files = imageItems.map((item) => item.getAsFile()).filter(Boolean) as File[];
     processFiles(files);
@@ -223,6 +255,18 @@ export function MessageInput({ onSend, disabled, isStreaming, onStop: _onStop, o
           rows={1}
         />
         <div className="input-actions">
+          <select
+            className="input-mode"
+            value={selectedMode}
+            onChange={(e) => {
+              setSelectedMode(e.target.value as MessageMode);
+              setModeChanged(true);
+            }}
+          >
+            {(Object.keys(MODE_LABELS) as MessageMode[]).map((m) => (
+              <option key={m} value={m}>{t(MODE_LABELS[m])}</option>
+            ))}
+          </select>
           <button
             className="input-attach"
             onClick={(e) => { onFlash(e); fileInputRef.current?.click(); }}
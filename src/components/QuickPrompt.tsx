@@ -0,0 +1,54 @@
+/**
+ * QuickPrompt — the small always-on-top window opened by the global hotkey.
+ *
+ * Enter hands the text to the main window, which sends it into the active
+ * conversation. Escape or losing focus hides the window.
+ */
+import { useEffect, useRef, useState, type KeyboardEvent } from 'react';
+import { invoke } from '../utils/invoke-shim';
+import { useI18n } from '../i18n';
+import '../styles/quickprompt.css';
+
+/** Renders the single-line quick prompt input */
+export function QuickPrompt() {
+  const { t } = useI18n();
+  const [text, setText] = useState('');
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    const focus = () => inputRef.current?.focus();
+    const hide = () => { invoke('hide_quick_prompt').catch(() => {}); };
+    focus();
+    window.addEventListener('focus', focus);
+    window.addEventListener('blur', hide);
+    return () => {
+      window.removeEventListener('focus', focus);
+      window.removeEventListener('blur', hide);
+    };
+  }, []);
+
+  const handleKeyDown = (e: KeyboardEvent<HTMLInputElement>) => {
+    if (e.key === 'Escape') {
+      invoke('hide_quick_prompt').catch(() => {});
+    } else if (e.key === 'Enter' && !e.nativeEvent.isComposing) {
+      e.preventDefault();
+      const value = text.trim();
+      setText('');
+      invoke('submit_quick_prompt', { text: value }).catch(() => {});
+    }
+  };
+
+  return (
+    <div className="quick-prompt" data-tauri-drag-region>
+      <input
+        ref={inputRef}
+        className="quick-prompt-input"
+        value={text}
+        onChange={(e) => setText(e.target.value)}
+        onKeyDown={handleKeyDown}
+        placeholder={t('quickPromptPlaceholder')}
+        autoFocus
+      />
+    </div>
+  );
+}
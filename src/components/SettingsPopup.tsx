@@ -21,6 +21,9 @@ import {
   IconOllama,
   IconHowToUse,
   IconFeedback,
+  IconBook,
+  IconCoin,
+  IconSync,
   IconCheck,
   IconChevronRight,
 } from './icons';
@@ -297,6 +300,36 @@ export function SettingsPopup({ open, onClose, onSelectSettingsPage, onReauth, o
           <span className="settings-popup-label">{t('sendFeedback')}</span>
           <span className="settings-popup-chevron"><IconChevronRight /></span>
         </button>
+
+        <button
+          className="settings-popup-item"
+          onClick={(e) => { onFlash(e); handleSubPage('budget'); }}
+          role="menuitem"
+        >
+          <span className="settings-popup-icon"><IconCoin /></span>
+          <span className="settings-popup-label">{t('budgetTitle')}</span>
+          <span className="settings-popup-chevron"><IconChevronRight /></span>
+        </button>
+
+        <button
+          className="settings-popup-item"
+          onClick={(e) => { onFlash(e); handleSubPage('sync'); }}
+          role="menuitem"
+        >
+          <span className="settings-popup-icon"><IconSync /></span>
+          <span className="settings-popup-label">{t('syncTitle')}</span>
+          <span className="settings-popup-chevron"><IconChevronRight /></span>
+        </button>
+
+        <button
+          className="settings-popup-item"
+          onClick={(e) => { onFlash(e); handleSubPage('logs'); }}
+          role="menuitem"
+        >
+          <span className="settings-popup-icon"><IconBook /></span>
+          <span className="settings-popup-label">{t('logsTitle')}</span>
+          <span className="settings-popup-chevron"><IconChevronRight /></span>
+        </button>
       </div>
     </div>
   );
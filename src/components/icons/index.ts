@@ -22,3 +22,4 @@ export { IconUser } from './IconUser';
 export { IconRobot } from './IconRobot';
 export { IconBook } from './IconBook';
 export { IconHamburger } from './IconHamburger';
+export { IconSync } from './IconSync';
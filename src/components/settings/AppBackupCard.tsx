@@ -0,0 +1,124 @@
+/**
+ * AppBackupCard — Passphrase and restore for the encrypted app data backup.
+ *
+ * Shown at the bottom of the Automation page. The "App Data Backup" task
+ * encrypts its archives with the passphrase set here; restoring an archive
+ * replaces settings, conversations, registries and the memory DB, then the
+ * backend restarts Winter.
+ *
+ * Tauri commands: has_backup_passphrase, set_backup_passphrase, restore_backup.
+ */
+import { useState, useEffect } from 'react';
+import { invoke } from '../../utils/invoke-shim';
+import { errorMessage } from '../../utils/errors';
+import { useI18n } from '../../i18n';
+
+interface RestoreReport {
+  restored: string[];
+  previous_copy: string;
+}
+
+interface AppBackupCardProps {
+  /** Click-flash ripple handler from useClickFlash */
+  onFlash: (e: React.MouseEvent<HTMLElement>) => void;
+}
+
+/**
+ * Backup passphrase form and restore form.
+ *
+ * @param onFlash - ripple effect callback on button click
+ */
+export function AppBackupCard({ onFlash }: AppBackupCardProps) {
+  const { t } = useI18n();
+  const [hasPassphrase, setHasPassphrase] = useState(false);
+  const [passphrase, setPassphrase] = useState('');
+  const [restorePath, setRestorePath] = useState('');
+  const [restorePassphrase, setRestorePassphrase] = useState('');
+  const [busy, setBusy] = useState(false);
+  const [status, setStatus] = useState<{ text: string; error: boolean } | null>(null);
+
+  useEffect(() => {
+    invoke<boolean>('has_backup_passphrase').then(setHasPassphrase).catch(() => {});
+  }, []);
+
+  const handleSetPassphrase = async (e: React.MouseEvent<HTMLElement>) => {
+    onFlash(e);
+    try {
+      await invoke('set_backup_passphrase', { passphrase });
+      setHasPassphrase(true);
+      setPassphrase('');
+      setStatus({ text: t('appBackupPassphraseSaved'), error: false });
+    } catch (err) {
+      setStatus({ text: errorMessage(err), error: true });
+    }
+  };
+
+  const handleRestore = async (e: React.MouseEvent<HTMLElement>) => {
+    onFlash(e);
+    if (!window.confirm(t('appBackupRestoreConfirm'))) return;
+    setBusy(true);
+    try {
+      const report = await invoke<RestoreReport>('restore_backup', {
+        path: restorePath,
+        passphrase: restorePassphrase || null,
+      });
+      setStatus({ text: `${t('appBackupRestored')} (${report.restored.length})`, error: false });
+    } catch (err) {
+      setStatus({ text: errorMessage(err), error: true });
+    }
+    setBusy(false);
+  };
+
+  return (
+    <div className="settings-automation-section">
+      <span className="settings-automation-section-title">{t('appBackupTitle')}</span>
+      <div className="settings-card settings-automation-backup">
+        <span className="settings-automation-backup-hint">
+          {hasPassphrase ? t('appBackupPassphraseSet') : t('appBackupNoPassphrase')}
+        </span>
+        <div className="settings-automation-backup-row">
+          <input
+            className="settings-automation-create-input"
+            type="password"
+            placeholder={t('appBackupPassphrase')}
+            value={passphrase}
+            onChange={(e) => setPassphrase(e.target.value)}
+          />
+          <button
+            className="settings-automation-create-submit"
+            disabled={!passphrase}
+            onClick={handleSetPassphrase}
+          >
+            {t('appBackupSetPassphrase')}
+          </button>
+        </div>
+        <div className="settings-automation-backup-row">
+          <input
+            className="settings-automation-create-input"
+            type="text"
+            placeholder={t('appBackupRestorePath')}
+            value={restorePath}
+            onChange={(e) => setRestorePath(e.target.value)}
+          />
+          <input
+            className="settings-automation-create-input"
+            type="password"
+            placeholder={t('appBackupRestorePassphrase')}
+            value={restorePassphrase}
+            onChange={(e) => setRestorePassphrase(e.target.value)}
+          />
+          <button
+            className="settings-automation-create-submit"
+            disabled={busy || !restorePath.trim()}
+            onClick={handleRestore}
+          >
+            {t('appBackupRestore')}
+          </button>
+        </div>
+        {status && (
+          <span className={`settings-automation-backup-status${status.error ? ' error' : ''}`}>{status.text}</span>
+        )}
+      </div>
+    </div>
+  );
+}
@@ -6,11 +6,15 @@
  * and inline form for creating new cron tasks.
  *
  * Tauri commands: get_services_status, get_scheduler_status, control_service,
- * toggle_task, run_task_now, delete_task, create_task.
+ * toggle_task, run_task_now, delete_task, create_task. The Phoenix watchdog
+ * and the encrypted app data backup have their own cards, `PhoenixCard` and
+ * `AppBackupCard`.
  */
 import { useState, useEffect, useRef } from 'react';
 import { invoke } from '../../utils/invoke-shim';
 import { useI18n } from '../../i18n';
+import { AppBackupCard } from './AppBackupCard';
+import { PhoenixCard } from './PhoenixCard';
 import '../../styles/settings-automation.css';
 
 interface ServiceStatusInfo {
@@ -387,6 +391,10 @@ export function AutomationPage({ onFlash }: AutomationPageProps) {
         )}
       </div>
 
+      <PhoenixCard onFlash={onFlash} />
+
+      <AppBackupCard onFlash={onFlash} />
+
       <button
         className="settings-automation-refresh-btn"
         onClick={(e) => { onFlash(e); fetchStatus(); }}
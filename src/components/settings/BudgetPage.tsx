@@ -0,0 +1,174 @@
+/**
+ * BudgetPage — Daily/weekly spending budget for Claude API use.
+ *
+ * Shows this period's tracked usage from `get_budget_status` and edits the
+ * limits via `set_budget_settings`. Past the warning threshold a toast
+ * appears; once the limit is reached, chats ask for confirmation, switch to a
+ * cheaper model or answer with Ollama, depending on `on_exceed`.
+ */
+import { useState, useEffect } from 'react';
+import { invoke } from '../../utils/invoke-shim';
+import { errorMessage } from '../../utils/errors';
+import { useI18n, type TranslationKey } from '../../i18n';
+import '../../styles/settings-feedback.css';
+import '../../styles/settings-budget.css';
+
+type ExceedAction = 'confirm' | 'fallback_model' | 'ollama';
+
+interface BudgetSettings {
+  enabled: boolean;
+  period: 'daily' | 'weekly';
+  token_limit: number;
+  cost_limit: number;
+  warn_percent: number;
+  on_exceed: ExceedAction;
+  fallback_model: string;
+}
+
+interface BudgetStatus {
+  tokens_used: number;
+  cost_used: number;
+  fraction: number;
+  state: 'ok' | 'warning' | 'exceeded';
+}
+
+const EXCEED_LABELS: Record<ExceedAction, TranslationKey> = {
+  confirm: 'budgetExceedConfirm',
+  fallback_model: 'budgetExceedFallback',
+  ollama: 'budgetExceedOllama',
+};
+
+interface BudgetPageProps {
+  /** Click-flash ripple handler from useClickFlash */
+  onFlash: (e: React.MouseEvent<HTMLElement>) => void;
+}
+
+/**
+ * Settings page for the spending budget.
+ *
+ * @param onFlash - ripple effect callback on save button click
+ */
+export function BudgetPage({ onFlash }: BudgetPageProps) {
+  const { t } = useI18n();
+  const [settings, setSettings] = useState<BudgetSettings | null>(null);
+  const [status, setStatus] = useState<BudgetStatus | null>(null);
+  const [saveState, setSaveState] = useState<'idle' | 'saved' | 'error'>('idle');
+  const [error, setError] = useState('');
+
+  const refreshStatus = () => {
+    invoke<BudgetStatus>('get_budget_status').then(setStatus).catch(() => {});
+  };
+
+  useEffect(() => {
+    invoke<BudgetSettings>('get_budget_settings').then(setSettings).catch(() => {});
+    refreshStatus();
+  }, []);
+
+  if (!settings) return null;
+
+  const update = (patch: Partial<BudgetSettings>) => {
+    setSettings({ ...settings, ...patch });
+    setSaveState('idle');
+  };
+
+  const handleSave = async (e: React.MouseEvent<HTMLElement>) => {
+    onFlash(e);
+    try {
+      setSettings(await invoke<BudgetSettings>('set_budget_settings', { settings }));
+      setSaveState('saved');
+      refreshStatus();
+      setTimeout(() => setSaveState('idle'), 3000);
+    } catch (err) {
+      setError(errorMessage(err));
+      setSaveState('error');
+    }
+  };
+
+  return (
+    <div className="settings-budget">
+      {status && (
+        <div className={`settings-budget-usage ${status.state}`}>
+          <span>{t(settings.period === 'daily' ? 'budgetUsedToday' : 'budgetUsedWeek')}</span>
+          <span>
+            {status.tokens_used.toLocaleString()} {t('budgetTokens')} · ${status.cost_used.toFixed(2)}
+            {settings.enabled && status.fraction > 0 && ` · ${Math.round(status.fraction * 100)}%`}
+          </span>
+        </div>
+      )}
+
+      <label className="settings-feedback-attach">
+        <input type="checkbox" checked={settings.enabled} onChange={(e) => update({ enabled: e.target.checked })} />
+        {t('budgetEnabled')}
+      </label>
+
+      <label className="settings-budget-row">
+        {t('budgetPeriod')}
+        <select value={settings.period} onChange={(e) => update({ period: e.target.value as BudgetSettings['period'] })}>
+          <option value="daily">{t('budgetDaily')}</option>
+          <option value="weekly">{t('budgetWeekly')}</option>
+        </select>
+      </label>
+
+      <label className="settings-budget-row">
+        {t('budgetTokenLimit')}
+        <input
+          type="number"
+          min={0}
+          step={10000}
+          value={settings.token_limit}
+          onChange={(e) => update({ token_limit: Math.max(0, Math.floor(Number(e.target.value) || 0)) })}
+        />
+      </label>
+
+      <label className="settings-budget-row">
+        {t('budgetCostLimit')}
+        <input
+          type="number"
+          min={0}
+          step={0.5}
+          value={settings.cost_limit}
+          onChange={(e) => update({ cost_limit: Math.max(0, Number(e.target.value) || 0) })}
+        />
+      </label>
+
+      <label className="settings-budget-row">
+        {t('budgetWarnPercent')}
+        <input
+          type="number"
+          min={1}
+          max={100}
+          value={settings.warn_percent}
+          onChange={(e) => update({ warn_percent: Math.floor(Number(e.target.value) || 0) })}
+        />
+      </label>
+
+      <label className="settings-budget-row">
+        {t('budgetOnExceed')}
+        <select value={settings.on_exceed} onChange={(e) => update({ on_exceed: e.target.value as ExceedAction })}>
+          {(Object.keys(EXCEED_LABELS) as ExceedAction[]).map((action) => (
+            <option key={action} value={action}>{t(EXCEED_LABELS[action])}</option>
+          ))}
+        </select>
+      </label>
+
+      {settings.on_exceed === 'fallback_model' && (
+        <label className="settings-budget-row">
+          {t('budgetFallbackModel')}
+          <input
+            type="text"
+            value={settings.fallback_model}
+            onChange={(e) => update({ fallback_model: e.target.value })}
+          />
+        </label>
+      )}
+
+      <div className="settings-feedback-actions">
+        {saveState === 'saved' && <span className="settings-feedback-status sent">{t('budgetSaved')}</span>}
+        {saveState === 'error' && <span className="settings-feedback-status error">{error}</span>}
+        <button className="settings-send-btn" onClick={handleSave}>
+          {t('budgetSave')}
+        </button>
+      </div>
+    </div>
+  );
+}
@@ -2,13 +2,22 @@
  * FeedbackPage — Textarea + send button for submitting user feedback.
  *
  * Invokes 'send_feedback' Tauri command with the trimmed text.
+ * When crash reports exist, offers an opt-in checkbox to attach the newest one.
+ * Diagnostics (version, OS, last error, sanitized log tail) are opt-in too.
+ * 'Open GitHub issue' opens a pre-filled issue in the browser instead.
+ * When feedback is turned off or has no destination, sending is disabled.
  * Shows sent/error status inline for 3 seconds, then resets.
  */
-import { useState } from 'react';
+import { useState, useEffect } from 'react';
 import { invoke } from '../../utils/invoke-shim';
 import { useI18n } from '../../i18n';
 import '../../styles/settings-feedback.css';
 
+interface FeedbackSettings {
+  enabled: boolean;
+  destination: { kind: 'discord' | 'relay'; url: string } | null;
+}
+
 interface FeedbackPageProps {
   /** Click-flash ripple handler from useClickFlash */
   onFlash: (e: React.MouseEvent<HTMLElement>) => void;
@@ -24,14 +33,30 @@ export function FeedbackPage({ onFlash }: FeedbackPageProps) {
   const [feedbackText, setFeedbackText] = useState('');
   const [sending, setSending] = useState(false);
   const [status, setStatus] = useState<'idle' | 'sent' | 'error'>('idle');
+  const [hasCrashReport, setHasCrashReport] = useState(false);
+  const [attachCrash, setAttachCrash] = useState(false);
+  const [available, setAvailable] = useState(true);
+  const [includeDiagnostics, setIncludeDiagnostics] = useState(false);
+
+  useEffect(() => {
+    invoke<FeedbackSettings>('get_feedback_settings')
+      .then((settings) => setAvailable(settings.enabled && settings.destination !== null))
+      .catch(() => {});
+  }, []);
+
+  useEffect(() => {
+    invoke<unknown[]>('get_crash_reports')
+      .then((reports) => setHasCrashReport(reports.length > 0))
+      .catch(() => {});
+  }, []);
 
   const handleSend = async (e: React.MouseEvent<HTMLElement>) => {
     onFlash(e);
-    if (!feedbackText.trim() || sending) return;
+    if (!feedbackText.trim() || sending || !available) return;
     setSending(true);
     setStatus('idle');
     try {
-      await invoke('send_feedback', { text: feedbackText.trim() });
+      await invoke('send_feedback', { text: feedbackText.trim(), attachCrashReport: attachCrash, includeDiagnostics });
       setStatus('sent');
       setFeedbackText('');
       setTimeout(() => setStatus('idle'), 3000);
@@ -41,6 +66,16 @@ export function FeedbackPage({ onFlash }: FeedbackPageProps) {
     setSending(false);
   };
 
+  const handleOpenIssue = async (e: React.MouseEvent<HTMLElement>) => {
+    onFlash(e);
+    if (!feedbackText.trim()) return;
+    try {
+      await invoke('open_feedback_issue', { title: '', text: feedbackText.trim(), includeDiagnostics });
+    } catch {
+      setStatus('error');
+    }
+  };
+
   return (
     <div className="settings-feedback">
       <textarea
@@ -49,13 +84,27 @@ export function FeedbackPage({ onFlash }: FeedbackPageProps) {
         value={feedbackText}
         onChange={(e) => setFeedbackText(e.target.value)}
       />
+      {hasCrashReport && (
+        <label className="settings-feedback-attach">
+          <input type="checkbox" checked={attachCrash} onChange={(e) => setAttachCrash(e.target.checked)} />
+          {t('feedbackAttachCrash')}
+        </label>
+      )}
+      <label className="settings-feedback-attach">
+        <input type="checkbox" checked={includeDiagnostics} onChange={(e) => setIncludeDiagnostics(e.target.checked)} />
+        {t('feedbackIncludeDiagnostics')}
+      </label>
       <div className="settings-feedback-actions">
+        {!available && <span className="settings-feedback-status error">{t('feedbackUnavailable')}</span>}
         {status === 'sent' && <span className="settings-feedback-status sent">{t('feedbackSent')}</span>}
         {status === 'error' && <span className="settings-feedback-status error">{t('feedbackError')}</span>}
+        <button className="settings-send-btn" onClick={handleOpenIssue} disabled={!feedbackText.trim()}>
+          {t('feedbackOpenIssue')}
+        </button>
         <button
           className="settings-send-btn"
           onClick={handleSend}
-          disabled={sending || !feedbackText.trim()}
+          disabled={sending || !feedbackText.trim() || !available}
         >
           {sending ? t('feedbackSending') : t('feedbackSend')}
         </button>
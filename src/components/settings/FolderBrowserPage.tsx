@@ -1,7 +1,8 @@
 /**
  * FolderBrowserPage — File system directory browser with history, search, and folder creation.
  *
- * Tauri commands: opencode_get_path, opencode_list_files, search_directories, create_directory.
+ * Tauri commands: opencode_get_path, opencode_list_files, search_directories, create_directory,
+ * bookmark_list, bookmark_add, bookmark_remove, get_recent_directories.
  * Props: current workingDirectory + onChangeDirectory callback (owned by Chat).
  */
 import { useState, useEffect, useCallback, useRef } from 'react';
@@ -9,6 +10,12 @@ import { invoke } from '../../utils/invoke-shim';
 import { useI18n } from '../../i18n';
 import '../../styles/settings-folder.css';
 
+interface Bookmark {
+  path: string;
+  name: string;
+  exists: boolean;
+}
+
 interface FolderBrowserPageProps {
   /** Click-flash ripple handler from useClickFlash */
   onFlash: (e: React.MouseEvent<HTMLElement>) => void;
@@ -43,6 +50,8 @@ export function FolderBrowserPage({
   const [newFolderName, setNewFolderName] = useState('');
   const [history, setHistory] = useState<string[]>([workingDirectory || '/home']);
   const [historyIdx, setHistoryIdx] = useState(0);
+  const [bookmarks, setBookmarks] = useState<Bookmark[]>([]);
+  const [recentDirs, setRecentDirs] = useState<Array<{ path: string; used_at: string }>>([]);
   const homePathRef = useRef('');
   const dropdownRef = useRef<HTMLDivElement>(null);
   const createBtnRef = useRef<HTMLButtonElement>(null);
@@ -63,6 +72,22 @@ export function FolderBrowserPage({
       .catch(() => {});
   }, []);
 
+  useEffect(() => {
+    invoke<Bookmark[]>('bookmark_list').then(setBookmarks).catch(() => {});
+    invoke<Array<{ path: string; used_at: string }>>('get_recent_directories', { limit: 8 })
+      .then(setRecentDirs)
+      .catch(() => {});
+  }, []);
+
+  const isBookmarked = bookmarks.some(b => b.path === browsePath.replace(/\/+$/, ''));
+
+  const toggleBookmark = async () => {
+    try {
+      const updated = await invoke<Bookmark[]>(isBookmarked ? 'bookmark_remove' : 'bookmark_add', { path: browsePath });
+      setBookmarks(updated);
+    } catch { /* best-effort */ }
+  };
+
   const navigateTo = useCallback(async (dirPath: string, pushHistory = true) => {
     setLoading(true);
     const relPath = toRel(dirPath);
@@ -154,7 +179,7 @@ export function FolderBrowserPage({
       try {
         const home = homePathRef.current || '/home';
         const results: Array<{ name: string; absolute: string }> = await invoke('search_directories', {
-          root: home, query: q, maxResults: 20,
+          root: home, query: q, maxResults: 20, searchId: 'folder-browser',
         });
         setSearchResults(results);
       } catch { setSearchResults([]); }
@@ -165,6 +190,7 @@ export function FolderBrowserPage({
 
   const hasQuery = searchFocused && searchValue.trim().length > 0;
   const showDropdown = hasQuery && (searchResults.length > 0 || searchDone);
+  const showRecent = searchFocused && !hasQuery && recentDirs.length > 0;
 
   const handleCreateFolder = async () => {
     const name = newFolderName.trim();
@@ -230,6 +256,31 @@ export function FolderBrowserPage({
               ))}
             </div>
           )}
+          {showRecent && (
+            <div className="settings-folder-dropdown">
+              {recentDirs.map((d) => (
+                <button
+                  key={d.path}
+                  className="settings-folder-dropdown-item"
+                  onMouseDown={(e) => {
+                    e.preventDefault();
+                    onFlash(e);
+                    navigateTo(d.path);
+                    setSearchFocused(false);
+                  }}
+                >
+                  <span className="settings-folder-dropdown-icon">
+                    <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="1.8" strokeLinecap="round" strokeLinejoin="round">
+                      <circle cx="12" cy="12" r="10" />
+                      <polyline points="12 6 12 12 16 14" />
+                    </svg>
+                  </span>
+                  <span className="settings-folder-dropdown-name">{d.path.split('/').filter(Boolean).pop() || d.path}</span>
+                  <span className="settings-folder-dropdown-path">{d.path}</span>
+                </button>
+              ))}
+            </div>
+          )}
         </div>
         <button
           className="settings-folder-go-btn"
@@ -300,6 +351,15 @@ export function FolderBrowserPage({
               <line x1="9" y1="14" x2="15" y2="14" />
             </svg>
           </button>
+          <button
+            className={`settings-folder-nav-btn${isBookmarked ? ' active' : ''}`}
+            onClick={(e) => { onFlash(e); toggleBookmark(); }}
+            title={isBookmarked ? 'Remove bookmark' : 'Bookmark'}
+          >
+            <svg width="16" height="16" viewBox="0 0 24 24" fill={isBookmarked ? 'currentColor' : 'none'} stroke="currentColor" strokeWidth="2" strokeLinecap="round" strokeLinejoin="round">
+              <polygon points="12 2 15.09 8.26 22 9.27 17 14.14 18.18 21.02 12 17.77 5.82 21.02 7 14.14 2 9.27 8.91 8.26 12 2" />
+            </svg>
+          </button>
         </div>
         <button
           className="settings-folder-toggle-btn"
@@ -315,6 +375,22 @@ export function FolderBrowserPage({
         </button>
       </div>
 
+      {bookmarks.length > 0 && (
+        <div className="settings-folder-bookmarks">
+          {bookmarks.map((b) => (
+            <button
+              key={b.path}
+              className={`settings-folder-bookmark${b.exists ? '' : ' missing'}`}
+              onClick={(e) => { onFlash(e); if (b.exists) navigateTo(b.path); }}
+              disabled={!b.exists}
+              title={b.path}
+            >
+              {b.name}
+            </button>
+          ))}
+        </div>
+      )}
+
       <div className="settings-folder-actions">
         <button
           className="settings-folder-select-btn"
@@ -0,0 +1,155 @@
+/**
+ * HealthPanel — "everything is fine / X is broken" summary from `get_system_health`.
+ *
+ * Shown at the top of the Logs page. Lists the backend's problem messages
+ * when anything needs attention, followed by one status row per area
+ * (sign-in, Claude API, Ollama, OpenCode, scheduler, services, disk).
+ */
+import { useState, useEffect, useCallback } from 'react';
+import { invoke } from '../../utils/invoke-shim';
+import { errorMessage } from '../../utils/errors';
+import { useI18n, type TranslationKey } from '../../i18n';
+
+interface SystemHealth {
+  healthy: boolean;
+  problems: string[];
+  checked_at: string;
+  auth: { authenticated: boolean; expires_at: number | null; expired: boolean; can_refresh: boolean };
+  claude: { reachable: boolean; latency_ms: number; error: string | null };
+  ollama: { enabled: boolean; running: boolean; version: string | null; error: string | null };
+  opencode: { enabled: boolean; profile: string; health: { healthy: boolean; latency_ms: number; error?: string } };
+  scheduler: {
+    initialized: boolean;
+    paused: boolean;
+    tasks: number;
+    enabled_tasks: number;
+    running_tasks: number;
+    failing_tasks: string[];
+  };
+  failing_services: { id: string; name: string }[];
+  disk: { path: string; free_bytes: number | null; total_bytes: number | null; low: boolean };
+}
+
+/** `ok: null` renders a neutral dot (area turned off). */
+interface HealthRow {
+  label: TranslationKey;
+  ok: boolean | null;
+  detail: string;
+}
+
+function formatGb(bytes: number): string {
+  return `${(bytes / 1024 ** 3).toFixed(1)} GB`;
+}
+
+function rows(h: SystemHealth): HealthRow[] {
+  return [
+    {
+      label: 'healthAuth',
+      ok: h.auth.authenticated && (!h.auth.expired || h.auth.can_refresh),
+      detail: h.auth.expires_at ? new Date(h.auth.expires_at).toLocaleString() : '',
+    },
+    {
+      label: 'healthClaude',
+      ok: h.claude.reachable,
+      detail: h.claude.reachable ? `${h.claude.latency_ms} ms` : h.claude.error ?? '',
+    },
+    {
+      label: 'healthOllama',
+      ok: h.ollama.enabled ? h.ollama.running : null,
+      detail: h.ollama.version ?? '',
+    },
+    {
+      label: 'healthOpenCode',
+      ok: h.opencode.enabled ? h.opencode.health.healthy : null,
+      detail: h.opencode.profile,
+    },
+    {
+      label: 'healthScheduler',
+      ok: h.scheduler.initialized && h.scheduler.failing_tasks.length === 0,
+      detail: `${h.scheduler.enabled_tasks}/${h.scheduler.tasks}${h.scheduler.paused ? ' (paused)' : ''}`,
+    },
+    {
+      label: 'healthServices',
+      ok: h.failing_services.length === 0,
+      detail: h.failing_services.map((s) => s.name).join(', '),
+    },
+    {
+      label: 'healthDisk',
+      ok: !h.disk.low,
+      detail: h.disk.free_bytes !== null ? formatGb(h.disk.free_bytes) : '',
+    },
+  ];
+}
+
+interface HealthPanelProps {
+  /** Click-flash ripple handler from useClickFlash */
+  onFlash: (e: React.MouseEvent<HTMLElement>) => void;
+}
+
+/**
+ * Fetches the system health snapshot on mount and on demand.
+ *
+ * @param onFlash - ripple effect callback on button click
+ */
+export function HealthPanel({ onFlash }: HealthPanelProps) {
+  const { t } = useI18n();
+  const [health, setHealth] = useState<SystemHealth | null>(null);
+  const [checking, setChecking] = useState(true);
+  const [error, setError] = useState('');
+
+  const check = useCallback(async () => {
+    setChecking(true);
+    try {
+      setHealth(await invoke<SystemHealth>('get_system_health'));
+      setError('');
+    } catch (e) {
+      setError(errorMessage(e));
+    }
+    setChecking(false);
+  }, []);
+
+  useEffect(() => {
+    check();
+  }, [check]);
+
+  return (
+    <div className="settings-health">
+      <div className="settings-logs-toolbar">
+        <span className={`settings-health-summary${health && !health.healthy ? ' bad' : ''}`}>
+          {checking && !health
+            ? t('healthChecking')
+            : error
+              ? error
+              : health?.healthy
+                ? t('healthOk')
+                : t('healthProblems')}
+        </span>
+        <button
+          className="settings-send-btn"
+          disabled={checking}
+          onClick={(e) => { onFlash(e); check(); }}
+        >
+          {t('healthCheck')}
+        </button>
+      </div>
+
+      {health && health.problems.length > 0 && (
+        <ul className="settings-health-problems">
+          {health.problems.map((p) => <li key={p}>{p}</li>)}
+        </ul>
+      )}
+
+      {health && (
+        <div className="settings-health-rows">
+          {rows(health).map((row) => (
+            <div key={row.label} className="settings-health-row">
+              <span className={`settings-health-dot ${row.ok === null ? 'off' : row.ok ? 'ok' : 'bad'}`} />
+              <span className="settings-health-label">{t(row.label)}</span>
+              <span className="settings-health-detail">{row.ok === null ? t('healthOff') : row.detail}</span>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
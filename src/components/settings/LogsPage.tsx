@@ -0,0 +1,152 @@
+/**
+ * LogsPage — In-app viewer for the app log.
+ *
+ * Opens with the system health summary (HealthPanel), then
+ * shows the newest entries from `get_app_logs`, filtered by minimum level,
+ * so failures (streaming, scheduler, bridge) can be inspected without a
+ * terminal. Also sets the app-wide log level via `set_log_settings`;
+ * per-module overrides are kept as they are. The API debug toggle
+ * (`set_api_debug_logging`) records redacted request metadata and SSE events
+ * to a separate file.
+ */
+import { useState, useEffect, useCallback } from 'react';
+import { invoke } from '../../utils/invoke-shim';
+import { useI18n } from '../../i18n';
+import { HealthPanel } from './HealthPanel';
+import '../../styles/settings-feedback.css';
+import '../../styles/settings-logs.css';
+
+type LogLevel = 'error' | 'warn' | 'info' | 'debug' | 'trace';
+
+interface LogEntry {
+  timestamp: string;
+  level: LogLevel;
+  target: string;
+  message: string;
+}
+
+interface LogSettings {
+  level: LogLevel | 'off';
+  modules: Record<string, string>;
+}
+
+const FILTER_LEVELS: LogLevel[] = ['error', 'warn', 'info', 'debug'];
+const APP_LEVELS: LogSettings['level'][] = ['error', 'warn', 'info', 'debug', 'trace'];
+const LOG_LINES = 300;
+
+interface LogsPageProps {
+  /** Click-flash ripple handler from useClickFlash */
+  onFlash: (e: React.MouseEvent<HTMLElement>) => void;
+}
+
+/**
+ * Settings page listing recent app log entries.
+ *
+ * @param onFlash - ripple effect callback on button click
+ */
+export function LogsPage({ onFlash }: LogsPageProps) {
+  const { t } = useI18n();
+  const [entries, setEntries] = useState<LogEntry[]>([]);
+  const [filter, setFilter] = useState<LogLevel>('info');
+  const [settings, setSettings] = useState<LogSettings | null>(null);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState(false);
+  const [apiDebug, setApiDebug] = useState(false);
+
+  const refresh = useCallback(async () => {
+    setLoading(true);
+    try {
+      const result = await invoke<LogEntry[]>('get_app_logs', { level: filter, lines: LOG_LINES });
+      setEntries(result.reverse());
+      setError(false);
+    } catch {
+      setError(true);
+    }
+    setLoading(false);
+  }, [filter]);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  useEffect(() => {
+    invoke<LogSettings>('get_log_settings').then(setSettings).catch(() => {});
+    invoke<boolean>('get_api_debug_logging').then(setApiDebug).catch(() => {});
+  }, []);
+
+  const handleAppLevel = async (level: LogSettings['level']) => {
+    if (!settings) return;
+    try {
+      const saved = await invoke<LogSettings>('set_log_settings', { settings: { ...settings, level } });
+      setSettings(saved);
+    } catch {}
+  };
+
+  const handleApiDebug = async (enabled: boolean) => {
+    try {
+      await invoke('set_api_debug_logging', { enabled });
+      setApiDebug(enabled);
+    } catch {}
+  };
+
+  return (
+    <div className="settings-logs">
+      <HealthPanel onFlash={onFlash} />
+
+      <div className="settings-logs-toolbar">
+        <div className="settings-logs-levels">
+          {FILTER_LEVELS.map((level) => (
+            <button
+              key={level}
+              className={`settings-logs-level${filter === level ? ' active' : ''}`}
+              onClick={(e) => { onFlash(e); setFilter(level); }}
+            >
+              {level}
+            </button>
+          ))}
+        </div>
+        <button className="settings-send-btn" onClick={(e) => { onFlash(e); refresh(); }}>
+          {t('logsRefresh')}
+        </button>
+      </div>
+
+      {loading && entries.length === 0 ? (
+        <div className="settings-logs-empty">{t('logsLoading')}</div>
+      ) : error ? (
+        <div className="settings-logs-empty">{t('logsError')}</div>
+      ) : entries.length === 0 ? (
+        <div className="settings-logs-empty">{t('logsEmpty')}</div>
+      ) : (
+        <div className="settings-logs-list">
+          {entries.map((entry, i) => (
+            <div key={`${entry.timestamp}-${i}`} className={`settings-logs-entry ${entry.level}`}>
+              <span className="settings-logs-time">{entry.timestamp.slice(11, 19)}</span>
+              <span className="settings-logs-badge">{entry.level}</span>
+              <span className="settings-logs-target">{entry.target}</span>
+              <span className="settings-logs-message">{entry.message}</span>
+            </div>
+          ))}
+        </div>
+      )}
+
+      {settings && (
+        <label className="settings-logs-app-level">
+          {t('logsAppLevel')}
+          <select
+            value={settings.level}
+            onChange={(e) => handleAppLevel(e.target.value as LogSettings['level'])}
+          >
+            {APP_LEVELS.map((level) => (
+              <option key={level} value={level}>{level}</option>
+            ))}
+          </select>
+        </label>
+      )}
+
+      <label className="settings-feedback-attach">
+        <input type="checkbox" checked={apiDebug} onChange={(e) => handleApiDebug(e.target.checked)} />
+        {t('logsApiDebug')}
+      </label>
+    </div>
+  );
+}
@@ -0,0 +1,130 @@
+/**
+ * PhoenixCard — Phoenix watchdog targets and recent actions.
+ *
+ * Shown on the Automation page below the services. Turns the watchdog on or
+ * off, enables individual targets and tails `phoenix.log`. Services stopped
+ * from this page, or with their own auto-restart watchdog, are skipped by
+ * Phoenix.
+ *
+ * Tauri commands: get_phoenix_config, set_phoenix_config, get_phoenix_log.
+ */
+import { useState, useEffect } from 'react';
+import { invoke } from '../../utils/invoke-shim';
+import { errorMessage } from '../../utils/errors';
+import { useI18n } from '../../i18n';
+
+/** Lines of `phoenix.log` shown under the targets. */
+const LOG_LINES = 20;
+
+interface PhoenixTarget {
+  id: string;
+  name: string;
+  kind: 'service' | 'process';
+  service_id?: string;
+  process_name?: string;
+  restart_command?: string | null;
+  enabled: boolean;
+}
+
+interface PhoenixConfig {
+  enabled: boolean;
+  interval_secs: number;
+  flap_window_secs: number;
+  flap_max_restarts: number;
+  targets: PhoenixTarget[];
+}
+
+interface PhoenixCardProps {
+  /** Click-flash ripple handler from useClickFlash */
+  onFlash: (e: React.MouseEvent<HTMLElement>) => void;
+}
+
+/**
+ * Phoenix on/off switch, per-target switches and the action log.
+ *
+ * @param onFlash - ripple effect callback on button click
+ */
+export function PhoenixCard({ onFlash }: PhoenixCardProps) {
+  const { t } = useI18n();
+  const [config, setConfig] = useState<PhoenixConfig | null>(null);
+  const [log, setLog] = useState('');
+  const [error, setError] = useState<string | null>(null);
+
+  const refreshLog = () => {
+    invoke<string>('get_phoenix_log', { lines: LOG_LINES }).then(setLog).catch(() => {});
+  };
+
+  useEffect(() => {
+    invoke<PhoenixConfig>('get_phoenix_config').then(setConfig).catch(() => {});
+    refreshLog();
+  }, []);
+
+  if (!config) return null;
+
+  const save = async (next: PhoenixConfig) => {
+    try {
+      await invoke('set_phoenix_config', { config: next });
+      setConfig(next);
+      setError(null);
+    } catch (err) {
+      setError(errorMessage(err));
+    }
+    refreshLog();
+  };
+
+  const handleToggle = (e: React.MouseEvent<HTMLElement>) => {
+    onFlash(e);
+    save({ ...config, enabled: !config.enabled });
+  };
+
+  const handleTargetToggle = (e: React.MouseEvent<HTMLElement>, id: string) => {
+    onFlash(e);
+    save({
+      ...config,
+      targets: config.targets.map((target) => (target.id === id ? { ...target, enabled: !target.enabled } : target)),
+    });
+  };
+
+  return (
+    <div className="settings-automation-section">
+      <div className="settings-automation-section-header">
+        <span className="settings-automation-section-title">{t('phoenixTitle')}</span>
+        <button
+          className="settings-automation-toggle-wrap"
+          onClick={handleToggle}
+          aria-label={config.enabled ? t('automationRunning') : t('automationStopped')}
+        >
+          <span className={`settings-automation-toggle${config.enabled ? ' on' : ''}`}>
+            <span className="settings-automation-toggle-dot" />
+          </span>
+        </button>
+      </div>
+      <div className="settings-card settings-automation-list">
+        {config.targets.map((target) => (
+          <div key={target.id} className="settings-automation-row settings-automation-row-divider">
+            <span className="settings-automation-name">{target.name}</span>
+            <span className="settings-automation-schedule">
+              {target.kind === 'service' ? target.service_id : target.process_name}
+            </span>
+            <div className="settings-automation-actions">
+              <button
+                className="settings-automation-toggle-wrap"
+                onClick={(e) => handleTargetToggle(e, target.id)}
+                aria-label={target.enabled ? t('automationRunning') : t('automationStopped')}
+              >
+                <span className={`settings-automation-toggle${target.enabled ? ' on' : ''}`}>
+                  <span className="settings-automation-toggle-dot" />
+                </span>
+              </button>
+            </div>
+          </div>
+        ))}
+        <div className="settings-automation-backup">
+          <span className="settings-automation-backup-hint">{t('phoenixHint')}</span>
+          {log && <pre className="settings-automation-phoenix-log">{log}</pre>}
+          {error && <span className="settings-automation-backup-status error">{error}</span>}
+        </div>
+      </div>
+    </div>
+  );
+}
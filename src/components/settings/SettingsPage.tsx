@@ -19,6 +19,9 @@ import { OllamaPage }         from './OllamaPage';
 import { FolderBrowserPage }  from './FolderBrowserPage';
 import { AutomationPage }     from './AutomationPage';
 import { ArchivePage }        from './ArchivePage';
+import { LogsPage }           from './LogsPage';
+import { BudgetPage }         from './BudgetPage';
+import { SyncPage }           from './SyncPage';
 import '../../styles/settings.css';
 
 /** Maps each page ID to its i18n title key */
@@ -31,6 +34,9 @@ const PAGE_TITLE_KEYS: Record<SettingsPageId, TranslationKey> = {
   ollama:     'ollamaTitle',
   folder:     'folderTitle',
   automation: 'automationTitle',
+  logs:       'logsTitle',
+  budget:     'budgetTitle',
+  sync:       'syncTitle',
 };
 
 interface SettingsPageProps {
@@ -106,6 +112,12 @@ export function SettingsPage({
         );
       case 'automation':
         return <AutomationPage onFlash={onFlash} />;
+      case 'logs':
+        return <LogsPage onFlash={onFlash} />;
+      case 'budget':
+        return <BudgetPage onFlash={onFlash} />;
+      case 'sync':
+        return <SyncPage onFlash={onFlash} />;
     }
   };
 
@@ -0,0 +1,214 @@
+/**
+ * SyncPage — Conversation and memory sync via WebDAV or S3.
+ *
+ * Edits the sync server settings (`set_sync_settings`), runs a sync on demand
+ * (`sync_now`) and lists conflicts: items changed both here and on another
+ * device since the last sync. Each conflict is settled by keeping this
+ * device's copy or the remote one (`resolve_sync_conflict`).
+ */
+import { useState, useEffect } from 'react';
+import { invoke } from '../../utils/invoke-shim';
+import { errorMessage } from '../../utils/errors';
+import { useI18n, type TranslationKey } from '../../i18n';
+import '../../styles/settings-feedback.css';
+import '../../styles/settings-budget.css';
+
+type SyncItem = 'conversations' | 'memory';
+type SyncAction = 'up_to_date' | 'pushed' | 'pulled' | 'conflict' | 'failed';
+
+interface SyncSettings {
+  enabled: boolean;
+  provider: 'webdav' | 's3';
+  endpoint: string;
+  username: string;
+  password: string;
+  bucket: string;
+  region: string;
+  prefix: string;
+  interval_minutes: number;
+}
+
+interface ItemReport {
+  item: SyncItem;
+  action: SyncAction;
+  error: string | null;
+}
+
+interface SyncStatus {
+  enabled: boolean;
+  last_sync: string | null;
+  last_report: ItemReport[];
+  conflicts: SyncItem[];
+}
+
+const ITEM_LABELS: Record<SyncItem, TranslationKey> = {
+  conversations: 'syncConversations',
+  memory: 'syncMemory',
+};
+
+const ACTION_LABELS: Record<SyncAction, TranslationKey> = {
+  up_to_date: 'syncUpToDate',
+  pushed: 'syncPushed',
+  pulled: 'syncPulled',
+  conflict: 'syncConflict',
+  failed: 'syncFailed',
+};
+
+interface SyncPageProps {
+  /** Click-flash ripple handler from useClickFlash */
+  onFlash: (e: React.MouseEvent<HTMLElement>) => void;
+}
+
+/**
+ * Settings page for WebDAV/S3 sync.
+ *
+ * @param onFlash - ripple effect callback on button click
+ */
+export function SyncPage({ onFlash }: SyncPageProps) {
+  const { t } = useI18n();
+  const [settings, setSettings] = useState<SyncSettings | null>(null);
+  const [status, setStatus] = useState<SyncStatus | null>(null);
+  const [busy, setBusy] = useState(false);
+  const [message, setMessage] = useState<{ text: string; error: boolean } | null>(null);
+
+  const refreshStatus = () => {
+    invoke<SyncStatus>('get_sync_status').then(setStatus).catch(() => {});
+  };
+
+  useEffect(() => {
+    invoke<SyncSettings>('get_sync_settings').then(setSettings).catch(() => {});
+    refreshStatus();
+  }, []);
+
+  if (!settings) return null;
+
+  const s3 = settings.provider === 's3';
+
+  const update = (patch: Partial<SyncSettings>) => {
+    setSettings({ ...settings, ...patch });
+    setMessage(null);
+  };
+
+  const run = async (action: () => Promise<unknown>, done: TranslationKey) => {
+    setBusy(true);
+    try {
+      await action();
+      setMessage({ text: t(done), error: false });
+    } catch (err) {
+      setMessage({ text: errorMessage(err), error: true });
+    }
+    refreshStatus();
+    setBusy(false);
+  };
+
+  const handleSave = (e: React.MouseEvent<HTMLElement>) => {
+    onFlash(e);
+    run(async () => setSettings(await invoke<SyncSettings>('set_sync_settings', { settings })), 'syncSaved');
+  };
+
+  const handleSyncNow = (e: React.MouseEvent<HTMLElement>) => {
+    onFlash(e);
+    run(() => invoke('sync_now'), 'syncDone');
+  };
+
+  const handleResolve = (e: React.MouseEvent<HTMLElement>, item: SyncItem, keepLocal: boolean) => {
+    onFlash(e);
+    run(() => invoke('resolve_sync_conflict', { item, keepLocal }), 'syncResolved');
+  };
+
+  return (
+    <div className="settings-budget">
+      <label className="settings-feedback-attach">
+        <input type="checkbox" checked={settings.enabled} onChange={(e) => update({ enabled: e.target.checked })} />
+        {t('syncEnabled')}
+      </label>
+
+      <label className="settings-budget-row">
+        {t('syncProvider')}
+        <select value={settings.provider} onChange={(e) => update({ provider: e.target.value as SyncSettings['provider'] })}>
+          <option value="webdav">WebDAV</option>
+          <option value="s3">S3</option>
+        </select>
+      </label>
+
+      <label className="settings-budget-row">
+        {s3 ? t('syncS3Endpoint') : t('syncWebdavUrl')}
+        <input type="url" value={settings.endpoint} onChange={(e) => update({ endpoint: e.target.value })} />
+      </label>
+
+      {s3 && (
+        <>
+          <label className="settings-budget-row">
+            {t('syncBucket')}
+            <input type="text" value={settings.bucket} onChange={(e) => update({ bucket: e.target.value })} />
+          </label>
+          <label className="settings-budget-row">
+            {t('syncRegion')}
+            <input type="text" value={settings.region} onChange={(e) => update({ region: e.target.value })} />
+          </label>
+          <label className="settings-budget-row">
+            {t('syncPrefix')}
+            <input type="text" value={settings.prefix} onChange={(e) => update({ prefix: e.target.value })} />
+          </label>
+        </>
+      )}
+
+      <label className="settings-budget-row">
+        {s3 ? t('syncAccessKey') : t('syncUsername')}
+        <input type="text" value={settings.username} onChange={(e) => update({ username: e.target.value })} />
+      </label>
+
+      <label className="settings-budget-row">
+        {s3 ? t('syncSecretKey') : t('syncPassword')}
+        <input type="password" value={settings.password} onChange={(e) => update({ password: e.target.value })} />
+      </label>
+
+      <label className="settings-budget-row">
+        {t('syncInterval')}
+        <input
+          type="number"
+          min={0}
+          value={settings.interval_minutes}
+          onChange={(e) => update({ interval_minutes: Math.max(0, Math.floor(Number(e.target.value) || 0)) })}
+        />
+      </label>
+
+      <div className="settings-feedback-actions">
+        {message && (
+          <span className={`settings-feedback-status ${message.error ? 'error' : 'sent'}`}>{message.text}</span>
+        )}
+        <button className="settings-send-btn" disabled={busy || !status?.enabled} onClick={handleSyncNow}>
+          {t('syncNow')}
+        </button>
+        <button className="settings-send-btn" disabled={busy} onClick={handleSave}>
+          {t('syncSave')}
+        </button>
+      </div>
+
+      {status?.last_sync && (
+        <div className="settings-budget-usage">
+          <span>{t('syncLast')} {new Date(status.last_sync).toLocaleString()}</span>
+          <span>
+            {status.last_report
+              .map((r) => `${t(ITEM_LABELS[r.item])}: ${r.error ?? t(ACTION_LABELS[r.action])}`)
+              .join(' · ')}
+          </span>
+        </div>
+      )}
+
+      {status?.conflicts.map((item) => (
+        <div key={item} className="settings-budget-usage exceeded">
+          <span>{t('syncConflictOn')} {t(ITEM_LABELS[item])}</span>
+          <span className="settings-sync-conflict-actions">
+            <button className="settings-send-btn" disabled={busy} onClick={(e) => handleResolve(e, item, true)}>
+              {t('syncKeepLocal')}
+            </button>
+            <button className="settings-send-btn" disabled={busy} onClick={(e) => handleResolve(e, item, false)}>
+              {t('syncKeepRemote')}
+            </button>
+          </span>
+        </div>
+      ))}
+    </div>
+  );
+}
@@ -8,4 +8,7 @@ export { OllamaPage }         from './OllamaPage';
 export { FolderBrowserPage }  from './FolderBrowserPage';
 export { AutomationPage }     from './AutomationPage';
 export { ArchivePage }        from './ArchivePage';
+export { LogsPage }           from './LogsPage';
+export { BudgetPage }         from './BudgetPage';
+export { SyncPage }           from './SyncPage';
 export { MobileLinkCard }     from './MobileLinkCard';
@@ -11,7 +11,7 @@
  * The public return type is identical to the original monolithic hook so that
  * `Chat.tsx` and any other consumers require zero import changes.
  */
-import { useState, useCallback, useRef } from 'react';
+import { useState, useCallback, useRef, useEffect } from 'react';
 import { invoke } from '../utils/invoke-shim';
 import type { Message, ImageAttachment, MessageMode, Session } from '../types';
 import { uid } from '../utils/uid';
@@ -70,11 +70,51 @@ export function useChat() {
     setOpencodeConnected
   );
 
+  const [activeMode, setActiveMode] = useState<MessageMode>('normal');
+
   const handleUsage = useCallback((delta: { input: number; output: number }) => {
     setUsage((prev) => ({ input: prev.input + delta.input, output: prev.output + delta.output }));
     sessionStore.bumpWeeklyUsage(delta);
   }, [sessionStore.bumpWeeklyUsage]);
 
+  /**
+   * Resolves the mode for a message: an explicit mode is remembered for the
+   * conversation, otherwise the conversation's remembered mode is restored.
+   *
+   * @param conversationId - The OpenCode session ID, or the local session ID.
+   * @param mode - Mode chosen for this message, if any.
+   */
+  const resolveMode = useCallback(async (conversationId: string, mode?: MessageMode): Promise<MessageMode> => {
+    if (mode) {
+      await invoke('set_conversation_mode', { conversationId, mode }).catch(() => {});
+      setActiveMode(mode);
+      return mode;
+    }
+    const restored = await invoke<MessageMode>('get_conversation_mode', { conversationId }).catch(() => 'normal' as const);
+    setActiveMode(restored);
+    return restored;
+  }, []);
+
+  // Keep the input's mode selector in sync with the conversation being viewed.
+  const activeConversationId = sessionStore.activeSession
+    ? sessionStore.activeSession.ocSessionId ?? sessionStore.activeSession.id
+    : null;
+  useEffect(() => {
+    if (!activeConversationId) {
+      setActiveMode('normal');
+      return;
+    }
+    let cancelled = false;
+    invoke<MessageMode>('get_conversation_mode', { conversationId: activeConversationId })
+      .catch(() => 'normal' as const)
+      .then((mode) => {
+        if (!cancelled) setActiveMode(mode);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [activeConversationId]);
+
   /**
    * Sends a user message, optionally with image attachments.
    *
@@ -127,13 +167,14 @@ export function useChat() {
         sessionStore.setSessions((prev: Session[]) => [newSession, ...prev]);
         sessionStore.setActiveSessionId(newSession.id);
         sessionStore.setIsDraft(false);
+        const newMode = await resolveMode(newSession.ocSessionId ?? newSession.id, mode);
         streaming.streamResponse(
           newSession.id,
           [userMsg],
           sessionStore.updateSession,
           handleUsage,
           newSession.ocSessionId,
-          mode,
+          newMode,
           opencodeConnected
         );
         return;
@@ -181,17 +222,21 @@ export function useChat() {
       const allMessages = currentSession ? [...currentSession.messages, userMsg] : [userMsg];
       const ocSessionId = currentSession?.ocSessionId;
 
+      const sessionMode = await resolveMode(ocSessionId ?? activeId, mode);
+
       if (opencodeConnected && !ocSessionId) {
         try {
           const ocId = await invoke<string>('opencode_create_session');
           sessionStore.updateSession(activeId, (s) => ({ ...s, ocSessionId: ocId }));
+          // The conversation is now keyed by its OpenCode session
+          if (sessionMode !== 'normal') resolveMode(ocId, sessionMode);
           streaming.streamResponse(
             activeId,
             allMessages,
             sessionStore.updateSession,
             handleUsage,
             ocId,
-            mode,
+            sessionMode,
             opencodeConnected
           );
         } catch {
@@ -201,7 +246,7 @@ export function useChat() {
             sessionStore.updateSession,
             handleUsage,
             undefined,
-            mode,
+            sessionMode,
             opencodeConnected
           );
         }
@@ -212,12 +257,12 @@ export function useChat() {
           sessionStore.updateSession,
           handleUsage,
           ocSessionId,
-          mode,
+          sessionMode,
           opencodeConnected
         );
       }
     },
-    [streaming, sessionStore, opencodeConnected, handleUsage]
+    [streaming, sessionStore, opencodeConnected, handleUsage, resolveMode]
   );
 
   /**
@@ -281,6 +326,7 @@ export function useChat() {
     activeSession: sessionStore.activeSession ?? draftSession,
     activeSessionId: sessionStore.activeSessionId ?? '__draft__',
     isDraft: sessionStore.isDraft,
+    activeMode,
     isStreaming: streaming.isStreaming,
     streamingSessionId: streaming.streamingSessionId,
     loaded: sessionStore.loaded,
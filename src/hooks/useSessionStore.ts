@@ -6,6 +6,7 @@
  * - OpenCode session list synchronisation on startup and reload
  * - Create, delete, rename, archive, and reorder operations
  * - Weekly token-usage persistence
+ * - Reloading conversations pulled by sync (`sync-pulled`)
  *
  * State is saved to disk with a 500 ms debounce after every change (skipped
  * while streaming or when OpenCode is connected, since OC owns persistence
@@ -509,6 +510,28 @@ export function useSessionStore(
     } catch (_) {}
   }, []);
 
+  // ── Sync: reload conversations pulled from another device ───────────────────
+
+  useEffect(() => {
+    if (!isTauri || opencodeConnected) return;
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+    import('@tauri-apps/api/event').then(({ listen }) =>
+      listen<string[]>('sync-pulled', (event) => {
+        if (event.payload.includes('conversations') && storeRef.current) {
+          loadFromStore(storeRef.current);
+        }
+      }).then((fn) => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+    );
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, [opencodeConnected, loadFromStore]);
+
   // ── Weekly usage helper ───────────────────────────────────────────────────────
 
   /**
@@ -9,6 +9,8 @@ import { useState, useCallback, useRef } from 'react';
 import { invoke, createChannel } from '../utils/invoke-shim';
 import type { Session, Message, ChatStreamEvent, ImageAttachment, MessageMode, ToolActivity } from '../types';
 import { uid } from '../utils/uid';
+import { errorMessage, isAppError } from '../utils/errors';
+import { useI18n } from '../i18n';
 import { playMakima } from './useMakimaSound';
 
 // ── Constants ──────────────────────────────────────────────────────────────
@@ -87,6 +89,24 @@ const HANDLERS: HandlerMap = {
     s.reasoning += d.text;
   },
 
+  question(_d: { id: string; questions: unknown }, s) {
+    s.status = 'waiting for your answer...';
+  },
+
+  question_resolved(_d: { id: string }, s) {
+    s.status = 'thinking';
+  },
+
+  review(d: { agent: string; text: string }, s) {
+    s.content += `\n\n---\n**${d.agent} review**\n\n${d.text}\n`;
+    s.status = undefined;
+  },
+
+  command_result(d: { command: string; text: string; is_error: boolean }, s) {
+    s.content += d.is_error ? `**/${d.command} failed:** ${d.text}` : `**/${d.command}**\n\n${d.text}`;
+    s.status = undefined;
+  },
+
   usage(d: { input_tokens: number; output_tokens: number }, _s, onUsage) {
     onUsage({ input: d.input_tokens, output: d.output_tokens });
   },
@@ -137,6 +157,10 @@ export function useStreaming(): StreamingAPI {
   // proceeds if activeStreamIdRef still matches the ID it was created with.
   // This prevents stale cancel signals from killing a new stream.
   const activeStreamIdRef = useRef<string | null>(null);
+  // streamResponse is created once; read the current locale through a ref.
+  const { t } = useI18n();
+  const tRef = useRef(t);
+  tRef.current = t;
 
   const streamResponse = useCallback(
     (
@@ -314,7 +338,7 @@ export function useStreaming(): StreamingAPI {
       const handleError = (err: unknown) => {
         cancelFlush();
         // Truncate error messages to prevent huge base64 data from flooding the UI
-        let errMsg = String(err);
+        let errMsg = errorMessage(err);
         if (errMsg.length > 500) {
           errMsg = errMsg.slice(0, 500) + '... (truncated)';
         }
@@ -364,7 +388,24 @@ export function useStreaming(): StreamingAPI {
           return { role: m.role, content: m.content };
         });
 
-        invoke('chat_send', { messages: apiMessages, onEvent }).catch(handleError);
+        const send = (confirmBudget: boolean) =>
+          invoke('chat_send', {
+            messages: apiMessages,
+            mode: mode ?? 'normal',
+            conversationId: ocSessionId ?? sessionId,
+            confirmBudget,
+            onEvent,
+          });
+        // Over the spending budget: ask once, then send again past the limit
+        send(false)
+          .catch((err) => {
+            if (isAppError(err) && err.code === 'budget_exceeded'
+              && window.confirm(`${err.message}\n\n${tRef.current('budgetConfirmSend')}`)) {
+              return send(true);
+            }
+            throw err;
+          })
+          .catch(handleError);
       }
     },
     []
@@ -30,6 +30,9 @@ export const en = {
   personalize: 'Personalize',
   advanced: 'Advanced',
   inputPlaceholder: 'Ask Winter...',
+  modeNormal: 'Normal',
+  modeSearch: 'Search',
+  modeAnalyze: 'Analyze',
   emptyState: 'It doesn\u2019t have to be a snowman.',
   shortcutNewSession: 'New session',
   shortcutArchive: 'Archive session',
@@ -50,9 +53,13 @@ export const en = {
   feedbackTitle: 'How did Winter do?',
   feedbackPlaceholder: 'Tell us what you think...',
   feedbackSend: 'send',
+  feedbackAttachCrash: 'attach the latest crash report',
+  feedbackIncludeDiagnostics: 'include diagnostics (version, OS, recent logs)',
+  feedbackOpenIssue: 'open GitHub issue',
   feedbackSending: 'sending...',
   feedbackSent: 'Sent!',
   feedbackError: 'Failed to send. Check SMTP config.',
+  feedbackUnavailable: 'Feedback is turned off or has no destination configured.',
   archiveTitle: 'Archive',
   archiveEmpty: 'No sessions yet',
   archiveMessages: 'messages',
@@ -101,6 +108,7 @@ export const en = {
   toastArchived: 'session archived',
   toastDeleted: 'session deleted',
   toastNewSession: 'new session',
+  quickPromptPlaceholder: 'Ask Winter...',
   shortcutFocusChat: 'Focus chat',
   smtpPassword: 'Gmail app password',
   smtpPasswordPlaceholder: 'App password for SMTP...',
@@ -190,6 +198,88 @@ export const en = {
   automationCancel: 'Cancel',
   automationNotInstalled: 'Not installed',
   automationUnsupported: 'Unsupported',
+  logsTitle: 'Logs',
+  logsRefresh: 'Refresh',
+  logsLoading: 'Loading logs...',
+  logsError: 'Failed to load logs',
+  logsEmpty: 'No log entries',
+  logsAppLevel: 'App log level',
+  logsApiDebug: 'record API debug log (content and credentials redacted)',
+  healthChecking: 'Checking...',
+  healthOk: 'Everything is fine',
+  healthProblems: 'Needs attention',
+  healthCheck: 'Check again',
+  healthOff: 'off',
+  healthAuth: 'Sign-in',
+  healthClaude: 'Claude API',
+  healthOllama: 'Ollama',
+  healthOpenCode: 'OpenCode',
+  healthScheduler: 'Scheduler',
+  healthServices: 'Services',
+  healthDisk: 'Disk space',
+  budgetTitle: 'Spending budget',
+  budgetUsedToday: 'Used today',
+  budgetUsedWeek: 'Used this week',
+  budgetTokens: 'tokens',
+  budgetEnabled: 'Limit API spending',
+  budgetPeriod: 'Period',
+  budgetDaily: 'Daily',
+  budgetWeekly: 'Weekly',
+  budgetTokenLimit: 'Token limit (0 = none)',
+  budgetCostLimit: 'Cost limit in USD (0 = none)',
+  budgetWarnPercent: 'Warn at (%)',
+  budgetOnExceed: 'When the limit is reached',
+  budgetExceedConfirm: 'Ask before sending',
+  budgetExceedFallback: 'Switch to a cheaper model',
+  budgetExceedOllama: 'Answer with Ollama',
+  budgetFallbackModel: 'Fallback model',
+  budgetSave: 'Save',
+  budgetSaved: 'Saved',
+  budgetConfirmSend: 'Send anyway?',
+  toastBudgetWarning: 'Spending budget almost used',
+  toastBudgetExceeded: 'Spending budget reached',
+  appBackupTitle: 'App Data Backup',
+  appBackupNoPassphrase: 'Set a passphrase to enable the encrypted "App Data Backup" task.',
+  appBackupPassphraseSet: 'Backups are encrypted with your passphrase. Keep it safe: it cannot be recovered.',
+  appBackupPassphrase: 'New passphrase',
+  appBackupSetPassphrase: 'Set',
+  appBackupPassphraseSaved: 'Passphrase saved',
+  appBackupRestorePath: 'Backup file (.tar.zst.age)',
+  appBackupRestorePassphrase: 'Passphrase (if different)',
+  appBackupRestore: 'Restore',
+  appBackupRestoreConfirm: 'Replace current settings, conversations and memory with this backup? Winter will restart.',
+  appBackupRestored: 'Restored, restarting',
+  phoenixTitle: 'Phoenix Watchdog',
+  phoenixHint: 'Restarts the targets below when they go down. Services stopped here, or with their own auto-restart, are skipped.',
+  syncTitle: 'Sync',
+  syncEnabled: 'Sync conversations and memory',
+  syncProvider: 'Server type',
+  syncWebdavUrl: 'WebDAV folder URL',
+  syncS3Endpoint: 'S3 endpoint',
+  syncBucket: 'Bucket',
+  syncRegion: 'Region',
+  syncPrefix: 'Key prefix',
+  syncUsername: 'User name',
+  syncPassword: 'Password',
+  syncAccessKey: 'Access key ID',
+  syncSecretKey: 'Secret access key',
+  syncInterval: 'Sync every (minutes, 0 = manual)',
+  syncNow: 'Sync now',
+  syncSave: 'Save',
+  syncSaved: 'Saved',
+  syncDone: 'Sync finished',
+  syncResolved: 'Conflict resolved',
+  syncLast: 'Last sync:',
+  syncConversations: 'Conversations',
+  syncMemory: 'Memory',
+  syncUpToDate: 'up to date',
+  syncPushed: 'uploaded',
+  syncPulled: 'downloaded',
+  syncConflict: 'conflict',
+  syncFailed: 'failed',
+  syncConflictOn: 'Changed on both devices:',
+  syncKeepLocal: 'Keep this device',
+  syncKeepRemote: 'Keep remote',
 } as const;
 
 export type TranslationKey = keyof typeof en;
@@ -32,6 +32,9 @@ export const ja: Record<TranslationKey, string> = {
   personalize: '\u30d1\u30fc\u30bd\u30ca\u30e9\u30a4\u30ba',
   advanced: '\u8a73\u7d30\u8a2d\u5b9a',
   inputPlaceholder: 'Winter\u306b\u805e\u3044\u3066\u307f\u3066...',
+  modeNormal: '通常',
+  modeSearch: '検索',
+  modeAnalyze: '分析',
   emptyState: '雪だるまじゃなくてもいいよ。',
   shortcutNewSession: '\u65b0\u3057\u3044\u30bb\u30c3\u30b7\u30e7\u30f3',
   shortcutArchive: '\u30bb\u30c3\u30b7\u30e7\u30f3\u4fdd\u5b58',
@@ -52,9 +55,13 @@ export const ja: Record<TranslationKey, string> = {
   feedbackTitle: 'Winter\u306f\u3069\u3046\u3060\u3063\u305f\uff1f',
   feedbackPlaceholder: '\u611f\u60f3\u3092\u805e\u304b\u305b\u3066\u304f\u3060\u3055\u3044...',
   feedbackSend: '\u9001\u4fe1',
+  feedbackAttachCrash: '最新のクラッシュレポートを添付',
+  feedbackIncludeDiagnostics: '診断情報を含める（バージョン、OS、最近のログ）',
+  feedbackOpenIssue: 'GitHub Issue を開く',
   feedbackSending: '\u9001\u4fe1\u4e2d...',
   feedbackSent: '\u9001\u4fe1\u3057\u305f\uff01',
   feedbackError: '\u9001\u4fe1\u5931\u6557\u3002SMTP\u8a2d\u5b9a\u3092\u78ba\u8a8d\u3057\u3066\u304f\u3060\u3055\u3044\u3002',
+  feedbackUnavailable: 'フィードバックはオフになっているか、送信先が設定されていません。',
   archiveTitle: '\u30a2\u30fc\u30ab\u30a4\u30d6',
   archiveEmpty: '\u30bb\u30c3\u30b7\u30e7\u30f3\u304c\u3042\u308a\u307e\u305b\u3093',
   archiveMessages: '\u30e1\u30c3\u30bb\u30fc\u30b8',
@@ -105,6 +112,7 @@ export const ja: Record<TranslationKey, string> = {
   toastArchived: 'セッション保存済み',
   toastDeleted: 'セッション削除済み',
   toastNewSession: '新しいセッション',
+  quickPromptPlaceholder: 'Winterに質問...',
   shortcutFocusChat: 'チャットにフォーカス',
   smtpPassword: 'Gmailアプリパスワード',
   smtpPasswordPlaceholder: 'SMTP用アプリパスワード...',
@@ -194,4 +202,86 @@ export const ja: Record<TranslationKey, string> = {
   automationCancel: 'キャンセル',
   automationNotInstalled: '未インストール',
   automationUnsupported: '未サポート',
+  logsTitle: 'ログ',
+  logsRefresh: '更新',
+  logsLoading: 'ログを読み込み中...',
+  logsError: 'ログの読み込みに失敗しました',
+  logsEmpty: 'ログはありません',
+  logsAppLevel: 'アプリのログレベル',
+  logsApiDebug: 'API デバッグログを記録（内容と認証情報は伏せられます）',
+  healthChecking: '確認中...',
+  healthOk: 'すべて正常です',
+  healthProblems: '対応が必要です',
+  healthCheck: '再確認',
+  healthOff: 'オフ',
+  healthAuth: 'サインイン',
+  healthClaude: 'Claude API',
+  healthOllama: 'Ollama',
+  healthOpenCode: 'OpenCode',
+  healthScheduler: 'スケジューラ',
+  healthServices: 'サービス',
+  healthDisk: 'ディスク空き容量',
+  budgetTitle: '利用予算',
+  budgetUsedToday: '今日の使用量',
+  budgetUsedWeek: '今週の使用量',
+  budgetTokens: 'トークン',
+  budgetEnabled: 'API の利用額を制限',
+  budgetPeriod: '期間',
+  budgetDaily: '毎日',
+  budgetWeekly: '毎週',
+  budgetTokenLimit: 'トークン上限（0 = なし）',
+  budgetCostLimit: '費用上限 USD（0 = なし）',
+  budgetWarnPercent: '警告するしきい値（%）',
+  budgetOnExceed: '上限に達したとき',
+  budgetExceedConfirm: '送信前に確認',
+  budgetExceedFallback: '安いモデルに切り替え',
+  budgetExceedOllama: 'Ollama で回答',
+  budgetFallbackModel: '代替モデル',
+  budgetSave: '保存',
+  budgetSaved: '保存しました',
+  budgetConfirmSend: 'このまま送信しますか？',
+  toastBudgetWarning: '利用予算の上限が近づいています',
+  toastBudgetExceeded: '利用予算の上限に達しました',
+  appBackupTitle: 'アプリデータのバックアップ',
+  appBackupNoPassphrase: '暗号化された「App Data Backup」タスクを使うにはパスフレーズを設定してください。',
+  appBackupPassphraseSet: 'バックアップはパスフレーズで暗号化されます。復元できないので大切に保管してください。',
+  appBackupPassphrase: '新しいパスフレーズ',
+  appBackupSetPassphrase: '設定',
+  appBackupPassphraseSaved: 'パスフレーズを保存しました',
+  appBackupRestorePath: 'バックアップファイル (.tar.zst.age)',
+  appBackupRestorePassphrase: 'パスフレーズ（異なる場合）',
+  appBackupRestore: '復元',
+  appBackupRestoreConfirm: '現在の設定・会話・メモリをこのバックアップで置き換えますか？Winter は再起動します。',
+  appBackupRestored: '復元しました。再起動します',
+  phoenixTitle: 'Phoenix ウォッチドッグ',
+  phoenixHint: '停止した対象を自動で再起動します。ここで停止したサービスや、独自の自動再起動があるサービスは対象外です。',
+  syncTitle: '同期',
+  syncEnabled: '会話とメモリを同期',
+  syncProvider: 'サーバーの種類',
+  syncWebdavUrl: 'WebDAV フォルダの URL',
+  syncS3Endpoint: 'S3 エンドポイント',
+  syncBucket: 'バケット',
+  syncRegion: 'リージョン',
+  syncPrefix: 'キーのプレフィックス',
+  syncUsername: 'ユーザー名',
+  syncPassword: 'パスワード',
+  syncAccessKey: 'アクセスキー ID',
+  syncSecretKey: 'シークレットアクセスキー',
+  syncInterval: '同期間隔（分、0 = 手動）',
+  syncNow: '今すぐ同期',
+  syncSave: '保存',
+  syncSaved: '保存しました',
+  syncDone: '同期が完了しました',
+  syncResolved: '競合を解決しました',
+  syncLast: '最終同期:',
+  syncConversations: '会話',
+  syncMemory: 'メモリ',
+  syncUpToDate: '最新',
+  syncPushed: 'アップロード済み',
+  syncPulled: 'ダウンロード済み',
+  syncConflict: '競合',
+  syncFailed: '失敗',
+  syncConflictOn: '両方のデバイスで変更されています:',
+  syncKeepLocal: 'このデバイスを優先',
+  syncKeepRemote: 'リモートを優先',
 };
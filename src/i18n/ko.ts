@@ -32,6 +32,9 @@ export const ko: Record<TranslationKey, string> = {
   personalize: '개인화',
   advanced: '고급',
   inputPlaceholder: 'Winter에게 물어보세요...',
+  modeNormal: '일반',
+  modeSearch: '검색',
+  modeAnalyze: '분석',
   emptyState: '눈사람 아니어도 좋아.',
   shortcutNewSession: '새 세션',
   shortcutArchive: '세션 보관',
@@ -54,9 +57,13 @@ export const ko: Record<TranslationKey, string> = {
   feedbackTitle: '피드백 보내기', 
   feedbackPlaceholder: '자유롭게 의견을 남겨주세요...',
   feedbackSend: '보내기',
+  feedbackAttachCrash: '최근 크래시 리포트 첨부',
+  feedbackIncludeDiagnostics: '진단 정보 포함 (버전, OS, 최근 로그)',
+  feedbackOpenIssue: 'GitHub 이슈 열기',
   feedbackSending: '보내는 중...',
   feedbackSent: '보냈어!',
   feedbackError: '전송 실패. 잠시 후 다시 시도해주세요.',
+  feedbackUnavailable: '피드백이 꺼져 있거나 보낼 곳이 설정되지 않았어.',
 
   archiveTitle: '보관',
   archiveEmpty: '세션이 없어요',
@@ -108,6 +115,7 @@ export const ko: Record<TranslationKey, string> = {
   toastArchived: '세션 보관됨',
   toastDeleted: '세션 삭제됨',
   toastNewSession: '새 세션',
+  quickPromptPlaceholder: 'Winter에게 물어보기...',
   shortcutFocusChat: '채팅 포커스',
   smtpPassword: 'Gmail 앱 비밀번호',
   smtpPasswordPlaceholder: 'SMTP용 앱 비밀번호...',
@@ -197,4 +205,86 @@ export const ko: Record<TranslationKey, string> = {
   automationCancel: '취소',
   automationNotInstalled: '미설치',
   automationUnsupported: '미지원',
+  logsTitle: '로그',
+  logsRefresh: '새로고침',
+  logsLoading: '로그 불러오는 중...',
+  logsError: '로그를 불러오지 못했습니다',
+  logsEmpty: '로그가 없습니다',
+  logsAppLevel: '앱 로그 수준',
+  logsApiDebug: 'API 디버그 로그 기록 (내용과 인증 정보는 가려짐)',
+  healthChecking: '확인 중...',
+  healthOk: '모두 정상입니다',
+  healthProblems: '확인이 필요합니다',
+  healthCheck: '다시 확인',
+  healthOff: '꺼짐',
+  healthAuth: '로그인',
+  healthClaude: 'Claude API',
+  healthOllama: 'Ollama',
+  healthOpenCode: 'OpenCode',
+  healthScheduler: '스케줄러',
+  healthServices: '서비스',
+  healthDisk: '디스크 공간',
+  budgetTitle: '사용 예산',
+  budgetUsedToday: '오늘 사용량',
+  budgetUsedWeek: '이번 주 사용량',
+  budgetTokens: '토큰',
+  budgetEnabled: 'API 사용량 제한',
+  budgetPeriod: '기간',
+  budgetDaily: '매일',
+  budgetWeekly: '매주',
+  budgetTokenLimit: '토큰 한도 (0 = 없음)',
+  budgetCostLimit: '비용 한도 USD (0 = 없음)',
+  budgetWarnPercent: '경고 기준 (%)',
+  budgetOnExceed: '한도에 도달하면',
+  budgetExceedConfirm: '보내기 전에 확인',
+  budgetExceedFallback: '더 저렴한 모델로 전환',
+  budgetExceedOllama: 'Ollama로 답변',
+  budgetFallbackModel: '대체 모델',
+  budgetSave: '저장',
+  budgetSaved: '저장됨',
+  budgetConfirmSend: '그래도 보낼까요?',
+  toastBudgetWarning: '사용 예산이 거의 소진되었습니다',
+  toastBudgetExceeded: '사용 예산에 도달했습니다',
+  appBackupTitle: '앱 데이터 백업',
+  appBackupNoPassphrase: '암호화된 "App Data Backup" 작업을 사용하려면 암호를 설정하세요.',
+  appBackupPassphraseSet: '백업은 암호로 암호화됩니다. 복구할 수 없으니 안전하게 보관하세요.',
+  appBackupPassphrase: '새 암호',
+  appBackupSetPassphrase: '설정',
+  appBackupPassphraseSaved: '암호를 저장했습니다',
+  appBackupRestorePath: '백업 파일 (.tar.zst.age)',
+  appBackupRestorePassphrase: '암호 (다른 경우)',
+  appBackupRestore: '복원',
+  appBackupRestoreConfirm: '현재 설정, 대화, 메모리를 이 백업으로 바꿀까요? Winter가 다시 시작됩니다.',
+  appBackupRestored: '복원 완료, 다시 시작합니다',
+  phoenixTitle: 'Phoenix 워치독',
+  phoenixHint: '아래 대상이 멈추면 다시 시작합니다. 여기서 중지한 서비스나 자체 자동 재시작이 있는 서비스는 건너뜁니다.',
+  syncTitle: '동기화',
+  syncEnabled: '대화와 메모리 동기화',
+  syncProvider: '서버 종류',
+  syncWebdavUrl: 'WebDAV 폴더 URL',
+  syncS3Endpoint: 'S3 엔드포인트',
+  syncBucket: '버킷',
+  syncRegion: '리전',
+  syncPrefix: '키 접두사',
+  syncUsername: '사용자 이름',
+  syncPassword: '암호',
+  syncAccessKey: '액세스 키 ID',
+  syncSecretKey: '시크릿 액세스 키',
+  syncInterval: '동기화 간격 (분, 0 = 수동)',
+  syncNow: '지금 동기화',
+  syncSave: '저장',
+  syncSaved: '저장됨',
+  syncDone: '동기화 완료',
+  syncResolved: '충돌을 해결했습니다',
+  syncLast: '마지막 동기화:',
+  syncConversations: '대화',
+  syncMemory: '메모리',
+  syncUpToDate: '최신',
+  syncPushed: '업로드됨',
+  syncPulled: '다운로드됨',
+  syncConflict: '충돌',
+  syncFailed: '실패',
+  syncConflictOn: '두 기기에서 모두 변경됨:',
+  syncKeepLocal: '이 기기 유지',
+  syncKeepRemote: '원격 유지',
 };
\ No newline at end of file
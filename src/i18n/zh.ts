@@ -32,6 +32,9 @@ export const zh: Record<TranslationKey, string> = {
   personalize: '\u4e2a\u6027\u5316',
   advanced: '\u9ad8\u7ea7',
   inputPlaceholder: '\u95ee\u95eeWinter...',
+  modeNormal: '普通',
+  modeSearch: '搜索',
+  modeAnalyze: '分析',
   emptyState: '不一定要堆雪人。',
   shortcutNewSession: '\u65b0\u4f1a\u8bdd',
   shortcutArchive: '\u5f52\u6863\u4f1a\u8bdd',
@@ -52,9 +55,13 @@ export const zh: Record<TranslationKey, string> = {
   feedbackTitle: 'Winter\u600e\u4e48\u6837\uff1f',
   feedbackPlaceholder: '\u544a\u8bc9\u6211\u4eec\u4f60\u7684\u60f3\u6cd5...',
   feedbackSend: '\u53d1\u9001',
+  feedbackAttachCrash: '附上最新的崩溃报告',
+  feedbackIncludeDiagnostics: '附带诊断信息（版本、系统、最近日志）',
+  feedbackOpenIssue: '打开 GitHub Issue',
   feedbackSending: '\u53d1\u9001\u4e2d...',
   feedbackSent: '\u5df2\u53d1\u9001\uff01',
   feedbackError: '\u53d1\u9001\u5931\u8d25\u3002\u8bf7\u68c0\u67e5SMTP\u914d\u7f6e\u3002',
+  feedbackUnavailable: '反馈已关闭或尚未配置发送目标。',
   archiveTitle: '\u5f52\u6863',
   archiveEmpty: '\u8fd8\u6ca1\u6709\u4f1a\u8bdd',
   archiveMessages: '\u6761\u6d88\u606f',
@@ -105,6 +112,7 @@ export const zh: Record<TranslationKey, string> = {
   toastArchived: '会话已归档',
   toastDeleted: '会话已删除',
   toastNewSession: '新会话',
+  quickPromptPlaceholder: '向 Winter 提问...',
   shortcutFocusChat: '聚焦聊天',
   smtpPassword: 'Gmail应用密码',
   smtpPasswordPlaceholder: 'SMTP应用密码...',
@@ -194,4 +202,86 @@ export const zh: Record<TranslationKey, string> = {
   automationCancel: '取消',
   automationNotInstalled: '未安装',
   automationUnsupported: '不支持',
+  logsTitle: '日志',
+  logsRefresh: '刷新',
+  logsLoading: '正在加载日志...',
+  logsError: '加载日志失败',
+  logsEmpty: '暂无日志',
+  logsAppLevel: '应用日志级别',
+  logsApiDebug: '记录 API 调试日志（内容和凭据已隐去）',
+  healthChecking: '检查中...',
+  healthOk: '一切正常',
+  healthProblems: '需要处理',
+  healthCheck: '重新检查',
+  healthOff: '关闭',
+  healthAuth: '登录',
+  healthClaude: 'Claude API',
+  healthOllama: 'Ollama',
+  healthOpenCode: 'OpenCode',
+  healthScheduler: '调度器',
+  healthServices: '服务',
+  healthDisk: '磁盘空间',
+  budgetTitle: '使用预算',
+  budgetUsedToday: '今日用量',
+  budgetUsedWeek: '本周用量',
+  budgetTokens: '令牌',
+  budgetEnabled: '限制 API 花费',
+  budgetPeriod: '周期',
+  budgetDaily: '每天',
+  budgetWeekly: '每周',
+  budgetTokenLimit: '令牌上限（0 = 不限）',
+  budgetCostLimit: '费用上限 USD（0 = 不限）',
+  budgetWarnPercent: '警告阈值（%）',
+  budgetOnExceed: '达到上限时',
+  budgetExceedConfirm: '发送前确认',
+  budgetExceedFallback: '切换到更便宜的模型',
+  budgetExceedOllama: '使用 Ollama 回答',
+  budgetFallbackModel: '备用模型',
+  budgetSave: '保存',
+  budgetSaved: '已保存',
+  budgetConfirmSend: '仍然发送？',
+  toastBudgetWarning: '使用预算即将用完',
+  toastBudgetExceeded: '已达到使用预算',
+  appBackupTitle: '应用数据备份',
+  appBackupNoPassphrase: '设置密码以启用加密的“App Data Backup”任务。',
+  appBackupPassphraseSet: '备份使用你的密码加密。请妥善保管，密码无法找回。',
+  appBackupPassphrase: '新密码',
+  appBackupSetPassphrase: '设置',
+  appBackupPassphraseSaved: '密码已保存',
+  appBackupRestorePath: '备份文件 (.tar.zst.age)',
+  appBackupRestorePassphrase: '密码（如不同）',
+  appBackupRestore: '恢复',
+  appBackupRestoreConfirm: '用此备份替换当前设置、对话和记忆？Winter 将重新启动。',
+  appBackupRestored: '已恢复，正在重启',
+  phoenixTitle: 'Phoenix 看门狗',
+  phoenixHint: '下列目标停止时自动重启。在此停止的服务或已开启自动重启的服务会被跳过。',
+  syncTitle: '同步',
+  syncEnabled: '同步对话和记忆',
+  syncProvider: '服务器类型',
+  syncWebdavUrl: 'WebDAV 文件夹 URL',
+  syncS3Endpoint: 'S3 端点',
+  syncBucket: '存储桶',
+  syncRegion: '区域',
+  syncPrefix: '键前缀',
+  syncUsername: '用户名',
+  syncPassword: '密码',
+  syncAccessKey: '访问密钥 ID',
+  syncSecretKey: '私有访问密钥',
+  syncInterval: '同步间隔（分钟，0 = 手动）',
+  syncNow: '立即同步',
+  syncSave: '保存',
+  syncSaved: '已保存',
+  syncDone: '同步完成',
+  syncResolved: '冲突已解决',
+  syncLast: '上次同步：',
+  syncConversations: '对话',
+  syncMemory: '记忆',
+  syncUpToDate: '已是最新',
+  syncPushed: '已上传',
+  syncPulled: '已下载',
+  syncConflict: '冲突',
+  syncFailed: '失败',
+  syncConflictOn: '两台设备上都有更改：',
+  syncKeepLocal: '保留本设备',
+  syncKeepRemote: '保留远程',
 };
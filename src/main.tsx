@@ -4,6 +4,11 @@ import { I18nProvider } from "./i18n/I18nProvider";
 import { ErrorBoundary } from "./components/ErrorBoundary";
 import { loadDirectory } from "./utils/platform";
 import App from "./App";
+import { QuickPrompt } from "./components/QuickPrompt";
+import "./styles/global.css";
+
+// The hotkey window loads the same bundle with ?window=quick-prompt
+const isQuickPrompt = new URLSearchParams(window.location.search).get("window") === "quick-prompt";
 
 // Pre-load the workspace directory for web mode before rendering
 loadDirectory().then(() => {
@@ -11,7 +16,7 @@ loadDirectory().then(() => {
     <React.StrictMode>
       <ErrorBoundary>
         <I18nProvider>
-          <App />
+          {isQuickPrompt ? <QuickPrompt /> : <App />}
         </I18nProvider>
       </ErrorBoundary>
     </React.StrictMode>,
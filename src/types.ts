@@ -63,7 +63,11 @@ export type ChatStreamEvent =
   | { event: 'ollama_status'; data: { status: string } }
   | { event: 'status'; data: { text: string } }
   | { event: 'usage'; data: { input_tokens: number; output_tokens: number } }
-  | { event: 'reasoning'; data: { text: string } };
+  | { event: 'reasoning'; data: { text: string } }
+  | { event: 'question'; data: { id: string; questions: unknown } }
+  | { event: 'question_resolved'; data: { id: string } }
+  | { event: 'review'; data: { agent: string; text: string } }
+  | { event: 'command_result'; data: { command: string; text: string; is_error: boolean } };
 
 // ── Tool Activity ──
 
@@ -0,0 +1,58 @@
+/**
+ * errors — helpers for errors returned by Tauri commands.
+ *
+ * Commands reject with a structured `AppError` (`{ code, message, retryable }`);
+ * older paths and the web shim may still reject with a plain string or an
+ * `Error`. These helpers accept any of them.
+ */
+
+/** Machine-readable error codes sent by the backend. */
+export type AppErrorCode =
+  | 'auth_expired'
+  | 'not_authenticated'
+  | 'invalid_input'
+  | 'not_found'
+  | 'network'
+  | 'timeout'
+  | 'api'
+  | 'unavailable'
+  | 'budget_exceeded'
+  | 'io'
+  | 'failed';
+
+/** Error payload rejected by Tauri commands. */
+export interface AppError {
+  code: AppErrorCode;
+  message: string;
+  /** Whether the same call may succeed if tried again later. */
+  retryable: boolean;
+}
+
+/**
+ * Returns `true` if `err` is a structured backend error.
+ *
+ * @param err - Value caught from a rejected `invoke` call.
+ */
+export function isAppError(err: unknown): err is AppError {
+  return (
+    typeof err === 'object' &&
+    err !== null &&
+    typeof (err as AppError).code === 'string' &&
+    typeof (err as AppError).message === 'string'
+  );
+}
+
+/**
+ * Human-readable message for any rejected `invoke` value.
+ *
+ * @param err - Value caught from a rejected `invoke` call.
+ * @returns The error message, without an "Error:" prefix.
+ *
+ * @example
+ * catch (e) { setError(errorMessage(e)); }
+ */
+export function errorMessage(err: unknown): string {
+  if (isAppError(err)) return err.message;
+  if (err instanceof Error) return err.message;
+  return String(err);
+}
@@ -230,6 +230,7 @@ const WEB_COMMANDS: Record<string, CommandHandler> = {
   async run_task_now() {},
   async delete_task() {},
   async create_task() {},
+  async has_backup_passphrase() { return false; },
 };
 
 // ── SSE-based streaming (opencode_send replacement) ──────────────────────